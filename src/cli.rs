@@ -1,7 +1,9 @@
+use crate::functions::object::ListStrategy;
 use clap::{Parser, ValueEnum};
+use std::time::Duration;
 
 /// Output format for validation
-#[derive(Debug, Clone, Copy, ValueEnum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 pub enum ValidateFormat {
     /// Validate as JSON
     Json,
@@ -9,6 +11,19 @@ pub enum ValidateFormat {
     Yaml,
     /// Validate as TOML
     Toml,
+    /// Lint the template itself instead of rendering it
+    Template,
+}
+
+/// Input format of a context file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ContextFormat {
+    /// Parse as JSON
+    Json,
+    /// Parse as YAML
+    Yaml,
+    /// Parse as TOML
+    Toml,
 }
 
 /// Output format for IDE metadata
@@ -22,6 +37,64 @@ pub enum IdeFormat {
     Toml,
 }
 
+/// Format used to re-serialize the rendered output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Format as JSON
+    Json,
+    /// Format as YAML
+    Yaml,
+    /// Format as TOML
+    Toml,
+}
+
+/// Line ending used for the rendered output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum LineEnding {
+    /// Unix line endings (\n)
+    Lf,
+    /// Windows line endings (\r\n)
+    Crlf,
+}
+
+/// Comment marker removed by --strip-comments
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum CommentSyntax {
+    /// `#` comments (YAML, TOML, shell, INI, Dockerfile)
+    #[default]
+    Hash,
+    /// `//` comments (JSONC, JavaScript, Go, HCL)
+    Slash,
+    /// `;` comments (INI, Lisp, assembly)
+    Semicolon,
+    /// `--` comments (SQL, Lua, Haskell)
+    Dash,
+}
+
+impl CommentSyntax {
+    /// The marker that starts a comment line
+    pub fn marker(self) -> &'static str {
+        match self {
+            CommentSyntax::Hash => "#",
+            CommentSyntax::Slash => "//",
+            CommentSyntax::Semicolon => ";",
+            CommentSyntax::Dash => "--",
+        }
+    }
+}
+
+/// When to color diagnostics
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum ColorChoice {
+    /// Color when stderr is a terminal and NO_COLOR is not set
+    #[default]
+    Auto,
+    /// Always color
+    Always,
+    /// Never color
+    Never,
+}
+
 /// A template rendering tool that uses Tera templates with environment variables
 #[derive(Parser, Debug)]
 #[command(name = "tmpltool")]
@@ -31,6 +104,7 @@ pub struct Cli {
     pub template: Option<String>,
 
     /// Output file (if not specified, prints to stdout)
+    /// May contain template syntax, e.g. "dist/{{ name }}.yaml"
     #[arg(short, long)]
     pub output: Option<String>,
 
@@ -39,7 +113,8 @@ pub struct Cli {
     #[arg(long)]
     pub trust: bool,
 
-    /// Validate output format (json, yaml, or toml)
+    /// Validate output format (json, yaml, or toml), or lint the template
+    /// without rendering it (template)
     /// If validation fails, the program exits with an error and shows the validation message
     #[arg(long, value_enum)]
     pub validate: Option<ValidateFormat>,
@@ -50,9 +125,173 @@ pub struct Cli {
     #[arg(long, value_enum)]
     pub ide: Option<IdeFormat>,
 
+    /// Only output functions in this category with --ide (e.g., kubernetes)
+    /// Can be specified multiple times: --category kubernetes --category string
+    #[arg(long = "category", value_name = "NAME", requires = "ide")]
+    pub categories: Vec<String>,
+
+    /// Render every function example from the metadata and report failures
+    /// Examples that need variables, files, commands or the network are skipped
+    /// Exits with an error if any example fails
+    #[arg(long)]
+    pub self_test: bool,
+
     /// Load environment variables from .env file(s)
     /// Can be specified multiple times: --env .env --env .env.local
     /// Files are loaded in order, later files override earlier ones
     #[arg(long = "env", value_name = "FILE")]
     pub env_files: Vec<String>,
+
+    /// Load template variables from a JSON, YAML or TOML file ("-" reads stdin)
+    /// Its top-level keys become variables; the format is inferred from the extension
+    /// Can be specified multiple times: later files are deep-merged over earlier ones
+    #[arg(long = "context", value_name = "FILE")]
+    pub context_files: Vec<String>,
+
+    /// Force the format of the --context files instead of inferring it
+    /// from the extension (required when reading the context from stdin)
+    #[arg(long, value_enum, value_name = "FORMAT")]
+    pub context_format: Option<ContextFormat>,
+
+    /// How arrays at the same key are combined when merging --context files:
+    /// replace (default), append, or unique
+    #[arg(long, value_enum, value_name = "STRATEGY", default_value_t = ListStrategy::Replace)]
+    pub context_list_strategy: ListStrategy,
+
+    /// Expose all environment variables as an object under this variable
+    /// name (e.g. --env-key env gives {{ env.HOME }})
+    #[arg(long, value_name = "NAME")]
+    pub env_key: Option<String>,
+
+    /// Expose all environment variables as top-level variables
+    /// (--context values take precedence over them)
+    #[arg(long)]
+    pub env_top_level: bool,
+
+    /// Add a directory to search for {% include %} / {% import %} templates
+    /// Can be specified multiple times; directories are searched in order
+    /// after the template's own directory
+    #[arg(long = "include-path", value_name = "DIR")]
+    pub include_paths: Vec<String>,
+
+    /// Preload all templates in a directory so their macros can be imported
+    /// by name (e.g. {% import "forms" as forms %}); a `_globals` template
+    /// in it is imported automatically into the main template
+    #[arg(long, value_name = "DIR")]
+    pub partials_dir: Option<String>,
+
+    /// Base directory for filesystem functions: relative paths are resolved
+    /// against it and, without --trust, access is limited to it
+    /// (default: the template's directory, or the current directory for stdin)
+    #[arg(long, value_name = "DIR")]
+    pub base_dir: Option<String>,
+
+    /// Verbose mode: log rendering phases with elapsed times to stderr
+    #[arg(short, long)]
+    pub verbose: bool,
+
+    /// Profile mode: count calls and time spent in each function,
+    /// printing a summary sorted by total time to stderr after rendering
+    #[arg(long)]
+    pub profile: bool,
+
+    /// Maximum output size in bytes
+    /// Rendering aborts with an error once the output exceeds this limit
+    #[arg(long, value_name = "BYTES")]
+    pub max_output_size: Option<u64>,
+
+    /// Render timeout in seconds
+    /// Aborts with an error if rendering takes longer (best-effort watchdog)
+    #[arg(long, value_name = "SECONDS", value_parser = parse_timeout)]
+    pub timeout: Option<Duration>,
+
+    /// Exit with an error if any warnings were emitted during rendering
+    /// (e.g., via warn()), even when the render otherwise succeeded
+    #[arg(long)]
+    pub fail_on_warn: bool,
+
+    /// Fail before rendering if the template uses a function, filter or
+    /// test that does not exist, even in branches that would not run
+    #[arg(long)]
+    pub strict_parse: bool,
+
+    /// Convert all line endings of the output to lf or crlf
+    /// (default: keep them as rendered)
+    #[arg(long, value_enum, value_name = "ENDING")]
+    pub line_ending: Option<LineEnding>,
+
+    /// Prepend a UTF-8 byte order mark to the output
+    #[arg(long)]
+    pub bom: bool,
+
+    /// Parse the rendered output as json, yaml or toml and re-serialize it
+    /// with consistent indentation and sorted keys
+    #[arg(long, value_enum, value_name = "FORMAT")]
+    pub format_output: Option<OutputFormat>,
+
+    /// Remove comment lines from the rendered output (see --comment-syntax)
+    #[arg(long)]
+    pub strip_comments: bool,
+
+    /// Comment marker removed by --strip-comments: hash (#), slash (//),
+    /// semicolon (;) or dash (--)
+    #[arg(long, value_enum, value_name = "SYNTAX", default_value_t = CommentSyntax::Hash, requires = "strip_comments")]
+    pub comment_syntax: CommentSyntax,
+
+    /// Check mode: render and compare with the existing --output file
+    /// without writing it; exits with an error and prints a diff if they differ
+    #[arg(long, requires = "output")]
+    pub check: bool,
+
+    /// Write a JSON manifest of the generated output (path, size in bytes,
+    /// SHA-256) to FILE after a successful render
+    #[arg(long, value_name = "FILE")]
+    pub manifest: Option<String>,
+
+    /// Write the assembled template variables (environment, --context files,
+    /// --env-key, args) as JSON to FILE (`-` for stdout) and exit without rendering
+    #[arg(long, value_name = "FILE")]
+    pub dump_context: Option<String>,
+
+    /// Interactive mode: prompt() asks for values that are not set
+    /// in the environment on stdin instead of failing
+    #[arg(long)]
+    pub interactive: bool,
+
+    /// Color error messages: auto (default, when stderr is a terminal and
+    /// NO_COLOR is not set), always, or never
+    #[arg(long, value_enum, value_name = "WHEN", default_value_t = ColorChoice::Auto)]
+    pub color: ColorChoice,
+
+    /// Disable colored error messages (same as --color never)
+    #[arg(long, conflicts_with = "color")]
+    pub no_color: bool,
+
+    /// Extra arguments after `--`, exposed to the template as the `args` list
+    /// (e.g. `tmpltool gen.tmpltool -- users orders`)
+    #[arg(last = true, value_name = "ARGS")]
+    pub args: Vec<String>,
+}
+
+impl Cli {
+    /// Get the effective color choice, taking --no-color into account
+    pub fn color_choice(&self) -> ColorChoice {
+        if self.no_color {
+            ColorChoice::Never
+        } else {
+            self.color
+        }
+    }
+}
+
+/// Parse and validate the --timeout value (must be a positive number of seconds)
+fn parse_timeout(value: &str) -> Result<Duration, String> {
+    let seconds: f64 = value
+        .parse()
+        .map_err(|_| format!("'{}' is not a number of seconds", value))?;
+    if !seconds.is_finite() || seconds <= 0.0 {
+        return Err("timeout must be a positive number of seconds".to_string());
+    }
+    Duration::try_from_secs_f64(seconds)
+        .map_err(|_| format!("timeout of {} seconds is too large", value))
 }
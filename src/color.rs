@@ -0,0 +1,73 @@
+//! Colored diagnostics for `--color`
+//!
+//! Error messages printed to stderr can be highlighted with ANSI colors: the
+//! `Error:` label, template locations, quoted names, and caret lines that
+//! point at the failing code. Coloring is decided once from the `--color`
+//! choice, the `NO_COLOR` environment variable, and whether stderr is a TTY.
+
+use crate::cli::ColorChoice;
+use regex::Regex;
+use std::io::IsTerminal;
+use std::sync::LazyLock;
+
+const RESET: &str = "\x1b[0m";
+const BOLD_RED: &str = "\x1b[1;31m";
+const CYAN: &str = "\x1b[36m";
+const YELLOW: &str = "\x1b[33m";
+
+/// Template locations such as `(in template.txt:3)`
+static LOCATION: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\(in [^()]+:\d+\)").unwrap());
+
+/// Single-quoted or backticked names such as `'foo'`
+static QUOTED: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"'[^'\s]+'|`[^`]+`").unwrap());
+
+/// Decide whether diagnostics on stderr should be colored
+///
+/// `always` and `never` are honored as-is. `auto` colors only when stderr is
+/// a terminal and `NO_COLOR` is unset or empty.
+pub fn use_color(choice: ColorChoice) -> bool {
+    match choice {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => {
+            let no_color = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+            !no_color && std::io::stderr().is_terminal()
+        }
+    }
+}
+
+/// Highlight an error message for terminal output
+///
+/// Returns the message unchanged when `enabled` is false.
+pub fn paint_error(message: &str, enabled: bool) -> String {
+    if !enabled {
+        return message.to_string();
+    }
+
+    message
+        .split('\n')
+        .map(paint_line)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn paint_line(line: &str) -> String {
+    let trimmed = line.trim();
+    if !trimmed.is_empty() && trimmed.chars().all(|c| matches!(c, '^' | '~' | '-')) {
+        return format!("{}{}{}", BOLD_RED, line, RESET);
+    }
+
+    let (label, rest) = match line.strip_prefix("Error:") {
+        Some(rest) => (format!("{}Error:{}", BOLD_RED, RESET), rest),
+        None => (String::new(), line),
+    };
+
+    let rest = QUOTED.replace_all(rest, |caps: &regex::Captures| {
+        format!("{}{}{}", YELLOW, &caps[0], RESET)
+    });
+    let rest = LOCATION.replace_all(&rest, |caps: &regex::Captures| {
+        format!("{}{}{}", CYAN, &caps[0], RESET)
+    });
+
+    format!("{}{}", label, rest)
+}
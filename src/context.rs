@@ -2,8 +2,10 @@
 ///
 /// This context provides information about the template execution environment,
 /// such as the base directory for resolving relative file paths.
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 
 /// Global context shared across all template functions
 ///
@@ -17,6 +19,27 @@ pub struct TemplateContext {
 
     /// Trust mode: if true, disables filesystem security restrictions
     trust_mode: bool,
+
+    /// Interactive mode: if true, `prompt()` may ask for missing values on stdin
+    interactive: bool,
+
+    /// Number of warnings emitted during this render (shared by all clones)
+    warnings: Arc<AtomicUsize>,
+
+    /// Named counters for `counter()` (shared by all clones)
+    counters: Arc<Mutex<HashMap<String, i64>>>,
+
+    /// Scratchpad for `set_var()` / `get_var()` (shared by all clones)
+    vars: Arc<Mutex<HashMap<String, minijinja::Value>>>,
+
+    /// Keys already seen by `once()` (shared by all clones)
+    once_keys: Arc<Mutex<HashSet<String>>>,
+
+    /// Values entered for `prompt()`, so each name is asked once (shared by all clones)
+    answers: Arc<Mutex<HashMap<String, String>>>,
+
+    /// Results of memoized function calls, keyed by name and arguments (shared by all clones)
+    memoized: Arc<Mutex<HashMap<String, minijinja::Value>>>,
 }
 
 impl TemplateContext {
@@ -25,6 +48,13 @@ impl TemplateContext {
         Self {
             base_dir: Arc::new(base_dir),
             trust_mode,
+            interactive: false,
+            warnings: Arc::new(AtomicUsize::new(0)),
+            counters: Arc::new(Mutex::new(HashMap::new())),
+            vars: Arc::new(Mutex::new(HashMap::new())),
+            once_keys: Arc::new(Mutex::new(HashSet::new())),
+            answers: Arc::new(Mutex::new(HashMap::new())),
+            memoized: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
@@ -49,6 +79,23 @@ impl TemplateContext {
         Ok(Self::new(cwd, trust_mode))
     }
 
+    /// Create a copy of this context with a different base directory
+    ///
+    /// The copy shares all per-render state (data cache, warnings, counters,
+    /// variables) with the original.
+    pub fn with_base_dir(&self, base_dir: PathBuf) -> Self {
+        Self {
+            base_dir: Arc::new(base_dir),
+            ..self.clone()
+        }
+    }
+
+    /// Enable or disable interactive mode (asking for missing values on stdin)
+    pub fn with_interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+
     /// Get the base directory for file operations
     pub fn base_dir(&self) -> &Path {
         &self.base_dir
@@ -59,6 +106,11 @@ impl TemplateContext {
         self.trust_mode
     }
 
+    /// Check if interactive mode is enabled
+    pub fn is_interactive(&self) -> bool {
+        self.interactive
+    }
+
     /// Resolve a relative path against the base directory
     ///
     /// # Arguments
@@ -79,4 +131,70 @@ impl TemplateContext {
             self.base_dir.join(path)
         }
     }
+
+    /// Record that a warning was emitted during this render
+    pub fn record_warning(&self) {
+        self.warnings.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Get the number of warnings emitted so far
+    pub fn warning_count(&self) -> usize {
+        self.warnings.load(Ordering::Relaxed)
+    }
+
+    /// Advance a named counter and return its new value
+    ///
+    /// The first call for a name returns `start`; each later call returns
+    /// the previous value plus one.
+    pub fn next_counter(&self, name: &str, start: i64) -> i64 {
+        let mut counters = self.counters.lock().unwrap_or_else(|e| e.into_inner());
+        let value = match counters.get(name) {
+            Some(previous) => previous.saturating_add(1),
+            None => start,
+        };
+        counters.insert(name.to_string(), value);
+        value
+    }
+
+    /// Store a value in the per-render scratchpad
+    pub fn set_var(&self, key: &str, value: minijinja::Value) {
+        let mut vars = self.vars.lock().unwrap_or_else(|e| e.into_inner());
+        vars.insert(key.to_string(), value);
+    }
+
+    /// Get a value from the per-render scratchpad
+    pub fn get_var(&self, key: &str) -> Option<minijinja::Value> {
+        let vars = self.vars.lock().unwrap_or_else(|e| e.into_inner());
+        vars.get(key).cloned()
+    }
+
+    /// Mark a key as seen, returning true only the first time it is marked
+    pub fn mark_once(&self, key: &str) -> bool {
+        let mut keys = self.once_keys.lock().unwrap_or_else(|e| e.into_inner());
+        keys.insert(key.to_string())
+    }
+
+    /// Get the value previously entered for a prompt
+    pub fn get_answer(&self, name: &str) -> Option<String> {
+        let answers = self.answers.lock().unwrap_or_else(|e| e.into_inner());
+        answers.get(name).cloned()
+    }
+
+    /// Remember the value entered for a prompt
+    pub fn record_answer(&self, name: &str, value: &str) {
+        let mut answers = self.answers.lock().unwrap_or_else(|e| e.into_inner());
+        answers.insert(name.to_string(), value.to_string());
+    }
+
+    /// Get the stored result of a memoized function call
+    pub fn get_memoized(&self, key: &str) -> Option<minijinja::Value> {
+        let memoized = self.memoized.lock().unwrap_or_else(|e| e.into_inner());
+        memoized.get(key).cloned()
+    }
+
+    /// Store the result of a memoized function call
+    pub fn store_memoized(&self, key: &str, value: minijinja::Value) {
+        let mut memoized = self.memoized.lock().unwrap_or_else(|e| e.into_inner());
+        memoized.insert(key.to_string(), value);
+    }
 }
@@ -0,0 +1,154 @@
+//! Template variables loaded from context files (`--context`)
+//!
+//! Each file is parsed as JSON, YAML or TOML, and its top-level keys become
+//! template variables. The format is inferred from the file extension unless
+//! it is forced with `--context-format`, which is required when the context
+//! is read from stdin (`--context -`).
+//!
+//! When several files are given they are layered in order: each file is
+//! deep-merged over the previous ones with the same logic as `object_merge`.
+
+use crate::cli::ContextFormat;
+use crate::functions::object::{ListStrategy, merge_json_values};
+use serde_json::{Map, Value};
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+
+/// Path that reads the context from stdin
+pub const STDIN: &str = "-";
+
+/// Infer the context format from a file extension
+///
+/// # Example
+///
+/// ```
+/// use tmpltool::cli::ContextFormat;
+/// use tmpltool::context_file::infer_format;
+///
+/// assert_eq!(infer_format("values.yml"), Some(ContextFormat::Yaml));
+/// assert_eq!(infer_format("values.conf"), None);
+/// ```
+pub fn infer_format(path: &str) -> Option<ContextFormat> {
+    let extension = Path::new(path).extension()?.to_str()?.to_lowercase();
+    match extension.as_str() {
+        "json" => Some(ContextFormat::Json),
+        "yaml" | "yml" => Some(ContextFormat::Yaml),
+        "toml" => Some(ContextFormat::Toml),
+        _ => None,
+    }
+}
+
+/// Parse context data in the given format
+///
+/// # Returns
+///
+/// Returns the top-level object, or an error if the content does not parse
+/// or is not an object
+pub fn parse_context(
+    content: &str,
+    format: ContextFormat,
+    name: &str,
+) -> Result<Map<String, Value>, String> {
+    let parsed: Result<Value, String> = match format {
+        ContextFormat::Json => serde_json::from_str(content).map_err(|e| e.to_string()),
+        ContextFormat::Yaml => serde_yaml::from_str(content).map_err(|e| e.to_string()),
+        ContextFormat::Toml => toml::from_str(content).map_err(|e| e.to_string()),
+    };
+    let parsed = parsed.map_err(|e| {
+        format!(
+            "Failed to parse context '{}' as {}: {}",
+            name,
+            format_name(format),
+            e
+        )
+    })?;
+
+    match parsed {
+        Value::Object(map) => Ok(map),
+        // An empty YAML document is null
+        Value::Null => Ok(Map::new()),
+        _ => Err(format!(
+            "Context '{}' must contain an object at the top level",
+            name
+        )),
+    }
+}
+
+/// Read and parse a context file, or stdin when `path` is `-`
+///
+/// # Arguments
+///
+/// * `path` - Path to the context file, or `-` for stdin
+/// * `format` - Forced format; inferred from the extension when `None`
+pub fn load_context(
+    path: &str,
+    format: Option<ContextFormat>,
+) -> Result<Map<String, Value>, String> {
+    let format = match format.or_else(|| infer_format(path)) {
+        Some(format) => format,
+        None if path == STDIN => {
+            return Err(
+                "Cannot infer the format of a context read from stdin; pass --context-format json|yaml|toml"
+                    .to_string(),
+            );
+        }
+        None => {
+            return Err(format!(
+                "Cannot infer the format of context file '{}' from its extension; pass --context-format json|yaml|toml",
+                path
+            ));
+        }
+    };
+
+    let content = if path == STDIN {
+        let mut buffer = String::new();
+        io::stdin()
+            .read_to_string(&mut buffer)
+            .map_err(|e| format!("Failed to read context from stdin: {}", e))?;
+        buffer
+    } else {
+        fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read context file '{}': {}", path, e))?
+    };
+
+    let name = if path == STDIN { "stdin" } else { path };
+    parse_context(&content, format, name)
+}
+
+/// Load context files in order, deep-merging each over the previous ones
+///
+/// # Arguments
+///
+/// * `paths` - Context files, `-` for stdin (at most once)
+/// * `format` - Forced format for every file; inferred per file when `None`
+/// * `list_strategy` - How arrays at the same key are combined
+pub fn load_contexts(
+    paths: &[String],
+    format: Option<ContextFormat>,
+    list_strategy: ListStrategy,
+) -> Result<Map<String, Value>, String> {
+    if paths.iter().filter(|path| *path == STDIN).count() > 1 {
+        return Err("The context can be read from stdin (--context -) only once".to_string());
+    }
+
+    let mut merged = Map::new();
+    for path in paths {
+        for (key, value) in load_context(path, format)? {
+            let value = match merged.remove(&key) {
+                Some(base) => merge_json_values(base, value, list_strategy),
+                None => value,
+            };
+            merged.insert(key, value);
+        }
+    }
+    Ok(merged)
+}
+
+fn format_name(format: ContextFormat) -> &'static str {
+    match format {
+        ContextFormat::Json => "JSON",
+        ContextFormat::Yaml => "YAML",
+        ContextFormat::Toml => "TOML",
+    }
+}
@@ -341,6 +341,413 @@ impl FilterFunction for ArrayMax {
     }
 }
 
+/// Helper to collect numeric values, erroring on empty input
+fn collect_numbers(array: &Value, fn_name: &str) -> Result<Vec<f64>, Error> {
+    let mut numbers: Vec<f64> = Vec::new();
+
+    if let Ok(seq) = array.try_iter() {
+        for item in seq {
+            numbers.push(value_to_f64(&item, fn_name)?);
+        }
+    }
+
+    if numbers.is_empty() {
+        return Err(Error::new(
+            ErrorKind::InvalidOperation,
+            format!("{} requires a non-empty array", fn_name),
+        ));
+    }
+
+    Ok(numbers)
+}
+
+/// Helper to compute population (or sample) variance
+fn variance(numbers: &[f64], sample: bool, fn_name: &str) -> Result<f64, Error> {
+    let n = numbers.len();
+    if sample && n < 2 {
+        return Err(Error::new(
+            ErrorKind::InvalidOperation,
+            format!("{} with sample=true requires at least 2 values", fn_name),
+        ));
+    }
+
+    let mean = numbers.iter().sum::<f64>() / n as f64;
+    let squared: f64 = numbers.iter().map(|x| (x - mean).powi(2)).sum();
+    let divisor = if sample { n - 1 } else { n };
+
+    Ok(squared / divisor as f64)
+}
+
+/// Metadata for the `sample` argument of variance/stddev
+const SAMPLE_ARG: ArgumentMetadata = ArgumentMetadata {
+    name: "sample",
+    arg_type: "boolean",
+    required: false,
+    default: Some("false"),
+    description: "Use sample statistics (divide by n-1) instead of population (divide by n)",
+};
+
+// ============================================
+// ArrayVariance
+// ============================================
+
+/// Calculate variance of array values.
+pub struct ArrayVariance;
+
+impl ArrayVariance {
+    fn compute(array: &Value, kwargs: &Kwargs) -> Result<Value, Error> {
+        let sample: bool = kwargs.get::<Option<bool>>("sample")?.unwrap_or(false);
+        let numbers = collect_numbers(array, "array_variance")?;
+        Ok(Value::from(variance(&numbers, sample, "array_variance")?))
+    }
+}
+
+impl FilterFunction for ArrayVariance {
+    const NAME: &'static str = "array_variance";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "array_variance",
+        category: "array",
+        description: "Calculate variance of array values",
+        arguments: &[ARRAY_ARG, SAMPLE_ARG],
+        return_type: "float",
+        examples: &[
+            "{{ array_variance(array=numbers) }}",
+            "{{ latencies | array_variance(sample=true) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let array: Value = kwargs.get("array")?;
+        extract_array(&array, "array_variance")?;
+        Self::compute(&array, &kwargs)
+    }
+
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        extract_array(value, "array_variance")?;
+        Self::compute(value, &kwargs)
+    }
+}
+
+// ============================================
+// ArrayStddev
+// ============================================
+
+/// Calculate standard deviation of array values.
+pub struct ArrayStddev;
+
+impl ArrayStddev {
+    fn compute(array: &Value, kwargs: &Kwargs) -> Result<Value, Error> {
+        let sample: bool = kwargs.get::<Option<bool>>("sample")?.unwrap_or(false);
+        let numbers = collect_numbers(array, "array_stddev")?;
+        Ok(Value::from(
+            variance(&numbers, sample, "array_stddev")?.sqrt(),
+        ))
+    }
+}
+
+impl FilterFunction for ArrayStddev {
+    const NAME: &'static str = "array_stddev";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "array_stddev",
+        category: "array",
+        description: "Calculate standard deviation of array values",
+        arguments: &[ARRAY_ARG, SAMPLE_ARG],
+        return_type: "float",
+        examples: &[
+            "{{ array_stddev(array=numbers) }}",
+            "{{ response_times | array_stddev | round(2) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let array: Value = kwargs.get("array")?;
+        extract_array(&array, "array_stddev")?;
+        Self::compute(&array, &kwargs)
+    }
+
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        extract_array(value, "array_stddev")?;
+        Self::compute(value, &kwargs)
+    }
+}
+
+// ============================================
+// ArrayPercentile
+// ============================================
+
+/// Calculate a percentile of array values using linear interpolation.
+pub struct ArrayPercentile;
+
+impl ArrayPercentile {
+    fn compute(array: &Value, kwargs: &Kwargs) -> Result<Value, Error> {
+        let p: f64 = kwargs.get("p")?;
+        if !(0.0..=100.0).contains(&p) {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                format!("array_percentile p must be between 0 and 100, got {}", p),
+            ));
+        }
+
+        let mut numbers = collect_numbers(array, "array_percentile")?;
+        numbers.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        // Linear interpolation between closest ranks
+        let rank = p / 100.0 * (numbers.len() - 1) as f64;
+        let lower = rank.floor() as usize;
+        let upper = rank.ceil() as usize;
+        let fraction = rank - lower as f64;
+        let result = numbers[lower] + (numbers[upper] - numbers[lower]) * fraction;
+
+        Ok(Value::from(result))
+    }
+}
+
+impl FilterFunction for ArrayPercentile {
+    const NAME: &'static str = "array_percentile";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "array_percentile",
+        category: "array",
+        description: "Calculate a percentile of array values using linear interpolation",
+        arguments: &[
+            ARRAY_ARG,
+            ArgumentMetadata {
+                name: "p",
+                arg_type: "number",
+                required: true,
+                default: None,
+                description: "Percentile to calculate (0-100)",
+            },
+        ],
+        return_type: "float",
+        examples: &[
+            "{{ array_percentile(array=latencies, p=95) }}",
+            "{{ latencies | array_percentile(p=99) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+    const POSITIONAL_ARG: Option<&'static str> = Some("p");
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let array: Value = kwargs.get("array")?;
+        extract_array(&array, "array_percentile")?;
+        Self::compute(&array, &kwargs)
+    }
+
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        extract_array(value, "array_percentile")?;
+        Self::compute(value, &kwargs)
+    }
+}
+
+// ============================================
+// ArrayStats
+// ============================================
+
+/// Summary statistics of array values in one call.
+pub struct ArrayStats;
+
+impl ArrayStats {
+    fn compute(array: &Value, kwargs: &Kwargs) -> Result<Value, Error> {
+        let sample: bool = kwargs.get::<Option<bool>>("sample")?.unwrap_or(false);
+
+        // Count, sum, min, max and running mean/variance (Welford) in a single pass
+        let mut numbers: Vec<f64> = Vec::new();
+        let (mut sum, mut mean, mut m2) = (0.0_f64, 0.0_f64, 0.0_f64);
+        let (mut min, mut max) = (f64::INFINITY, f64::NEG_INFINITY);
+        if let Ok(seq) = array.try_iter() {
+            for item in seq {
+                let x = value_to_f64(&item, "array_stats")?;
+                numbers.push(x);
+                sum += x;
+                min = min.min(x);
+                max = max.max(x);
+                let delta = x - mean;
+                mean += delta / numbers.len() as f64;
+                m2 += delta * (x - mean);
+            }
+        }
+
+        let count = numbers.len();
+        if count == 0 {
+            return Ok(Value::from_iter([
+                ("count", Value::from(0)),
+                ("sum", Value::from(0)),
+                ("min", Value::from(())),
+                ("max", Value::from(())),
+                ("mean", Value::from(())),
+                ("median", Value::from(())),
+                ("stddev", Value::from(())),
+            ]));
+        }
+        if sample && count < 2 {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                "array_stats with sample=true requires at least 2 values",
+            ));
+        }
+
+        numbers.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let median = if count.is_multiple_of(2) {
+            (numbers[count / 2 - 1] + numbers[count / 2]) / 2.0
+        } else {
+            numbers[count / 2]
+        };
+        let divisor = if sample { count - 1 } else { count };
+
+        Ok(Value::from_iter([
+            ("count", Value::from(count)),
+            ("sum", format_number(sum)),
+            ("min", format_number(min)),
+            ("max", format_number(max)),
+            ("mean", format_number(mean)),
+            ("median", format_number(median)),
+            ("stddev", Value::from((m2 / divisor as f64).sqrt())),
+        ]))
+    }
+}
+
+impl FilterFunction for ArrayStats {
+    const NAME: &'static str = "array_stats";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "array_stats",
+        category: "array",
+        description: "Summary statistics of array values: count, sum, min, max, mean, median and stddev",
+        arguments: &[ARRAY_ARG, SAMPLE_ARG],
+        return_type: "object",
+        examples: &[
+            "{{ array_stats(array=numbers) }}",
+            "{% set s = latencies | array_stats %}{{ s.mean }} ± {{ s.stddev | round(2) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let array: Value = kwargs.get("array")?;
+        extract_array(&array, "array_stats")?;
+        Self::compute(&array, &kwargs)
+    }
+
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        extract_array(value, "array_stats")?;
+        Self::compute(value, &kwargs)
+    }
+}
+
+// ============================================
+// ArrayScan / ArrayRunningTotal
+// ============================================
+
+/// Operations supported by `array_scan`
+const SCAN_OPS: &[&str] = &["sum", "product", "max", "min"];
+
+/// Running results of an associative operation over a numeric array
+fn scan(array: &Value, op: &str, fn_name: &str) -> Result<Value, Error> {
+    let combine: fn(f64, f64) -> f64 = match op {
+        "sum" => |acc, x| acc + x,
+        "product" => |acc, x| acc * x,
+        "max" => f64::max,
+        "min" => f64::min,
+        other => {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                format!(
+                    "{}: invalid op '{}'. Use: {}",
+                    fn_name,
+                    other,
+                    SCAN_OPS.join(", ")
+                ),
+            ));
+        }
+    };
+
+    let mut results = Vec::new();
+    let mut acc: Option<f64> = None;
+    for item in array.try_iter()? {
+        let number = value_to_f64(&item, fn_name)?;
+        let next = match acc {
+            Some(acc) => combine(acc, number),
+            None => number,
+        };
+        acc = Some(next);
+        results.push(format_number(next));
+    }
+    Ok(Value::from(results))
+}
+
+/// Cumulative sums of array values.
+pub struct ArrayRunningTotal;
+
+impl FilterFunction for ArrayRunningTotal {
+    const NAME: &'static str = "array_running_total";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "array_running_total",
+        category: "array",
+        description: "Cumulative sums of array values (same as array_scan with op=\"sum\")",
+        arguments: &[ARRAY_ARG],
+        return_type: "array",
+        examples: &[
+            "{{ array_running_total(array=[1, 2, 3, 4]) }}",
+            "{{ allocations | map(attribute=\"size\") | list | array_running_total }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let array: Value = kwargs.get("array")?;
+        extract_array(&array, "array_running_total")?;
+        scan(&array, "sum", "array_running_total")
+    }
+
+    fn call_as_filter(value: &Value, _kwargs: Kwargs) -> Result<Value, Error> {
+        extract_array(value, "array_running_total")?;
+        scan(value, "sum", "array_running_total")
+    }
+}
+
+/// Running results of sum, product, max or min over array values.
+pub struct ArrayScan;
+
+impl FilterFunction for ArrayScan {
+    const NAME: &'static str = "array_scan";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "array_scan",
+        category: "array",
+        description: "Running results of an operation (sum, product, max, min) over array values",
+        arguments: &[
+            ARRAY_ARG,
+            ArgumentMetadata {
+                name: "op",
+                arg_type: "string",
+                required: false,
+                default: Some("sum"),
+                description: "Operation: sum, product, max or min",
+            },
+        ],
+        return_type: "array",
+        examples: &[
+            "{{ array_scan(array=[3, 1, 4, 1, 5], op=\"max\") }}",
+            "{{ [1, 2, 3, 4] | array_scan(op=\"product\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+    const POSITIONAL_ARG: Option<&'static str> = Some("op");
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let array: Value = kwargs.get("array")?;
+        let op: Option<String> = kwargs.get("op")?;
+        extract_array(&array, "array_scan")?;
+        scan(&array, op.as_deref().unwrap_or("sum"), "array_scan")
+    }
+
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        let op: Option<String> = kwargs.get("op")?;
+        extract_array(value, "array_scan")?;
+        scan(value, op.as_deref().unwrap_or("sum"), "array_scan")
+    }
+}
+
 // ============================================
 // ArrayUnique
 // ============================================
@@ -464,3 +871,100 @@ impl FilterFunction for ArrayFlatten {
         Self::compute(value)
     }
 }
+
+// ============================================
+// FilterGlob
+// ============================================
+
+/// Keep the strings of an array that match a glob pattern.
+///
+/// `*` and `?` do not cross `/`; use `**` to match across directories.
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ filter_glob(array=list_dir(path="config"), pattern="*.yaml") }}
+/// ```
+///
+/// # Filter Syntax
+/// ```jinja
+/// {{ files | filter_glob(pattern="*.bak", invert=true) }}
+/// ```
+pub struct FilterGlob;
+
+impl FilterGlob {
+    fn compute(array: &Value, pattern: &str, invert: bool) -> Result<Value, Error> {
+        let glob = glob::Pattern::new(pattern).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("filter_glob: invalid pattern '{}': {}", pattern, e),
+            )
+        })?;
+        let options = glob::MatchOptions {
+            require_literal_separator: true,
+            ..Default::default()
+        };
+
+        let mut matched = Vec::new();
+        for item in array.try_iter()? {
+            let s = item.as_str().ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!("filter_glob requires an array of strings, found: {}", item),
+                )
+            })?;
+            if glob.matches_with(s, options) != invert {
+                matched.push(item.clone());
+            }
+        }
+
+        Ok(Value::from(matched))
+    }
+}
+
+impl FilterFunction for FilterGlob {
+    const NAME: &'static str = "filter_glob";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "filter_glob",
+        category: "array",
+        description: "Keep the strings of an array that match a glob pattern",
+        arguments: &[
+            ARRAY_ARG,
+            ArgumentMetadata {
+                name: "pattern",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Glob pattern (`*`, `?`, `[abc]`, `**` for any number of directories)",
+            },
+            ArgumentMetadata {
+                name: "invert",
+                arg_type: "boolean",
+                required: false,
+                default: Some("false"),
+                description: "Keep the elements that do NOT match instead",
+            },
+        ],
+        return_type: "array",
+        examples: &[
+            "{{ filter_glob(array=list_dir(path=\"config\"), pattern=\"*.yaml\") }}",
+            "{{ files | filter_glob(pattern=\"*.bak\", invert=true) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+    const POSITIONAL_ARG: Option<&'static str> = Some("pattern");
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let array: Value = kwargs.get("array")?;
+        let pattern: String = kwargs.get("pattern")?;
+        let invert: Option<bool> = kwargs.get("invert")?;
+        extract_array(&array, "filter_glob")?;
+        Self::compute(&array, &pattern, invert.unwrap_or(false))
+    }
+
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        let pattern: String = kwargs.get("pattern")?;
+        let invert: Option<bool> = kwargs.get("invert")?;
+        extract_array(value, "filter_glob")?;
+        Self::compute(value, &pattern, invert.unwrap_or(false))
+    }
+}
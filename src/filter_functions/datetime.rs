@@ -4,12 +4,14 @@
 //! ```jinja
 //! {{ format_date(timestamp=ts, format="%Y-%m-%d") }}
 //! {{ get_year(timestamp=ts) }}
+//! {{ day_of_week(timestamp=ts, name=true) }}
 //! ```
 //!
 //! # Filter Syntax
 //! ```jinja
 //! {{ ts | format_date(format="%Y-%m-%d") }}
 //! {{ ts | get_year }}
+//! {{ ts | quarter }}
 //! ```
 //!
 //! # Chaining
@@ -114,6 +116,7 @@ impl FilterFunction for FormatDate {
         ],
         syntax: SyntaxVariants::FUNCTION_AND_FILTER,
     };
+    const POSITIONAL_ARG: Option<&'static str> = Some("format");
 
     fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
         let timestamp: i64 = kwargs.get("timestamp")?;
@@ -437,3 +440,316 @@ impl FilterFunction for GetSecond {
         Self::compute(timestamp)
     }
 }
+
+// ============================================
+// DayOfWeek
+// ============================================
+
+/// Extract the day of the week from a Unix timestamp (0 = Monday ... 6 = Sunday).
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ day_of_week(timestamp=1704067200) }}
+/// {{ day_of_week(timestamp=1704067200, name=true) }}
+/// ```
+///
+/// # Filter Syntax
+/// ```jinja
+/// {{ 1704067200 | day_of_week }}
+/// {{ now() | day_of_week(name=true) }}
+/// ```
+pub struct DayOfWeek;
+
+impl DayOfWeek {
+    fn compute(timestamp: i64, name: bool) -> Result<Value, Error> {
+        let dt = timestamp_to_datetime(timestamp, "day_of_week")?;
+        if name {
+            Ok(Value::from(dt.format("%A").to_string()))
+        } else {
+            Ok(Value::from(dt.weekday().num_days_from_monday()))
+        }
+    }
+}
+
+impl FilterFunction for DayOfWeek {
+    const NAME: &'static str = "day_of_week";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "day_of_week",
+        category: "datetime",
+        description: "Extract the day of the week from a Unix timestamp (0 = Monday ... 6 = Sunday, or the English name)",
+        arguments: &[
+            TIMESTAMP_ARG,
+            ArgumentMetadata {
+                name: "name",
+                arg_type: "boolean",
+                required: false,
+                default: Some("false"),
+                description: "Return the day name (e.g., \"Monday\") instead of a number",
+            },
+        ],
+        return_type: "integer|string",
+        examples: &[
+            "{{ day_of_week(timestamp=1704067200) }}",
+            "{{ now() | day_of_week(name=true) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let timestamp: i64 = kwargs.get("timestamp")?;
+        let name: Option<bool> = kwargs.get("name")?;
+        Self::compute(timestamp, name.unwrap_or(false))
+    }
+
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        let timestamp = extract_timestamp(value, "day_of_week")?;
+        let name: Option<bool> = kwargs.get("name")?;
+        Self::compute(timestamp, name.unwrap_or(false))
+    }
+}
+
+// ============================================
+// WeekOfYear
+// ============================================
+
+/// Extract the ISO 8601 week number from a Unix timestamp (1-53).
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ week_of_year(timestamp=1704067200) }}
+/// ```
+///
+/// # Filter Syntax
+/// ```jinja
+/// {{ 1704067200 | week_of_year }}
+/// {{ now() | week_of_year }}
+/// ```
+pub struct WeekOfYear;
+
+impl WeekOfYear {
+    fn compute(timestamp: i64) -> Result<Value, Error> {
+        let dt = timestamp_to_datetime(timestamp, "week_of_year")?;
+        Ok(Value::from(dt.iso_week().week()))
+    }
+}
+
+impl FilterFunction for WeekOfYear {
+    const NAME: &'static str = "week_of_year";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "week_of_year",
+        category: "datetime",
+        description: "Extract the ISO 8601 week number from a Unix timestamp (1-53)",
+        arguments: &[TIMESTAMP_ARG],
+        return_type: "integer",
+        examples: &[
+            "{{ week_of_year(timestamp=1704067200) }}",
+            "{{ now() | week_of_year }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let timestamp: i64 = kwargs.get("timestamp")?;
+        Self::compute(timestamp)
+    }
+
+    fn call_as_filter(value: &Value, _kwargs: Kwargs) -> Result<Value, Error> {
+        let timestamp = extract_timestamp(value, "week_of_year")?;
+        Self::compute(timestamp)
+    }
+}
+
+// ============================================
+// DayOfYear
+// ============================================
+
+/// Extract the day of the year from a Unix timestamp (1-366).
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ day_of_year(timestamp=1704067200) }}
+/// ```
+///
+/// # Filter Syntax
+/// ```jinja
+/// {{ 1704067200 | day_of_year }}
+/// {{ now() | day_of_year }}
+/// ```
+pub struct DayOfYear;
+
+impl DayOfYear {
+    fn compute(timestamp: i64) -> Result<Value, Error> {
+        let dt = timestamp_to_datetime(timestamp, "day_of_year")?;
+        Ok(Value::from(dt.ordinal()))
+    }
+}
+
+impl FilterFunction for DayOfYear {
+    const NAME: &'static str = "day_of_year";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "day_of_year",
+        category: "datetime",
+        description: "Extract the day of the year from a Unix timestamp (1-366)",
+        arguments: &[TIMESTAMP_ARG],
+        return_type: "integer",
+        examples: &[
+            "{{ day_of_year(timestamp=1704067200) }}",
+            "{{ now() | day_of_year }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let timestamp: i64 = kwargs.get("timestamp")?;
+        Self::compute(timestamp)
+    }
+
+    fn call_as_filter(value: &Value, _kwargs: Kwargs) -> Result<Value, Error> {
+        let timestamp = extract_timestamp(value, "day_of_year")?;
+        Self::compute(timestamp)
+    }
+}
+
+// ============================================
+// Quarter
+// ============================================
+
+/// Extract the quarter from a Unix timestamp (1-4).
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ quarter(timestamp=1704067200) }}
+/// ```
+///
+/// # Filter Syntax
+/// ```jinja
+/// {{ 1704067200 | quarter }}
+/// {{ now() | quarter }}
+/// ```
+pub struct Quarter;
+
+impl Quarter {
+    fn compute(timestamp: i64) -> Result<Value, Error> {
+        let dt = timestamp_to_datetime(timestamp, "quarter")?;
+        Ok(Value::from((dt.month() - 1) / 3 + 1))
+    }
+}
+
+impl FilterFunction for Quarter {
+    const NAME: &'static str = "quarter";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "quarter",
+        category: "datetime",
+        description: "Extract the quarter from a Unix timestamp (1-4)",
+        arguments: &[TIMESTAMP_ARG],
+        return_type: "integer",
+        examples: &[
+            "{{ quarter(timestamp=1704067200) }}",
+            "{{ now() | quarter }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let timestamp: i64 = kwargs.get("timestamp")?;
+        Self::compute(timestamp)
+    }
+
+    fn call_as_filter(value: &Value, _kwargs: Kwargs) -> Result<Value, Error> {
+        let timestamp = extract_timestamp(value, "quarter")?;
+        Self::compute(timestamp)
+    }
+}
+
+// ============================================
+// TimeAgo
+// ============================================
+
+/// Describe a Unix timestamp relative to now ("3 days ago", "in 2 hours").
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ time_ago(timestamp=1704067200) }}
+/// ```
+///
+/// # Filter Syntax
+/// ```jinja
+/// {{ build_time | time_ago }}
+/// {{ expires_at | time_ago(now=1704067200) }}
+/// ```
+pub struct TimeAgo;
+
+impl TimeAgo {
+    /// Largest unit first; months and years use 30 and 365 days
+    const UNITS: &'static [(&'static str, i64)] = &[
+        ("year", 365 * 86_400),
+        ("month", 30 * 86_400),
+        ("week", 7 * 86_400),
+        ("day", 86_400),
+        ("hour", 3_600),
+        ("minute", 60),
+    ];
+
+    fn compute(timestamp: i64, now: Option<i64>) -> Result<Value, Error> {
+        timestamp_to_datetime(timestamp, "time_ago")?;
+        let now = now.unwrap_or_else(|| Utc::now().timestamp());
+        let diff = timestamp.saturating_sub(now);
+        let seconds = diff.saturating_abs();
+
+        let Some((unit, count)) = Self::UNITS
+            .iter()
+            .find(|(_, size)| seconds >= *size)
+            .map(|(unit, size)| (*unit, seconds / size))
+        else {
+            return Ok(Value::from("just now"));
+        };
+
+        let amount = if count == 1 {
+            format!("1 {}", unit)
+        } else {
+            format!("{} {}s", count, unit)
+        };
+        if diff < 0 {
+            Ok(Value::from(format!("{} ago", amount)))
+        } else {
+            Ok(Value::from(format!("in {}", amount)))
+        }
+    }
+}
+
+impl FilterFunction for TimeAgo {
+    const NAME: &'static str = "time_ago";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "time_ago",
+        category: "datetime",
+        description: "Describe a Unix timestamp relative to now, like \"3 days ago\" or \"in 2 hours\"",
+        arguments: &[
+            TIMESTAMP_ARG,
+            ArgumentMetadata {
+                name: "now",
+                arg_type: "integer",
+                required: false,
+                default: None,
+                description: "Reference Unix timestamp (default: the current time)",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ time_ago(timestamp=1704067200) }}",
+            "Last deployed {{ deployed_at | time_ago }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let timestamp: i64 = kwargs.get("timestamp")?;
+        let now: Option<i64> = kwargs.get("now")?;
+        Self::compute(timestamp, now)
+    }
+
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        let timestamp = extract_timestamp(value, "time_ago")?;
+        let now: Option<i64> = kwargs.get("now")?;
+        Self::compute(timestamp, now)
+    }
+}
@@ -336,3 +336,186 @@ impl FilterFunction for EscapeShell {
         Ok(Value::from(Self::escape(input)))
     }
 }
+
+/// JSON string escape function.
+///
+/// Produces the inside of a JSON string literal, without the surrounding quotes.
+pub struct JsonEscape;
+
+impl JsonEscape {
+    fn escape(input: &str, ascii: bool) -> String {
+        let mut output = String::with_capacity(input.len());
+        for c in input.chars() {
+            match c {
+                '"' => output.push_str("\\\""),
+                '\\' => output.push_str("\\\\"),
+                '\n' => output.push_str("\\n"),
+                '\r' => output.push_str("\\r"),
+                '\t' => output.push_str("\\t"),
+                '\u{08}' => output.push_str("\\b"),
+                '\u{0c}' => output.push_str("\\f"),
+                // U+2028/U+2029 are valid JSON but break JavaScript string literals
+                c if c.is_control()
+                    || c == '\u{2028}'
+                    || c == '\u{2029}'
+                    || (ascii && !c.is_ascii()) =>
+                {
+                    let mut units = [0u16; 2];
+                    for unit in c.encode_utf16(&mut units) {
+                        output.push_str(&format!("\\u{:04x}", unit));
+                    }
+                }
+                c => output.push(c),
+            }
+        }
+        output
+    }
+}
+
+impl FilterFunction for JsonEscape {
+    const NAME: &'static str = "json_escape";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "json_escape",
+        category: "encoding",
+        description: "Escape a string for use inside a JSON string literal (without adding quotes)",
+        arguments: &[
+            STRING_ARG,
+            ArgumentMetadata {
+                name: "ascii",
+                arg_type: "boolean",
+                required: false,
+                default: Some("false"),
+                description: "Escape all non-ASCII characters as \\uXXXX sequences",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{\"message\": \"{{ json_escape(string=message) }}\"}",
+            "{\"path\": \"{{ \"C:\\\\temp\" | json_escape }}\"}",
+            "{{ name | json_escape(ascii=true) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let input: String = kwargs.get("string")?;
+        let ascii: Option<bool> = kwargs.get("ascii")?;
+        Ok(Value::from(Self::escape(&input, ascii.unwrap_or(false))))
+    }
+
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        let input = value.as_str().ok_or_else(|| {
+            Error::new(ErrorKind::InvalidOperation, "json_escape requires a string")
+        })?;
+        let ascii: Option<bool> = kwargs.get("ascii")?;
+        Ok(Value::from(Self::escape(input, ascii.unwrap_or(false))))
+    }
+}
+
+/// YAML scalar quoting function.
+///
+/// Leaves plain scalars alone and quotes values YAML would read as something
+/// other than a string (booleans, nulls, numbers, dates) or could not parse
+/// unquoted (indicators, `: `, ` #`, surrounding whitespace).
+pub struct YamlQuote;
+
+impl YamlQuote {
+    /// Scalars that YAML 1.1 or 1.2 resolve to booleans or null
+    const RESERVED: &'static [&'static str] = &[
+        "y", "n", "yes", "no", "on", "off", "true", "false", "null", "~",
+    ];
+
+    fn quote(input: &str) -> String {
+        if !Self::needs_quotes(input) {
+            return input.to_string();
+        }
+        if input.chars().any(|c| c.is_control()) {
+            // Only double quotes can carry escapes like \n; JSON strings are valid YAML
+            return serde_json::to_string(input).unwrap_or_else(|_| format!("\"{}\"", input));
+        }
+        format!("'{}'", input.replace('\'', "''"))
+    }
+
+    fn needs_quotes(input: &str) -> bool {
+        let Some(first) = input.chars().next() else {
+            return true;
+        };
+
+        Self::RESERVED.contains(&input.to_ascii_lowercase().as_str())
+            || Self::looks_like_number(input)
+            || Self::looks_like_date(input)
+            || "-?:,[]{}#&*!|>'\"%@`".contains(first)
+            || first.is_whitespace()
+            || input.ends_with(char::is_whitespace)
+            || input.ends_with(':')
+            || input.contains(": ")
+            || input.contains(" #")
+            || input.chars().any(|c| c.is_control())
+    }
+
+    /// Integers, floats, hex/octal/binary, `.inf`/`.nan` and sexagesimal (`1:30`)
+    fn looks_like_number(input: &str) -> bool {
+        let unsigned = input.trim_start_matches(['+', '-']);
+        let lower = unsigned.to_ascii_lowercase();
+        if matches!(lower.as_str(), ".inf" | ".nan") {
+            return true;
+        }
+        if let Some(digits) = lower
+            .strip_prefix("0x")
+            .or_else(|| lower.strip_prefix("0o"))
+            .or_else(|| lower.strip_prefix("0b"))
+        {
+            return !digits.is_empty() && digits.chars().all(|c| c.is_ascii_hexdigit() || c == '_');
+        }
+
+        let compact: String = unsigned.chars().filter(|&c| c != '_' && c != ':').collect();
+        compact.chars().any(|c| c.is_ascii_digit())
+            && !compact.contains(char::is_whitespace)
+            && compact.parse::<f64>().is_ok()
+    }
+
+    /// Dates and timestamps starting with `YYYY-M-D`
+    fn looks_like_date(input: &str) -> bool {
+        let mut parts = input.splitn(3, '-');
+        let year = parts.next().unwrap_or_default();
+        let month = parts.next().unwrap_or_default();
+        let day: String = parts
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .take_while(|c| c.is_ascii_digit())
+            .collect();
+        let digits = |s: &str, len: std::ops::RangeInclusive<usize>| {
+            len.contains(&s.len()) && s.chars().all(|c| c.is_ascii_digit())
+        };
+        digits(year, 4..=4) && digits(month, 1..=2) && digits(&day, 1..=2)
+    }
+}
+
+impl FilterFunction for YamlQuote {
+    const NAME: &'static str = "yaml_quote";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "yaml_quote",
+        category: "encoding",
+        description: "Quote a string for YAML only when it would otherwise be read as another type or fail to parse",
+        arguments: &[STRING_ARG],
+        return_type: "string",
+        examples: &[
+            "{{ yaml_quote(string=\"yes\") }}",
+            "country: {{ country_code | yaml_quote }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let input: String = kwargs.get("string")?;
+        Ok(Value::from(Self::quote(&input)))
+    }
+
+    fn call_as_filter(value: &Value, _kwargs: Kwargs) -> Result<Value, Error> {
+        let input = value.as_str().ok_or_else(|| {
+            Error::new(ErrorKind::InvalidOperation, "yaml_quote requires a string")
+        })?;
+        Ok(Value::from(Self::quote(input)))
+    }
+}
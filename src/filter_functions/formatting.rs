@@ -4,12 +4,15 @@
 //! ```jinja
 //! {{ filesizeformat(bytes=1048576) }}
 //! {{ urlencode(string="hello world") }}
+//! {{ phone_format(string="(202) 555-0143", format="e164") }}
+//! {{ currency_format(value=1234.5, currency="EUR", locale="de-DE") }}
 //! ```
 //!
 //! # Filter Syntax
 //! ```jinja
 //! {{ 1048576 | filesizeformat }}
 //! {{ "hello world" | urlencode }}
+//! {{ 1234.5 | currency_format }}
 //! ```
 
 use super::FilterFunction;
@@ -17,6 +20,7 @@ use crate::functions::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVaria
 use minijinja::value::Kwargs;
 use minijinja::{Error, ErrorKind, Value};
 use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
+use phonenumber::{Mode, PhoneNumber, country};
 
 /// Helper to extract string from Value
 fn extract_string(value: &Value, fn_name: &str) -> Result<String, Error> {
@@ -146,3 +150,442 @@ impl FilterFunction for Urlencode {
         Ok(Value::from(Self::compute(&input)))
     }
 }
+
+// ============================================
+// PhoneFormat
+// ============================================
+
+/// Normalize a phone number to E.164, international, national or RFC 3966 format.
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ phone_format(string="(202) 555-0143") }}
+/// {# Output: +12025550143 #}
+/// ```
+///
+/// # Filter Syntax
+/// ```jinja
+/// {{ contact.phone | phone_format(format="national", country="GB") }}
+/// ```
+pub struct PhoneFormat;
+
+impl PhoneFormat {
+    /// Resolve a two-letter region code (e.g. "US", "gb")
+    pub(crate) fn country(code: &str, fn_name: &str) -> Result<country::Id, Error> {
+        code.trim().to_uppercase().parse().map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!(
+                    "{}: unknown country '{}' (expected a two-letter region code like \"US\")",
+                    fn_name, code
+                ),
+            )
+        })
+    }
+
+    /// Parse a number, returning it only when it is valid for its region
+    ///
+    /// Numbers starting with `+` carry their own country code; others are
+    /// read as national numbers of `country`.
+    pub(crate) fn parse_valid(input: &str, country: country::Id) -> Option<PhoneNumber> {
+        phonenumber::parse(Some(country), input)
+            .ok()
+            .filter(phonenumber::is_valid)
+    }
+
+    fn compute(input: &str, format: &str, country: &str) -> Result<String, Error> {
+        let mode = match format {
+            "e164" => Mode::E164,
+            "international" => Mode::International,
+            "national" => Mode::National,
+            "rfc3966" => Mode::Rfc3966,
+            other => {
+                return Err(Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!(
+                        "phone_format: unknown format '{}' (expected \"e164\", \"international\", \"national\" or \"rfc3966\")",
+                        other
+                    ),
+                ));
+            }
+        };
+        let country = Self::country(country, "phone_format")?;
+        let number = Self::parse_valid(input, country).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("phone_format: '{}' is not a valid phone number", input),
+            )
+        })?;
+        Ok(number.format().mode(mode).to_string())
+    }
+}
+
+impl FilterFunction for PhoneFormat {
+    const NAME: &'static str = "phone_format";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "phone_format",
+        category: "formatting",
+        description: "Normalize a phone number to E.164, international, national or RFC 3966 format",
+        arguments: &[
+            ArgumentMetadata {
+                name: "string",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Phone number in any common notation",
+            },
+            ArgumentMetadata {
+                name: "format",
+                arg_type: "string",
+                required: false,
+                default: Some("e164"),
+                description: "Output format: \"e164\", \"international\", \"national\" or \"rfc3966\"",
+            },
+            ArgumentMetadata {
+                name: "country",
+                arg_type: "string",
+                required: false,
+                default: Some("US"),
+                description: "Region used for numbers without a +country code prefix",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ phone_format(string=\"(202) 555-0143\") }}",
+            "{{ contact.phone | phone_format(format=\"national\", country=\"GB\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let input: String = kwargs.get("string")?;
+        let format: Option<String> = kwargs.get("format")?;
+        let country: Option<String> = kwargs.get("country")?;
+        Ok(Value::from(Self::compute(
+            &input,
+            format.as_deref().unwrap_or("e164"),
+            country.as_deref().unwrap_or("US"),
+        )?))
+    }
+
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        let input = extract_string(value, "phone_format")?;
+        let format: Option<String> = kwargs.get("format")?;
+        let country: Option<String> = kwargs.get("country")?;
+        Ok(Value::from(Self::compute(
+            &input,
+            format.as_deref().unwrap_or("e164"),
+            country.as_deref().unwrap_or("US"),
+        )?))
+    }
+}
+
+// ============================================
+// CurrencyFormat
+// ============================================
+
+/// Number and currency placement conventions of a locale
+struct LocaleFormat {
+    tag: &'static str,
+    group: &'static str,
+    decimal: &'static str,
+    symbol_first: bool,
+    symbol_space: bool,
+}
+
+const LOCALES: &[LocaleFormat] = &[
+    LocaleFormat {
+        tag: "en-US",
+        group: ",",
+        decimal: ".",
+        symbol_first: true,
+        symbol_space: false,
+    },
+    LocaleFormat {
+        tag: "en-GB",
+        group: ",",
+        decimal: ".",
+        symbol_first: true,
+        symbol_space: false,
+    },
+    LocaleFormat {
+        tag: "en-CA",
+        group: ",",
+        decimal: ".",
+        symbol_first: true,
+        symbol_space: false,
+    },
+    LocaleFormat {
+        tag: "en-AU",
+        group: ",",
+        decimal: ".",
+        symbol_first: true,
+        symbol_space: false,
+    },
+    LocaleFormat {
+        tag: "de-DE",
+        group: ".",
+        decimal: ",",
+        symbol_first: false,
+        symbol_space: true,
+    },
+    LocaleFormat {
+        tag: "de-CH",
+        group: "'",
+        decimal: ".",
+        symbol_first: true,
+        symbol_space: true,
+    },
+    LocaleFormat {
+        tag: "fr-FR",
+        group: " ",
+        decimal: ",",
+        symbol_first: false,
+        symbol_space: true,
+    },
+    LocaleFormat {
+        tag: "es-ES",
+        group: ".",
+        decimal: ",",
+        symbol_first: false,
+        symbol_space: true,
+    },
+    LocaleFormat {
+        tag: "it-IT",
+        group: ".",
+        decimal: ",",
+        symbol_first: false,
+        symbol_space: true,
+    },
+    LocaleFormat {
+        tag: "nl-NL",
+        group: ".",
+        decimal: ",",
+        symbol_first: true,
+        symbol_space: true,
+    },
+    LocaleFormat {
+        tag: "pl-PL",
+        group: " ",
+        decimal: ",",
+        symbol_first: false,
+        symbol_space: true,
+    },
+    LocaleFormat {
+        tag: "pt-BR",
+        group: ".",
+        decimal: ",",
+        symbol_first: true,
+        symbol_space: true,
+    },
+    LocaleFormat {
+        tag: "sv-SE",
+        group: " ",
+        decimal: ",",
+        symbol_first: false,
+        symbol_space: true,
+    },
+    LocaleFormat {
+        tag: "ja-JP",
+        group: ",",
+        decimal: ".",
+        symbol_first: true,
+        symbol_space: false,
+    },
+    LocaleFormat {
+        tag: "zh-CN",
+        group: ",",
+        decimal: ".",
+        symbol_first: true,
+        symbol_space: false,
+    },
+];
+
+/// ISO 4217 code, display symbol and number of minor-unit digits
+const CURRENCIES: &[(&str, &str, usize)] = &[
+    ("USD", "$", 2),
+    ("EUR", "€", 2),
+    ("GBP", "£", 2),
+    ("JPY", "¥", 0),
+    ("CNY", "¥", 2),
+    ("CHF", "CHF", 2),
+    ("CAD", "CA$", 2),
+    ("AUD", "A$", 2),
+    ("PLN", "zł", 2),
+    ("SEK", "kr", 2),
+    ("NOK", "kr", 2),
+    ("DKK", "kr", 2),
+    ("CZK", "Kč", 2),
+    ("BRL", "R$", 2),
+    ("INR", "₹", 2),
+    ("KRW", "₩", 0),
+];
+
+/// Format a number as a localized currency amount
+///
+/// Symbol placement, digit grouping and the decimal separator come from
+/// `locale`; the number of decimals comes from `currency` (e.g. 0 for JPY).
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ currency_format(value=1234.5, currency="EUR", locale="de-DE") }}
+/// {# Output: 1.234,50 € #}
+/// ```
+///
+/// # Filter Syntax
+/// ```jinja
+/// {{ plan.price | currency_format(currency="GBP", locale="en-GB") }}
+/// ```
+pub struct CurrencyFormat;
+
+impl CurrencyFormat {
+    fn locale(tag: &str) -> Result<&'static LocaleFormat, Error> {
+        let normalized = tag.trim().replace('_', "-");
+        LOCALES
+            .iter()
+            .find(|l| l.tag.eq_ignore_ascii_case(&normalized))
+            .ok_or_else(|| {
+                let known: Vec<&str> = LOCALES.iter().map(|l| l.tag).collect();
+                Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!(
+                        "currency_format: unknown locale '{}' (supported: {})",
+                        tag,
+                        known.join(", ")
+                    ),
+                )
+            })
+    }
+
+    fn currency(code: &str) -> Result<(&'static str, usize), Error> {
+        let code = code.trim();
+        CURRENCIES
+            .iter()
+            .find(|(c, _, _)| c.eq_ignore_ascii_case(code))
+            .map(|(_, symbol, decimals)| (*symbol, *decimals))
+            .ok_or_else(|| {
+                let known: Vec<&str> = CURRENCIES.iter().map(|(c, _, _)| *c).collect();
+                Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!(
+                        "currency_format: unknown currency '{}' (supported: {})",
+                        code,
+                        known.join(", ")
+                    ),
+                )
+            })
+    }
+
+    /// Accept numbers and numeric strings (e.g. values read from the environment)
+    fn amount(value: &Value) -> Result<f64, Error> {
+        let amount = match value.as_str() {
+            Some(s) => s.trim().parse::<f64>().ok(),
+            None => f64::try_from(value.clone()).ok(),
+        };
+        amount.filter(|a| a.is_finite()).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("currency_format requires a number, found: {}", value),
+            )
+        })
+    }
+
+    /// Insert `sep` between groups of three digits
+    fn group_digits(digits: &str, sep: &str) -> String {
+        let mut out = String::with_capacity(digits.len() + digits.len() / 3 * sep.len());
+        for (i, ch) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                out.push_str(sep);
+            }
+            out.push(ch);
+        }
+        out
+    }
+
+    fn compute(amount: f64, currency: &str, locale: &str) -> Result<String, Error> {
+        let (symbol, decimals) = Self::currency(currency)?;
+        let locale = Self::locale(locale)?;
+
+        // `format!` rounds ties to even; money is rounded half away from zero
+        let scale = 10f64.powi(decimals as i32);
+        let fixed = format!("{:.*}", decimals, (amount.abs() * scale).round() / scale);
+        let (int_part, frac_part) = match fixed.split_once('.') {
+            Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+            None => (fixed.as_str(), None),
+        };
+        let mut number = Self::group_digits(int_part, locale.group);
+        if let Some(frac_part) = frac_part {
+            number.push_str(locale.decimal);
+            number.push_str(frac_part);
+        }
+
+        // Amounts that round to zero are shown without a sign
+        let negative = amount < 0.0 && fixed.bytes().any(|b| b.is_ascii_digit() && b != b'0');
+        let sign = if negative { "-" } else { "" };
+        let space = if locale.symbol_space { " " } else { "" };
+        Ok(if locale.symbol_first {
+            format!("{}{}{}{}", sign, symbol, space, number)
+        } else {
+            format!("{}{}{}{}", sign, number, space, symbol)
+        })
+    }
+}
+
+impl FilterFunction for CurrencyFormat {
+    const NAME: &'static str = "currency_format";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "currency_format",
+        category: "formatting",
+        description: "Format a number as a localized currency amount (symbol placement, grouping, decimals)",
+        arguments: &[
+            ArgumentMetadata {
+                name: "value",
+                arg_type: "number",
+                required: true,
+                default: None,
+                description: "Amount to format (numeric strings are accepted)",
+            },
+            ArgumentMetadata {
+                name: "currency",
+                arg_type: "string",
+                required: false,
+                default: Some("USD"),
+                description: "ISO 4217 currency code, e.g. \"EUR\" or \"JPY\"",
+            },
+            ArgumentMetadata {
+                name: "locale",
+                arg_type: "string",
+                required: false,
+                default: Some("en-US"),
+                description: "Locale controlling separators and symbol placement, e.g. \"de-DE\"",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ currency_format(value=1234.5) }}",
+            "{{ plan.price | currency_format(currency=\"EUR\", locale=\"de-DE\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let value: Value = kwargs.get("value")?;
+        let currency: Option<String> = kwargs.get("currency")?;
+        let locale: Option<String> = kwargs.get("locale")?;
+        Ok(Value::from(Self::compute(
+            Self::amount(&value)?,
+            currency.as_deref().unwrap_or("USD"),
+            locale.as_deref().unwrap_or("en-US"),
+        )?))
+    }
+
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        let currency: Option<String> = kwargs.get("currency")?;
+        let locale: Option<String> = kwargs.get("locale")?;
+        Ok(Value::from(Self::compute(
+            Self::amount(value)?,
+            currency.as_deref().unwrap_or("USD"),
+            locale.as_deref().unwrap_or("en-US"),
+        )?))
+    }
+}
@@ -73,7 +73,7 @@ fn extract_string(value: &Value, fn_name: &str) -> Result<String, Error> {
 pub struct K8sLabelSafe;
 
 impl K8sLabelSafe {
-    fn compute(value: &str) -> String {
+    pub(crate) fn compute(value: &str) -> String {
         // Convert to lowercase
         let mut result = value.to_lowercase();
 
@@ -262,7 +262,7 @@ impl FilterFunction for K8sDnsLabelSafe {
 pub struct K8sAnnotationSafe;
 
 impl K8sAnnotationSafe {
-    fn compute(value: &str) -> String {
+    pub(crate) fn compute(value: &str) -> String {
         // Replace newlines, tabs, and control characters with spaces for single-line values
         let result: String = value
             .chars()
@@ -6,6 +6,8 @@
 //! {{ round(number=3.14159, decimals=2) }}
 //! {{ ceil(number=3.1) }}
 //! {{ floor(number=3.9) }}
+//! {{ to_roman(value=2024) }}
+//! {{ from_roman(string="MMXXIV") }}
 //! ```
 //!
 //! # Filter Syntax
@@ -14,6 +16,7 @@
 //! {{ 3.14159 | round(decimals=2) }}
 //! {{ 3.1 | ceil }}
 //! {{ 3.9 | floor }}
+//! {{ loop.index | to_roman }}
 //! ```
 //!
 //! # Chaining
@@ -146,6 +149,7 @@ impl FilterFunction for Round {
         ],
         syntax: SyntaxVariants::FUNCTION_AND_FILTER,
     };
+    const POSITIONAL_ARG: Option<&'static str> = Some("decimals");
 
     fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
         let number: Value = kwargs.get("number")?;
@@ -234,3 +238,151 @@ impl FilterFunction for Floor {
         Ok(Value::from(Self::compute(num)))
     }
 }
+
+// ============================================
+// ToRoman / FromRoman
+// ============================================
+
+/// Roman numeral symbols from largest to smallest, including subtractive pairs
+const ROMAN_SYMBOLS: &[(u32, &str)] = &[
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+/// Largest value expressible in standard Roman numerals
+const ROMAN_MAX: u32 = 3999;
+
+/// Convert an integer (1-3999) to a Roman numeral.
+pub struct ToRoman;
+
+impl ToRoman {
+    fn compute(value: &Value) -> Result<String, Error> {
+        let num = extract_number(value, "to_roman")?;
+        if num.fract() != 0.0 || num < 1.0 || num > ROMAN_MAX as f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                format!(
+                    "to_roman: value must be an integer between 1 and {}, found: {}",
+                    ROMAN_MAX, value
+                ),
+            ));
+        }
+        Ok(Self::encode(num as u32))
+    }
+
+    fn encode(mut num: u32) -> String {
+        let mut out = String::new();
+        for (amount, symbol) in ROMAN_SYMBOLS {
+            while num >= *amount {
+                out.push_str(symbol);
+                num -= amount;
+            }
+        }
+        out
+    }
+}
+
+impl FilterFunction for ToRoman {
+    const NAME: &'static str = "to_roman";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "to_roman",
+        category: "math",
+        description: "Convert an integer between 1 and 3999 to a Roman numeral",
+        arguments: &[ArgumentMetadata {
+            name: "value",
+            arg_type: "integer",
+            required: true,
+            default: None,
+            description: "Integer to convert (1-3999)",
+        }],
+        return_type: "string",
+        examples: &["{{ to_roman(value=2024) }}", "{{ loop.index | to_roman }}"],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let value: Value = kwargs.get("value")?;
+        Ok(Value::from(Self::compute(&value)?))
+    }
+
+    fn call_as_filter(value: &Value, _kwargs: Kwargs) -> Result<Value, Error> {
+        Ok(Value::from(Self::compute(value)?))
+    }
+}
+
+/// Convert a Roman numeral to an integer.
+pub struct FromRoman;
+
+impl FromRoman {
+    /// Parse a numeral, accepting only the canonical form (so "IIII" or "IC" are rejected)
+    fn compute(input: &str) -> Result<u32, Error> {
+        let numeral = input.trim().to_uppercase();
+        let mut rest = numeral.as_str();
+        let mut total = 0;
+        for (amount, symbol) in ROMAN_SYMBOLS {
+            while let Some(stripped) = rest.strip_prefix(symbol) {
+                total += amount;
+                rest = stripped;
+            }
+        }
+
+        if !(1..=ROMAN_MAX).contains(&total)
+            || !rest.is_empty()
+            || ToRoman::encode(total) != numeral
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                format!("from_roman: '{}' is not a valid Roman numeral", input),
+            ));
+        }
+        Ok(total)
+    }
+}
+
+impl FilterFunction for FromRoman {
+    const NAME: &'static str = "from_roman";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "from_roman",
+        category: "math",
+        description: "Convert a Roman numeral (I-MMMCMXCIX, case-insensitive) to an integer",
+        arguments: &[ArgumentMetadata {
+            name: "string",
+            arg_type: "string",
+            required: true,
+            default: None,
+            description: "Roman numeral to convert",
+        }],
+        return_type: "integer",
+        examples: &[
+            "{{ from_roman(string=\"MMXXIV\") }}",
+            "{{ \"xiv\" | from_roman }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let input: String = kwargs.get("string")?;
+        Ok(Value::from(Self::compute(&input)?))
+    }
+
+    fn call_as_filter(value: &Value, _kwargs: Kwargs) -> Result<Value, Error> {
+        let input = value.as_str().ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("from_roman requires a string, found: {}", value),
+            )
+        })?;
+        Ok(Value::from(Self::compute(input)?))
+    }
+}
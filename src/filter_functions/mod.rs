@@ -61,10 +61,13 @@ pub fn get_all_metadata() -> Vec<&'static FunctionMetadata> {
         &encoding::EscapeHtml::METADATA,
         &encoding::EscapeXml::METADATA,
         &encoding::EscapeShell::METADATA,
+        &encoding::JsonEscape::METADATA,
+        &encoding::YamlQuote::METADATA,
         // Serialization functions
         &serialization::ToJson::METADATA,
         &serialization::ToYaml::METADATA,
         &serialization::ToToml::METADATA,
+        &serialization::ToEnvFile::METADATA,
         &serialization::ParseJson::METADATA,
         &serialization::ParseYaml::METADATA,
         &serialization::ParseToml::METADATA,
@@ -73,8 +76,11 @@ pub fn get_all_metadata() -> Vec<&'static FunctionMetadata> {
         &math::Round::METADATA,
         &math::Ceil::METADATA,
         &math::Floor::METADATA,
+        &math::ToRoman::METADATA,
+        &math::FromRoman::METADATA,
         // String functions
         &string::RegexReplace::METADATA,
+        &string::Replace::METADATA,
         &string::Substring::METADATA,
         &string::Truncate::METADATA,
         &string::WordCount::METADATA,
@@ -85,6 +91,7 @@ pub fn get_all_metadata() -> Vec<&'static FunctionMetadata> {
         &string::StripAnsi::METADATA,
         &string::NormalizeWhitespace::METADATA,
         &string::Slugify::METADATA,
+        &string::ToAscii::METADATA,
         &string::Indent::METADATA,
         &string::Dedent::METADATA,
         &string::Quote::METADATA,
@@ -95,16 +102,35 @@ pub fn get_all_metadata() -> Vec<&'static FunctionMetadata> {
         &string::ToKebabCase::METADATA,
         &string::PadLeft::METADATA,
         &string::PadRight::METADATA,
+        &string::Pad::METADATA,
         &string::Repeat::METADATA,
         &string::Reverse::METADATA,
+        &string::StringWidth::METADATA,
+        &string::Comment::METADATA,
+        &string::CodeBlock::METADATA,
+        &string::WrapIn::METADATA,
+        &string::Surround::METADATA,
+        &string::Strip::METADATA,
+        &string::LStrip::METADATA,
+        &string::RStrip::METADATA,
+        &string::Partition::METADATA,
+        &string::RPartition::METADATA,
+        &string::MaskEmail::METADATA,
         // Array functions
         &array::ArraySum::METADATA,
         &array::ArrayAvg::METADATA,
         &array::ArrayMedian::METADATA,
         &array::ArrayMin::METADATA,
         &array::ArrayMax::METADATA,
+        &array::ArrayVariance::METADATA,
+        &array::ArrayStddev::METADATA,
+        &array::ArrayPercentile::METADATA,
+        &array::ArrayStats::METADATA,
+        &array::ArrayRunningTotal::METADATA,
+        &array::ArrayScan::METADATA,
         &array::ArrayUnique::METADATA,
         &array::ArrayFlatten::METADATA,
+        &array::FilterGlob::METADATA,
         // DateTime functions
         &datetime::FormatDate::METADATA,
         &datetime::GetYear::METADATA,
@@ -113,6 +139,11 @@ pub fn get_all_metadata() -> Vec<&'static FunctionMetadata> {
         &datetime::GetHour::METADATA,
         &datetime::GetMinute::METADATA,
         &datetime::GetSecond::METADATA,
+        &datetime::DayOfWeek::METADATA,
+        &datetime::WeekOfYear::METADATA,
+        &datetime::DayOfYear::METADATA,
+        &datetime::Quarter::METADATA,
+        &datetime::TimeAgo::METADATA,
         // Path functions
         &path::Basename::METADATA,
         &path::Dirname::METADATA,
@@ -127,6 +158,8 @@ pub fn get_all_metadata() -> Vec<&'static FunctionMetadata> {
         &object::ObjectKeys::METADATA,
         &object::ObjectValues::METADATA,
         &object::ObjectFlatten::METADATA,
+        &object::Compact::METADATA,
+        &object::Merge::METADATA,
         // Kubernetes functions
         &kubernetes::K8sLabelSafe::METADATA,
         &kubernetes::K8sDnsLabelSafe::METADATA,
@@ -134,6 +167,8 @@ pub fn get_all_metadata() -> Vec<&'static FunctionMetadata> {
         // Formatting functions
         &formatting::Filesizeformat::METADATA,
         &formatting::Urlencode::METADATA,
+        &formatting::PhoneFormat::METADATA,
+        &formatting::CurrencyFormat::METADATA,
     ]
 }
 
@@ -166,11 +201,14 @@ pub fn register_all(env: &mut Environment) {
     encoding::EscapeHtml::register(env);
     encoding::EscapeXml::register(env);
     encoding::EscapeShell::register(env);
+    encoding::JsonEscape::register(env);
+    encoding::YamlQuote::register(env);
 
     // Phase 4: Serialization functions
     serialization::ToJson::register(env);
     serialization::ToYaml::register(env);
     serialization::ToToml::register(env);
+    serialization::ToEnvFile::register(env);
     serialization::ParseJson::register(env);
     serialization::ParseYaml::register(env);
     serialization::ParseToml::register(env);
@@ -180,9 +218,12 @@ pub fn register_all(env: &mut Environment) {
     math::Round::register(env);
     math::Ceil::register(env);
     math::Floor::register(env);
+    math::ToRoman::register(env);
+    math::FromRoman::register(env);
 
     // Phase 6: String functions
     string::RegexReplace::register(env);
+    string::Replace::register(env);
     string::Substring::register(env);
     string::Truncate::register(env);
     string::WordCount::register(env);
@@ -199,8 +240,15 @@ pub fn register_all(env: &mut Environment) {
     array::ArrayMedian::register(env);
     array::ArrayMin::register(env);
     array::ArrayMax::register(env);
+    array::ArrayVariance::register(env);
+    array::ArrayStddev::register(env);
+    array::ArrayPercentile::register(env);
+    array::ArrayStats::register(env);
+    array::ArrayRunningTotal::register(env);
+    array::ArrayScan::register(env);
     array::ArrayUnique::register(env);
     array::ArrayFlatten::register(env);
+    array::FilterGlob::register(env);
 
     // Phase 8: DateTime functions
     datetime::FormatDate::register(env);
@@ -210,6 +258,11 @@ pub fn register_all(env: &mut Environment) {
     datetime::GetHour::register(env);
     datetime::GetMinute::register(env);
     datetime::GetSecond::register(env);
+    datetime::DayOfWeek::register(env);
+    datetime::WeekOfYear::register(env);
+    datetime::DayOfYear::register(env);
+    datetime::Quarter::register(env);
+    datetime::TimeAgo::register(env);
 
     // Phase 9: Path functions
     path::Basename::register(env);
@@ -227,6 +280,8 @@ pub fn register_all(env: &mut Environment) {
     object::ObjectKeys::register(env);
     object::ObjectValues::register(env);
     object::ObjectFlatten::register(env);
+    object::Compact::register(env);
+    object::Merge::register(env);
 
     // Phase 12: Kubernetes functions
     kubernetes::K8sLabelSafe::register(env);
@@ -236,9 +291,12 @@ pub fn register_all(env: &mut Environment) {
     // Formatting functions (migrated from src/filters)
     formatting::Filesizeformat::register(env);
     formatting::Urlencode::register(env);
+    formatting::PhoneFormat::register(env);
+    formatting::CurrencyFormat::register(env);
 
     // String filters (migrated from src/filters)
     string::Slugify::register(env);
+    string::ToAscii::register(env);
     string::Indent::register(env);
     string::Dedent::register(env);
     string::Quote::register(env);
@@ -249,6 +307,18 @@ pub fn register_all(env: &mut Environment) {
     string::ToKebabCase::register(env);
     string::PadLeft::register(env);
     string::PadRight::register(env);
+    string::Pad::register(env);
     string::Repeat::register(env);
     string::Reverse::register(env);
+    string::StringWidth::register(env);
+    string::Comment::register(env);
+    string::CodeBlock::register(env);
+    string::WrapIn::register(env);
+    string::Surround::register(env);
+    string::Strip::register(env);
+    string::LStrip::register(env);
+    string::RStrip::register(env);
+    string::Partition::register(env);
+    string::RPartition::register(env);
+    string::MaskEmail::register(env);
 }
@@ -5,6 +5,8 @@
 //! {{ object_keys(object=config) }}
 //! {{ object_values(object=config) }}
 //! {{ object_flatten(object=nested) }}
+//! {{ compact(value=config) }}
+//! {{ merge(object=base, other=overlay) }}
 //! ```
 //!
 //! # Filter Syntax
@@ -12,6 +14,8 @@
 //! {{ config | object_keys }}
 //! {{ config | object_values }}
 //! {{ nested | object_flatten }}
+//! {{ config | compact }}
+//! {{ base | merge(other=overlay) }}
 //! ```
 //!
 //! # Chaining
@@ -22,7 +26,8 @@
 
 use super::FilterFunction;
 use crate::functions::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
-use minijinja::value::Kwargs;
+use crate::functions::object::{ListStrategy, merge_json_values};
+use minijinja::value::{Kwargs, ValueKind};
 use minijinja::{Error, ErrorKind, Value};
 use serde_json::Map;
 
@@ -59,6 +64,43 @@ fn ensure_object(
     }
 }
 
+/// Metadata for the `sort` argument of object_keys / object_values
+const SORT_ARG: ArgumentMetadata = ArgumentMetadata {
+    name: "sort",
+    arg_type: "boolean",
+    required: false,
+    default: Some("false"),
+    description: "Return entries sorted by key instead of in the object's own order",
+};
+
+/// Entries of an object in its own order, or sorted by key when `sort` is set
+fn object_entries(
+    object: &Value,
+    sort: bool,
+    fn_name: &str,
+) -> Result<Vec<(String, Value)>, Error> {
+    if object.kind() != ValueKind::Map {
+        return Err(Error::new(
+            ErrorKind::InvalidOperation,
+            format!("{} requires an object, not an array or primitive", fn_name),
+        ));
+    }
+
+    let mut entries = Vec::new();
+    for key in object.try_iter()? {
+        let value = object.get_item(&key)?;
+        let key = match key.as_str() {
+            Some(s) => s.to_string(),
+            None => key.to_string(),
+        };
+        entries.push((key, value));
+    }
+    if sort {
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+    }
+    Ok(entries)
+}
+
 // ============================================
 // ObjectKeys
 // ============================================
@@ -69,7 +111,7 @@ fn ensure_object(
 /// ```jinja
 /// {% set config = {"host": "localhost", "port": 8080, "debug": true} %}
 /// {% set keys = object_keys(object=config) %}
-/// {# Result: ["host", "port", "debug"] #}
+/// {# Result: ["debug", "host", "port"] #}
 /// ```
 ///
 /// # Filter Syntax
@@ -82,10 +124,12 @@ fn ensure_object(
 pub struct ObjectKeys;
 
 impl ObjectKeys {
-    fn compute(json_value: serde_json::Value) -> Result<Value, Error> {
-        let map = ensure_object(json_value, "object_keys")?;
-        let keys: Vec<String> = map.keys().cloned().collect();
-        Ok(Value::from_serialize(&keys))
+    fn compute(object: &Value, kwargs: &Kwargs) -> Result<Value, Error> {
+        let sort: Option<bool> = kwargs.get("sort")?;
+        let entries = object_entries(object, sort.unwrap_or(false), "object_keys")?;
+        Ok(Value::from_iter(
+            entries.into_iter().map(|(key, _)| Value::from(key)),
+        ))
     }
 }
 
@@ -94,25 +138,23 @@ impl FilterFunction for ObjectKeys {
     const METADATA: FunctionMetadata = FunctionMetadata {
         name: "object_keys",
         category: "object",
-        description: "Get object keys as an array",
-        arguments: &[OBJECT_ARG],
+        description: "Get object keys as an array, in the object's own order or sorted",
+        arguments: &[OBJECT_ARG, SORT_ARG],
         return_type: "array",
         examples: &[
             "{{ object_keys(object=config) }}",
-            "{{ config | object_keys }}",
+            "{{ config | object_keys(sort=true) }}",
         ],
         syntax: SyntaxVariants::FUNCTION_AND_FILTER,
     };
 
     fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
         let object: Value = kwargs.get("object")?;
-        let json_value = value_to_json(&object, "object_keys")?;
-        Self::compute(json_value)
+        Self::compute(&object, &kwargs)
     }
 
-    fn call_as_filter(value: &Value, _kwargs: Kwargs) -> Result<Value, Error> {
-        let json_value = value_to_json(value, "object_keys")?;
-        Self::compute(json_value)
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        Self::compute(value, &kwargs)
     }
 }
 
@@ -126,7 +168,7 @@ impl FilterFunction for ObjectKeys {
 /// ```jinja
 /// {% set config = {"host": "localhost", "port": 8080, "debug": true} %}
 /// {% set values = object_values(object=config) %}
-/// {# Result: ["localhost", 8080, true] #}
+/// {# Result: [true, "localhost", 8080] #}
 /// ```
 ///
 /// # Filter Syntax
@@ -139,10 +181,12 @@ impl FilterFunction for ObjectKeys {
 pub struct ObjectValues;
 
 impl ObjectValues {
-    fn compute(json_value: serde_json::Value) -> Result<Value, Error> {
-        let map = ensure_object(json_value, "object_values")?;
-        let values: Vec<&serde_json::Value> = map.values().collect();
-        Ok(Value::from_serialize(&values))
+    fn compute(object: &Value, kwargs: &Kwargs) -> Result<Value, Error> {
+        let sort: Option<bool> = kwargs.get("sort")?;
+        let entries = object_entries(object, sort.unwrap_or(false), "object_values")?;
+        Ok(Value::from_iter(
+            entries.into_iter().map(|(_, value)| value),
+        ))
     }
 }
 
@@ -151,25 +195,23 @@ impl FilterFunction for ObjectValues {
     const METADATA: FunctionMetadata = FunctionMetadata {
         name: "object_values",
         category: "object",
-        description: "Get object values as an array",
-        arguments: &[OBJECT_ARG],
+        description: "Get object values as an array, in the object's own order or sorted by key",
+        arguments: &[OBJECT_ARG, SORT_ARG],
         return_type: "array",
         examples: &[
             "{{ object_values(object=config) }}",
-            "{{ config | object_values }}",
+            "{{ config | object_values(sort=true) }}",
         ],
         syntax: SyntaxVariants::FUNCTION_AND_FILTER,
     };
 
     fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
         let object: Value = kwargs.get("object")?;
-        let json_value = value_to_json(&object, "object_values")?;
-        Self::compute(json_value)
+        Self::compute(&object, &kwargs)
     }
 
-    fn call_as_filter(value: &Value, _kwargs: Kwargs) -> Result<Value, Error> {
-        let json_value = value_to_json(value, "object_values")?;
-        Self::compute(json_value)
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        Self::compute(value, &kwargs)
     }
 }
 
@@ -286,3 +328,209 @@ impl FilterFunction for ObjectFlatten {
         Self::compute(json_value, &delimiter)
     }
 }
+
+// ============================================
+// Compact
+// ============================================
+
+/// Remove null and empty-string entries from an array or object.
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ compact(value=[1, none, "", "a"]) }}
+/// {# Result: [1, "a"] #}
+///
+/// {{ compact(value={"name": "app", "tag": none}) }}
+/// {# Result: {"name": "app"} #}
+/// ```
+///
+/// # Filter Syntax
+/// ```jinja
+/// {{ config | compact | to_yaml }}
+/// {{ config | compact(recursive=true) | to_yaml }}
+/// ```
+pub struct Compact;
+
+impl Compact {
+    fn compute(json_value: serde_json::Value, recursive: bool) -> Result<Value, Error> {
+        match json_value {
+            serde_json::Value::Array(_) | serde_json::Value::Object(_) => Ok(
+                Value::from_serialize(Self::compact_value(json_value, recursive)),
+            ),
+            _ => Err(Error::new(
+                ErrorKind::InvalidOperation,
+                "compact requires an array or object",
+            )),
+        }
+    }
+
+    fn is_empty(value: &serde_json::Value) -> bool {
+        match value {
+            serde_json::Value::Null => true,
+            serde_json::Value::String(s) => s.is_empty(),
+            _ => false,
+        }
+    }
+
+    fn compact_value(value: serde_json::Value, recursive: bool) -> serde_json::Value {
+        let nested = |v: serde_json::Value| {
+            if recursive {
+                Self::compact_value(v, recursive)
+            } else {
+                v
+            }
+        };
+
+        match value {
+            serde_json::Value::Array(arr) => serde_json::Value::Array(
+                arr.into_iter()
+                    .filter(|v| !Self::is_empty(v))
+                    .map(nested)
+                    .collect(),
+            ),
+            serde_json::Value::Object(map) => serde_json::Value::Object(
+                map.into_iter()
+                    .filter(|(_, v)| !Self::is_empty(v))
+                    .map(|(k, v)| (k, nested(v)))
+                    .collect(),
+            ),
+            other => other,
+        }
+    }
+}
+
+impl FilterFunction for Compact {
+    const NAME: &'static str = "compact";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "compact",
+        category: "object",
+        description: "Remove null and empty-string elements from an array, or keys with null/empty-string values from an object",
+        arguments: &[
+            ArgumentMetadata {
+                name: "value",
+                arg_type: "array|object",
+                required: true,
+                default: None,
+                description: "The array or object to compact",
+            },
+            ArgumentMetadata {
+                name: "recursive",
+                arg_type: "boolean",
+                required: false,
+                default: Some("false"),
+                description: "Also compact nested arrays and objects",
+            },
+        ],
+        return_type: "array|object",
+        examples: &[
+            "{{ compact(value=[1, none, \"\", \"a\"]) }}",
+            "{{ config | compact | to_yaml }}",
+            "{{ config | compact(recursive=true) | to_yaml }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let value: Value = kwargs.get("value")?;
+        let recursive: Option<bool> = kwargs.get("recursive")?;
+
+        let json_value = value_to_json(&value, "compact")?;
+        Self::compute(json_value, recursive.unwrap_or(false))
+    }
+
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        let recursive: Option<bool> = kwargs.get("recursive")?;
+
+        let json_value = value_to_json(value, "compact")?;
+        Self::compute(json_value, recursive.unwrap_or(false))
+    }
+}
+
+// ============================================
+// Merge
+// ============================================
+
+/// Deep merge an overlay object into a base object.
+///
+/// Shares the recursive merge logic (and `list_strategy` option) with
+/// `object_merge`, but reads naturally in filter chains.
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ merge(object={"a": 1}, other={"b": 2}) }}
+/// {# Result: {"a": 1, "b": 2} #}
+/// ```
+///
+/// # Filter Syntax
+/// ```jinja
+/// {{ base | merge(other=env_overlay) | merge(other=local_overlay) }}
+/// {{ base | merge(other=overlay, list_strategy="append") }}
+/// ```
+pub struct Merge;
+
+impl Merge {
+    fn compute(base: &Value, other: &Value, list_strategy: Option<String>) -> Result<Value, Error> {
+        let list_strategy = match list_strategy {
+            Some(s) => ListStrategy::parse(&s)?,
+            None => ListStrategy::Replace,
+        };
+
+        let base = ensure_object(value_to_json(base, "merge")?, "merge")?;
+        let other = ensure_object(value_to_json(other, "merge")?, "merge")?;
+
+        let merged = merge_json_values(
+            serde_json::Value::Object(base),
+            serde_json::Value::Object(other),
+            list_strategy,
+        );
+
+        Ok(Value::from_serialize(&merged))
+    }
+}
+
+impl FilterFunction for Merge {
+    const NAME: &'static str = "merge";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "merge",
+        category: "object",
+        description: "Deep merge an overlay object into a base object (overlay values take precedence)",
+        arguments: &[
+            OBJECT_ARG,
+            ArgumentMetadata {
+                name: "other",
+                arg_type: "object",
+                required: true,
+                default: None,
+                description: "Overlay object (takes precedence)",
+            },
+            ArgumentMetadata {
+                name: "list_strategy",
+                arg_type: "string",
+                required: false,
+                default: Some("replace"),
+                description: "How nested arrays are merged: \"replace\", \"append\", or \"unique\"",
+            },
+        ],
+        return_type: "object",
+        examples: &[
+            "{{ merge(object={\"a\": 1}, other={\"b\": 2}) }}",
+            "{{ base | merge(other=overlay) | merge(other=local) }}",
+            "{{ base | merge(other=overlay, list_strategy=\"append\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+    const POSITIONAL_ARG: Option<&'static str> = Some("other");
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let object: Value = kwargs.get("object")?;
+        let other: Value = kwargs.get("other")?;
+        let list_strategy: Option<String> = kwargs.get("list_strategy")?;
+        Self::compute(&object, &other, list_strategy)
+    }
+
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        let other: Value = kwargs.get("other")?;
+        let list_strategy: Option<String> = kwargs.get("list_strategy")?;
+        Self::compute(value, &other, list_strategy)
+    }
+}
@@ -4,6 +4,7 @@
 //! ```jinja
 //! {{ to_json(object=config) }}
 //! {{ to_yaml(object=config) }}
+//! {{ to_env_file(object=config) }}
 //! {{ parse_json(string='{"key": "value"}') }}
 //! ```
 //!
@@ -11,6 +12,7 @@
 //! ```jinja
 //! {{ config | to_json }}
 //! {{ config | to_yaml }}
+//! {{ config | to_env_file }}
 //! {{ '{"key": "value"}' | parse_json }}
 //! ```
 //!
@@ -21,7 +23,7 @@
 
 use super::FilterFunction;
 use crate::functions::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
-use minijinja::value::Kwargs;
+use minijinja::value::{Kwargs, ValueKind};
 use minijinja::{Error, ErrorKind, Value};
 
 /// Common metadata for object argument (serialization)
@@ -42,6 +44,25 @@ const STRING_ARG: ArgumentMetadata = ArgumentMetadata {
     description: "The string to parse",
 };
 
+/// Metadata for the `sort_keys` argument of the parse functions
+const SORT_KEYS_ARG: ArgumentMetadata = ArgumentMetadata {
+    name: "sort_keys",
+    arg_type: "boolean",
+    required: false,
+    default: Some("false"),
+    description: "Sort object keys recursively (no-op: parsed objects are always sorted)",
+};
+
+/// Accept the `sort_keys` argument of the parse functions
+///
+/// Parsed data goes through `serde_json::Value`, whose maps are sorted, so
+/// keys come out recursively sorted either way. The argument is still read
+/// so templates that pass it are not rejected as having an unknown argument.
+fn accept_sort_keys(kwargs: &Kwargs) -> Result<(), Error> {
+    let _: Option<bool> = kwargs.get("sort_keys")?;
+    Ok(())
+}
+
 // ============================================
 // Serialization (Object -> String)
 // ============================================
@@ -243,6 +264,129 @@ impl FilterFunction for ToToml {
     }
 }
 
+/// Convert a flat object to systemd `EnvironmentFile` lines.
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ to_env_file(object=config) }}
+/// ```
+///
+/// # Filter Syntax
+/// ```jinja
+/// {{ config | to_env_file }}
+/// ```
+///
+/// # Note
+///
+/// Lines are `KEY=value` without `export`. Values are only double-quoted when
+/// systemd would otherwise change them (whitespace, quotes, backslashes, `$`,
+/// a leading `#`); inside quotes `"`, `\`, `` ` `` and `$` are escaped.
+pub struct ToEnvFile;
+
+impl ToEnvFile {
+    fn serialize(value: &Value) -> Result<String, Error> {
+        if value.kind() != ValueKind::Map {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                format!("to_env_file requires an object, found: {}", value.kind()),
+            ));
+        }
+
+        let mut output = String::new();
+        for key in value.try_iter()? {
+            let name = key.to_string();
+            if !Self::is_valid_name(&name) {
+                return Err(Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!("to_env_file: '{}' is not a valid variable name", name),
+                ));
+            }
+
+            let item = value.get_item(&key)?;
+            let text = match item.kind() {
+                ValueKind::Undefined | ValueKind::None => String::new(),
+                ValueKind::Map | ValueKind::Seq => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidOperation,
+                        format!(
+                            "to_env_file requires a flat object, but '{}' is {}",
+                            name,
+                            item.kind()
+                        ),
+                    ));
+                }
+                _ => item.to_string(),
+            };
+
+            output.push_str(&name);
+            output.push('=');
+            output.push_str(&Self::quote(&text));
+            output.push('\n');
+        }
+        Ok(output)
+    }
+
+    /// Variable names must match `[A-Za-z_][A-Za-z0-9_]*`
+    fn is_valid_name(name: &str) -> bool {
+        let mut chars = name.chars();
+        matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+    }
+
+    /// Double-quote a value only when systemd would not read it back verbatim
+    fn quote(value: &str) -> String {
+        let needs_quotes = value.starts_with('#')
+            || value
+                .chars()
+                .any(|c| c.is_whitespace() || matches!(c, '"' | '\'' | '\\' | '`' | '$'));
+        if !needs_quotes {
+            return value.to_string();
+        }
+
+        let mut quoted = String::with_capacity(value.len() + 2);
+        quoted.push('"');
+        for c in value.chars() {
+            if matches!(c, '"' | '\\' | '`' | '$') {
+                quoted.push('\\');
+            }
+            quoted.push(c);
+        }
+        quoted.push('"');
+        quoted
+    }
+}
+
+impl FilterFunction for ToEnvFile {
+    const NAME: &'static str = "to_env_file";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "to_env_file",
+        category: "serialization",
+        description: "Convert a flat object to systemd EnvironmentFile lines (KEY=value, no export, minimal quoting)",
+        arguments: &[ArgumentMetadata {
+            name: "object",
+            arg_type: "object",
+            required: true,
+            default: None,
+            description: "Flat object of variable names to scalar values",
+        }],
+        return_type: "string",
+        examples: &[
+            "{{ to_env_file(object={\"PORT\": 8080, \"GREETING\": \"hello world\"}) }}",
+            "{{ env_vars | to_env_file }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let object: Value = kwargs.get("object")?;
+        Ok(Value::from(Self::serialize(&object)?))
+    }
+
+    fn call_as_filter(value: &Value, _kwargs: Kwargs) -> Result<Value, Error> {
+        Ok(Value::from(Self::serialize(value)?))
+    }
+}
+
 // ============================================
 // Parsing (String -> Object)
 // ============================================
@@ -279,7 +423,7 @@ impl FilterFunction for ParseJson {
         name: "parse_json",
         category: "serialization",
         description: "Parse JSON string into object",
-        arguments: &[STRING_ARG],
+        arguments: &[STRING_ARG, SORT_KEYS_ARG],
         return_type: "any",
         examples: &[
             "{{ parse_json(string='{\"key\": \"value\"}') }}",
@@ -290,13 +434,15 @@ impl FilterFunction for ParseJson {
 
     fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
         let input: String = kwargs.get("string")?;
+        accept_sort_keys(&kwargs)?;
         Self::parse(&input)
     }
 
-    fn call_as_filter(value: &Value, _kwargs: Kwargs) -> Result<Value, Error> {
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
         let input = value.as_str().ok_or_else(|| {
             Error::new(ErrorKind::InvalidOperation, "parse_json requires a string")
         })?;
+        accept_sort_keys(&kwargs)?;
         Self::parse(input)
     }
 }
@@ -342,7 +488,7 @@ impl FilterFunction for ParseYaml {
         name: "parse_yaml",
         category: "serialization",
         description: "Parse YAML string into object",
-        arguments: &[STRING_ARG],
+        arguments: &[STRING_ARG, SORT_KEYS_ARG],
         return_type: "any",
         examples: &[
             "{{ parse_yaml(string='key: value') }}",
@@ -353,13 +499,15 @@ impl FilterFunction for ParseYaml {
 
     fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
         let input: String = kwargs.get("string")?;
+        accept_sort_keys(&kwargs)?;
         Self::parse(&input)
     }
 
-    fn call_as_filter(value: &Value, _kwargs: Kwargs) -> Result<Value, Error> {
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
         let input = value.as_str().ok_or_else(|| {
             Error::new(ErrorKind::InvalidOperation, "parse_yaml requires a string")
         })?;
+        accept_sort_keys(&kwargs)?;
         Self::parse(input)
     }
 }
@@ -405,7 +553,7 @@ impl FilterFunction for ParseToml {
         name: "parse_toml",
         category: "serialization",
         description: "Parse TOML string into object",
-        arguments: &[STRING_ARG],
+        arguments: &[STRING_ARG, SORT_KEYS_ARG],
         return_type: "any",
         examples: &[
             "{{ parse_toml(string='key = \"value\"') }}",
@@ -416,13 +564,15 @@ impl FilterFunction for ParseToml {
 
     fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
         let input: String = kwargs.get("string")?;
+        accept_sort_keys(&kwargs)?;
         Self::parse(&input)
     }
 
-    fn call_as_filter(value: &Value, _kwargs: Kwargs) -> Result<Value, Error> {
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
         let input = value.as_str().ok_or_else(|| {
             Error::new(ErrorKind::InvalidOperation, "parse_toml requires a string")
         })?;
+        accept_sort_keys(&kwargs)?;
         Self::parse(input)
     }
 }
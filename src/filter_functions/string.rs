@@ -3,6 +3,7 @@
 //! # Function Syntax
 //! ```jinja
 //! {{ regex_replace(string="hello123", pattern="[0-9]+", replacement="-") }}
+//! {{ replace(string="a-b-c", from="-", to="_", count=1) }}
 //! {{ truncate(string="Hello World", length=8) }}
 //! {{ word_count(string="Hello World") }}
 //! ```
@@ -25,6 +26,8 @@ use crate::functions::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVaria
 use minijinja::value::Kwargs;
 use minijinja::{Environment, Error, ErrorKind, Value};
 use regex::Regex;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 /// Common metadata for string argument
 const STRING_ARG: ArgumentMetadata = ArgumentMetadata {
@@ -35,6 +38,45 @@ const STRING_ARG: ArgumentMetadata = ArgumentMetadata {
     description: "The string to process",
 };
 
+/// Metadata for the `graphemes` argument of position-based functions
+const GRAPHEMES_ARG: ArgumentMetadata = ArgumentMetadata {
+    name: "graphemes",
+    arg_type: "boolean",
+    required: false,
+    default: Some("false"),
+    description: "Count user-perceived characters (grapheme clusters) instead of Unicode scalar values",
+};
+
+/// Metadata for the `width_aware` argument of padding functions
+const WIDTH_AWARE_ARG: ArgumentMetadata = ArgumentMetadata {
+    name: "width_aware",
+    arg_type: "boolean",
+    required: false,
+    default: Some("false"),
+    description: "Measure display columns (wide CJK and emoji characters count as 2) instead of characters",
+};
+
+/// Helper to measure a string in characters or display columns
+fn text_len(input: &str, width_aware: bool) -> usize {
+    if width_aware {
+        input.width()
+    } else {
+        input.chars().count()
+    }
+}
+
+/// Helper to split a string into characters or grapheme clusters
+fn split_units(input: &str, graphemes: bool) -> Vec<&str> {
+    if graphemes {
+        input.graphemes(true).collect()
+    } else {
+        input
+            .char_indices()
+            .map(|(i, c)| &input[i..i + c.len_utf8()])
+            .collect()
+    }
+}
+
 /// Helper to extract string from Value
 fn extract_string(value: &Value, fn_name: &str) -> Result<String, Error> {
     value.as_str().map(|s| s.to_string()).ok_or_else(|| {
@@ -121,6 +163,177 @@ impl FilterFunction for RegexReplace {
     }
 }
 
+// ============================================
+// Replace
+// ============================================
+
+/// Replace occurrences of a substring or regex, optionally only the first N.
+///
+/// Overrides MiniJinja's built-in `replace` filter while staying compatible
+/// with its positional form.
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ replace(string="a-b-c", from="-", to="_", count=1) }}
+/// ```
+///
+/// # Filter Syntax
+/// ```jinja
+/// {{ "a-b-c" | replace("-", "_") }}
+/// {{ "v1.2.3" | replace(from="[0-9]+", to="N", regex=true) }}
+/// ```
+pub struct Replace;
+
+impl Replace {
+    fn compute(
+        input: &str,
+        from: &str,
+        to: &str,
+        count: Option<i64>,
+        regex: bool,
+    ) -> Result<String, Error> {
+        // Negative (or missing) count replaces every occurrence
+        let limit = count.and_then(|n| usize::try_from(n).ok());
+        if regex {
+            let re = Regex::new(from).map_err(|e| {
+                Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!("replace: invalid regex pattern '{}': {}", from, e),
+                )
+            })?;
+            Ok(match limit {
+                Some(0) => input.to_string(),
+                Some(n) => re.replacen(input, n, to).to_string(),
+                None => re.replace_all(input, to).to_string(),
+            })
+        } else {
+            Ok(match limit {
+                Some(n) => input.replacen(from, to, n),
+                None => input.replace(from, to),
+            })
+        }
+    }
+
+    /// Read `from`/`to`, preferring positional values over keyword arguments
+    fn required(positional: Option<String>, kwargs: &Kwargs, name: &str) -> Result<String, Error> {
+        match positional {
+            Some(value) => Ok(value),
+            None => kwargs.get(name),
+        }
+    }
+}
+
+impl FilterFunction for Replace {
+    const NAME: &'static str = "replace";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "replace",
+        category: "string",
+        description: "Replace occurrences of a substring (or regex), optionally only the first N",
+        arguments: &[
+            STRING_ARG,
+            ArgumentMetadata {
+                name: "from",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Substring to find (a regex pattern when regex=true)",
+            },
+            ArgumentMetadata {
+                name: "to",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Replacement (may use $1 / ${name} groups when regex=true)",
+            },
+            ArgumentMetadata {
+                name: "count",
+                arg_type: "integer",
+                required: false,
+                default: Some("-1"),
+                description: "Maximum number of replacements; negative replaces all",
+            },
+            ArgumentMetadata {
+                name: "regex",
+                arg_type: "boolean",
+                required: false,
+                default: Some("false"),
+                description: "Treat from as a regex pattern",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ replace(string=\"a-b-c\", from=\"-\", to=\"_\", count=1) }}",
+            "{{ \"a-b-c\" | replace(\"-\", \"_\") }}",
+            "{{ version | replace(from=\"^v\", to=\"\", regex=true) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let string: String = kwargs.get("string")?;
+        let from: String = kwargs.get("from")?;
+        let to: String = kwargs.get("to")?;
+        let count: Option<i64> = kwargs.get("count")?;
+        let regex: Option<bool> = kwargs.get("regex")?;
+        Ok(Value::from(Self::compute(
+            &string,
+            &from,
+            &to,
+            count,
+            regex.unwrap_or(false),
+        )?))
+    }
+
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        let string = extract_string(value, "replace")?;
+        let from: String = kwargs.get("from")?;
+        let to: String = kwargs.get("to")?;
+        let count: Option<i64> = kwargs.get("count")?;
+        let regex: Option<bool> = kwargs.get("regex")?;
+        Ok(Value::from(Self::compute(
+            &string,
+            &from,
+            &to,
+            count,
+            regex.unwrap_or(false),
+        )?))
+    }
+
+    /// Custom registration to keep the built-in positional form: `| replace("a", "b", 1)`
+    fn register(env: &mut Environment) {
+        env.add_function(Self::NAME, Self::call_as_function);
+        env.add_filter(
+            Self::NAME,
+            |value: &Value,
+             from: Option<String>,
+             to: Option<String>,
+             count: Option<i64>,
+             kwargs: Kwargs|
+             -> Result<Value, Error> {
+                // Like the built-in filter, non-string values are replaced in their string form
+                let string = match value.as_str() {
+                    Some(s) => s.to_string(),
+                    None => value.to_string(),
+                };
+                let from = Self::required(from, &kwargs, "from")?;
+                let to = Self::required(to, &kwargs, "to")?;
+                let count = match count {
+                    Some(count) => Some(count),
+                    None => kwargs.get("count")?,
+                };
+                let regex: Option<bool> = kwargs.get("regex")?;
+                Ok(Value::from(Self::compute(
+                    &string,
+                    &from,
+                    &to,
+                    count,
+                    regex.unwrap_or(false),
+                )?))
+            },
+        );
+    }
+}
+
 // ============================================
 // Substring
 // ============================================
@@ -140,23 +353,23 @@ impl FilterFunction for RegexReplace {
 pub struct Substring;
 
 impl Substring {
-    fn compute(input: &str, start: i64, length: Option<usize>) -> String {
-        let chars: Vec<char> = input.chars().collect();
-        let len = chars.len() as i64;
+    fn compute(input: &str, start: i64, length: Option<usize>, graphemes: bool) -> String {
+        let units = split_units(input, graphemes);
+        let len = units.len() as i64;
 
         // Handle negative start (count from end)
         let start_idx = if start < 0 {
             (len + start).max(0) as usize
         } else {
-            (start as usize).min(chars.len())
+            (start as usize).min(units.len())
         };
 
         let end_idx = match length {
-            Some(l) => (start_idx + l).min(chars.len()),
-            None => chars.len(),
+            Some(l) => (start_idx + l).min(units.len()),
+            None => units.len(),
         };
 
-        chars[start_idx..end_idx].iter().collect()
+        units[start_idx..end_idx].concat()
     }
 }
 
@@ -182,11 +395,13 @@ impl FilterFunction for Substring {
                 default: None,
                 description: "Number of characters to extract",
             },
+            GRAPHEMES_ARG,
         ],
         return_type: "string",
         examples: &[
             "{{ substring(string=\"hello world\", start=0, length=5) }}",
             "{{ \"hello world\" | substring(start=-5) }}",
+            "{{ title | substring(start=0, length=10, graphemes=true) }}",
         ],
         syntax: SyntaxVariants::FUNCTION_AND_FILTER,
     };
@@ -195,14 +410,26 @@ impl FilterFunction for Substring {
         let string: String = kwargs.get("string")?;
         let start: i64 = kwargs.get("start")?;
         let length: Option<usize> = kwargs.get("length")?;
-        Ok(Value::from(Self::compute(&string, start, length)))
+        let graphemes: Option<bool> = kwargs.get("graphemes")?;
+        Ok(Value::from(Self::compute(
+            &string,
+            start,
+            length,
+            graphemes.unwrap_or(false),
+        )))
     }
 
     fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
         let string = extract_string(value, "substring")?;
         let start: i64 = kwargs.get("start")?;
         let length: Option<usize> = kwargs.get("length")?;
-        Ok(Value::from(Self::compute(&string, start, length)))
+        let graphemes: Option<bool> = kwargs.get("graphemes")?;
+        Ok(Value::from(Self::compute(
+            &string,
+            start,
+            length,
+            graphemes.unwrap_or(false),
+        )))
     }
 }
 
@@ -222,24 +449,25 @@ impl FilterFunction for Substring {
 /// ```jinja
 /// {{ "Hello World" | truncate(length=8) }}
 /// {{ text | truncate(length=100, suffix="...") }}
+/// {{ title | truncate(length=20, graphemes=true) }}
 /// ```
 pub struct Truncate;
 
 impl Truncate {
-    fn compute(input: &str, length: usize, suffix: &str) -> String {
-        let chars: Vec<char> = input.chars().collect();
+    fn compute(input: &str, length: usize, suffix: &str, graphemes: bool) -> String {
+        let units = split_units(input, graphemes);
 
-        if chars.len() <= length {
+        if units.len() <= length {
             return input.to_string();
         }
 
-        let suffix_len = suffix.chars().count();
+        let suffix_len = split_units(suffix, graphemes).len();
         if length <= suffix_len {
             return suffix.to_string();
         }
 
         let truncate_at = length - suffix_len;
-        chars[..truncate_at].iter().collect::<String>() + suffix
+        units[..truncate_at].concat() + suffix
     }
 }
 
@@ -265,27 +493,42 @@ impl FilterFunction for Truncate {
                 default: Some("..."),
                 description: "Suffix to append when truncated",
             },
+            GRAPHEMES_ARG,
         ],
         return_type: "string",
         examples: &[
             "{{ truncate(string=\"Hello World\", length=8) }}",
             "{{ \"Hello World\" | truncate(length=8, suffix=\">>\") }}",
+            "{{ title | truncate(length=20, graphemes=true) }}",
         ],
         syntax: SyntaxVariants::FUNCTION_AND_FILTER,
     };
+    const POSITIONAL_ARG: Option<&'static str> = Some("length");
 
     fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
         let string: String = kwargs.get("string")?;
         let length: usize = kwargs.get("length")?;
         let suffix: String = kwargs.get("suffix").unwrap_or_else(|_| "...".to_string());
-        Ok(Value::from(Self::compute(&string, length, &suffix)))
+        let graphemes: Option<bool> = kwargs.get("graphemes")?;
+        Ok(Value::from(Self::compute(
+            &string,
+            length,
+            &suffix,
+            graphemes.unwrap_or(false),
+        )))
     }
 
     fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
         let string = extract_string(value, "truncate")?;
         let length: usize = kwargs.get("length")?;
         let suffix: String = kwargs.get("suffix").unwrap_or_else(|_| "...".to_string());
-        Ok(Value::from(Self::compute(&string, length, &suffix)))
+        let graphemes: Option<bool> = kwargs.get("graphemes")?;
+        Ok(Value::from(Self::compute(
+            &string,
+            length,
+            &suffix,
+            graphemes.unwrap_or(false),
+        )))
     }
 }
 
@@ -496,6 +739,7 @@ impl FilterFunction for Wrap {
         ],
         syntax: SyntaxVariants::FUNCTION_AND_FILTER,
     };
+    const POSITIONAL_ARG: Option<&'static str> = Some("width");
 
     fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
         let string: String = kwargs.get("string")?;
@@ -532,8 +776,8 @@ impl FilterFunction for Wrap {
 pub struct Center;
 
 impl Center {
-    fn compute(input: &str, width: usize, pad_char: char) -> String {
-        let str_len = input.chars().count();
+    fn compute(input: &str, width: usize, pad_char: char, width_aware: bool) -> String {
+        let str_len = text_len(input, width_aware);
 
         if str_len >= width {
             return input.to_string();
@@ -574,21 +818,30 @@ impl FilterFunction for Center {
                 default: Some(" "),
                 description: "Padding character",
             },
+            WIDTH_AWARE_ARG,
         ],
         return_type: "string",
         examples: &[
             "{{ center(string=\"hello\", width=11) }}",
             "{{ \"hello\" | center(width=11, char=\"-\") }}",
+            "{{ \"日本\" | center(width=8, width_aware=true) }}",
         ],
         syntax: SyntaxVariants::FUNCTION_AND_FILTER,
     };
+    const POSITIONAL_ARG: Option<&'static str> = Some("width");
 
     fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
         let string: String = kwargs.get("string")?;
         let width: usize = kwargs.get("width")?;
         let pad_char: Option<String> = kwargs.get("char")?;
         let pad_char = pad_char.and_then(|s| s.chars().next()).unwrap_or(' ');
-        Ok(Value::from(Self::compute(&string, width, pad_char)))
+        let width_aware: Option<bool> = kwargs.get("width_aware")?;
+        Ok(Value::from(Self::compute(
+            &string,
+            width,
+            pad_char,
+            width_aware.unwrap_or(false),
+        )))
     }
 
     fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
@@ -596,7 +849,13 @@ impl FilterFunction for Center {
         let width: usize = kwargs.get("width")?;
         let pad_char: Option<String> = kwargs.get("char")?;
         let pad_char = pad_char.and_then(|s| s.chars().next()).unwrap_or(' ');
-        Ok(Value::from(Self::compute(&string, width, pad_char)))
+        let width_aware: Option<bool> = kwargs.get("width_aware")?;
+        Ok(Value::from(Self::compute(
+            &string,
+            width,
+            pad_char,
+            width_aware.unwrap_or(false),
+        )))
     }
 }
 
@@ -765,17 +1024,26 @@ impl FilterFunction for NormalizeWhitespace {
 /// ```jinja
 /// {{ slugify(string="Hello World!") }}
 /// {# Output: hello-world #}
+/// {{ slugify(string="Café Olé", transliterate=true) }}
+/// {# Output: cafe-ole #}
 /// ```
 ///
 /// # Filter Syntax
 /// ```jinja
 /// {{ "Hello World!" | slugify }}
-/// {{ title | slugify }}
+/// {{ title | slugify(transliterate=true) }}
 /// ```
 pub struct Slugify;
 
 impl Slugify {
-    fn compute(input: &str) -> String {
+    fn compute(input: &str, transliterate: bool) -> String {
+        // Map accented and other non-ASCII characters to ASCII first
+        let input = if transliterate {
+            deunicode::deunicode(input)
+        } else {
+            input.to_string()
+        };
+
         input
             .to_lowercase()
             .chars()
@@ -804,23 +1072,110 @@ impl FilterFunction for Slugify {
         name: "slugify",
         category: "string",
         description: "Convert string to URL-friendly slug",
-        arguments: &[STRING_ARG],
+        arguments: &[
+            STRING_ARG,
+            ArgumentMetadata {
+                name: "transliterate",
+                arg_type: "boolean",
+                required: false,
+                default: Some("false"),
+                description: "Convert accented and non-ASCII characters to ASCII (e.g., é -> e) instead of dropping them",
+            },
+        ],
         return_type: "string",
         examples: &[
             "{{ slugify(string=\"Hello World!\") }}",
             "{{ title | slugify }}",
+            "{{ slugify(string=\"Café Olé\", transliterate=true) }}",
         ],
         syntax: SyntaxVariants::FUNCTION_AND_FILTER,
     };
 
     fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
         let string: String = kwargs.get("string")?;
-        Ok(Value::from(Self::compute(&string)))
+        let transliterate: Option<bool> = kwargs.get("transliterate")?;
+        Ok(Value::from(Self::compute(
+            &string,
+            transliterate.unwrap_or(false),
+        )))
     }
 
-    fn call_as_filter(value: &Value, _kwargs: Kwargs) -> Result<Value, Error> {
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
         let string = extract_string(value, "slugify")?;
-        Ok(Value::from(Self::compute(&string)))
+        let transliterate: Option<bool> = kwargs.get("transliterate")?;
+        Ok(Value::from(Self::compute(
+            &string,
+            transliterate.unwrap_or(false),
+        )))
+    }
+}
+
+// ============================================
+// ToAscii
+// ============================================
+
+/// Transliterate a whole string to ASCII, keeping case, spacing and punctuation.
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ to_ascii(string="Crème Brûlée") }}
+/// {# Output: Creme Brulee #}
+/// ```
+///
+/// # Filter Syntax
+/// ```jinja
+/// {{ label | to_ascii }}
+/// {{ "Ελλάδα" | to_ascii }}
+/// ```
+pub struct ToAscii;
+
+impl ToAscii {
+    fn compute(input: &str, unknown: &str) -> String {
+        deunicode::deunicode_with_tofu(input, unknown)
+    }
+}
+
+impl FilterFunction for ToAscii {
+    const NAME: &'static str = "to_ascii";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "to_ascii",
+        category: "string",
+        description: "Transliterate a string to ASCII (e.g., é -> e, Ж -> Zh) without changing case or separators",
+        arguments: &[
+            STRING_ARG,
+            ArgumentMetadata {
+                name: "unknown",
+                arg_type: "string",
+                required: false,
+                default: Some("?"),
+                description: "Replacement for characters that have no ASCII transliteration",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ to_ascii(string=\"Crème Brûlée\") }}",
+            "{{ label | to_ascii }}",
+            "{{ name | to_ascii(unknown=\"\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let string: String = kwargs.get("string")?;
+        let unknown: Option<String> = kwargs.get("unknown")?;
+        Ok(Value::from(Self::compute(
+            &string,
+            unknown.as_deref().unwrap_or("?"),
+        )))
+    }
+
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        let string = extract_string(value, "to_ascii")?;
+        let unknown: Option<String> = kwargs.get("unknown")?;
+        Ok(Value::from(Self::compute(
+            &string,
+            unknown.as_deref().unwrap_or("?"),
+        )))
     }
 }
 
@@ -884,6 +1239,7 @@ impl FilterFunction for Indent {
         ],
         syntax: SyntaxVariants::FUNCTION_AND_FILTER,
     };
+    const POSITIONAL_ARG: Option<&'static str> = Some("spaces");
 
     fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
         let string: String = kwargs.get("string")?;
@@ -896,19 +1252,6 @@ impl FilterFunction for Indent {
         let spaces: usize = kwargs.get("spaces").unwrap_or(4);
         Ok(Value::from(Self::compute(&string, spaces)))
     }
-
-    /// Custom registration to support positional argument: `| indent(4)`
-    fn register(env: &mut Environment) {
-        env.add_function(Self::NAME, Self::call_as_function);
-        // Register filter with optional positional argument for spaces
-        env.add_filter(
-            Self::NAME,
-            |value: &Value, spaces: Option<usize>| -> Result<Value, Error> {
-                let string = extract_string(value, "indent")?;
-                Ok(Value::from(Self::compute(&string, spaces.unwrap_or(4))))
-            },
-        );
-    }
 }
 
 // ============================================
@@ -1049,6 +1392,7 @@ impl FilterFunction for Quote {
         ],
         syntax: SyntaxVariants::FUNCTION_AND_FILTER,
     };
+    const POSITIONAL_ARG: Option<&'static str> = Some("style");
 
     fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
         let string: String = kwargs.get("string")?;
@@ -1415,8 +1759,8 @@ impl FilterFunction for ToKebabCase {
 pub struct PadLeft;
 
 impl PadLeft {
-    fn compute(input: &str, length: usize, pad_char: char) -> String {
-        let current_len = input.chars().count();
+    fn compute(input: &str, length: usize, pad_char: char, width_aware: bool) -> String {
+        let current_len = text_len(input, width_aware);
         if current_len >= length {
             return input.to_string();
         }
@@ -1448,21 +1792,30 @@ impl FilterFunction for PadLeft {
                 default: Some(" "),
                 description: "Padding character",
             },
+            WIDTH_AWARE_ARG,
         ],
         return_type: "string",
         examples: &[
             "{{ pad_left(string=\"5\", length=3, char=\"0\") }}",
             "{{ \"5\" | pad_left(length=3, char=\"0\") }}",
+            "{{ name | pad_left(length=20, width_aware=true) }}",
         ],
         syntax: SyntaxVariants::FUNCTION_AND_FILTER,
     };
+    const POSITIONAL_ARG: Option<&'static str> = Some("length");
 
     fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
         let string: String = kwargs.get("string")?;
         let length: usize = kwargs.get("length")?;
         let pad_char: Option<String> = kwargs.get("char")?;
         let pad_char = pad_char.and_then(|s| s.chars().next()).unwrap_or(' ');
-        Ok(Value::from(Self::compute(&string, length, pad_char)))
+        let width_aware: Option<bool> = kwargs.get("width_aware")?;
+        Ok(Value::from(Self::compute(
+            &string,
+            length,
+            pad_char,
+            width_aware.unwrap_or(false),
+        )))
     }
 
     fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
@@ -1470,7 +1823,13 @@ impl FilterFunction for PadLeft {
         let length: usize = kwargs.get("length")?;
         let pad_char: Option<String> = kwargs.get("char")?;
         let pad_char = pad_char.and_then(|s| s.chars().next()).unwrap_or(' ');
-        Ok(Value::from(Self::compute(&string, length, pad_char)))
+        let width_aware: Option<bool> = kwargs.get("width_aware")?;
+        Ok(Value::from(Self::compute(
+            &string,
+            length,
+            pad_char,
+            width_aware.unwrap_or(false),
+        )))
     }
 }
 
@@ -1494,8 +1853,8 @@ impl FilterFunction for PadLeft {
 pub struct PadRight;
 
 impl PadRight {
-    fn compute(input: &str, length: usize, pad_char: char) -> String {
-        let current_len = input.chars().count();
+    fn compute(input: &str, length: usize, pad_char: char, width_aware: bool) -> String {
+        let current_len = text_len(input, width_aware);
         if current_len >= length {
             return input.to_string();
         }
@@ -1527,21 +1886,30 @@ impl FilterFunction for PadRight {
                 default: Some(" "),
                 description: "Padding character",
             },
+            WIDTH_AWARE_ARG,
         ],
         return_type: "string",
         examples: &[
             "{{ pad_right(string=\"5\", length=3, char=\"0\") }}",
             "{{ \"5\" | pad_right(length=3, char=\"0\") }}",
+            "{{ name | pad_right(length=20, width_aware=true) }}",
         ],
         syntax: SyntaxVariants::FUNCTION_AND_FILTER,
     };
+    const POSITIONAL_ARG: Option<&'static str> = Some("length");
 
     fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
         let string: String = kwargs.get("string")?;
         let length: usize = kwargs.get("length")?;
         let pad_char: Option<String> = kwargs.get("char")?;
         let pad_char = pad_char.and_then(|s| s.chars().next()).unwrap_or(' ');
-        Ok(Value::from(Self::compute(&string, length, pad_char)))
+        let width_aware: Option<bool> = kwargs.get("width_aware")?;
+        Ok(Value::from(Self::compute(
+            &string,
+            length,
+            pad_char,
+            width_aware.unwrap_or(false),
+        )))
     }
 
     fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
@@ -1549,7 +1917,134 @@ impl FilterFunction for PadRight {
         let length: usize = kwargs.get("length")?;
         let pad_char: Option<String> = kwargs.get("char")?;
         let pad_char = pad_char.and_then(|s| s.chars().next()).unwrap_or(' ');
-        Ok(Value::from(Self::compute(&string, length, pad_char)))
+        let width_aware: Option<bool> = kwargs.get("width_aware")?;
+        Ok(Value::from(Self::compute(
+            &string,
+            length,
+            pad_char,
+            width_aware.unwrap_or(false),
+        )))
+    }
+}
+
+// ============================================
+// Pad
+// ============================================
+
+/// Pad string to a minimum length on the chosen side(s).
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ pad(string="5", length=3, char="0") }}
+/// {# Output: 005 #}
+/// {{ pad(string="ab", length=5, char="*", side="both") }}
+/// {# Output: *ab** #}
+/// ```
+///
+/// # Filter Syntax
+/// ```jinja
+/// {{ "5" | pad(length=3, char="0") }}
+/// {{ name | pad(length=20, side="right") }}
+/// ```
+pub struct Pad;
+
+impl Pad {
+    fn compute(
+        input: &str,
+        length: usize,
+        pad_char: char,
+        side: &str,
+        width_aware: bool,
+    ) -> Result<String, Error> {
+        let current_len = text_len(input, width_aware);
+        let total_padding = length.saturating_sub(current_len);
+        let (left, right) = match side {
+            "left" => (total_padding, 0),
+            "right" => (0, total_padding),
+            // Odd padding puts the extra character on the right
+            "both" => (total_padding / 2, total_padding - total_padding / 2),
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!("pad: invalid side '{}'. Use: left, right, both", side),
+                ));
+            }
+        };
+
+        Ok(format!(
+            "{}{}{}",
+            pad_char.to_string().repeat(left),
+            input,
+            pad_char.to_string().repeat(right)
+        ))
+    }
+
+    /// Read padding options shared by both syntaxes and pad the string
+    fn call_with(string: &str, kwargs: &Kwargs) -> Result<Value, Error> {
+        let length: usize = kwargs.get("length")?;
+        let pad_char: Option<String> = kwargs.get("char")?;
+        let pad_char = pad_char.and_then(|s| s.chars().next()).unwrap_or(' ');
+        let side: Option<String> = kwargs.get("side")?;
+        let width_aware: Option<bool> = kwargs.get("width_aware")?;
+        Ok(Value::from(Self::compute(
+            string,
+            length,
+            pad_char,
+            side.as_deref().unwrap_or("left"),
+            width_aware.unwrap_or(false),
+        )?))
+    }
+}
+
+impl FilterFunction for Pad {
+    const NAME: &'static str = "pad";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "pad",
+        category: "string",
+        description: "Pad string to a minimum length on the left, right, or both sides",
+        arguments: &[
+            STRING_ARG,
+            ArgumentMetadata {
+                name: "length",
+                arg_type: "integer",
+                required: true,
+                default: None,
+                description: "Minimum total length",
+            },
+            ArgumentMetadata {
+                name: "char",
+                arg_type: "string",
+                required: false,
+                default: Some(" "),
+                description: "Padding character",
+            },
+            ArgumentMetadata {
+                name: "side",
+                arg_type: "string",
+                required: false,
+                default: Some("left"),
+                description: "Where to pad: \"left\", \"right\", or \"both\" (extra character goes right)",
+            },
+            WIDTH_AWARE_ARG,
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ pad(string=\"5\", length=3, char=\"0\") }}",
+            "{{ name | pad(length=20, side=\"right\") }}",
+            "{{ \"ab\" | pad(length=6, char=\"*\", side=\"both\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+    const POSITIONAL_ARG: Option<&'static str> = Some("length");
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let string: String = kwargs.get("string")?;
+        Self::call_with(&string, &kwargs)
+    }
+
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        let string = extract_string(value, "pad")?;
+        Self::call_with(&string, &kwargs)
     }
 }
 
@@ -1601,6 +2096,7 @@ impl FilterFunction for Repeat {
         ],
         syntax: SyntaxVariants::FUNCTION_AND_FILTER,
     };
+    const POSITIONAL_ARG: Option<&'static str> = Some("count");
 
     fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
         let string: String = kwargs.get("string")?;
@@ -1665,3 +2161,794 @@ impl FilterFunction for Reverse {
         Ok(Value::from(Self::compute(&string)))
     }
 }
+
+// ============================================
+// StringWidth
+// ============================================
+
+/// Get the display width of a string in terminal columns.
+///
+/// Wide characters (CJK, most emoji) count as 2 columns, zero-width
+/// characters such as combining marks count as 0.
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ string_width(string="日本語") }}
+/// {# Output: 6 #}
+/// ```
+///
+/// # Filter Syntax
+/// ```jinja
+/// {{ "hello" | string_width }}
+/// {{ name | string_width }}
+/// ```
+pub struct StringWidth;
+
+impl FilterFunction for StringWidth {
+    const NAME: &'static str = "string_width";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "string_width",
+        category: "string",
+        description: "Get the display width of a string in columns (wide CJK and emoji characters count as 2)",
+        arguments: &[STRING_ARG],
+        return_type: "integer",
+        examples: &[
+            "{{ string_width(string=\"日本語\") }}",
+            "{{ name | string_width }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let string: String = kwargs.get("string")?;
+        Ok(Value::from(string.width()))
+    }
+
+    fn call_as_filter(value: &Value, _kwargs: Kwargs) -> Result<Value, Error> {
+        let string = extract_string(value, "string_width")?;
+        Ok(Value::from(string.width()))
+    }
+}
+
+// ============================================
+// Comment
+// ============================================
+
+/// Turn text into a source code comment.
+///
+/// Line styles (`//`, `#`) prefix every line; block styles (`/* */`,
+/// `<!-- -->`) wrap the text in a single block with one line per input line;
+/// a closer in the text (`*/`, `-->`) is broken up (`* /`, `-- >`).
+/// With `width`, long lines are word-wrapped so that the commented lines
+/// (including the comment prefix) fit within that many characters.
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ comment(string="Generated file - do not edit", style="#") }}
+/// {# Output: # Generated file - do not edit #}
+/// ```
+///
+/// # Filter Syntax
+/// ```jinja
+/// {{ header | comment(style="/* */", width=80) }}
+/// ```
+pub struct Comment;
+
+impl Comment {
+    /// Supported styles, as listed in error messages
+    const STYLES: &'static str = "//, #, /* */, <!-- -->";
+
+    pub(crate) fn compute(input: &str, style: &str, width: Option<usize>) -> Result<String, Error> {
+        // (opening line, per-line prefix, closing line, (closer, escaped closer))
+        let (open, prefix, close, escape) = match style {
+            "//" => (None, "// ", None, None),
+            "#" => (None, "# ", None, None),
+            "/* */" => (Some("/*"), " * ", Some(" */"), Some(("*/", "* /"))),
+            "<!-- -->" => (Some("<!--"), "  ", Some("-->"), Some(("-->", "-- >"))),
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!(
+                        "comment: unknown style '{}' (expected one of: {})",
+                        style,
+                        Self::STYLES
+                    ),
+                ));
+            }
+        };
+
+        // A closer inside the text would end the block comment early
+        let input = match escape {
+            Some((closer, escaped)) => input.replace(closer, escaped),
+            None => input.to_string(),
+        };
+
+        let lines: Vec<String> = match width {
+            Some(width) => {
+                let prefix_len = prefix.chars().count();
+                if width <= prefix_len {
+                    return Err(Error::new(
+                        ErrorKind::InvalidOperation,
+                        format!("comment: width must be greater than {}", prefix_len),
+                    ));
+                }
+                let mut wrapped = Vec::new();
+                for line in input.lines() {
+                    if line.trim().is_empty() {
+                        wrapped.push(String::new());
+                    } else {
+                        let text = Wrap::compute(line, width - prefix_len, "")?;
+                        wrapped.extend(text.lines().map(str::to_string));
+                    }
+                }
+                wrapped
+            }
+            None => input.lines().map(str::to_string).collect(),
+        };
+
+        let mut result = Vec::with_capacity(lines.len() + 2);
+        result.extend(open.map(str::to_string));
+        for line in &lines {
+            if line.is_empty() {
+                // Avoid trailing whitespace on blank comment lines
+                result.push(prefix.trim_end().to_string());
+            } else {
+                result.push(format!("{}{}", prefix, line));
+            }
+        }
+        result.extend(close.map(str::to_string));
+
+        Ok(result.join("\n"))
+    }
+}
+
+impl FilterFunction for Comment {
+    const NAME: &'static str = "comment";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "comment",
+        category: "string",
+        description: "Turn text into a source code comment, optionally word-wrapped to a width",
+        arguments: &[
+            STRING_ARG,
+            ArgumentMetadata {
+                name: "style",
+                arg_type: "string",
+                required: false,
+                default: Some("#"),
+                description: "Comment syntax: \"//\", \"#\", \"/* */\", or \"<!-- -->\"",
+            },
+            ArgumentMetadata {
+                name: "width",
+                arg_type: "integer",
+                required: false,
+                default: None,
+                description: "Maximum line width including the comment prefix (no wrapping if omitted)",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ comment(string=\"Generated file - do not edit\", style=\"//\") }}",
+            "{{ header | comment(style=\"/* */\", width=80) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let string: String = kwargs.get("string")?;
+        let style: Option<String> = kwargs.get("style")?;
+        let width: Option<usize> = kwargs.get("width")?;
+        Ok(Value::from(Self::compute(
+            &string,
+            style.as_deref().unwrap_or("#"),
+            width,
+        )?))
+    }
+
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        let string = extract_string(value, "comment")?;
+        let style: Option<String> = kwargs.get("style")?;
+        let width: Option<usize> = kwargs.get("width")?;
+        Ok(Value::from(Self::compute(
+            &string,
+            style.as_deref().unwrap_or("#"),
+            width,
+        )?))
+    }
+}
+
+// ============================================
+// CodeBlock
+// ============================================
+
+/// Wrap text in a Markdown fenced code block.
+///
+/// The fence is three backticks, or one more than the longest run of
+/// backticks in the content, so embedded fences cannot close the block early.
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ code_block(string=read_file(path="config.yaml"), lang="yaml") }}
+/// ```
+///
+/// # Filter Syntax
+/// ```jinja
+/// {{ snippet | code_block(lang="toml") }}
+/// ```
+pub struct CodeBlock;
+
+impl CodeBlock {
+    fn compute(input: &str, lang: &str) -> Result<String, Error> {
+        if lang.contains('`') || lang.contains('\n') {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                format!(
+                    "code_block: lang must not contain backticks or newlines, found: '{}'",
+                    lang
+                ),
+            ));
+        }
+
+        let longest_run = input.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+        let fence = "`".repeat((longest_run + 1).max(3));
+        // A single trailing newline would otherwise leave a blank line before the closing fence
+        let body = input.strip_suffix('\n').unwrap_or(input);
+
+        Ok(format!("{}{}\n{}\n{}", fence, lang.trim(), body, fence))
+    }
+}
+
+impl FilterFunction for CodeBlock {
+    const NAME: &'static str = "code_block";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "code_block",
+        category: "string",
+        description: "Wrap text in a Markdown fenced code block, lengthening the fence if the text contains backticks",
+        arguments: &[
+            STRING_ARG,
+            ArgumentMetadata {
+                name: "lang",
+                arg_type: "string",
+                required: false,
+                default: Some("\"\""),
+                description: "Language tag written after the opening fence (e.g. \"yaml\")",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ code_block(string=\"port: 8080\", lang=\"yaml\") }}",
+            "{{ read_file(path=\"Cargo.toml\") | code_block(lang=\"toml\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+    const POSITIONAL_ARG: Option<&'static str> = Some("lang");
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let string: String = kwargs.get("string")?;
+        let lang: Option<String> = kwargs.get("lang")?;
+        Ok(Value::from(Self::compute(
+            &string,
+            lang.as_deref().unwrap_or(""),
+        )?))
+    }
+
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        let string = extract_string(value, "code_block")?;
+        let lang: Option<String> = kwargs.get("lang")?;
+        Ok(Value::from(Self::compute(
+            &string,
+            lang.as_deref().unwrap_or(""),
+        )?))
+    }
+}
+
+// ============================================
+// WrapIn / Surround
+// ============================================
+
+/// String form of a value; numbers and booleans are accepted as well as strings
+fn display_string(value: &Value) -> String {
+    match value.as_str() {
+        Some(s) => s.to_string(),
+        None => value.to_string(),
+    }
+}
+
+/// Surround a value with a prefix and a suffix.
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ wrap_in(string="item", before="[", after="]") }}
+/// {# Output: [item] #}
+/// ```
+///
+/// # Filter Syntax
+/// ```jinja
+/// {{ name | wrap_in(before="<b>", after="</b>") }}
+/// ```
+pub struct WrapIn;
+
+impl FilterFunction for WrapIn {
+    const NAME: &'static str = "wrap_in";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "wrap_in",
+        category: "string",
+        description: "Surround a value with a prefix and a suffix",
+        arguments: &[
+            ArgumentMetadata {
+                name: "string",
+                arg_type: "any",
+                required: true,
+                default: None,
+                description: "The value to wrap (non-strings are converted to their string form)",
+            },
+            ArgumentMetadata {
+                name: "before",
+                arg_type: "string",
+                required: false,
+                default: Some("\"\""),
+                description: "Text placed before the value",
+            },
+            ArgumentMetadata {
+                name: "after",
+                arg_type: "string",
+                required: false,
+                default: Some("\"\""),
+                description: "Text placed after the value",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ wrap_in(string=\"item\", before=\"[\", after=\"]\") }}",
+            "{{ name | wrap_in(before=\"<b>\", after=\"</b>\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let value: Value = kwargs.get("string")?;
+        Self::call_as_filter(&value, kwargs)
+    }
+
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        let before: Option<String> = kwargs.get("before")?;
+        let after: Option<String> = kwargs.get("after")?;
+        Ok(Value::from(format!(
+            "{}{}{}",
+            before.unwrap_or_default(),
+            display_string(value),
+            after.unwrap_or_default()
+        )))
+    }
+}
+
+/// Surround a value with the same text on both sides.
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ surround(string="id", with="`") }}
+/// {# Output: `id` #}
+/// ```
+///
+/// # Filter Syntax
+/// ```jinja
+/// {{ title | surround(with="**") }}
+/// ```
+pub struct Surround;
+
+impl FilterFunction for Surround {
+    const NAME: &'static str = "surround";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "surround",
+        category: "string",
+        description: "Surround a value with the same text on both sides",
+        arguments: &[
+            ArgumentMetadata {
+                name: "string",
+                arg_type: "any",
+                required: true,
+                default: None,
+                description: "The value to wrap (non-strings are converted to their string form)",
+            },
+            ArgumentMetadata {
+                name: "with",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Text placed before and after the value",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ surround(string=\"id\", with=\"`\") }}",
+            "{{ title | surround(with=\"**\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+    const POSITIONAL_ARG: Option<&'static str> = Some("with");
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let value: Value = kwargs.get("string")?;
+        Self::call_as_filter(&value, kwargs)
+    }
+
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        let with: String = kwargs.get("with")?;
+        Ok(Value::from(format!(
+            "{}{}{}",
+            with,
+            display_string(value),
+            with
+        )))
+    }
+}
+
+// ============================================
+// Strip / LStrip / RStrip
+// ============================================
+
+/// Metadata for the `chars` argument of the strip functions
+const CHARS_ARG: ArgumentMetadata = ArgumentMetadata {
+    name: "chars",
+    arg_type: "string",
+    required: false,
+    default: None,
+    description: "Characters to remove, in any order (default: whitespace)",
+};
+
+/// Which ends of the string the strip functions remove characters from
+#[derive(Clone, Copy)]
+enum StripSide {
+    Both,
+    Start,
+    End,
+}
+
+/// Helper to strip any of `chars` (or whitespace) from one or both ends
+fn strip_chars(input: &str, chars: Option<&str>, side: StripSide) -> String {
+    let matches = |c: char| match chars {
+        Some(set) => set.contains(c),
+        None => c.is_whitespace(),
+    };
+    match side {
+        StripSide::Both => input.trim_matches(matches),
+        StripSide::Start => input.trim_start_matches(matches),
+        StripSide::End => input.trim_end_matches(matches),
+    }
+    .to_string()
+}
+
+/// Remove characters from both ends of a string.
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ strip(string="\"quoted\"", chars="\"") }}
+/// ```
+///
+/// # Filter Syntax
+/// ```jinja
+/// {{ "  padded  " | strip }}
+/// {{ "--name--" | strip(chars="-") }}
+/// ```
+pub struct Strip;
+
+impl FilterFunction for Strip {
+    const NAME: &'static str = "strip";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "strip",
+        category: "string",
+        description: "Remove the given characters (default: whitespace) from both ends of a string",
+        arguments: &[STRING_ARG, CHARS_ARG],
+        return_type: "string",
+        examples: &[
+            "{{ strip(string=\"  hello  \") }}",
+            "{{ \"'quoted'\" | strip(chars=\"'\\\"\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+    const POSITIONAL_ARG: Option<&'static str> = Some("chars");
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let string: String = kwargs.get("string")?;
+        let chars: Option<String> = kwargs.get("chars")?;
+        Ok(Value::from(strip_chars(
+            &string,
+            chars.as_deref(),
+            StripSide::Both,
+        )))
+    }
+
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        let string = extract_string(value, "strip")?;
+        let chars: Option<String> = kwargs.get("chars")?;
+        Ok(Value::from(strip_chars(
+            &string,
+            chars.as_deref(),
+            StripSide::Both,
+        )))
+    }
+}
+
+/// Remove characters from the start of a string.
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ lstrip(string="0042", chars="0") }}
+/// ```
+///
+/// # Filter Syntax
+/// ```jinja
+/// {{ "/api/v1" | lstrip(chars="/") }}
+/// ```
+pub struct LStrip;
+
+impl FilterFunction for LStrip {
+    const NAME: &'static str = "lstrip";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "lstrip",
+        category: "string",
+        description: "Remove the given characters (default: whitespace) from the start of a string",
+        arguments: &[STRING_ARG, CHARS_ARG],
+        return_type: "string",
+        examples: &[
+            "{{ lstrip(string=\"0042\", chars=\"0\") }}",
+            "{{ path | lstrip(chars=\"/\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+    const POSITIONAL_ARG: Option<&'static str> = Some("chars");
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let string: String = kwargs.get("string")?;
+        let chars: Option<String> = kwargs.get("chars")?;
+        Ok(Value::from(strip_chars(
+            &string,
+            chars.as_deref(),
+            StripSide::Start,
+        )))
+    }
+
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        let string = extract_string(value, "lstrip")?;
+        let chars: Option<String> = kwargs.get("chars")?;
+        Ok(Value::from(strip_chars(
+            &string,
+            chars.as_deref(),
+            StripSide::Start,
+        )))
+    }
+}
+
+/// Remove characters from the end of a string.
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ rstrip(string="https://example.com/", chars="/") }}
+/// ```
+///
+/// # Filter Syntax
+/// ```jinja
+/// {{ base_url | rstrip(chars="/") }}/api
+/// ```
+pub struct RStrip;
+
+impl FilterFunction for RStrip {
+    const NAME: &'static str = "rstrip";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "rstrip",
+        category: "string",
+        description: "Remove the given characters (default: whitespace) from the end of a string",
+        arguments: &[STRING_ARG, CHARS_ARG],
+        return_type: "string",
+        examples: &[
+            "{{ rstrip(string=\"https://example.com/\", chars=\"/\") }}",
+            "{{ line | rstrip }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+    const POSITIONAL_ARG: Option<&'static str> = Some("chars");
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let string: String = kwargs.get("string")?;
+        let chars: Option<String> = kwargs.get("chars")?;
+        Ok(Value::from(strip_chars(
+            &string,
+            chars.as_deref(),
+            StripSide::End,
+        )))
+    }
+
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        let string = extract_string(value, "rstrip")?;
+        let chars: Option<String> = kwargs.get("chars")?;
+        Ok(Value::from(strip_chars(
+            &string,
+            chars.as_deref(),
+            StripSide::End,
+        )))
+    }
+}
+
+// ============================================
+// Partition / RPartition
+// ============================================
+
+/// Metadata for the `sep` argument of the partition functions
+const SEP_ARG: ArgumentMetadata = ArgumentMetadata {
+    name: "sep",
+    arg_type: "string",
+    required: true,
+    default: None,
+    description: "Separator to split on (must not be empty)",
+};
+
+/// Helper to split around the first (or last) separator like Python's `str.partition`
+fn partition(input: &str, sep: &str, last: bool, fn_name: &str) -> Result<Value, Error> {
+    if sep.is_empty() {
+        return Err(Error::new(
+            ErrorKind::InvalidOperation,
+            format!("{}: separator must not be empty", fn_name),
+        ));
+    }
+    let found = if last {
+        input.rfind(sep)
+    } else {
+        input.find(sep)
+    };
+    let parts = match found {
+        Some(idx) => [&input[..idx], sep, &input[idx + sep.len()..]],
+        // Not found: the whole string goes to the side that was searched from
+        None if last => ["", "", input],
+        None => [input, "", ""],
+    };
+    Ok(Value::from_iter(
+        parts.iter().map(|part| Value::from(*part)),
+    ))
+}
+
+/// Split a string around the first occurrence of a separator.
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ partition(string="key=value=with=equals", sep="=") }}
+/// {# Output: ["key", "=", "value=with=equals"] #}
+/// ```
+///
+/// # Filter Syntax
+/// ```jinja
+/// {% set key, _, value = line | partition(sep="=") %}
+/// ```
+pub struct Partition;
+
+impl FilterFunction for Partition {
+    const NAME: &'static str = "partition";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "partition",
+        category: "string",
+        description: "Split a string on the first occurrence of a separator into [before, sep, after]",
+        arguments: &[STRING_ARG, SEP_ARG],
+        return_type: "array",
+        examples: &[
+            "{{ partition(string=\"key=value=with=equals\", sep=\"=\") }}",
+            "{% set key, _, value = line | partition(sep=\"=\") %}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+    const POSITIONAL_ARG: Option<&'static str> = Some("sep");
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let string: String = kwargs.get("string")?;
+        let sep: String = kwargs.get("sep")?;
+        partition(&string, &sep, false, "partition")
+    }
+
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        let string = extract_string(value, "partition")?;
+        let sep: String = kwargs.get("sep")?;
+        partition(&string, &sep, false, "partition")
+    }
+}
+
+/// Split a string around the last occurrence of a separator.
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ rpartition(string="archive.tar.gz", sep=".") }}
+/// {# Output: ["archive.tar", ".", "gz"] #}
+/// ```
+///
+/// # Filter Syntax
+/// ```jinja
+/// {{ (image | rpartition(sep=":"))[2] }}
+/// ```
+pub struct RPartition;
+
+impl FilterFunction for RPartition {
+    const NAME: &'static str = "rpartition";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "rpartition",
+        category: "string",
+        description: "Split a string on the last occurrence of a separator into [before, sep, after]",
+        arguments: &[STRING_ARG, SEP_ARG],
+        return_type: "array",
+        examples: &[
+            "{{ rpartition(string=\"archive.tar.gz\", sep=\".\") }}",
+            "{{ (image | rpartition(sep=\":\"))[2] }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+    const POSITIONAL_ARG: Option<&'static str> = Some("sep");
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let string: String = kwargs.get("string")?;
+        let sep: String = kwargs.get("sep")?;
+        partition(&string, &sep, true, "rpartition")
+    }
+
+    fn call_as_filter(value: &Value, kwargs: Kwargs) -> Result<Value, Error> {
+        let string = extract_string(value, "rpartition")?;
+        let sep: String = kwargs.get("sep")?;
+        partition(&string, &sep, true, "rpartition")
+    }
+}
+
+// ============================================
+// MaskEmail
+// ============================================
+
+/// Redact the local part of an email address, keeping its first character and the domain.
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ mask_email(string="alice@example.com") }}
+/// {# Output: a***@example.com #}
+/// ```
+///
+/// # Filter Syntax
+/// ```jinja
+/// {{ user.email | mask_email }}
+/// ```
+pub struct MaskEmail;
+
+impl MaskEmail {
+    fn compute(input: &str) -> Result<String, Error> {
+        let invalid = || {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("mask_email: '{}' is not a valid email address", input),
+            )
+        };
+        if !crate::is_functions::validation::Email::validate(input) {
+            return Err(invalid());
+        }
+        let (local, domain) = input.split_once('@').ok_or_else(invalid)?;
+        let first = local.chars().next().ok_or_else(invalid)?;
+        // A fixed-size mask so the length of the local part is not revealed
+        Ok(format!("{}***@{}", first, domain))
+    }
+}
+
+impl FilterFunction for MaskEmail {
+    const NAME: &'static str = "mask_email";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "mask_email",
+        category: "string",
+        description: "Mask the local part of an email address, keeping its first character and the full domain",
+        arguments: &[STRING_ARG],
+        return_type: "string",
+        examples: &[
+            "{{ mask_email(string=\"alice@example.com\") }}",
+            "{{ user.email | mask_email }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let string: String = kwargs.get("string")?;
+        Ok(Value::from(Self::compute(&string)?))
+    }
+
+    fn call_as_filter(value: &Value, _kwargs: Kwargs) -> Result<Value, Error> {
+        let string = extract_string(value, "mask_email")?;
+        Ok(Value::from(Self::compute(&string)?))
+    }
+}
@@ -48,7 +48,7 @@
 
 use crate::functions::metadata::FunctionMetadata;
 use minijinja::value::Kwargs;
-use minijinja::{Environment, Error, Value};
+use minijinja::{Environment, Error, ErrorKind, Value};
 
 /// Trait for types that can be registered as both a MiniJinja function and filter.
 ///
@@ -77,6 +77,13 @@ pub trait FilterFunction: 'static {
     /// Metadata describing this function (required for IDE integration).
     const METADATA: FunctionMetadata;
 
+    /// Keyword argument that the filter also accepts positionally.
+    ///
+    /// When set, `{{ text | truncate(80) }}` is the same as
+    /// `{{ text | truncate(length=80) }}`. The default `register` passes the
+    /// positional value to `call_as_filter` as that keyword argument.
+    const POSITIONAL_ARG: Option<&'static str> = None;
+
     /// Handle function-style calls where all arguments come from kwargs.
     ///
     /// Example: `{{ sha256(string="hello") }}`
@@ -95,10 +102,46 @@ pub trait FilterFunction: 'static {
     /// This registers the implementation as both a function and a filter,
     /// allowing users to choose their preferred syntax.
     ///
-    /// Override this method for filters that need custom positional argument
-    /// handling (e.g., `| indent(4)` instead of `| indent(spaces=4)`).
+    /// Filters with a single positional argument declare it with
+    /// `POSITIONAL_ARG`; override this method only for filters that take
+    /// several positional arguments (e.g., `| replace("a", "b")`).
     fn register(env: &mut Environment) {
         env.add_function(Self::NAME, Self::call_as_function);
-        env.add_filter(Self::NAME, Self::call_as_filter);
+        match Self::POSITIONAL_ARG {
+            None => env.add_filter(Self::NAME, Self::call_as_filter),
+            Some(arg) => env.add_filter(
+                Self::NAME,
+                move |value: &Value, positional: Option<Value>, kwargs: Kwargs| {
+                    let kwargs = with_positional(Self::NAME, arg, positional, kwargs)?;
+                    Self::call_as_filter(value, kwargs)
+                },
+            ),
+        }
+    }
+}
+
+/// Add a positional filter argument to the kwargs under its keyword name
+fn with_positional(
+    filter: &str,
+    arg: &'static str,
+    positional: Option<Value>,
+    kwargs: Kwargs,
+) -> Result<Kwargs, Error> {
+    let Some(positional) = positional else {
+        return Ok(kwargs);
+    };
+    if kwargs.has(arg) {
+        return Err(Error::new(
+            ErrorKind::TooManyArguments,
+            format!(
+                "{}: '{}' was given both positionally and as a keyword argument",
+                filter, arg
+            ),
+        ));
+    }
+    let mut pairs = vec![(arg.to_string(), positional)];
+    for key in kwargs.args() {
+        pairs.push((key.to_string(), kwargs.peek::<Value>(key)?));
     }
+    Ok(Kwargs::from_iter(pairs))
 }
@@ -0,0 +1,96 @@
+//! Canonical formatting of rendered output (`--format-output`)
+//!
+//! The rendered output is parsed as JSON, YAML or TOML and serialized again,
+//! so hand-indented template output comes out with consistent indentation
+//! and keys sorted alphabetically. Comments and the original quoting style
+//! are not preserved.
+//!
+//! YAML output may contain several `---`-separated documents; each is
+//! formatted on its own.
+
+use crate::cli::OutputFormat;
+use serde::Deserialize;
+
+/// Parse and re-serialize rendered output in the given format
+///
+/// The result always ends with a single newline. Parse errors name the
+/// line of the rendered output they occurred on.
+///
+/// # Example
+///
+/// ```
+/// use tmpltool::cli::OutputFormat;
+/// use tmpltool::format_output::format_output;
+///
+/// let formatted = format_output("{\"b\": 1,   \"a\": [1,2]}", OutputFormat::Json).unwrap();
+/// assert_eq!(formatted, "{\n  \"a\": [\n    1,\n    2\n  ],\n  \"b\": 1\n}\n");
+/// ```
+pub fn format_output(output: &str, format: OutputFormat) -> Result<String, String> {
+    match format {
+        OutputFormat::Json => format_json(output),
+        OutputFormat::Yaml => format_yaml(output),
+        OutputFormat::Toml => format_toml(output),
+    }
+}
+
+fn format_json(output: &str) -> Result<String, String> {
+    let value: serde_json::Value =
+        serde_json::from_str(output).map_err(|e| parse_error("JSON", output, Some(e.line()), e))?;
+    let formatted = serde_json::to_string_pretty(&value)
+        .map_err(|e| format!("Failed to format output as JSON: {}", e))?;
+    Ok(formatted + "\n")
+}
+
+fn format_yaml(output: &str) -> Result<String, String> {
+    let mut documents = Vec::new();
+    for document in serde_yaml::Deserializer::from_str(output) {
+        let value = serde_yaml::Value::deserialize(document).map_err(|e| {
+            let line = e.location().map(|location| location.line());
+            parse_error("YAML", output, line, e)
+        })?;
+        // Going through serde_json sorts mapping keys
+        let value: serde_json::Value = serde_json::to_value(&value)
+            .map_err(|e| format!("Failed to format output as YAML: {}", e))?;
+        let formatted = serde_yaml::to_string(&value)
+            .map_err(|e| format!("Failed to format output as YAML: {}", e))?;
+        documents.push(formatted);
+    }
+    Ok(documents.join("---\n"))
+}
+
+fn format_toml(output: &str) -> Result<String, String> {
+    let value: toml::Table = toml::from_str(output).map_err(|e| {
+        let line = e
+            .span()
+            .and_then(|span| output.get(..span.start))
+            .map(|before| before.matches('\n').count() + 1);
+        parse_error("TOML", output, line, e.message())
+    })?;
+    let formatted =
+        toml::to_string(&value).map_err(|e| format!("Failed to format output as TOML: {}", e))?;
+    Ok(formatted.trim_end().to_string() + "\n")
+}
+
+/// Error message for rendered output that does not parse, quoting the offending line
+fn parse_error(
+    format: &str,
+    output: &str,
+    line: Option<usize>,
+    error: impl std::fmt::Display,
+) -> String {
+    let error = error.to_string();
+    let error = error.trim_end();
+    match line {
+        Some(line) => match output.lines().nth(line.saturating_sub(1)) {
+            Some(text) => format!(
+                "Failed to format output as {}: rendered output line {}: {}\n  {} | {}",
+                format, line, error, line, text
+            ),
+            None => format!(
+                "Failed to format output as {}: rendered output line {} (end of output): {}",
+                format, line, error
+            ),
+        },
+        None => format!("Failed to format output as {}: {}", format, error),
+    }
+}
@@ -8,6 +8,9 @@
 //! - Finding elements
 //! - Filtering by conditions
 //! - Set operations (intersection, difference, union)
+//! - Constructing arrays (fill, zeros)
+//! - Transposing 2D arrays
+//! - Sliding windows
 //!
 //! Note: array_unique, array_flatten are now in filter_functions/array.rs
 //! with dual function+filter syntax support.
@@ -353,6 +356,107 @@ impl Function for ArrayGroupBy {
     }
 }
 
+/// Build a lookup object from an array, keyed by an item field
+pub struct ArrayIndexBy;
+
+impl Function for ArrayIndexBy {
+    const NAME: &'static str = "array_index_by";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "array_index_by",
+        category: "array",
+        description: "Build an object mapping each item's key value to the item",
+        arguments: &[
+            ArgumentMetadata {
+                name: "array",
+                arg_type: "array",
+                required: true,
+                default: None,
+                description: "Array of objects to index",
+            },
+            ArgumentMetadata {
+                name: "key",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Key path to index by (supports dot notation)",
+            },
+            ArgumentMetadata {
+                name: "keep",
+                arg_type: "string",
+                required: false,
+                default: Some("last"),
+                description: "Which item wins on duplicate keys: \"last\" or \"first\"",
+            },
+        ],
+        return_type: "object",
+        examples: &[
+            "{% set by_name = array_index_by(array=services, key=\"name\") %}{{ by_name.api.port }}",
+            "{{ array_index_by(array=users, key=\"profile.email\", keep=\"first\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let array: Value = kwargs.get("array")?;
+        let key: String = kwargs.get("key")?;
+        let keep: Option<String> = kwargs.get("keep")?;
+
+        if !matches!(array.kind(), minijinja::value::ValueKind::Seq) {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                "array_index_by requires an array",
+            ));
+        }
+
+        let keep_first = match keep.as_deref().unwrap_or("last") {
+            "last" => false,
+            "first" => true,
+            other => {
+                return Err(Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!("array_index_by: invalid keep '{}'. Use: last, first", other),
+                ));
+            }
+        };
+
+        let key_parts: Vec<&str> = key.split('.').collect();
+        let mut index = serde_json::Map::new();
+
+        if let Ok(seq) = array.try_iter() {
+            for item in seq {
+                let json_value: serde_json::Value = serde_json::to_value(&item).map_err(|e| {
+                    Error::new(
+                        ErrorKind::InvalidOperation,
+                        format!("Failed to convert item: {}", e),
+                    )
+                })?;
+
+                // Navigate through the key path; items without the key are skipped
+                let mut current = Some(&json_value);
+                for part in &key_parts {
+                    current = current
+                        .and_then(|v| v.as_object())
+                        .and_then(|o| o.get(*part));
+                }
+
+                let index_key = match current {
+                    Some(serde_json::Value::String(s)) => s.clone(),
+                    Some(serde_json::Value::Number(n)) => n.to_string(),
+                    Some(serde_json::Value::Bool(b)) => b.to_string(),
+                    _ => continue,
+                };
+
+                if keep_first && index.contains_key(&index_key) {
+                    continue;
+                }
+                index.insert(index_key, json_value);
+            }
+        }
+
+        Ok(Value::from_serialize(&index))
+    }
+}
+
 /// Take first N elements from array
 pub struct ArrayTake;
 
@@ -700,32 +804,7 @@ impl Function for ArrayFilterBy {
                 if let Some(obj) = json_value.as_object()
                     && let Some(item_val) = obj.get(&key)
                 {
-                    let matches = match op.as_str() {
-                        "eq" => *item_val == compare_value,
-                        "ne" => *item_val != compare_value,
-                        "gt" => compare_numeric(item_val, &compare_value, |a, b| a > b),
-                        "lt" => compare_numeric(item_val, &compare_value, |a, b| a < b),
-                        "gte" => compare_numeric(item_val, &compare_value, |a, b| a >= b),
-                        "lte" => compare_numeric(item_val, &compare_value, |a, b| a <= b),
-                        "contains" => {
-                            if let (Some(s1), Some(s2)) =
-                                (item_val.as_str(), compare_value.as_str())
-                            {
-                                s1.contains(s2)
-                            } else {
-                                false
-                            }
-                        }
-                        _ => {
-                            return Err(Error::new(
-                                ErrorKind::InvalidOperation,
-                                format!(
-                                    "Invalid operator '{}'. Use: eq, ne, gt, lt, gte, lte, contains",
-                                    op
-                                ),
-                            ));
-                        }
-                    };
+                    let matches = compare_by_op(item_val, &op, &compare_value)?;
 
                     if matches {
                         result.push(json_value);
@@ -738,6 +817,42 @@ impl Function for ArrayFilterBy {
     }
 }
 
+/// Compare two JSON values using one of the `array_filter_by` operators
+///
+/// Supported operators: eq, ne, gt, lt, gte, lte, contains
+pub(crate) fn compare_by_op(
+    item_val: &serde_json::Value,
+    op: &str,
+    compare_value: &serde_json::Value,
+) -> Result<bool, Error> {
+    let matches = match op {
+        "eq" => item_val == compare_value,
+        "ne" => item_val != compare_value,
+        "gt" => compare_numeric(item_val, compare_value, |a, b| a > b),
+        "lt" => compare_numeric(item_val, compare_value, |a, b| a < b),
+        "gte" => compare_numeric(item_val, compare_value, |a, b| a >= b),
+        "lte" => compare_numeric(item_val, compare_value, |a, b| a <= b),
+        "contains" => {
+            if let (Some(s1), Some(s2)) = (item_val.as_str(), compare_value.as_str()) {
+                s1.contains(s2)
+            } else {
+                false
+            }
+        }
+        _ => {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                format!(
+                    "Invalid operator '{}'. Use: eq, ne, gt, lt, gte, lte, contains",
+                    op
+                ),
+            ));
+        }
+    };
+
+    Ok(matches)
+}
+
 fn compare_numeric<F>(a: &serde_json::Value, b: &serde_json::Value, cmp: F) -> bool
 where
     F: Fn(f64, f64) -> bool,
@@ -1155,3 +1270,260 @@ impl Function for ArraySymmetricDifference {
         Ok(Value::from_serialize(&result))
     }
 }
+
+/// Largest array `array_fill` and `array_zeros` build, the same limit as MiniJinja's `range`
+const MAX_FILL_COUNT: usize = 100_000;
+
+/// Parse a non-negative `count` argument, at most [`MAX_FILL_COUNT`]
+fn parse_count(count: i64, fn_name: &str) -> Result<usize, Error> {
+    let count = usize::try_from(count).map_err(|_| {
+        Error::new(
+            ErrorKind::InvalidOperation,
+            format!("{} count must be non-negative, got {}", fn_name, count),
+        )
+    })?;
+    if count > MAX_FILL_COUNT {
+        return Err(Error::new(
+            ErrorKind::InvalidOperation,
+            format!(
+                "{} count must be at most {}, got {}",
+                fn_name, MAX_FILL_COUNT, count
+            ),
+        ));
+    }
+    Ok(count)
+}
+
+/// Create an array containing `count` copies of a value
+pub struct ArrayFill;
+
+impl Function for ArrayFill {
+    const NAME: &'static str = "array_fill";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "array_fill",
+        category: "array",
+        description: "Create an array containing count copies of a value",
+        arguments: &[
+            ArgumentMetadata {
+                name: "value",
+                arg_type: "any",
+                required: true,
+                default: None,
+                description: "Value to repeat",
+            },
+            ArgumentMetadata {
+                name: "count",
+                arg_type: "integer",
+                required: true,
+                default: None,
+                description: "Number of copies, at most 100000 (0 yields an empty array)",
+            },
+        ],
+        return_type: "array",
+        examples: &[
+            "{{ array_fill(value=\"-\", count=3) }}",
+            "{{ array_fill(value={\"enabled\": false}, count=2) | tojson }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let value: Value = kwargs.get("value")?;
+        let count: i64 = kwargs.get("count")?;
+        let count = parse_count(count, "array_fill")?;
+
+        Ok(Value::from(vec![value; count]))
+    }
+}
+
+/// Create an array of `count` zeros
+pub struct ArrayZeros;
+
+impl Function for ArrayZeros {
+    const NAME: &'static str = "array_zeros";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "array_zeros",
+        category: "array",
+        description: "Create an array of count zeros (shorthand for array_fill with value=0)",
+        arguments: &[ArgumentMetadata {
+            name: "count",
+            arg_type: "integer",
+            required: true,
+            default: None,
+            description: "Number of zeros, at most 100000 (0 yields an empty array)",
+        }],
+        return_type: "array",
+        examples: &["{{ array_zeros(count=4) }}"],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let count: i64 = kwargs.get("count")?;
+        let count = parse_count(count, "array_zeros")?;
+
+        Ok(Value::from(vec![0; count]))
+    }
+}
+
+/// Transpose an array of arrays (rows become columns)
+pub struct Transpose;
+
+impl Function for Transpose {
+    const NAME: &'static str = "transpose";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "transpose",
+        category: "array",
+        description: "Transpose an array of arrays, swapping rows and columns",
+        arguments: &[
+            ArgumentMetadata {
+                name: "matrix",
+                arg_type: "array",
+                required: true,
+                default: None,
+                description: "Array of row arrays",
+            },
+            ArgumentMetadata {
+                name: "pad",
+                arg_type: "any",
+                required: false,
+                default: None,
+                description: "Fill value for missing cells in ragged input (errors on ragged input if omitted)",
+            },
+        ],
+        return_type: "array",
+        examples: &[
+            "{{ transpose(matrix=[[1, 2, 3], [4, 5, 6]]) | tojson }}",
+            "{{ transpose(matrix=[[1, 2], [3]], pad=none) | tojson }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let matrix: Value = kwargs.get("matrix")?;
+        let pad: Option<Value> = if kwargs.has("pad") {
+            Some(kwargs.get("pad")?)
+        } else {
+            None
+        };
+
+        if !matches!(matrix.kind(), minijinja::value::ValueKind::Seq) {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                "transpose requires an array of arrays",
+            ));
+        }
+
+        let mut rows: Vec<Vec<Value>> = Vec::new();
+        for row in matrix.try_iter()? {
+            if !matches!(row.kind(), minijinja::value::ValueKind::Seq) {
+                return Err(Error::new(
+                    ErrorKind::InvalidOperation,
+                    "transpose requires every row to be an array",
+                ));
+            }
+            rows.push(row.try_iter()?.collect());
+        }
+
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        if pad.is_none()
+            && let Some((index, row)) = rows.iter().enumerate().find(|(_, r)| r.len() != width)
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                format!(
+                    "transpose: row {} has {} items, expected {} (pass pad=... to fill ragged rows)",
+                    index,
+                    row.len(),
+                    width
+                ),
+            ));
+        }
+
+        let pad = pad.unwrap_or_default();
+        let columns: Vec<Vec<Value>> = (0..width)
+            .map(|col| {
+                rows.iter()
+                    .map(|row| row.get(col).cloned().unwrap_or_else(|| pad.clone()))
+                    .collect()
+            })
+            .collect();
+
+        Ok(Value::from(columns))
+    }
+}
+
+/// Sliding windows over an array
+pub struct ArrayWindow;
+
+impl Function for ArrayWindow {
+    const NAME: &'static str = "array_window";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "array_window",
+        category: "array",
+        description: "Split an array into sliding windows of a fixed size",
+        arguments: &[
+            ArgumentMetadata {
+                name: "array",
+                arg_type: "array",
+                required: true,
+                default: None,
+                description: "Array to slide over",
+            },
+            ArgumentMetadata {
+                name: "size",
+                arg_type: "integer",
+                required: true,
+                default: None,
+                description: "Number of items in each window",
+            },
+            ArgumentMetadata {
+                name: "step",
+                arg_type: "integer",
+                required: false,
+                default: Some("1"),
+                description: "Items to advance between windows (step=size gives non-overlapping windows)",
+            },
+        ],
+        return_type: "array",
+        examples: &[
+            "{{ array_window(array=[1, 2, 3, 4], size=2) | tojson }}",
+            "{{ array_window(array=[1, 2, 3, 4, 5, 6], size=2, step=3) | tojson }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let array: Value = kwargs.get("array")?;
+        let size: usize = kwargs.get("size")?;
+        let step: usize = kwargs.get::<Option<usize>>("step")?.unwrap_or(1);
+
+        if !matches!(array.kind(), minijinja::value::ValueKind::Seq) {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                "array_window requires an array",
+            ));
+        }
+        if size == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                "array_window size must be greater than 0",
+            ));
+        }
+        if step == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                "array_window step must be greater than 0",
+            ));
+        }
+
+        // Only full windows are returned; a shorter array yields no windows
+        let items: Vec<Value> = array.try_iter()?.collect();
+        let windows: Vec<Value> = items
+            .windows(size)
+            .step_by(step)
+            .map(|window| Value::from(window.to_vec()))
+            .collect();
+
+        Ok(Value::from(windows))
+    }
+}
@@ -0,0 +1,185 @@
+//! Disk cache for expensive function results
+//!
+//! `cache(compute="resolve_dns", hostname="db.internal", ttl=300)` stores the
+//! result of a function call in a cache directory under the base directory,
+//! so iterative re-renders don't repeat slow lookups. Unlike memoization,
+//! which lasts for one render, cached values survive between renders until
+//! their TTL expires.
+//!
+//! Computing a value requires trust mode, like the other functions that
+//! reach outside the template. Without `--trust` the cache is read-only: a
+//! missing or expired value is an error that names the file to supply it in.
+
+use super::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
+use super::traits::ContextStateFunction;
+use crate::TemplateContext;
+use minijinja::value::{Kwargs, ValueKind};
+use minijinja::{Error, ErrorKind, State, Value};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Cache directory, relative to the base directory
+pub const CACHE_DIR: &str = ".tmpltool-cache";
+
+/// One cached value on disk
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    key: String,
+    /// Unix time (seconds) the value was computed
+    created: u64,
+    value: serde_json::Value,
+}
+
+fn cache_error(message: String) -> Error {
+    Error::new(ErrorKind::InvalidOperation, format!("cache: {}", message))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Path of the cache file for a key
+fn entry_path(context: &TemplateContext, key: &str) -> PathBuf {
+    let hash = hex::encode(Sha256::digest(key.as_bytes()));
+    context
+        .base_dir()
+        .join(CACHE_DIR)
+        .join(format!("{}.json", hash))
+}
+
+/// Read a cached value that is still fresh
+fn read_entry(path: &Path, key: &str, ttl: Option<u64>) -> Option<serde_json::Value> {
+    let content = fs::read_to_string(path).ok()?;
+    let entry: CacheEntry = serde_json::from_str(&content).ok()?;
+    let fresh = ttl.is_none_or(|ttl| now().saturating_sub(entry.created) < ttl);
+    (entry.key == key && fresh).then_some(entry.value)
+}
+
+fn write_entry(path: &Path, key: &str, value: &serde_json::Value) -> Result<(), Error> {
+    let entry = CacheEntry {
+        key: key.to_string(),
+        created: now(),
+        value: value.clone(),
+    };
+    let write = || -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(&entry)? + "\n")
+    };
+    write().map_err(|e| {
+        cache_error(format!(
+            "failed to write cache file '{}': {}",
+            path.display(),
+            e
+        ))
+    })
+}
+
+/// Cache a function result on disk between renders
+pub struct Cache;
+
+impl ContextStateFunction for Cache {
+    const NAME: &'static str = "cache";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "cache",
+        category: "cache",
+        description: "Return a function result cached on disk under the base directory, computing it on a miss (requires --trust to compute)",
+        arguments: &[
+            ArgumentMetadata {
+                name: "compute",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Name of the function to call on a miss (e.g., \"resolve_dns\"); other keyword arguments are passed to it",
+            },
+            ArgumentMetadata {
+                name: "key",
+                arg_type: "string",
+                required: false,
+                default: None,
+                description: "Cache key (default: derived from the function name and its arguments)",
+            },
+            ArgumentMetadata {
+                name: "ttl",
+                arg_type: "integer",
+                required: false,
+                default: None,
+                description: "Seconds a cached value stays fresh (default: never expires)",
+            },
+        ],
+        return_type: "any",
+        examples: &[
+            "{{ cache(compute=\"resolve_dns\", hostname=\"db.internal\", ttl=300) }}",
+            "{% set nodes = cache(key=\"nodes\", compute=\"read_json_file\", path=\"inventory.json\", ttl=3600) %}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(context: Arc<TemplateContext>, state: &State, kwargs: Kwargs) -> Result<Value, Error> {
+        let compute: String = kwargs.get("compute")?;
+        let key: Option<String> = kwargs.get("key")?;
+        let ttl: Option<u64> = kwargs.get("ttl")?;
+
+        let mut args = Vec::new();
+        for name in kwargs.args() {
+            if !matches!(name, "compute" | "key" | "ttl") {
+                args.push((name, kwargs.get::<Value>(name)?));
+            }
+        }
+
+        // Arguments are sorted by name, so the default key does not depend
+        // on the order they were passed in
+        let key = match key {
+            Some(key) => key,
+            None => {
+                let args_json = serde_json::to_value(
+                    args.iter()
+                        .map(|(name, value)| (name.to_string(), value.clone()))
+                        .collect::<std::collections::BTreeMap<_, _>>(),
+                )
+                .map_err(|e| {
+                    cache_error(format!("cannot build a key from the arguments: {}", e))
+                })?;
+                format!("{}({})", compute, args_json)
+            }
+        };
+
+        let path = entry_path(&context, &key);
+        if let Some(value) = read_entry(&path, &key, ttl) {
+            return Ok(Value::from_serialize(&value));
+        }
+
+        if !context.is_trust_mode() {
+            return Err(cache_error(format!(
+                "no fresh cached value for '{}'. Run with --trust to compute it with {}(), or supply it in '{}'",
+                key,
+                compute,
+                path.display()
+            )));
+        }
+
+        let function = state
+            .lookup(&compute)
+            .filter(|value| value.kind() == ValueKind::Plain)
+            .ok_or_else(|| cache_error(format!("unknown function '{}'", compute)))?;
+        let result = function.call(state, &[Value::from(Kwargs::from_iter(args))])?;
+        let value = serde_json::to_value(&result).map_err(|e| {
+            cache_error(format!(
+                "the result of {}() cannot be stored: {}",
+                compute, e
+            ))
+        })?;
+        write_entry(&path, &key, &value)?;
+
+        // Return the stored form so hits and misses behave the same
+        Ok(Value::from_serialize(&value))
+    }
+}
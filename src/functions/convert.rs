@@ -0,0 +1,292 @@
+//! Explicit type conversion functions for MiniJinja templates
+//!
+//! This module provides coercion functions with well-defined rules, so
+//! templates do not depend on MiniJinja's implicit conversions:
+//! - `to_int`: Convert to an integer (floats are truncated toward zero)
+//! - `to_float`: Convert to a floating point number
+//! - `to_bool`: Convert to a boolean ("true"/"1"/"yes"/"on" are true)
+//! - `to_string`: Convert to a string (arrays and objects become JSON)
+//!
+//! `to_int`, `to_float` and `to_bool` accept an optional `default` that is
+//! returned instead of an error when the value cannot be converted.
+
+use super::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
+use super::traits::Function;
+use minijinja::value::{Kwargs, ValueKind};
+use minijinja::{Error, ErrorKind, Value};
+
+/// Return `default` if given, otherwise a conversion error
+fn fallback(
+    default: Option<Value>,
+    fn_name: &str,
+    value: &Value,
+    target: &str,
+) -> Result<Value, Error> {
+    default.ok_or_else(|| {
+        Error::new(
+            ErrorKind::InvalidOperation,
+            format!(
+                "{}: cannot convert {} to {}",
+                fn_name,
+                describe(value),
+                target
+            ),
+        )
+    })
+}
+
+/// Short description of a value for error messages
+fn describe(value: &Value) -> String {
+    match value.kind() {
+        ValueKind::String => format!("'{}'", value.as_str().unwrap_or_default()),
+        ValueKind::Undefined => "undefined".to_string(),
+        ValueKind::None => "none".to_string(),
+        ValueKind::Seq => "an array".to_string(),
+        ValueKind::Map => "an object".to_string(),
+        _ => value.to_string(),
+    }
+}
+
+/// Convert a value to an integer
+pub struct ToInt;
+
+impl Function for ToInt {
+    const NAME: &'static str = "to_int";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "to_int",
+        category: "convert",
+        description: "Convert a value to an integer (floats and numeric strings are truncated toward zero, booleans become 1/0)",
+        arguments: &[
+            ArgumentMetadata {
+                name: "value",
+                arg_type: "any",
+                required: true,
+                default: None,
+                description: "Value to convert",
+            },
+            ArgumentMetadata {
+                name: "default",
+                arg_type: "any",
+                required: false,
+                default: None,
+                description: "Value returned when conversion fails (errors if omitted)",
+            },
+        ],
+        return_type: "integer",
+        examples: &[
+            "{{ to_int(value=\"42\") }}",
+            "{{ to_int(value=\"3.9\") }}",
+            "{{ to_int(value=get_env(name=\"WORKERS\", default=\"\"), default=4) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let value: Value = kwargs.get("value")?;
+        let default: Option<Value> = kwargs.get("default")?;
+
+        let converted = match value.kind() {
+            ValueKind::Bool => Some(i64::from(value.is_true())),
+            ValueKind::Number if value.is_integer() => value.as_i64(),
+            ValueKind::Number => f64::try_from(value.clone()).ok().and_then(float_to_int),
+            ValueKind::String => {
+                let s = value.as_str().unwrap_or_default().trim();
+                s.parse::<i64>()
+                    .ok()
+                    .or_else(|| s.parse::<f64>().ok().and_then(float_to_int))
+            }
+            _ => None,
+        };
+
+        match converted {
+            Some(i) => Ok(Value::from(i)),
+            None => fallback(default, "to_int", &value, "an integer"),
+        }
+    }
+}
+
+/// Truncate a float toward zero, rejecting NaN, infinity and out-of-range values
+fn float_to_int(f: f64) -> Option<i64> {
+    let truncated = f.trunc();
+    if truncated.is_finite() && truncated >= i64::MIN as f64 && truncated <= i64::MAX as f64 {
+        Some(truncated as i64)
+    } else {
+        None
+    }
+}
+
+/// Convert a value to a floating point number
+pub struct ToFloat;
+
+impl Function for ToFloat {
+    const NAME: &'static str = "to_float";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "to_float",
+        category: "convert",
+        description: "Convert a value to a floating point number (booleans become 1.0/0.0)",
+        arguments: &[
+            ArgumentMetadata {
+                name: "value",
+                arg_type: "any",
+                required: true,
+                default: None,
+                description: "Value to convert",
+            },
+            ArgumentMetadata {
+                name: "default",
+                arg_type: "any",
+                required: false,
+                default: None,
+                description: "Value returned when conversion fails (errors if omitted)",
+            },
+        ],
+        return_type: "float",
+        examples: &[
+            "{{ to_float(value=\"3.14\") }}",
+            "{{ to_float(value=\"n/a\", default=0.0) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let value: Value = kwargs.get("value")?;
+        let default: Option<Value> = kwargs.get("default")?;
+
+        let converted = match value.kind() {
+            ValueKind::Bool => Some(if value.is_true() { 1.0 } else { 0.0 }),
+            ValueKind::Number => f64::try_from(value.clone()).ok(),
+            ValueKind::String => value
+                .as_str()
+                .unwrap_or_default()
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|f| f.is_finite()),
+            _ => None,
+        };
+
+        match converted {
+            Some(f) => Ok(Value::from(f)),
+            None => fallback(default, "to_float", &value, "a float"),
+        }
+    }
+}
+
+/// Convert a value to a boolean
+pub struct ToBool;
+
+impl Function for ToBool {
+    const NAME: &'static str = "to_bool";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "to_bool",
+        category: "convert",
+        description: "Convert a value to a boolean (\"true\"/\"1\"/\"yes\"/\"y\"/\"on\" are true, \"false\"/\"0\"/\"no\"/\"n\"/\"off\"/\"\" are false, case-insensitive)",
+        arguments: &[
+            ArgumentMetadata {
+                name: "value",
+                arg_type: "any",
+                required: true,
+                default: None,
+                description: "Value to convert",
+            },
+            ArgumentMetadata {
+                name: "default",
+                arg_type: "any",
+                required: false,
+                default: None,
+                description: "Value returned when conversion fails (errors if omitted)",
+            },
+        ],
+        return_type: "boolean",
+        examples: &[
+            "{{ to_bool(value=\"yes\") }}",
+            "{% if to_bool(value=get_env(name=\"DEBUG\", default=\"false\")) %}debug{% endif %}",
+            "{{ to_bool(value=\"maybe\", default=false) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let value: Value = kwargs.get("value")?;
+        let default: Option<Value> = kwargs.get("default")?;
+
+        let converted = match value.kind() {
+            ValueKind::Bool => Some(value.is_true()),
+            ValueKind::None => Some(false),
+            ValueKind::Number => f64::try_from(value.clone()).ok().map(|f| f != 0.0),
+            ValueKind::String => {
+                match value
+                    .as_str()
+                    .unwrap_or_default()
+                    .trim()
+                    .to_lowercase()
+                    .as_str()
+                {
+                    "true" | "1" | "yes" | "y" | "on" => Some(true),
+                    "false" | "0" | "no" | "n" | "off" | "" => Some(false),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+
+        match converted {
+            Some(b) => Ok(Value::from(b)),
+            None => fallback(default, "to_bool", &value, "a boolean"),
+        }
+    }
+}
+
+/// Convert a value to a string
+pub struct ToString;
+
+impl Function for ToString {
+    const NAME: &'static str = "to_string";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "to_string",
+        category: "convert",
+        description: "Convert a value to a string (arrays and objects become compact JSON, none/undefined become the default)",
+        arguments: &[
+            ArgumentMetadata {
+                name: "value",
+                arg_type: "any",
+                required: true,
+                default: None,
+                description: "Value to convert",
+            },
+            ArgumentMetadata {
+                name: "default",
+                arg_type: "string",
+                required: false,
+                default: Some(""),
+                description: "String returned for none or undefined values",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ to_string(value=42) }}",
+            "{{ to_string(value=[1, 2]) }}",
+            "{{ to_string(value=none, default=\"n/a\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let value: Value = kwargs.get("value")?;
+        let default: Option<String> = kwargs.get("default")?;
+
+        let converted = match value.kind() {
+            ValueKind::Undefined | ValueKind::None => default.unwrap_or_default(),
+            ValueKind::String => value.as_str().unwrap_or_default().to_string(),
+            ValueKind::Seq | ValueKind::Map => serde_json::to_string(&value).map_err(|e| {
+                Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!("to_string: failed to serialize value: {}", e),
+                )
+            })?,
+            _ => value.to_string(),
+        };
+
+        Ok(Value::from(converted))
+    }
+}
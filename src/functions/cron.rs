@@ -0,0 +1,481 @@
+//! Cron expression functions for MiniJinja templates
+//!
+//! This module provides:
+//! - `cron_describe`: Explain a five-field cron expression in plain English
+
+use super::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
+use super::traits::Function;
+use minijinja::value::Kwargs;
+use minijinja::{Error, ErrorKind, Value};
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const WEEKDAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+/// One comma-separated element of a cron field
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Part {
+    /// `5`
+    Value(u32),
+    /// `1-5`
+    Range(u32, u32),
+    /// `*/15`, `10/15` or `10-30/5`; `to` is the field maximum for `*` and `10/15`
+    Step {
+        from: u32,
+        to: u32,
+        every: u32,
+        all: bool,
+    },
+}
+
+/// A parsed cron field: `*` or a list of parts
+#[derive(Debug, Clone, PartialEq)]
+enum Field {
+    Any,
+    Parts(Vec<Part>),
+}
+
+impl Field {
+    /// The single plain value of the field, if that is all it contains
+    fn single(&self) -> Option<u32> {
+        match self {
+            Field::Parts(parts) => match parts.as_slice() {
+                [Part::Value(v)] => Some(*v),
+                _ => None,
+            },
+            Field::Any => None,
+        }
+    }
+
+    /// The step of a `*/n` field
+    fn every(&self) -> Option<u32> {
+        match self {
+            Field::Parts(parts) => match parts.as_slice() {
+                [Part::Step { every, all, .. }] if *all => Some(*every),
+                _ => None,
+            },
+            Field::Any => None,
+        }
+    }
+
+    /// All values when the field is a list of plain values
+    fn values(&self) -> Option<Vec<u32>> {
+        match self {
+            Field::Parts(parts) => parts
+                .iter()
+                .map(|p| match p {
+                    Part::Value(v) => Some(*v),
+                    _ => None,
+                })
+                .collect(),
+            Field::Any => None,
+        }
+    }
+}
+
+/// Static description of one of the five cron fields
+struct FieldSpec {
+    name: &'static str,
+    min: u32,
+    max: u32,
+    names: &'static [&'static str],
+    /// Offset of `names[0]` (months are 1-based, weekdays 0-based)
+    names_start: u32,
+}
+
+const MINUTE: FieldSpec = FieldSpec {
+    name: "minute",
+    min: 0,
+    max: 59,
+    names: &[],
+    names_start: 0,
+};
+const HOUR: FieldSpec = FieldSpec {
+    name: "hour",
+    min: 0,
+    max: 23,
+    names: &[],
+    names_start: 0,
+};
+const DAY_OF_MONTH: FieldSpec = FieldSpec {
+    name: "day-of-month",
+    min: 1,
+    max: 31,
+    names: &[],
+    names_start: 0,
+};
+const MONTH: FieldSpec = FieldSpec {
+    name: "month",
+    min: 1,
+    max: 12,
+    names: &MONTHS,
+    names_start: 1,
+};
+// 7 is accepted as an alias for Sunday
+const DAY_OF_WEEK: FieldSpec = FieldSpec {
+    name: "day-of-week",
+    min: 0,
+    max: 7,
+    names: &WEEKDAYS,
+    names_start: 0,
+};
+
+impl FieldSpec {
+    fn parse(&self, text: &str) -> Result<Field, String> {
+        if text == "*" || text == "?" {
+            return Ok(Field::Any);
+        }
+        text.split(',')
+            .map(|part| self.parse_part(part))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Field::Parts)
+    }
+
+    fn parse_part(&self, text: &str) -> Result<Part, String> {
+        let (range, step) = match text.split_once('/') {
+            Some((range, step)) => (range, Some(step)),
+            None => (text, None),
+        };
+
+        let (from, to, all) = if range == "*" {
+            (self.min, self.max, true)
+        } else if let Some((a, b)) = range.split_once('-') {
+            let (a, b) = (self.parse_value(a)?, self.parse_value(b)?);
+            if a > b {
+                return Err(format!("{} range {} is reversed", self.name, range));
+            }
+            (a, b, false)
+        } else {
+            let value = self.parse_value(range)?;
+            // "10/15" means every 15 starting at 10
+            let to = if step.is_some() { self.max } else { value };
+            (value, to, false)
+        };
+
+        match step {
+            None if from == to && !all => Ok(Part::Value(from)),
+            None => Ok(Part::Range(from, to)),
+            Some(step) => {
+                let every: u32 = step
+                    .parse()
+                    .ok()
+                    .filter(|n| *n > 0)
+                    .ok_or_else(|| format!("invalid {} step '{}'", self.name, step))?;
+                Ok(Part::Step {
+                    from,
+                    to,
+                    every,
+                    all,
+                })
+            }
+        }
+    }
+
+    fn parse_value(&self, text: &str) -> Result<u32, String> {
+        let by_name = self
+            .names
+            .iter()
+            .position(|name| text.len() == 3 && name[..3].eq_ignore_ascii_case(text));
+        let value = match by_name {
+            Some(index) => index as u32 + self.names_start,
+            None => text
+                .parse()
+                .map_err(|_| format!("invalid {} value '{}'", self.name, text))?,
+        };
+        if value < self.min || value > self.max {
+            return Err(format!(
+                "{} value {} is out of range {}-{}",
+                self.name, value, self.min, self.max
+            ));
+        }
+        Ok(value)
+    }
+}
+
+/// Join items as "a", "a and b", "a, b and c"
+fn join_list(items: &[String]) -> String {
+    match items {
+        [] => String::new(),
+        [one] => one.clone(),
+        [rest @ .., last] => format!("{} and {}", rest.join(", "), last),
+    }
+}
+
+fn hh_mm(hour: u32, minute: u32) -> String {
+    format!("{:02}:{:02}", hour, minute)
+}
+
+fn month_name(month: u32) -> String {
+    MONTHS[(month as usize + 11) % 12].to_string()
+}
+
+fn weekday_name(day: u32) -> String {
+    WEEKDAYS[day as usize % 7].to_string()
+}
+
+fn plural(n: u32, unit: &str) -> String {
+    if n == 1 {
+        format!("{} {}", n, unit)
+    } else {
+        format!("{} {}s", n, unit)
+    }
+}
+
+/// Describe the parts of a list field, naming each value with `name`
+fn describe_parts(parts: &[Part], name: fn(u32) -> String, unit: &str) -> String {
+    let items: Vec<String> = parts
+        .iter()
+        .map(|part| match *part {
+            Part::Value(v) => name(v),
+            Part::Range(a, b) => format!("{} through {}", name(a), name(b)),
+            Part::Step {
+                from, to, every, ..
+            } => format!(
+                "every {} from {} through {}",
+                plural(every, unit),
+                name(from),
+                name(to)
+            ),
+        })
+        .collect();
+    join_list(&items)
+}
+
+/// Describe a cron expression in plain English
+pub struct CronDescribe;
+
+impl CronDescribe {
+    fn describe(expression: &str) -> Result<String, String> {
+        let expanded = match expression.trim() {
+            "@reboot" => return Ok("At system startup".to_string()),
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            other if other.starts_with('@') => {
+                return Err(format!("unknown macro '{}'", other));
+            }
+            other => other,
+        };
+
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(format!(
+                "expected 5 fields (minute hour day-of-month month day-of-week), got {}",
+                fields.len()
+            ));
+        }
+
+        let minute = MINUTE.parse(fields[0])?;
+        let hour = HOUR.parse(fields[1])?;
+        let day_of_month = DAY_OF_MONTH.parse(fields[2])?;
+        let month = MONTH.parse(fields[3])?;
+        let day_of_week = DAY_OF_WEEK.parse(fields[4])?;
+
+        let mut time = Self::describe_time(&minute, &hour);
+        let daily = day_of_month == Field::Any && month == Field::Any && day_of_week == Field::Any;
+        if daily && hour != Field::Any && hour.every().is_none() {
+            time.push_str(" every day");
+        }
+
+        let mut segments = vec![time];
+        match (&day_of_month, &day_of_week) {
+            (Field::Parts(dom), Field::Parts(dow)) => {
+                // Cron runs when either day field matches
+                segments.push(format!(
+                    "{} or {}",
+                    Self::describe_day_of_month(dom),
+                    Self::describe_day_of_week(dow, false)
+                ));
+            }
+            (Field::Parts(dom), Field::Any) => segments.push(Self::describe_day_of_month(dom)),
+            (Field::Any, Field::Parts(dow)) => segments.push(Self::describe_day_of_week(dow, true)),
+            (Field::Any, Field::Any) => {}
+        }
+        if let Field::Parts(parts) = &month {
+            segments.push(Self::describe_month(parts));
+        }
+
+        Ok(segments.join(", "))
+    }
+
+    fn describe_time(minute: &Field, hour: &Field) -> String {
+        // Fixed times: "At 02:30", "At 09:00 and 17:00"
+        if let (Some(minutes), Some(hours)) = (minute.values(), hour.values())
+            && minutes.len() * hours.len() <= 4
+        {
+            let times: Vec<String> = hours
+                .iter()
+                .flat_map(|h| minutes.iter().map(move |m| hh_mm(*h, *m)))
+                .collect();
+            return format!("At {}", join_list(&times));
+        }
+
+        if minute.single() == Some(0) {
+            if *hour == Field::Any {
+                return "Every hour".to_string();
+            }
+            if let Some(n) = hour.every() {
+                return format!("Every {}", plural(n, "hour"));
+            }
+        }
+
+        let minute_text = match minute {
+            Field::Any => "Every minute".to_string(),
+            Field::Parts(parts) => match parts.as_slice() {
+                [
+                    Part::Step {
+                        every, all: true, ..
+                    },
+                ] => format!("Every {}", plural(*every, "minute")),
+                [
+                    Part::Step {
+                        from, to, every, ..
+                    },
+                ] => format!(
+                    "Every {}, minutes {} through {} past the hour",
+                    plural(*every, "minute"),
+                    from,
+                    to
+                ),
+                [Part::Range(a, b)] => {
+                    format!("Every minute, minutes {} through {} past the hour", a, b)
+                }
+                [Part::Value(1)] => "At 1 minute past the hour".to_string(),
+                [Part::Value(m)] => format!("At {} minutes past the hour", m),
+                _ => format!(
+                    "At minutes {} past the hour",
+                    describe_parts(parts, |m| m.to_string(), "minute")
+                ),
+            },
+        };
+
+        let hour_text = match hour {
+            Field::Any => return minute_text,
+            Field::Parts(parts) => match parts.as_slice() {
+                [
+                    Part::Step {
+                        every, all: true, ..
+                    },
+                ] => format!("every {}", plural(*every, "hour")),
+                [
+                    Part::Step {
+                        from, to, every, ..
+                    },
+                ] => format!(
+                    "every {}, between {} and {}",
+                    plural(*every, "hour"),
+                    hh_mm(*from, 0),
+                    hh_mm(*to, 59)
+                ),
+                [Part::Value(h)] => format!("between {} and {}", hh_mm(*h, 0), hh_mm(*h, 59)),
+                [Part::Range(a, b)] => format!("between {} and {}", hh_mm(*a, 0), hh_mm(*b, 59)),
+                _ => format!(
+                    "during hours {}",
+                    describe_parts(parts, |h| format!("{:02}", h), "hour")
+                ),
+            },
+        };
+
+        format!("{}, {}", minute_text, hour_text)
+    }
+
+    fn describe_day_of_month(parts: &[Part]) -> String {
+        match parts {
+            [Part::Value(d)] => format!("on day {} of the month", d),
+            [Part::Range(a, b)] => format!("between day {} and {} of the month", a, b),
+            [
+                Part::Step {
+                    every, all: true, ..
+                },
+            ] => format!("every {}", plural(*every, "day")),
+            _ => format!(
+                "on days {} of the month",
+                describe_parts(parts, |d| d.to_string(), "day")
+            ),
+        }
+    }
+
+    fn describe_day_of_week(parts: &[Part], only: bool) -> String {
+        let prefix = if only { "only on" } else { "on" };
+        match parts {
+            [Part::Range(a, b)] => format!("{} through {}", weekday_name(*a), weekday_name(*b)),
+            [
+                Part::Step {
+                    every, all: true, ..
+                },
+            ] => {
+                format!("every {} of the week", plural(*every, "day"))
+            }
+            _ => format!("{} {}", prefix, describe_parts(parts, weekday_name, "day")),
+        }
+    }
+
+    fn describe_month(parts: &[Part]) -> String {
+        match parts {
+            [Part::Range(a, b)] => format!("{} through {}", month_name(*a), month_name(*b)),
+            [
+                Part::Step {
+                    every, all: true, ..
+                },
+            ] => format!("every {}", plural(*every, "month")),
+            _ => format!("only in {}", describe_parts(parts, month_name, "month")),
+        }
+    }
+}
+
+impl Function for CronDescribe {
+    const NAME: &'static str = "cron_describe";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "cron_describe",
+        category: "datetime",
+        description: "Describe a five-field cron expression in plain English",
+        arguments: &[ArgumentMetadata {
+            name: "expression",
+            arg_type: "string",
+            required: true,
+            default: None,
+            description: "Cron expression (minute hour day-of-month month day-of-week) or a macro like @daily",
+        }],
+        return_type: "string",
+        examples: &[
+            "{{ cron_describe(expression=\"30 2 * * *\") }}",
+            "# {{ cron_describe(expression=job.schedule) }}\nschedule: \"{{ job.schedule }}\"",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let expression: String = kwargs.get("expression")?;
+
+        let description = Self::describe(&expression).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("cron_describe: invalid expression '{}': {}", expression, e),
+            )
+        })?;
+        Ok(Value::from(description))
+    }
+}
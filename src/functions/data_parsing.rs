@@ -4,18 +4,47 @@
 //! - read_json_file: Read and parse JSON file
 //! - read_yaml_file: Read and parse YAML file
 //! - read_toml_file: Read and parse TOML file
+//! - read_json_value, read_yaml_value, read_toml_value: Read a single value at
+//!   a dotted key from a data file
+//!
+//! The file readers are memoized per render (see `memoize`), and the value
+//! readers reuse the same stored result, so reading one file repeatedly
+//! (e.g., inside a loop) only parses it once. Pass `cache=false` for files
+//! that may change during the render.
 //!
 //! Note: parse_json, parse_yaml, parse_toml (string parsing) are now in
 //! filter_functions/serialization.rs with dual function+filter syntax support.
 
+use super::memoize::{self, CACHE_ARG};
 use super::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
 use super::traits::ContextFunction;
 use crate::TemplateContext;
-use minijinja::value::Kwargs;
+use minijinja::value::{Kwargs, ValueKind};
 use minijinja::{Error, ErrorKind, Value};
 use std::fs;
+use std::path::Path;
 use std::sync::Arc;
 
+/// Parser for the content of a data file
+type ParseFn = fn(&str, &Path) -> Result<serde_json::Value, Error>;
+
+/// Read and parse a data file, after the path security checks
+fn read_data(context: &TemplateContext, path: &str, parse: ParseFn) -> Result<Value, Error> {
+    if !context.is_trust_mode() {
+        crate::functions::filesystem::validate_path_security(path)?;
+    }
+
+    let resolved_path = context.resolve_path(path);
+    let content = fs::read_to_string(&resolved_path).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidOperation,
+            format!("Failed to read file '{}': {}", resolved_path.display(), e),
+        )
+    })?;
+
+    Ok(Value::from_serialize(parse(&content, &resolved_path)?))
+}
+
 /// Read and parse a JSON file
 pub struct ReadJsonFile;
 
@@ -25,50 +54,28 @@ impl ContextFunction for ReadJsonFile {
         name: "read_json_file",
         category: "data_parsing",
         description: "Read and parse a JSON file",
-        arguments: &[ArgumentMetadata {
-            name: "path",
-            arg_type: "string",
-            required: true,
-            default: None,
-            description: "Path to the JSON file",
-        }],
+        arguments: &[
+            ArgumentMetadata {
+                name: "path",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Path to the JSON file",
+            },
+            CACHE_ARG,
+        ],
         return_type: "object|array",
         examples: &[
             "{% set config = read_json_file(path=\"config.json\") %}",
             "{{ read_json_file(path=\"data.json\").items | length }}",
+            "{{ read_json_file(path=\"status.json\", cache=false).state }}",
         ],
         syntax: SyntaxVariants::FUNCTION_ONLY,
     };
 
     fn call(context: Arc<TemplateContext>, kwargs: Kwargs) -> Result<Value, Error> {
         let path: String = kwargs.get("path")?;
-
-        // Security checks
-        if !context.is_trust_mode() {
-            crate::functions::filesystem::validate_path_security(&path)?;
-        }
-
-        let resolved_path = context.resolve_path(&path);
-
-        let content = fs::read_to_string(&resolved_path).map_err(|e| {
-            Error::new(
-                ErrorKind::InvalidOperation,
-                format!("Failed to read file '{}': {}", resolved_path.display(), e),
-            )
-        })?;
-
-        let json_value: serde_json::Value = serde_json::from_str(&content).map_err(|e| {
-            Error::new(
-                ErrorKind::InvalidOperation,
-                format!(
-                    "Failed to parse JSON from file '{}': {}",
-                    resolved_path.display(),
-                    e
-                ),
-            )
-        })?;
-
-        Ok(Value::from_serialize(&json_value))
+        read_data(&context, &path, parse_json)
     }
 }
 
@@ -81,13 +88,16 @@ impl ContextFunction for ReadYamlFile {
         name: "read_yaml_file",
         category: "data_parsing",
         description: "Read and parse a YAML file",
-        arguments: &[ArgumentMetadata {
-            name: "path",
-            arg_type: "string",
-            required: true,
-            default: None,
-            description: "Path to the YAML file",
-        }],
+        arguments: &[
+            ArgumentMetadata {
+                name: "path",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Path to the YAML file",
+            },
+            CACHE_ARG,
+        ],
         return_type: "object|array",
         examples: &[
             "{% set config = read_yaml_file(path=\"config.yaml\") %}",
@@ -98,39 +108,7 @@ impl ContextFunction for ReadYamlFile {
 
     fn call(context: Arc<TemplateContext>, kwargs: Kwargs) -> Result<Value, Error> {
         let path: String = kwargs.get("path")?;
-
-        if !context.is_trust_mode() {
-            crate::functions::filesystem::validate_path_security(&path)?;
-        }
-
-        let resolved_path = context.resolve_path(&path);
-
-        let content = fs::read_to_string(&resolved_path).map_err(|e| {
-            Error::new(
-                ErrorKind::InvalidOperation,
-                format!("Failed to read file '{}': {}", resolved_path.display(), e),
-            )
-        })?;
-
-        let yaml_value: serde_yaml::Value = serde_yaml::from_str(&content).map_err(|e| {
-            Error::new(
-                ErrorKind::InvalidOperation,
-                format!(
-                    "Failed to parse YAML from file '{}': {}",
-                    resolved_path.display(),
-                    e
-                ),
-            )
-        })?;
-
-        let json_value = serde_yaml_to_json(yaml_value).map_err(|e| {
-            Error::new(
-                ErrorKind::InvalidOperation,
-                format!("Failed to convert YAML to JSON: {}", e),
-            )
-        })?;
-
-        Ok(Value::from_serialize(&json_value))
+        read_data(&context, &path, parse_yaml)
     }
 }
 
@@ -143,13 +121,16 @@ impl ContextFunction for ReadTomlFile {
         name: "read_toml_file",
         category: "data_parsing",
         description: "Read and parse a TOML file",
-        arguments: &[ArgumentMetadata {
-            name: "path",
-            arg_type: "string",
-            required: true,
-            default: None,
-            description: "Path to the TOML file",
-        }],
+        arguments: &[
+            ArgumentMetadata {
+                name: "path",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Path to the TOML file",
+            },
+            CACHE_ARG,
+        ],
         return_type: "object",
         examples: &[
             "{% set config = read_toml_file(path=\"Cargo.toml\") %}",
@@ -160,39 +141,194 @@ impl ContextFunction for ReadTomlFile {
 
     fn call(context: Arc<TemplateContext>, kwargs: Kwargs) -> Result<Value, Error> {
         let path: String = kwargs.get("path")?;
+        read_data(&context, &path, parse_toml)
+    }
+}
 
-        if !context.is_trust_mode() {
-            crate::functions::filesystem::validate_path_security(&path)?;
-        }
+/// Parse JSON file content
+fn parse_json(content: &str, path: &Path) -> Result<serde_json::Value, Error> {
+    serde_json::from_str(content).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidOperation,
+            format!("Failed to parse JSON from file '{}': {}", path.display(), e),
+        )
+    })
+}
+
+/// Parse YAML file content
+fn parse_yaml(content: &str, path: &Path) -> Result<serde_json::Value, Error> {
+    let yaml_value: serde_yaml::Value = serde_yaml::from_str(content).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidOperation,
+            format!("Failed to parse YAML from file '{}': {}", path.display(), e),
+        )
+    })?;
+
+    serde_yaml_to_json(yaml_value).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidOperation,
+            format!("Failed to convert YAML to JSON: {}", e),
+        )
+    })
+}
+
+/// Parse TOML file content
+fn parse_toml(content: &str, path: &Path) -> Result<serde_json::Value, Error> {
+    let toml_value: toml::Value = toml::from_str(content).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidOperation,
+            format!("Failed to parse TOML from file '{}': {}", path.display(), e),
+        )
+    })?;
+
+    toml_to_json(toml_value).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidOperation,
+            format!("Failed to convert TOML to JSON: {}", e),
+        )
+    })
+}
+
+/// Look up a dot-separated key (e.g., "server.ports.0") in a parsed value
+fn lookup_key(value: &Value, key: &str) -> Option<Value> {
+    let mut current = value.clone();
+    for part in key.split('.') {
+        let next = match current.kind() {
+            ValueKind::Map => current.get_item(&Value::from(part)).ok(),
+            ValueKind::Seq => part
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| current.get_item(&Value::from(index)).ok()),
+            _ => None,
+        };
+        current = next.filter(|v| !v.is_undefined())?;
+    }
+    Some(current)
+}
+
+/// Read a data file and return the value at `key`, or `default` when absent
+///
+/// The parsed file is shared with the memoized file reader `reader`, so
+/// `read_json_value` and `read_json_file` on the same path parse it once.
+fn read_value(
+    context: &TemplateContext,
+    kwargs: &Kwargs,
+    reader: &str,
+    parse: ParseFn,
+) -> Result<Value, Error> {
+    let path: String = kwargs.get("path")?;
+    let key: String = kwargs.get("key")?;
+    let default: Option<Value> = kwargs.get("default")?;
+    let use_cache = kwargs.get::<Option<bool>>("cache")?.unwrap_or(true);
+
+    let data = if use_cache {
+        let args = [Value::from(Kwargs::from_iter([(
+            "path",
+            Value::from(path.as_str()),
+        )]))];
+        memoize::cached_call(context, reader, &args, || read_data(context, &path, parse))?
+    } else {
+        read_data(context, &path, parse)?
+    };
+
+    Ok(lookup_key(&data, &key)
+        .or(default)
+        .unwrap_or(Value::UNDEFINED))
+}
+
+/// Metadata for the `path` argument of single-value readers
+const VALUE_PATH_ARG: ArgumentMetadata = ArgumentMetadata {
+    name: "path",
+    arg_type: "string",
+    required: true,
+    default: None,
+    description: "Path to the data file",
+};
 
-        let resolved_path = context.resolve_path(&path);
-
-        let content = fs::read_to_string(&resolved_path).map_err(|e| {
-            Error::new(
-                ErrorKind::InvalidOperation,
-                format!("Failed to read file '{}': {}", resolved_path.display(), e),
-            )
-        })?;
-
-        let toml_value: toml::Value = toml::from_str(&content).map_err(|e| {
-            Error::new(
-                ErrorKind::InvalidOperation,
-                format!(
-                    "Failed to parse TOML from file '{}': {}",
-                    resolved_path.display(),
-                    e
-                ),
-            )
-        })?;
-
-        let json_value = toml_to_json(toml_value).map_err(|e| {
-            Error::new(
-                ErrorKind::InvalidOperation,
-                format!("Failed to convert TOML to JSON: {}", e),
-            )
-        })?;
-
-        Ok(Value::from_serialize(&json_value))
+/// Metadata for the `key` argument of single-value readers
+const VALUE_KEY_ARG: ArgumentMetadata = ArgumentMetadata {
+    name: "key",
+    arg_type: "string",
+    required: true,
+    default: None,
+    description: "Dot-separated key (e.g., \"database.host\" or \"servers.0.name\")",
+};
+
+/// Metadata for the `default` argument of single-value readers
+const VALUE_DEFAULT_ARG: ArgumentMetadata = ArgumentMetadata {
+    name: "default",
+    arg_type: "any",
+    required: false,
+    default: None,
+    description: "Value returned when the key is absent (undefined if omitted)",
+};
+
+/// Read a single value from a JSON file
+pub struct ReadJsonValue;
+
+impl ContextFunction for ReadJsonValue {
+    const NAME: &'static str = "read_json_value";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "read_json_value",
+        category: "data_parsing",
+        description: "Read a JSON file and return the value at a dot-separated key",
+        arguments: &[VALUE_PATH_ARG, VALUE_KEY_ARG, VALUE_DEFAULT_ARG, CACHE_ARG],
+        return_type: "any",
+        examples: &[
+            "{{ read_json_value(path=\"package.json\", key=\"version\") }}",
+            "{{ read_json_value(path=\"config.json\", key=\"server.port\", default=8080) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(context: Arc<TemplateContext>, kwargs: Kwargs) -> Result<Value, Error> {
+        read_value(&context, &kwargs, ReadJsonFile::NAME, parse_json)
+    }
+}
+
+/// Read a single value from a YAML file
+pub struct ReadYamlValue;
+
+impl ContextFunction for ReadYamlValue {
+    const NAME: &'static str = "read_yaml_value";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "read_yaml_value",
+        category: "data_parsing",
+        description: "Read a YAML file and return the value at a dot-separated key",
+        arguments: &[VALUE_PATH_ARG, VALUE_KEY_ARG, VALUE_DEFAULT_ARG, CACHE_ARG],
+        return_type: "any",
+        examples: &[
+            "{{ read_yaml_value(path=\"values.yaml\", key=\"image.tag\") }}",
+            "{{ read_yaml_value(path=\"values.yaml\", key=\"replicas\", default=1) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(context: Arc<TemplateContext>, kwargs: Kwargs) -> Result<Value, Error> {
+        read_value(&context, &kwargs, ReadYamlFile::NAME, parse_yaml)
+    }
+}
+
+/// Read a single value from a TOML file
+pub struct ReadTomlValue;
+
+impl ContextFunction for ReadTomlValue {
+    const NAME: &'static str = "read_toml_value";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "read_toml_value",
+        category: "data_parsing",
+        description: "Read a TOML file and return the value at a dot-separated key",
+        arguments: &[VALUE_PATH_ARG, VALUE_KEY_ARG, VALUE_DEFAULT_ARG, CACHE_ARG],
+        return_type: "any",
+        examples: &[
+            "{{ read_toml_value(path=\"Cargo.toml\", key=\"package.version\") }}",
+            "{{ read_toml_value(path=\"config.toml\", key=\"server.host\", default=\"localhost\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(context: Arc<TemplateContext>, kwargs: Kwargs) -> Result<Value, Error> {
+        read_value(&context, &kwargs, ReadTomlFile::NAME, parse_toml)
     }
 }
 
@@ -6,6 +6,9 @@
 //! - `date_add`: Add days to timestamp
 //! - `date_diff`: Calculate difference between timestamps
 //! - `timezone_convert`: Convert timestamp between timezones
+//! - `tz_convert`: Show an epoch or ISO date-time in another timezone
+//! - `parse_duration`: Parse a human duration ("1h30m") into seconds
+//! - `business_days_between`: Count weekdays between two dates, skipping holidays
 //!
 //! Note: format_date, get_year, get_month, get_day, get_hour, get_minute, get_second
 //! are now in filter_functions/datetime.rs with dual function+filter syntax support.
@@ -14,10 +17,12 @@
 
 use super::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
 use super::traits::Function;
-use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc};
 use chrono_tz::Tz;
-use minijinja::value::Kwargs;
+use minijinja::value::{Kwargs, ValueKind};
 use minijinja::{Error, ErrorKind, Value};
+use std::collections::HashSet;
+use std::fmt::Write;
 
 /// Get current Unix timestamp, optionally formatted
 pub struct Now;
@@ -288,3 +293,343 @@ impl Function for TimezoneConvert {
         Ok(Value::from(dt_to.timestamp()))
     }
 }
+
+/// Show an epoch or ISO date-time in another timezone
+pub struct TzConvert;
+
+impl TzConvert {
+    /// Default output format: ISO 8601 with the target zone's offset
+    const DEFAULT_FORMAT: &'static str = "%Y-%m-%dT%H:%M:%S%:z";
+
+    /// Naive date-time layouts interpreted in the `from` zone
+    const NAIVE_FORMATS: &'static [&'static str] = &[
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%d %H:%M",
+    ];
+
+    fn parse_tz(name: &str) -> Result<Tz, Error> {
+        name.parse().map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("tz_convert: unknown timezone '{}'", name),
+            )
+        })
+    }
+
+    /// Resolve the input to an instant; naive strings are read as `from` local time
+    fn to_utc(value: &Value, from: Tz) -> Result<DateTime<Utc>, Error> {
+        let invalid = || {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!(
+                    "tz_convert: timestamp must be a Unix timestamp or an ISO 8601 date-time, got '{}'",
+                    value
+                ),
+            )
+        };
+
+        if let Some(ts) = value.as_i64() {
+            return DateTime::from_timestamp(ts, 0).ok_or_else(invalid);
+        }
+        let text = value.as_str().ok_or_else(invalid)?.trim();
+
+        if let Ok(dt) = DateTime::parse_from_rfc3339(text) {
+            return Ok(dt.with_timezone(&Utc));
+        }
+        let naive = Self::NAIVE_FORMATS
+            .iter()
+            .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+            .or_else(|| {
+                NaiveDate::parse_from_str(text, "%Y-%m-%d")
+                    .ok()
+                    .and_then(|date| date.and_hms_opt(0, 0, 0))
+            })
+            .ok_or_else(invalid)?;
+
+        // Ambiguous local times (DST fall-back) resolve to the earlier instant
+        from.from_local_datetime(&naive)
+            .earliest()
+            .map(|dt| dt.with_timezone(&Utc))
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!("tz_convert: '{}' does not exist in {}", text, from),
+                )
+            })
+    }
+}
+
+impl Function for TzConvert {
+    const NAME: &'static str = "tz_convert";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "tz_convert",
+        category: "datetime",
+        description: "Convert an epoch or ISO 8601 date-time to another timezone and format it",
+        arguments: &[
+            ArgumentMetadata {
+                name: "timestamp",
+                arg_type: "integer|string",
+                required: true,
+                default: None,
+                description: "Unix timestamp or ISO 8601 date-time (e.g., \"2024-01-01T09:00:00\")",
+            },
+            ArgumentMetadata {
+                name: "from",
+                arg_type: "string",
+                required: false,
+                default: Some("UTC"),
+                description: "Timezone of date-times without an offset",
+            },
+            ArgumentMetadata {
+                name: "to",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Target timezone (e.g., Asia/Tokyo)",
+            },
+            ArgumentMetadata {
+                name: "format",
+                arg_type: "string",
+                required: false,
+                default: Some("%Y-%m-%dT%H:%M:%S%:z"),
+                description: "strftime format for the result (%Z prints the zone abbreviation)",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ tz_convert(timestamp=1704067200, to=\"Asia/Tokyo\") }}",
+            "{{ tz_convert(timestamp=\"2024-06-01 09:00\", from=\"Europe/Warsaw\", to=\"America/New_York\", format=\"%H:%M %Z\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let timestamp: Value = kwargs.get("timestamp")?;
+        let from: Option<String> = kwargs.get("from")?;
+        let to: String = kwargs.get("to")?;
+        let format: Option<String> = kwargs.get("format")?;
+
+        let from = Self::parse_tz(from.as_deref().unwrap_or("UTC"))?;
+        let to = Self::parse_tz(&to)?;
+        let instant = Self::to_utc(&timestamp, from)?;
+
+        let mut formatted = String::new();
+        write!(
+            formatted,
+            "{}",
+            instant
+                .with_timezone(&to)
+                .format(format.as_deref().unwrap_or(Self::DEFAULT_FORMAT))
+        )
+        .map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!(
+                    "tz_convert: invalid format string '{}'",
+                    format.as_deref().unwrap_or_default()
+                ),
+            )
+        })?;
+        Ok(Value::from(formatted))
+    }
+}
+
+/// Parse a human-friendly duration string into seconds
+pub struct ParseDuration;
+
+impl ParseDuration {
+    /// Seconds per unit, keyed by every accepted spelling
+    fn unit_seconds(unit: &str) -> Option<f64> {
+        let seconds = match unit {
+            "ms" | "msec" | "msecs" | "millisecond" | "milliseconds" => 0.001,
+            "s" | "sec" | "secs" | "second" | "seconds" => 1.0,
+            "m" | "min" | "mins" | "minute" | "minutes" => 60.0,
+            "h" | "hr" | "hrs" | "hour" | "hours" => 3_600.0,
+            "d" | "day" | "days" => 86_400.0,
+            "w" | "week" | "weeks" => 604_800.0,
+            _ => return None,
+        };
+        Some(seconds)
+    }
+
+    /// Sum `<number><unit>` parts such as "1h30m", "90s", "1.5 days"
+    fn parse(input: &str) -> Result<f64, String> {
+        let mut chars = input.trim().chars().peekable();
+        if chars.peek().is_none() {
+            return Err("duration is empty".to_string());
+        }
+
+        let mut total = 0.0;
+        while chars.peek().is_some() {
+            let number: String =
+                std::iter::from_fn(|| chars.next_if(|c| c.is_ascii_digit() || *c == '.')).collect();
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+            let unit: String =
+                std::iter::from_fn(|| chars.next_if(|c| c.is_ascii_alphabetic())).collect();
+            while chars.next_if(|c| c.is_whitespace() || *c == ',').is_some() {}
+
+            if number.is_empty() {
+                return Err(match chars.peek() {
+                    Some(c) if unit.is_empty() => format!("unexpected character '{}'", c),
+                    _ => format!("expected a number before '{}'", unit),
+                });
+            }
+            let value: f64 = number
+                .parse()
+                .map_err(|_| format!("invalid number '{}'", number))?;
+            if unit.is_empty() {
+                return Err(format!("missing unit after '{}'", number));
+            }
+            let factor = Self::unit_seconds(&unit.to_ascii_lowercase())
+                .ok_or_else(|| format!("unknown unit '{}'", unit))?;
+            total += value * factor;
+        }
+        Ok(total)
+    }
+}
+
+impl Function for ParseDuration {
+    const NAME: &'static str = "parse_duration";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "parse_duration",
+        category: "datetime",
+        description: "Parse a human duration like \"1h30m\", \"90s\" or \"2d\" into total seconds",
+        arguments: &[ArgumentMetadata {
+            name: "string",
+            arg_type: "string",
+            required: true,
+            default: None,
+            description: "Duration made of <number><unit> parts (units: ms, s, m, h, d, w)",
+        }],
+        return_type: "number",
+        examples: &[
+            "{{ parse_duration(string=\"1h30m\") }}",
+            "timeoutSeconds: {{ parse_duration(string=get_env(name=\"TIMEOUT\", default=\"90s\")) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let input: String = kwargs.get("string")?;
+
+        let seconds = Self::parse(&input).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("parse_duration: invalid duration '{}': {}", input, e),
+            )
+        })?;
+
+        // Whole seconds stay integers so they can be used as-is in configs
+        if seconds.fract() == 0.0 && seconds <= i64::MAX as f64 {
+            Ok(Value::from(seconds as i64))
+        } else {
+            Ok(Value::from(seconds))
+        }
+    }
+}
+
+/// Count weekdays between two dates, skipping holidays
+pub struct BusinessDaysBetween;
+
+impl BusinessDaysBetween {
+    /// Read a `YYYY-MM-DD` string or a Unix timestamp (UTC date) as a date
+    fn to_date(value: &Value, what: &str) -> Result<NaiveDate, Error> {
+        if let Some(ts) = value.as_i64() {
+            if let Some(dt) = DateTime::from_timestamp(ts, 0) {
+                return Ok(dt.date_naive());
+            }
+        } else if let Some(text) = value.as_str() {
+            // Accept full date-times too, using only their date part
+            let date = text.trim().get(..10).unwrap_or(text);
+            if let Ok(date) = NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+                return Ok(date);
+            }
+        }
+        Err(Error::new(
+            ErrorKind::InvalidOperation,
+            format!(
+                "business_days_between: {} must be a YYYY-MM-DD date or a Unix timestamp, got '{}'",
+                what, value
+            ),
+        ))
+    }
+
+    /// Weekdays in the half-open range `[start, end)`
+    fn count(start: NaiveDate, end: NaiveDate, holidays: &HashSet<NaiveDate>) -> i64 {
+        let (from, to, sign) = if start <= end {
+            (start, end, 1)
+        } else {
+            (end, start, -1)
+        };
+        let days = from
+            .iter_days()
+            .take_while(|day| *day < to)
+            .filter(|day| day.weekday().num_days_from_monday() < 5 && !holidays.contains(day))
+            .count() as i64;
+        days * sign
+    }
+}
+
+impl Function for BusinessDaysBetween {
+    const NAME: &'static str = "business_days_between";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "business_days_between",
+        category: "datetime",
+        description: "Count weekdays (Monday-Friday) from start up to, but not including, end, skipping holidays",
+        arguments: &[
+            ArgumentMetadata {
+                name: "start",
+                arg_type: "string|integer",
+                required: true,
+                default: None,
+                description: "Start date (YYYY-MM-DD or Unix timestamp), counted",
+            },
+            ArgumentMetadata {
+                name: "end",
+                arg_type: "string|integer",
+                required: true,
+                default: None,
+                description: "End date (YYYY-MM-DD or Unix timestamp), not counted",
+            },
+            ArgumentMetadata {
+                name: "holidays",
+                arg_type: "array",
+                required: false,
+                default: Some("[]"),
+                description: "Dates to skip (YYYY-MM-DD strings or Unix timestamps)",
+            },
+        ],
+        return_type: "integer",
+        examples: &[
+            "{{ business_days_between(start=\"2024-01-01\", end=\"2024-01-15\") }}",
+            "{{ business_days_between(start=opened_at, end=now(), holidays=[\"2024-12-25\", \"2024-12-26\"]) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let start: Value = kwargs.get("start")?;
+        let end: Value = kwargs.get("end")?;
+        let holidays: Option<Value> = kwargs.get("holidays")?;
+
+        let start = Self::to_date(&start, "start")?;
+        let end = Self::to_date(&end, "end")?;
+
+        let mut holiday_dates = HashSet::new();
+        if let Some(holidays) = holidays.filter(|h| !h.is_none()) {
+            if holidays.kind() != ValueKind::Seq {
+                return Err(Error::new(
+                    ErrorKind::InvalidOperation,
+                    "business_days_between: holidays must be an array of dates",
+                ));
+            }
+            for holiday in holidays.try_iter()? {
+                holiday_dates.insert(Self::to_date(&holiday, "each holiday")?);
+            }
+        }
+
+        Ok(Value::from(Self::count(start, end, &holiday_dates)))
+    }
+}
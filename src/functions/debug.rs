@@ -3,13 +3,17 @@
 //! This module provides functions for:
 //! - Debugging values: `debug`, `inspect`, `type_of`
 //! - Assertions and validation: `assert`, `warn`, `abort`
+//! - Listing the variables a template sees: `context`
 
 use super::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
-use super::traits::Function;
-use minijinja::value::Kwargs;
-use minijinja::{Error, ErrorKind, Value};
+use super::traits::{ContextFunction, Function, StateFunction};
+use crate::TemplateContext;
+use minijinja::value::{Kwargs, ValueKind};
+use minijinja::{Error, ErrorKind, State, Value};
+use std::collections::HashSet;
+use std::sync::Arc;
 
-/// Print value to stderr and return it (for debugging)
+/// Print a value's structure to stderr (for debugging)
 pub struct Debug;
 
 impl Function for Debug {
@@ -17,7 +21,7 @@ impl Function for Debug {
     const METADATA: FunctionMetadata = FunctionMetadata {
         name: "debug",
         category: "debug",
-        description: "Print value to stderr and return it unchanged (for debugging)",
+        description: "Print a value's type and structure to stderr and return an empty string",
         arguments: &[ArgumentMetadata {
             name: "value",
             arg_type: "any",
@@ -25,15 +29,77 @@ impl Function for Debug {
             default: None,
             description: "Value to debug print",
         }],
-        return_type: "any",
-        examples: &["{% set config = debug(value=parse_json(string='{\"port\": 8080}')) %}"],
+        return_type: "string",
+        examples: &[
+            "{{ debug(value=parse_json(string='{\"port\": 8080}')) }}",
+            "{{ debug(value=config.servers) }}",
+        ],
         syntax: SyntaxVariants::FUNCTION_ONLY,
     };
 
     fn call(kwargs: Kwargs) -> Result<Value, Error> {
         let value: Value = kwargs.get("value")?;
-        eprintln!("[DEBUG] {}", value);
-        Ok(value)
+        eprintln!("[DEBUG] {}", describe_value(&value).trim_end());
+        Ok(Value::from(""))
+    }
+}
+
+/// Describe a value's type and structure as an indented tree
+///
+/// Objects list their keys, arrays their indices, and scalars their type and
+/// value. Works on any value, including undefined and non-serializable ones.
+pub fn describe_value(value: &Value) -> String {
+    let mut out = String::new();
+    describe_into(&mut out, value, 0);
+    out
+}
+
+fn describe_into(out: &mut String, value: &Value, depth: usize) {
+    use std::fmt::Write;
+
+    match value.kind() {
+        ValueKind::Map => {
+            let keys: Vec<Value> = value.try_iter().map(|it| it.collect()).unwrap_or_default();
+            writeln!(out, "object ({} keys)", keys.len()).ok();
+            for key in keys {
+                let item = value.get_item(&key).unwrap_or(Value::UNDEFINED);
+                write!(out, "{}{}: ", "  ".repeat(depth + 1), key).ok();
+                describe_into(out, &item, depth + 1);
+            }
+        }
+        ValueKind::Seq | ValueKind::Iterable => {
+            let items: Vec<Value> = value.try_iter().map(|it| it.collect()).unwrap_or_default();
+            writeln!(out, "{} ({} items)", type_name(value), items.len()).ok();
+            for (index, item) in items.iter().enumerate() {
+                write!(out, "{}[{}]: ", "  ".repeat(depth + 1), index).ok();
+                describe_into(out, item, depth + 1);
+            }
+        }
+        ValueKind::String => {
+            writeln!(out, "string {:?}", value.as_str().unwrap_or_default()).ok();
+        }
+        ValueKind::Undefined | ValueKind::None => {
+            writeln!(out, "{}", type_name(value)).ok();
+        }
+        _ => {
+            writeln!(out, "{} {}", type_name(value), value).ok();
+        }
+    }
+}
+
+/// Get the type name of a value as reported by `type_of`
+pub fn type_name(value: &Value) -> &'static str {
+    match value.kind() {
+        ValueKind::Undefined => "undefined",
+        ValueKind::None => "null",
+        ValueKind::Bool => "boolean",
+        ValueKind::Number => "number",
+        ValueKind::String => "string",
+        ValueKind::Bytes => "bytes",
+        ValueKind::Seq => "array",
+        ValueKind::Map => "object",
+        ValueKind::Iterable => "iterable",
+        _ => "unknown",
     }
 }
 
@@ -45,7 +111,7 @@ impl Function for TypeOf {
     const METADATA: FunctionMetadata = FunctionMetadata {
         name: "type_of",
         category: "debug",
-        description: "Get the type name of a value",
+        description: "Get the type name of a value (string, number, boolean, array, object, null, undefined)",
         arguments: &[ArgumentMetadata {
             name: "value",
             arg_type: "any",
@@ -58,27 +124,14 @@ impl Function for TypeOf {
             "{{ type_of(value=\"hello\") }}",
             "{{ type_of(value=123) }}",
             "{{ type_of(value=[1, 2, 3]) }}",
+            "{% if type_of(value=config.hosts) == \"array\" %}...{% endif %}",
         ],
         syntax: SyntaxVariants::FUNCTION_ONLY,
     };
 
     fn call(kwargs: Kwargs) -> Result<Value, Error> {
         let value: Value = kwargs.get("value")?;
-
-        let type_name = match value.kind() {
-            minijinja::value::ValueKind::Undefined => "undefined",
-            minijinja::value::ValueKind::None => "undefined",
-            minijinja::value::ValueKind::Bool => "bool",
-            minijinja::value::ValueKind::Number => "number",
-            minijinja::value::ValueKind::String => "string",
-            minijinja::value::ValueKind::Bytes => "bytes",
-            minijinja::value::ValueKind::Seq => "array",
-            minijinja::value::ValueKind::Map => "object",
-            minijinja::value::ValueKind::Iterable => "iterable",
-            _ => "unknown",
-        };
-
-        Ok(Value::from(type_name))
+        Ok(Value::from(type_name(&value)))
     }
 }
 
@@ -118,14 +171,14 @@ impl Function for Assert {
     const METADATA: FunctionMetadata = FunctionMetadata {
         name: "assert",
         category: "debug",
-        description: "Assert a condition is true, or fail with an error message",
+        description: "Assert a condition is truthy, or fail with an error message",
         arguments: &[
             ArgumentMetadata {
                 name: "condition",
-                arg_type: "boolean",
+                arg_type: "any",
                 required: true,
                 default: None,
-                description: "Condition to check",
+                description: "Condition to check (any falsy value fails)",
             },
             ArgumentMetadata {
                 name: "message",
@@ -135,34 +188,41 @@ impl Function for Assert {
                 description: "Error message if assertion fails",
             },
         ],
-        return_type: "boolean",
-        examples: &["{{ assert(condition=port != \"\", message=\"PORT is required\") }}"],
+        return_type: "string",
+        examples: &[
+            "{{ assert(condition=port != \"\", message=\"PORT is required\") }}",
+            "{{ assert(condition=replicas > 0, message=\"replicas must be positive\") }}",
+        ],
         syntax: SyntaxVariants::FUNCTION_ONLY,
     };
 
     fn call(kwargs: Kwargs) -> Result<Value, Error> {
-        let condition: bool = kwargs.get("condition")?;
+        let condition: Value = kwargs.get("condition")?;
         let message: String = kwargs
             .get("message")
             .unwrap_or_else(|_| "Assertion failed".to_string());
 
-        if !condition {
+        if !condition.is_true() {
             return Err(Error::new(ErrorKind::InvalidOperation, message));
         }
 
-        Ok(Value::from(true))
+        // Empty string so `{{ assert(...) }}` leaves no trace in the output
+        Ok(Value::from(""))
     }
 }
 
 /// Print a warning message to stderr and continue
+///
+/// Warnings are counted on the template context so `--fail-on-warn` can
+/// turn an otherwise successful render into a failure.
 pub struct Warn;
 
-impl Function for Warn {
+impl ContextFunction for Warn {
     const NAME: &'static str = "warn";
     const METADATA: FunctionMetadata = FunctionMetadata {
         name: "warn",
         category: "debug",
-        description: "Print a warning message to stderr and continue",
+        description: "Print a warning message to stderr and continue (fails the run with --fail-on-warn)",
         arguments: &[ArgumentMetadata {
             name: "message",
             arg_type: "string",
@@ -175,9 +235,10 @@ impl Function for Warn {
         syntax: SyntaxVariants::FUNCTION_ONLY,
     };
 
-    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+    fn call(context: Arc<TemplateContext>, kwargs: Kwargs) -> Result<Value, Error> {
         let message: String = kwargs.get("message")?;
         eprintln!("[WARNING] {}", message);
+        context.record_warning();
         Ok(Value::from(""))
     }
 }
@@ -208,3 +269,46 @@ impl Function for Abort {
         Err(Error::new(ErrorKind::InvalidOperation, message))
     }
 }
+
+/// Return every variable visible to the template as an object
+///
+/// Includes the render context, `{% set %}` variables and loop variables of
+/// the calling scope. Globals such as functions and macros are left out.
+pub struct RenderContext;
+
+impl StateFunction for RenderContext {
+    const NAME: &'static str = "context";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "context",
+        category: "debug",
+        description: "Return every variable visible to the template (context values and {% set %} variables) as an object",
+        arguments: &[],
+        return_type: "object",
+        examples: &[
+            "{{ context() | to_json(pretty=true) }}",
+            "{% for name, value in context() | items %}{{ name }} = {{ value }}\n{% endfor %}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(state: &State, _kwargs: Kwargs) -> Result<Value, Error> {
+        let globals: HashSet<&str> = state.env().globals().map(|(name, _)| name).collect();
+        let mut names: Vec<String> = state
+            .known_variables()
+            .into_iter()
+            .filter(|name| !globals.contains(name.as_ref()))
+            .map(|name| name.into_owned())
+            .collect();
+        names.sort_unstable();
+
+        Ok(Value::from_iter(names.into_iter().filter_map(|name| {
+            let value = state.lookup(&name)?;
+            (!is_callable(&value)).then_some((name, value))
+        })))
+    }
+}
+
+/// Whether a value is a macro or function rather than data
+fn is_callable(value: &Value) -> bool {
+    value.kind() == ValueKind::Plain || super::template::is_macro(value)
+}
@@ -0,0 +1,354 @@
+//! Diagram generation functions for MiniJinja templates
+//!
+//! This module provides functions that turn node and edge lists into diagram
+//! source code:
+//! - `to_mermaid`: Emit a Mermaid flowchart
+//! - `to_dot`: Emit a Graphviz DOT graph
+//!
+//! Nodes are strings (used as both id and label) or objects with `id` and an
+//! optional `label`. Edges are objects with `from`, `to` and an optional
+//! `label`. Nodes referenced only by edges are created implicitly.
+
+use super::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
+use super::traits::Function;
+use minijinja::value::{Kwargs, ValueKind};
+use minijinja::{Error, ErrorKind, Value};
+use std::fmt::Write;
+
+/// A diagram node
+struct Node {
+    id: String,
+    label: Option<String>,
+}
+
+/// A diagram edge
+struct Edge {
+    from: String,
+    to: String,
+    label: Option<String>,
+}
+
+/// Metadata for the `nodes` argument
+const NODES_ARG: ArgumentMetadata = ArgumentMetadata {
+    name: "nodes",
+    arg_type: "array",
+    required: false,
+    default: Some("[]"),
+    description: "Nodes as strings or objects with \"id\" and optional \"label\"",
+};
+
+/// Metadata for the `edges` argument
+const EDGES_ARG: ArgumentMetadata = ArgumentMetadata {
+    name: "edges",
+    arg_type: "array",
+    required: true,
+    default: None,
+    description: "Edges as objects with \"from\", \"to\" and optional \"label\"",
+};
+
+/// Metadata for the `direction` argument
+const DIRECTION_ARG: ArgumentMetadata = ArgumentMetadata {
+    name: "direction",
+    arg_type: "string",
+    required: false,
+    default: Some("TD"),
+    description: "Layout direction: \"TD\" (top-down), \"LR\", \"BT\", or \"RL\"",
+};
+
+/// Read an optional string field from an object
+fn get_field(item: &Value, field: &str) -> Option<String> {
+    item.get_attr(field)
+        .ok()
+        .filter(|v| !v.is_undefined() && !v.is_none())
+        .map(|v| match v.as_str() {
+            Some(s) => s.to_string(),
+            None => v.to_string(),
+        })
+}
+
+/// Read a required string field from an object
+fn require_field(
+    item: &Value,
+    field: &str,
+    kind: &str,
+    index: usize,
+    fn_name: &str,
+) -> Result<String, Error> {
+    get_field(item, field).ok_or_else(|| {
+        Error::new(
+            ErrorKind::InvalidOperation,
+            format!(
+                "{}: {} at index {} is missing '{}'",
+                fn_name, kind, index, field
+            ),
+        )
+    })
+}
+
+/// Parse the `nodes` argument
+fn parse_nodes(nodes: Option<Value>, fn_name: &str) -> Result<Vec<Node>, Error> {
+    let Some(nodes) = nodes else {
+        return Ok(Vec::new());
+    };
+
+    let mut result = Vec::new();
+    for (index, item) in iter_array(&nodes, "nodes", fn_name)?.enumerate() {
+        let node = match item.kind() {
+            ValueKind::String | ValueKind::Number => Node {
+                id: item.to_string(),
+                label: None,
+            },
+            ValueKind::Map => Node {
+                id: require_field(&item, "id", "node", index, fn_name)?,
+                label: get_field(&item, "label"),
+            },
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!(
+                        "{}: node at index {} must be a string or an object",
+                        fn_name, index
+                    ),
+                ));
+            }
+        };
+        result.push(node);
+    }
+    Ok(result)
+}
+
+/// Parse the `edges` argument
+fn parse_edges(edges: &Value, fn_name: &str) -> Result<Vec<Edge>, Error> {
+    let mut result = Vec::new();
+    for (index, item) in iter_array(edges, "edges", fn_name)?.enumerate() {
+        if item.kind() != ValueKind::Map {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                format!("{}: edge at index {} must be an object", fn_name, index),
+            ));
+        }
+        result.push(Edge {
+            from: require_field(&item, "from", "edge", index, fn_name)?,
+            to: require_field(&item, "to", "edge", index, fn_name)?,
+            label: get_field(&item, "label"),
+        });
+    }
+    Ok(result)
+}
+
+/// Iterate over an array argument
+fn iter_array<'a>(
+    value: &'a Value,
+    arg: &str,
+    fn_name: &str,
+) -> Result<impl Iterator<Item = Value> + 'a, Error> {
+    if value.kind() != ValueKind::Seq {
+        return Err(Error::new(
+            ErrorKind::InvalidOperation,
+            format!("{}: {} must be an array", fn_name, arg),
+        ));
+    }
+    value.try_iter()
+}
+
+/// Parse and validate the `direction` argument
+fn parse_direction(direction: Option<String>, fn_name: &str) -> Result<String, Error> {
+    let direction = direction.unwrap_or_else(|| "TD".to_string()).to_uppercase();
+    match direction.as_str() {
+        "TD" | "TB" | "LR" | "BT" | "RL" => Ok(direction),
+        _ => Err(Error::new(
+            ErrorKind::InvalidOperation,
+            format!(
+                "{}: invalid direction '{}'. Use: TD, LR, BT, RL",
+                fn_name, direction
+            ),
+        )),
+    }
+}
+
+/// Turn an arbitrary id into a valid Mermaid node id
+fn mermaid_id(id: &str) -> String {
+    id.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Escape text for a quoted Mermaid label
+fn mermaid_text(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
+
+/// Escape text for a quoted DOT string
+fn dot_text(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Emit a Mermaid flowchart from nodes and edges
+pub struct ToMermaid;
+
+impl Function for ToMermaid {
+    const NAME: &'static str = "to_mermaid";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "to_mermaid",
+        category: "diagram",
+        description: "Generate a Mermaid flowchart from arrays of nodes and edges",
+        arguments: &[NODES_ARG, EDGES_ARG, DIRECTION_ARG],
+        return_type: "string",
+        examples: &[
+            "{{ to_mermaid(edges=[{\"from\": \"api\", \"to\": \"db\", \"label\": \"reads\"}]) }}",
+            "{{ to_mermaid(nodes=services, edges=dependencies, direction=\"LR\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let nodes: Option<Value> = kwargs.get("nodes")?;
+        let edges: Value = kwargs.get("edges")?;
+        let direction: Option<String> = kwargs.get("direction")?;
+
+        let nodes = parse_nodes(nodes, "to_mermaid")?;
+        let edges = parse_edges(&edges, "to_mermaid")?;
+        let direction = parse_direction(direction, "to_mermaid")?;
+
+        // Edge-only nodes whose id had to be sanitized keep their original text as label
+        let mut declared: Vec<Node> = nodes;
+        for edge in &edges {
+            for id in [&edge.from, &edge.to] {
+                if mermaid_id(id) != *id && !declared.iter().any(|n| n.id == *id) {
+                    declared.push(Node {
+                        id: id.clone(),
+                        label: None,
+                    });
+                }
+            }
+        }
+
+        let mut out = String::new();
+        writeln!(out, "flowchart {}", direction).ok();
+        for node in &declared {
+            let label = node.label.as_deref().unwrap_or(&node.id);
+            writeln!(
+                out,
+                "    {}[\"{}\"]",
+                mermaid_id(&node.id),
+                mermaid_text(label)
+            )
+            .ok();
+        }
+        for edge in &edges {
+            match &edge.label {
+                Some(label) => writeln!(
+                    out,
+                    "    {} -->|\"{}\"| {}",
+                    mermaid_id(&edge.from),
+                    mermaid_text(label),
+                    mermaid_id(&edge.to)
+                ),
+                None => writeln!(
+                    out,
+                    "    {} --> {}",
+                    mermaid_id(&edge.from),
+                    mermaid_id(&edge.to)
+                ),
+            }
+            .ok();
+        }
+
+        Ok(Value::from(out.trim_end()))
+    }
+}
+
+/// Emit a Graphviz DOT graph from nodes and edges
+pub struct ToDot;
+
+impl Function for ToDot {
+    const NAME: &'static str = "to_dot";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "to_dot",
+        category: "diagram",
+        description: "Generate a Graphviz DOT graph from arrays of nodes and edges",
+        arguments: &[
+            NODES_ARG,
+            EDGES_ARG,
+            DIRECTION_ARG,
+            ArgumentMetadata {
+                name: "name",
+                arg_type: "string",
+                required: false,
+                default: Some("G"),
+                description: "Graph name",
+            },
+            ArgumentMetadata {
+                name: "directed",
+                arg_type: "boolean",
+                required: false,
+                default: Some("true"),
+                description: "Emit a directed graph (digraph) instead of an undirected one",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ to_dot(edges=[{\"from\": \"api\", \"to\": \"db\"}]) }}",
+            "{{ to_dot(nodes=services, edges=dependencies, direction=\"LR\", name=\"services\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let nodes: Option<Value> = kwargs.get("nodes")?;
+        let edges: Value = kwargs.get("edges")?;
+        let direction: Option<String> = kwargs.get("direction")?;
+        let name: Option<String> = kwargs.get("name")?;
+        let directed: Option<bool> = kwargs.get("directed")?;
+
+        let nodes = parse_nodes(nodes, "to_dot")?;
+        let edges = parse_edges(&edges, "to_dot")?;
+        let direction = parse_direction(direction, "to_dot")?;
+        let name = name.unwrap_or_else(|| "G".to_string());
+        let (keyword, arrow) = if directed.unwrap_or(true) {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+
+        // DOT calls top-down "TB"
+        let rankdir = if direction == "TD" { "TB" } else { &direction };
+
+        let mut out = String::new();
+        writeln!(out, "{} \"{}\" {{", keyword, dot_text(&name)).ok();
+        writeln!(out, "    rankdir={};", rankdir).ok();
+        for node in &nodes {
+            let label = node.label.as_deref().unwrap_or(&node.id);
+            writeln!(
+                out,
+                "    \"{}\" [label=\"{}\"];",
+                dot_text(&node.id),
+                dot_text(label)
+            )
+            .ok();
+        }
+        for edge in &edges {
+            write!(
+                out,
+                "    \"{}\" {} \"{}\"",
+                dot_text(&edge.from),
+                arrow,
+                dot_text(&edge.to)
+            )
+            .ok();
+            if let Some(label) = &edge.label {
+                write!(out, " [label=\"{}\"]", dot_text(label)).ok();
+            }
+            writeln!(out, ";").ok();
+        }
+        out.push('}');
+
+        Ok(Value::from(out))
+    }
+}
@@ -0,0 +1,89 @@
+//! Text diff functions for MiniJinja templates
+//!
+//! This module provides:
+//! - `diff_text`: Unified diff between two strings
+
+use super::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
+use super::traits::Function;
+use minijinja::Value;
+use minijinja::value::Kwargs;
+use similar::TextDiff;
+
+/// Produce a unified diff between two strings
+pub struct DiffText;
+
+impl Function for DiffText {
+    const NAME: &'static str = "diff_text";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "diff_text",
+        category: "diff",
+        description: "Produce a unified diff between two strings (empty if they are equal)",
+        arguments: &[
+            ArgumentMetadata {
+                name: "a",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Original text",
+            },
+            ArgumentMetadata {
+                name: "b",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "New text",
+            },
+            ArgumentMetadata {
+                name: "context",
+                arg_type: "integer",
+                required: false,
+                default: Some("3"),
+                description: "Number of unchanged lines shown around each change",
+            },
+            ArgumentMetadata {
+                name: "a_name",
+                arg_type: "string",
+                required: false,
+                default: Some("a"),
+                description: "Label for the original text in the diff header",
+            },
+            ArgumentMetadata {
+                name: "b_name",
+                arg_type: "string",
+                required: false,
+                default: Some("b"),
+                description: "Label for the new text in the diff header",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ diff_text(a=old_config, b=new_config) }}",
+            "{{ diff_text(a=read_file(path=\"old.yaml\"), b=read_file(path=\"new.yaml\"), context=1, a_name=\"old.yaml\", b_name=\"new.yaml\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, minijinja::Error> {
+        let a: String = kwargs.get("a")?;
+        let b: String = kwargs.get("b")?;
+        let context: Option<usize> = kwargs.get("context")?;
+        let a_name: Option<String> = kwargs.get("a_name")?;
+        let b_name: Option<String> = kwargs.get("b_name")?;
+
+        if a == b {
+            return Ok(Value::from(""));
+        }
+
+        let a_name = a_name.unwrap_or_else(|| "a".to_string());
+        let b_name = b_name.unwrap_or_else(|| "b".to_string());
+
+        let diff = TextDiff::from_lines(&a, &b);
+        let unified = diff
+            .unified_diff()
+            .context_radius(context.unwrap_or(3))
+            .header(&a_name, &b_name)
+            .to_string();
+
+        Ok(Value::from(unified))
+    }
+}
@@ -0,0 +1,273 @@
+//! Container image reference functions for MiniJinja templates
+//!
+//! This module provides functions for working with image references such as
+//! `registry.example.com/ns/app:1.2@sha256:...`:
+//! - `parse_docker_ref`: Split a reference into registry, repository, tag and digest
+//! - `build_docker_ref`: Reassemble a normalized reference from its components
+//!
+//! References follow the Docker rules: the first path component is a registry
+//! only if it contains a `.` or `:` or is `localhost`; otherwise the image is
+//! on Docker Hub (`docker.io`), where single-name images live under `library/`.
+
+use super::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
+use super::traits::Function;
+use minijinja::value::Kwargs;
+use minijinja::{Error, ErrorKind, Value};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Registry used when a reference does not name one
+const DEFAULT_REGISTRY: &str = "docker.io";
+
+/// Tag used when a reference has neither a tag nor a digest
+const DEFAULT_TAG: &str = "latest";
+
+/// One repository path component: lowercase alphanumerics joined by `.`, `_`, `__` or dashes
+static PATH_COMPONENT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[a-z0-9]+(?:(?:\.|_|__|-+)[a-z0-9]+)*$").unwrap());
+
+/// Registry host with optional port (hostname, IPv4 or bracketed IPv6)
+static REGISTRY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?:[a-zA-Z0-9](?:[a-zA-Z0-9-]*[a-zA-Z0-9])?(?:\.[a-zA-Z0-9](?:[a-zA-Z0-9-]*[a-zA-Z0-9])?)*|\[[0-9a-fA-F:]+\])(?::[0-9]+)?$").unwrap()
+});
+
+static TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[\w][\w.-]{0,127}$").unwrap());
+
+static DIGEST: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[a-z0-9]+(?:[.+_-][a-z0-9]+)*:[a-fA-F0-9]{32,}$").unwrap());
+
+/// Components of an image reference
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DockerRef {
+    pub registry: String,
+    pub repository: String,
+    pub tag: Option<String>,
+    pub digest: Option<String>,
+}
+
+fn invalid(fn_name: &str, message: String) -> Error {
+    Error::new(
+        ErrorKind::InvalidOperation,
+        format!("{}: {}", fn_name, message),
+    )
+}
+
+/// Check a registry host, repository path, tag and digest against the reference grammar
+fn validate_parts(
+    registry: &str,
+    repository: &str,
+    tag: Option<&str>,
+    digest: Option<&str>,
+) -> Result<(), String> {
+    if !REGISTRY.is_match(registry) {
+        return Err(format!("invalid registry '{}'", registry));
+    }
+    if repository.is_empty() || !repository.split('/').all(|c| PATH_COMPONENT.is_match(c)) {
+        return Err(format!(
+            "invalid repository '{}' (lowercase letters, digits and separators only)",
+            repository
+        ));
+    }
+    if let Some(tag) = tag
+        && !TAG.is_match(tag)
+    {
+        return Err(format!("invalid tag '{}'", tag));
+    }
+    if let Some(digest) = digest
+        && !DIGEST.is_match(digest)
+    {
+        return Err(format!(
+            "invalid digest '{}' (expected algorithm:hex)",
+            digest
+        ));
+    }
+    Ok(())
+}
+
+/// Parse an image reference, filling in Docker's defaults
+///
+/// # Example
+///
+/// ```
+/// use tmpltool::functions::docker::parse_reference;
+///
+/// let parsed = parse_reference("nginx").unwrap();
+/// assert_eq!(parsed.registry, "docker.io");
+/// assert_eq!(parsed.repository, "library/nginx");
+/// assert_eq!(parsed.tag.as_deref(), Some("latest"));
+/// ```
+pub fn parse_reference(reference: &str) -> Result<DockerRef, Error> {
+    const FN: &str = "parse_docker_ref";
+    if reference.is_empty() {
+        return Err(invalid(FN, "empty image reference".to_string()));
+    }
+
+    let (name, digest) = match reference.split_once('@') {
+        Some((name, digest)) => (name, Some(digest.to_string())),
+        None => (reference, None),
+    };
+
+    // A ':' after the last '/' starts the tag; earlier ones belong to a registry port
+    let last_slash = name.rfind('/').map_or(0, |i| i + 1);
+    let (name, tag) = match name[last_slash..].rfind(':') {
+        Some(i) => (
+            &name[..last_slash + i],
+            Some(name[last_slash + i + 1..].to_string()),
+        ),
+        None => (name, None),
+    };
+
+    let (registry, repository) = match name.split_once('/') {
+        Some((first, rest))
+            if first.contains('.') || first.contains(':') || first == "localhost" =>
+        {
+            (first.to_string(), rest.to_string())
+        }
+        _ => (DEFAULT_REGISTRY.to_string(), name.to_string()),
+    };
+    let repository = if registry == DEFAULT_REGISTRY && !repository.contains('/') {
+        format!("library/{}", repository)
+    } else {
+        repository
+    };
+
+    validate_parts(&registry, &repository, tag.as_deref(), digest.as_deref()).map_err(|e| {
+        invalid(
+            FN,
+            format!("malformed image reference '{}': {}", reference, e),
+        )
+    })?;
+
+    let tag = match (tag, &digest) {
+        (None, None) => Some(DEFAULT_TAG.to_string()),
+        (tag, _) => tag,
+    };
+
+    Ok(DockerRef {
+        registry,
+        repository,
+        tag,
+        digest,
+    })
+}
+
+/// Split an image reference into its components
+pub struct ParseDockerRef;
+
+impl Function for ParseDockerRef {
+    const NAME: &'static str = "parse_docker_ref";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "parse_docker_ref",
+        category: "docker",
+        description: "Split an image reference into {registry, repository, tag, digest} (defaults: docker.io, latest)",
+        arguments: &[ArgumentMetadata {
+            name: "string",
+            arg_type: "string",
+            required: true,
+            default: None,
+            description: "Image reference (e.g., \"registry.example.com/ns/app:1.2\")",
+        }],
+        return_type: "object",
+        examples: &[
+            "{{ parse_docker_ref(string=\"nginx:1.25\").repository }}",
+            "{% set ref = parse_docker_ref(string=image) %}{{ ref.registry }}/{{ ref.repository }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let string: String = kwargs.get("string")?;
+        let parsed = parse_reference(&string)?;
+        Ok(Value::from_iter([
+            ("registry", Value::from(parsed.registry)),
+            ("repository", Value::from(parsed.repository)),
+            ("tag", Value::from(parsed.tag)),
+            ("digest", Value::from(parsed.digest)),
+        ]))
+    }
+}
+
+/// Reassemble a normalized image reference from its components
+pub struct BuildDockerRef;
+
+impl Function for BuildDockerRef {
+    const NAME: &'static str = "build_docker_ref";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "build_docker_ref",
+        category: "docker",
+        description: "Build a normalized image reference, omitting docker.io and preferring the digest over the tag",
+        arguments: &[
+            ArgumentMetadata {
+                name: "registry",
+                arg_type: "string",
+                required: false,
+                default: Some("docker.io"),
+                description: "Registry host, with port if needed (omitted from the result when docker.io)",
+            },
+            ArgumentMetadata {
+                name: "repository",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Repository path (e.g., \"ns/app\")",
+            },
+            ArgumentMetadata {
+                name: "tag",
+                arg_type: "string",
+                required: false,
+                default: None,
+                description: "Image tag",
+            },
+            ArgumentMetadata {
+                name: "digest",
+                arg_type: "string",
+                required: false,
+                default: None,
+                description: "Image digest (e.g., \"sha256:...\")",
+            },
+            ArgumentMetadata {
+                name: "prefer_tag",
+                arg_type: "boolean",
+                required: false,
+                default: Some("false"),
+                description: "Keep the tag instead of the digest when both are given",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ build_docker_ref(repository=\"library/nginx\", tag=\"1.25\") }}",
+            "{% set ref = parse_docker_ref(string=image) %}{{ build_docker_ref(registry=\"mirror.internal:5000\", repository=ref.repository, tag=ref.tag, digest=ref.digest) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        const FN: &str = "build_docker_ref";
+        let registry: Option<String> = kwargs.get("registry")?;
+        let repository: String = kwargs.get("repository")?;
+        let tag: Option<String> = kwargs.get("tag")?;
+        let digest: Option<String> = kwargs.get("digest")?;
+        let prefer_tag: bool = kwargs.get::<Option<bool>>("prefer_tag")?.unwrap_or(false);
+
+        let registry = registry.unwrap_or_else(|| DEFAULT_REGISTRY.to_string());
+        validate_parts(&registry, &repository, tag.as_deref(), digest.as_deref())
+            .map_err(|e| invalid(FN, e))?;
+
+        let mut reference = if registry == DEFAULT_REGISTRY {
+            repository
+                .strip_prefix("library/")
+                .filter(|name| !name.contains('/'))
+                .unwrap_or(&repository)
+                .to_string()
+        } else {
+            format!("{}/{}", registry, repository)
+        };
+        match (tag, digest) {
+            (Some(tag), Some(_)) if prefer_tag => reference = format!("{}:{}", reference, tag),
+            (_, Some(digest)) => reference = format!("{}@{}", reference, digest),
+            (Some(tag), None) => reference = format!("{}:{}", reference, tag),
+            (None, None) => {}
+        }
+        Ok(Value::from(reference))
+    }
+}
@@ -3,12 +3,21 @@
 //! This module provides functions for accessing and filtering environment variables:
 //! - `get_env`: Get an environment variable with optional default value
 //! - `filter_env`: Filter environment variables by glob pattern
+//! - `env_to_object`: Build a nested object from prefixed environment variables
+//! - `to_env_keys`: Flatten a nested object into uppercase environment-style keys
+//! - `secret`: Resolve a secret from an environment variable or a file
+//! - `prompt`: Ask for a value on stdin (with `--interactive`) when it is not set
 
+use super::filesystem::validate_path_security;
 use super::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
-use super::traits::Function;
+use super::traits::{ContextFunction, Function};
+use crate::TemplateContext;
 use minijinja::value::Kwargs;
 use minijinja::{Error, ErrorKind, Value};
 use std::collections::HashMap;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::sync::Arc;
 
 /// Get environment variable with optional default
 pub struct GetEnv;
@@ -120,6 +129,504 @@ impl Function for FilterEnv {
     }
 }
 
+/// Build a nested object from prefixed environment variables
+pub struct EnvToObject;
+
+impl Function for EnvToObject {
+    const NAME: &'static str = "env_to_object";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "env_to_object",
+        category: "environment",
+        description: "Build a nested object from environment variables sharing a prefix, splitting names on a delimiter (e.g., APP__DB__HOST becomes db.host)",
+        arguments: &[
+            ArgumentMetadata {
+                name: "prefix",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Variable name prefix (e.g., \"APP\" matches APP__*)",
+            },
+            ArgumentMetadata {
+                name: "delimiter",
+                arg_type: "string",
+                required: false,
+                default: Some("__"),
+                description: "Separator between the prefix and nesting levels",
+            },
+            ArgumentMetadata {
+                name: "lowercase",
+                arg_type: "boolean",
+                required: false,
+                default: Some("true"),
+                description: "Lowercase the resulting keys",
+            },
+        ],
+        return_type: "object",
+        examples: &[
+            "{% set config = env_to_object(prefix=\"APP\") %}{{ config.db.host }}",
+            "{{ env_to_object(prefix=\"MYAPP\", delimiter=\"_\", lowercase=false) | tojson }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let prefix: String = kwargs.get("prefix")?;
+        let delimiter: Option<String> = kwargs.get("delimiter")?;
+        let lowercase: Option<bool> = kwargs.get("lowercase")?;
+        let delimiter = delimiter.unwrap_or_else(|| "__".to_string());
+        let lowercase = lowercase.unwrap_or(true);
+
+        if delimiter.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                "env_to_object: delimiter cannot be empty",
+            ));
+        }
+
+        // "APP" and "APP__" both select APP__* variables
+        let full_prefix = if prefix.ends_with(&delimiter) {
+            prefix
+        } else {
+            format!("{}{}", prefix, delimiter)
+        };
+
+        let mut vars: Vec<(String, String)> = std::env::vars()
+            .filter(|(key, _)| key.starts_with(&full_prefix))
+            .collect();
+        vars.sort();
+
+        let mut root = serde_json::Map::new();
+        for (name, value) in vars {
+            let rest = &name[full_prefix.len()..];
+            let parts: Vec<String> = rest
+                .split(delimiter.as_str())
+                .filter(|part| !part.is_empty())
+                .map(|part| {
+                    if lowercase {
+                        part.to_lowercase()
+                    } else {
+                        part.to_string()
+                    }
+                })
+                .collect();
+
+            if parts.is_empty() {
+                continue;
+            }
+            insert_nested(&mut root, &parts, value, &name)?;
+        }
+
+        Ok(Value::from_serialize(serde_json::Value::Object(root)))
+    }
+}
+
+/// Insert a value at a nested key path, failing if a path is both a value and an object
+fn insert_nested(
+    root: &mut serde_json::Map<String, serde_json::Value>,
+    parts: &[String],
+    value: String,
+    var_name: &str,
+) -> Result<(), Error> {
+    let conflict = || {
+        Error::new(
+            ErrorKind::InvalidOperation,
+            format!(
+                "env_to_object: '{}' conflicts with another variable (a key cannot be both a value and an object)",
+                var_name
+            ),
+        )
+    };
+
+    let (last, parents) = parts.split_last().expect("parts is not empty");
+    let mut current = root;
+    for part in parents {
+        let entry = current
+            .entry(part.clone())
+            .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+        current = entry.as_object_mut().ok_or_else(conflict)?;
+    }
+
+    if current.contains_key(last) {
+        return Err(conflict());
+    }
+    current.insert(last.clone(), serde_json::Value::String(value));
+    Ok(())
+}
+
+/// Flatten a nested object into uppercase environment-style keys
+pub struct ToEnvKeys;
+
+impl ToEnvKeys {
+    /// Uppercase a key segment, replacing characters not allowed in variable names with `_`
+    fn segment(key: &str) -> String {
+        key.chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_uppercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect()
+    }
+
+    fn flatten(
+        value: &serde_json::Value,
+        name: String,
+        delimiter: &str,
+        result: &mut serde_json::Map<String, serde_json::Value>,
+    ) -> Result<(), Error> {
+        let join = |segment: String| {
+            if name.is_empty() {
+                segment
+            } else {
+                format!("{}{}{}", name, delimiter, segment)
+            }
+        };
+
+        let leaf = match value {
+            serde_json::Value::Object(map) => {
+                for (key, val) in map {
+                    Self::flatten(val, join(Self::segment(key)), delimiter, result)?;
+                }
+                return Ok(());
+            }
+            serde_json::Value::Array(items) => {
+                for (index, val) in items.iter().enumerate() {
+                    Self::flatten(val, join(index.to_string()), delimiter, result)?;
+                }
+                return Ok(());
+            }
+            serde_json::Value::String(s) => s.clone(),
+            serde_json::Value::Null => String::new(),
+            other => other.to_string(),
+        };
+
+        if result.contains_key(&name) {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                format!("to_env_keys: key '{}' is produced more than once", name),
+            ));
+        }
+        result.insert(name, serde_json::Value::String(leaf));
+        Ok(())
+    }
+}
+
+impl Function for ToEnvKeys {
+    const NAME: &'static str = "to_env_keys";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "to_env_keys",
+        category: "environment",
+        description: "Flatten a nested object into uppercase environment-style keys (e.g., db.host becomes APP_DB_HOST); the inverse of env_to_object",
+        arguments: &[
+            ArgumentMetadata {
+                name: "object",
+                arg_type: "object",
+                required: true,
+                default: None,
+                description: "Nested object to flatten",
+            },
+            ArgumentMetadata {
+                name: "prefix",
+                arg_type: "string",
+                required: false,
+                default: Some(""),
+                description: "Prefix prepended to every key (e.g., \"APP\")",
+            },
+            ArgumentMetadata {
+                name: "delimiter",
+                arg_type: "string",
+                required: false,
+                default: Some("_"),
+                description: "Separator between the prefix and nesting levels",
+            },
+        ],
+        return_type: "object",
+        examples: &[
+            "{{ to_env_keys(object={\"db\": {\"host\": \"localhost\"}}, prefix=\"APP\") | tojson }}",
+            "{% for name, value in to_env_keys(object=config) | items %}{{ name }}={{ value }}\n{% endfor %}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let object: Value = kwargs.get("object")?;
+        let prefix: Option<String> = kwargs.get("prefix")?;
+        let delimiter: Option<String> = kwargs.get("delimiter")?;
+        let delimiter = delimiter.unwrap_or_else(|| "_".to_string());
+
+        let json_value: serde_json::Value = serde_json::to_value(&object).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("Failed to convert object: {}", e),
+            )
+        })?;
+        if !json_value.is_object() {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                "to_env_keys requires an object",
+            ));
+        }
+
+        // "APP" and "APP_" produce the same APP_* keys
+        let prefix = prefix.unwrap_or_default();
+        let prefix = prefix.strip_suffix(delimiter.as_str()).unwrap_or(&prefix);
+
+        let mut result = serde_json::Map::new();
+        ToEnvKeys::flatten(&json_value, prefix.to_string(), &delimiter, &mut result)?;
+
+        Ok(Value::from_serialize(serde_json::Value::Object(result)))
+    }
+}
+
+/// Resolve a secret from an environment variable or a file
+///
+/// With a `key`, the env backend reads `NAME_KEY` (uppercased, like
+/// `to_env_keys`) and the file backend reads `name/key`, matching how
+/// Kubernetes exposes secret keys as variables or mounted files.
+pub struct Secret;
+
+impl Secret {
+    /// Look up the secret in the environment, returning the variable name tried
+    fn from_env(name: &str, key: Option<&str>) -> (String, Option<String>) {
+        let var = match key {
+            Some(key) => format!("{}_{}", ToEnvKeys::segment(name), ToEnvKeys::segment(key)),
+            None => name.to_string(),
+        };
+        let value = std::env::var(&var).ok();
+        (var, value)
+    }
+
+    /// Read the secret from a file under the base directory, returning the path tried
+    fn from_file(
+        context: &TemplateContext,
+        name: &str,
+        key: Option<&str>,
+    ) -> Result<(String, Option<String>), Error> {
+        let path = match key {
+            Some(key) => format!("{}/{}", name, key),
+            None => name.to_string(),
+        };
+        if !context.is_trust_mode() {
+            validate_path_security(&path)?;
+        }
+
+        let resolved_path = context.resolve_path(&path);
+        if !resolved_path.is_file() {
+            return Ok((path, None));
+        }
+        let content = fs::read_to_string(&resolved_path).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("Failed to read file '{}': {}", resolved_path.display(), e),
+            )
+        })?;
+
+        // Secret files usually end with a newline that is not part of the value
+        let value = content
+            .strip_suffix('\n')
+            .map(|v| v.strip_suffix('\r').unwrap_or(v))
+            .unwrap_or(&content)
+            .to_string();
+        Ok((path, Some(value)))
+    }
+}
+
+impl ContextFunction for Secret {
+    const NAME: &'static str = "secret";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "secret",
+        category: "environment",
+        description: "Resolve a secret from an environment variable or a file, so templates do not depend on where secrets come from",
+        arguments: &[
+            ArgumentMetadata {
+                name: "name",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Secret name: the variable name (env) or the file path (file)",
+            },
+            ArgumentMetadata {
+                name: "key",
+                arg_type: "string",
+                required: false,
+                default: None,
+                description: "Key within the secret: reads NAME_KEY (env) or name/key (file)",
+            },
+            ArgumentMetadata {
+                name: "backend",
+                arg_type: "string",
+                required: false,
+                default: Some("env"),
+                description: "Where to read the secret from: \"env\" or \"file\"",
+            },
+            ArgumentMetadata {
+                name: "required",
+                arg_type: "boolean",
+                required: false,
+                default: Some("false"),
+                description: "Fail when the secret is absent instead of returning none",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ secret(name=\"DB_PASSWORD\", required=true) }}",
+            "{{ secret(name=\"db\", key=\"password\", backend=\"file\") }}",
+            "{{ secret(name=\"db\", key=\"password\", backend=get_env(name=\"SECRET_BACKEND\", default=\"env\")) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(context: Arc<TemplateContext>, kwargs: Kwargs) -> Result<Value, Error> {
+        let name: String = kwargs.get("name")?;
+        let key: Option<String> = kwargs.get("key")?;
+        let backend: Option<String> = kwargs.get("backend")?;
+        let required: Option<bool> = kwargs.get("required")?;
+
+        let (source, value) = match backend.as_deref().unwrap_or("env") {
+            "env" => {
+                let (var, value) = Secret::from_env(&name, key.as_deref());
+                (format!("environment variable '{}'", var), value)
+            }
+            "file" => {
+                let (path, value) = Secret::from_file(&context, &name, key.as_deref())?;
+                (format!("file '{}'", path), value)
+            }
+            other => {
+                return Err(Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!(
+                        "secret: unknown backend '{}' (expected \"env\" or \"file\")",
+                        other
+                    ),
+                ));
+            }
+        };
+
+        match value {
+            Some(value) => Ok(Value::from(value)),
+            None if required.unwrap_or(false) => Err(Error::new(
+                ErrorKind::UndefinedError,
+                format!("secret: required secret not found in {}", source),
+            )),
+            None => Ok(Value::from(())),
+        }
+    }
+}
+
+/// Ask for a value on stdin when it is not set in the environment
+pub struct Prompt;
+
+impl Prompt {
+    /// Print the question to stderr and read one line from stdin
+    ///
+    /// Returns `None` when stdin is closed (e.g. the template itself was piped in).
+    fn ask(message: &str, default: Option<&str>) -> Result<Option<String>, Error> {
+        let question = match default {
+            Some(default) => format!("{} [{}]: ", message, default),
+            None => format!("{}: ", message),
+        };
+        let io_error = |e: io::Error| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("prompt: failed to read input: {}", e),
+            )
+        };
+
+        let mut stderr = io::stderr().lock();
+        stderr.write_all(question.as_bytes()).map_err(io_error)?;
+        stderr.flush().map_err(io_error)?;
+
+        let mut line = String::new();
+        if io::stdin().lock().read_line(&mut line).map_err(io_error)? == 0 {
+            return Ok(None);
+        }
+        Ok(Some(line.trim_end_matches(['\n', '\r']).to_string()))
+    }
+}
+
+impl ContextFunction for Prompt {
+    const NAME: &'static str = "prompt";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "prompt",
+        category: "environment",
+        description: "Get a value from an environment variable, asking for it on stdin in --interactive mode when it is not set",
+        arguments: &[
+            ArgumentMetadata {
+                name: "name",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Environment variable checked first; answers are remembered under this name",
+            },
+            ArgumentMetadata {
+                name: "message",
+                arg_type: "string",
+                required: false,
+                default: None,
+                description: "Question shown to the user (default: the name)",
+            },
+            ArgumentMetadata {
+                name: "default",
+                arg_type: "string",
+                required: false,
+                default: None,
+                description: "Value used for an empty answer, or when not interactive",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ prompt(name=\"PROJECT_NAME\", message=\"Project name\") }}",
+            "{{ prompt(name=\"LICENSE\", message=\"License\", default=\"MIT\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(context: Arc<TemplateContext>, kwargs: Kwargs) -> Result<Value, Error> {
+        let name: String = kwargs.get("name")?;
+        let message: Option<String> = kwargs.get("message")?;
+        let default: Option<String> = kwargs.get("default")?;
+
+        if let Ok(value) = std::env::var(&name) {
+            return Ok(Value::from(value));
+        }
+        if let Some(answer) = context.get_answer(&name) {
+            return Ok(Value::from(answer));
+        }
+
+        if context.is_interactive() {
+            let message = message.as_deref().unwrap_or(&name);
+            if let Some(answer) = Prompt::ask(message, default.as_deref())? {
+                let answer = match &default {
+                    Some(default) if answer.is_empty() => default.clone(),
+                    _ => answer,
+                };
+                context.record_answer(&name, &answer);
+                return Ok(Value::from(answer));
+            }
+        }
+
+        match default {
+            Some(default) => Ok(Value::from(default)),
+            None if context.is_interactive() => Err(Error::new(
+                ErrorKind::UndefinedError,
+                format!(
+                    "prompt: no value for '{}' (stdin was closed before an answer was given)",
+                    name
+                ),
+            )),
+            None => Err(Error::new(
+                ErrorKind::UndefinedError,
+                format!(
+                    "prompt: no value for '{}' (set the {} environment variable or use --interactive)",
+                    name, name
+                ),
+            )),
+        }
+    }
+}
+
 /// Convert a glob pattern to a regex pattern
 fn glob_to_regex(pattern: &str) -> String {
     let mut regex = String::from("^");
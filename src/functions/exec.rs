@@ -3,16 +3,19 @@
 //! This module provides the ability to execute external commands from templates.
 //! This is a powerful but potentially dangerous feature, so it requires trust mode.
 //!
-//! Two functions are provided:
+//! Three functions are provided:
 //! - `exec(command)` - Simple execution, returns stdout, throws on error
 //! - `exec_raw(command)` - Full control, returns object with exit code, stdout, stderr
+//! - `git(field)` - Repository information (commit, branch, tag, dirty) for build provenance
 
+use super::memoize::CACHE_ARG;
 use super::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
 use super::traits::ContextFunction;
 use crate::TemplateContext;
 use minijinja::value::Kwargs;
 use minijinja::{Error, ErrorKind, Value};
 use std::collections::HashMap;
+use std::path::Path;
 use std::process::{Command, Stdio};
 use std::sync::Arc;
 
@@ -182,3 +185,111 @@ fn execute_command(command: &str, timeout_secs: u64) -> Result<Value, Error> {
 
     Ok(Value::from_object(result))
 }
+
+/// Get git repository information for the template's base directory
+pub struct Git;
+
+impl ContextFunction for Git {
+    const NAME: &'static str = "git";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "git",
+        category: "exec",
+        description: "Get git repository information (commit, branch, tag, dirty) for the template directory",
+        arguments: &[
+            ArgumentMetadata {
+                name: "field",
+                arg_type: "string",
+                required: false,
+                default: Some("commit"),
+                description: "Field to read: \"commit\", \"branch\", \"tag\", or \"dirty\"",
+            },
+            ArgumentMetadata {
+                name: "required",
+                arg_type: "boolean",
+                required: false,
+                default: Some("false"),
+                description: "Fail if git is missing or the directory is not a repository (otherwise returns empty)",
+            },
+            CACHE_ARG,
+        ],
+        return_type: "string|boolean",
+        examples: &[
+            "{{ git(field=\"commit\") }}",
+            "version: {{ git(field=\"tag\") or \"dev\" }}",
+            "{% if git(field=\"dirty\") %}-dirty{% endif %}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(context: Arc<TemplateContext>, kwargs: Kwargs) -> Result<Value, Error> {
+        // Security check: git is only available in trust mode
+        if !context.is_trust_mode() {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                "Security: git() function requires trust mode. Use --trust flag to enable command execution.",
+            ));
+        }
+
+        let field: Option<String> = kwargs.get("field")?;
+        let required: Option<bool> = kwargs.get("required")?;
+        let field = field.unwrap_or_else(|| "commit".to_string());
+        let required = required.unwrap_or(false);
+
+        if !matches!(field.as_str(), "commit" | "branch" | "tag" | "dirty") {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                format!(
+                    "git: invalid field '{}'. Use: commit, branch, tag, dirty",
+                    field
+                ),
+            ));
+        }
+
+        match git_field(context.base_dir(), &field) {
+            Ok(value) => Ok(value),
+            Err(message) if required => Err(Error::new(
+                ErrorKind::InvalidOperation,
+                format!("git: {}", message),
+            )),
+            Err(_) if field == "dirty" => Ok(Value::from(false)),
+            Err(_) => Ok(Value::from("")),
+        }
+    }
+}
+
+/// Run git in `dir` and return its trimmed stdout, or an error describing the failure
+fn run_git(dir: &Path, args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| format!("failed to run git: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(stderr.trim().to_string());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Read a single git field for the repository containing `dir`
+fn git_field(dir: &Path, field: &str) -> Result<Value, String> {
+    run_git(dir, &["rev-parse", "--is-inside-work-tree"])
+        .map_err(|_| format!("'{}' is not inside a git repository", dir.display()))?;
+
+    match field {
+        "commit" => run_git(dir, &["rev-parse", "HEAD"]).map(Value::from),
+        // A detached HEAD has no branch
+        "branch" => run_git(dir, &["rev-parse", "--abbrev-ref", "HEAD"])
+            .map(|branch| Value::from(if branch == "HEAD" { "" } else { &branch })),
+        // Only a tag pointing at HEAD counts; no tag is not an error
+        "tag" => Ok(Value::from(
+            run_git(dir, &["describe", "--tags", "--exact-match", "HEAD"]).unwrap_or_default(),
+        )),
+        "dirty" => run_git(dir, &["status", "--porcelain"]).map(|s| Value::from(!s.is_empty())),
+        _ => unreachable!("field is validated by the caller"),
+    }
+}
@@ -1,13 +1,15 @@
 //! File system functions
 //!
 //! Provides functions for interacting with the file system:
-//! - read_file: Read file contents
+//! - read_file: Read file contents (optionally a byte range)
 //! - file_exists: Check if file exists
 //! - list_dir: List directory contents
 //! - glob: List files by pattern
 //! - file_size: Get file size
 //! - file_modified: Get file modification timestamp
 //! - read_lines: Read lines from a file
+//! - file_head / file_tail: First or last N lines of a file as a string
+//! - include_if_exists: Render an optional file as a template, or return a default
 //!
 //! Note: basename, dirname, file_extension, join_path, normalize_path are now in
 //! filter_functions/path.rs with dual function+filter syntax support.
@@ -15,12 +17,16 @@
 //! Note: is_file, is_dir, is_symlink are now in is_functions/filesystem.rs with
 //! dual function+is-test syntax support.
 
+use super::memoize::CACHE_ARG;
 use super::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
-use super::traits::ContextFunction;
+use super::traits::{ContextFunction, ContextStateFunction};
 use crate::TemplateContext;
 use minijinja::value::Kwargs;
-use minijinja::{Error, ErrorKind, Value};
+use minijinja::{Error, ErrorKind, State, Value};
+use std::collections::BTreeMap;
 use std::fs;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
 use std::sync::Arc;
 
 /// Validate path security (prevent absolute paths and parent directory traversal)
@@ -60,17 +66,35 @@ impl ContextFunction for ReadFile {
         name: "read_file",
         category: "filesystem",
         description: "Read file contents as string",
-        arguments: &[ArgumentMetadata {
-            name: "path",
-            arg_type: "string",
-            required: true,
-            default: None,
-            description: "Path to the file to read",
-        }],
+        arguments: &[
+            ArgumentMetadata {
+                name: "path",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Path to the file to read",
+            },
+            ArgumentMetadata {
+                name: "offset",
+                arg_type: "integer",
+                required: false,
+                default: Some("0"),
+                description: "Byte offset to start reading from",
+            },
+            ArgumentMetadata {
+                name: "length",
+                arg_type: "integer",
+                required: false,
+                default: None,
+                description: "Maximum number of bytes to read (default: to the end of the file)",
+            },
+            CACHE_ARG,
+        ],
         return_type: "string",
         examples: &[
             "{{ read_file(path=\"config.txt\") }}",
             "{% set content = read_file(path=\"data.json\") %}",
+            "{{ read_file(path=\"large.csv\", offset=0, length=1024) }}",
         ],
         syntax: SyntaxVariants::FUNCTION_ONLY,
     };
@@ -89,8 +113,16 @@ impl ContextFunction for ReadFile {
             ));
         }
 
+        let offset: Option<u64> = kwargs.get("offset")?;
+        let length: Option<u64> = kwargs.get("length")?;
+
         let resolved_path = context.resolve_path(&path);
 
+        if offset.is_some() || length.is_some() {
+            let content = read_byte_range(&resolved_path, offset.unwrap_or(0), length)?;
+            return Ok(Value::from(content));
+        }
+
         let content = fs::read_to_string(&resolved_path).map_err(|e| {
             Error::new(
                 ErrorKind::InvalidOperation,
@@ -102,6 +134,39 @@ impl ContextFunction for ReadFile {
     }
 }
 
+/// Read up to `length` bytes starting at `offset` without loading the whole file
+fn read_byte_range(path: &Path, offset: u64, length: Option<u64>) -> Result<String, Error> {
+    let read_error = |e: std::io::Error| {
+        Error::new(
+            ErrorKind::InvalidOperation,
+            format!("Failed to read file '{}': {}", path.display(), e),
+        )
+    };
+
+    let mut file = fs::File::open(path).map_err(read_error)?;
+    file.seek(SeekFrom::Start(offset)).map_err(read_error)?;
+
+    let mut buffer = Vec::new();
+    match length {
+        Some(length) => file.take(length).read_to_end(&mut buffer),
+        None => file.read_to_end(&mut buffer),
+    }
+    .map_err(read_error)?;
+
+    String::from_utf8(buffer).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidOperation,
+            format!(
+                "Bytes {}..{} of '{}' are not valid UTF-8: {}",
+                offset,
+                offset + e.as_bytes().len() as u64,
+                path.display(),
+                e.utf8_error()
+            ),
+        )
+    })
+}
+
 /// Check if file exists
 pub struct FileExists;
 
@@ -150,13 +215,16 @@ impl ContextFunction for ListDir {
         name: "list_dir",
         category: "filesystem",
         description: "List files and directories in a directory",
-        arguments: &[ArgumentMetadata {
-            name: "path",
-            arg_type: "string",
-            required: true,
-            default: None,
-            description: "Directory path to list",
-        }],
+        arguments: &[
+            ArgumentMetadata {
+                name: "path",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Directory path to list",
+            },
+            CACHE_ARG,
+        ],
         return_type: "array",
         examples: &["{% for file in list_dir(path=\".\") %}{{ file }}{% endfor %}"],
         syntax: SyntaxVariants::FUNCTION_ONLY,
@@ -217,13 +285,16 @@ impl ContextFunction for Glob {
         name: "glob",
         category: "filesystem",
         description: "List files matching a glob pattern",
-        arguments: &[ArgumentMetadata {
-            name: "pattern",
-            arg_type: "string",
-            required: true,
-            default: None,
-            description: "Glob pattern (e.g., \"*.txt\", \"**/*.json\")",
-        }],
+        arguments: &[
+            ArgumentMetadata {
+                name: "pattern",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Glob pattern (e.g., \"*.txt\", \"**/*.json\")",
+            },
+            CACHE_ARG,
+        ],
         return_type: "array",
         examples: &[
             "{% for f in glob(pattern=\"*.txt\") %}{{ f }}{% endfor %}",
@@ -427,6 +498,7 @@ impl ContextFunction for ReadLines {
                 default: Some("10"),
                 description: "Number of lines to read (positive=first N, negative=last N, 0=all)",
             },
+            CACHE_ARG,
         ],
         return_type: "array",
         examples: &[
@@ -495,3 +567,225 @@ impl ContextFunction for ReadLines {
         Ok(Value::from(lines))
     }
 }
+
+/// Metadata for the `path` argument of `file_head` / `file_tail`
+const HEAD_TAIL_PATH_ARG: ArgumentMetadata = ArgumentMetadata {
+    name: "path",
+    arg_type: "string",
+    required: true,
+    default: None,
+    description: "Path to the file",
+};
+
+/// Metadata for the `lines` argument of `file_head` / `file_tail`
+const HEAD_TAIL_LINES_ARG: ArgumentMetadata = ArgumentMetadata {
+    name: "lines",
+    arg_type: "integer",
+    required: false,
+    default: Some("10"),
+    description: "Number of lines to return",
+};
+
+/// Check path security and open a file for `file_head` / `file_tail`
+fn open_checked(context: &TemplateContext, path: &str) -> Result<fs::File, Error> {
+    if !context.is_trust_mode() {
+        validate_path_security(path)?;
+    }
+
+    let resolved_path = context.resolve_path(path);
+    fs::File::open(&resolved_path).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidOperation,
+            format!("Failed to read file '{}': {}", resolved_path.display(), e),
+        )
+    })
+}
+
+/// Convert an I/O error while reading `path` into a template error
+fn io_error(path: &str, e: std::io::Error) -> Error {
+    Error::new(
+        ErrorKind::InvalidOperation,
+        format!("Failed to read file '{}': {}", path, e),
+    )
+}
+
+/// Get the first N lines of a file
+pub struct FileHead;
+
+impl ContextFunction for FileHead {
+    const NAME: &'static str = "file_head";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "file_head",
+        category: "filesystem",
+        description: "Get the first N lines of a file as a string (stops reading after N lines)",
+        arguments: &[HEAD_TAIL_PATH_ARG, HEAD_TAIL_LINES_ARG, CACHE_ARG],
+        return_type: "string",
+        examples: &[
+            "{{ file_head(path=\"data.csv\", lines=1) }}",
+            "{{ file_head(path=\"CHANGELOG.md\", lines=20) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(context: Arc<TemplateContext>, kwargs: Kwargs) -> Result<Value, Error> {
+        let path: String = kwargs.get("path")?;
+        let lines: Option<u64> = kwargs.get("lines")?;
+        let lines = lines.unwrap_or(10) as usize;
+
+        let file = open_checked(&context, &path)?;
+        let mut reader = BufReader::new(file);
+        let mut result: Vec<String> = Vec::new();
+        let mut buffer = Vec::new();
+
+        while result.len() < lines {
+            buffer.clear();
+            if reader
+                .read_until(b'\n', &mut buffer)
+                .map_err(|e| io_error(&path, e))?
+                == 0
+            {
+                break;
+            }
+            let line = String::from_utf8_lossy(&buffer);
+            result.push(line.trim_end_matches(['\n', '\r']).to_string());
+        }
+
+        Ok(Value::from(result.join("\n")))
+    }
+}
+
+/// Get the last N lines of a file
+pub struct FileTail;
+
+impl ContextFunction for FileTail {
+    const NAME: &'static str = "file_tail";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "file_tail",
+        category: "filesystem",
+        description: "Get the last N lines of a file as a string (reads backwards from the end)",
+        arguments: &[HEAD_TAIL_PATH_ARG, HEAD_TAIL_LINES_ARG, CACHE_ARG],
+        return_type: "string",
+        examples: &[
+            "{{ file_tail(path=\"logs/app.log\") }}",
+            "{{ file_tail(path=\"logs/app.log\", lines=50) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(context: Arc<TemplateContext>, kwargs: Kwargs) -> Result<Value, Error> {
+        let path: String = kwargs.get("path")?;
+        let lines: Option<u64> = kwargs.get("lines")?;
+        let lines = lines.unwrap_or(10) as usize;
+
+        if lines == 0 {
+            return Ok(Value::from(""));
+        }
+
+        let mut file = open_checked(&context, &path)?;
+        let bytes = read_tail(&mut file, lines).map_err(|e| io_error(&path, e))?;
+        let text = String::from_utf8_lossy(&bytes);
+
+        let all_lines: Vec<&str> = text.lines().collect();
+        let start = all_lines.len().saturating_sub(lines);
+        Ok(Value::from(all_lines[start..].join("\n")))
+    }
+}
+
+/// Read chunks backwards from the end of a file until it holds more than `lines` lines
+///
+/// The returned bytes may start with a partial line, which the caller drops
+/// by keeping only the last `lines` lines.
+fn read_tail(file: &mut fs::File, lines: usize) -> std::io::Result<Vec<u8>> {
+    const CHUNK_SIZE: u64 = 8192;
+
+    let mut position = file.seek(SeekFrom::End(0))?;
+    let mut chunks: Vec<Vec<u8>> = Vec::new();
+    let mut newlines = 0;
+
+    while position > 0 {
+        let size = CHUNK_SIZE.min(position);
+        position -= size;
+        file.seek(SeekFrom::Start(position))?;
+
+        let mut chunk = vec![0; size as usize];
+        file.read_exact(&mut chunk)?;
+        newlines += chunk.iter().filter(|&&b| b == b'\n').count();
+
+        // A trailing newline ends the last line rather than starting a new one
+        if chunks.is_empty() && chunk.last() == Some(&b'\n') {
+            newlines -= 1;
+        }
+        chunks.push(chunk);
+
+        if newlines >= lines {
+            break;
+        }
+    }
+
+    Ok(chunks.into_iter().rev().flatten().collect())
+}
+
+/// Render a file as a template if it exists, otherwise return a default
+pub struct IncludeIfExists;
+
+impl ContextStateFunction for IncludeIfExists {
+    const NAME: &'static str = "include_if_exists";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "include_if_exists",
+        category: "filesystem",
+        description: "Render a file as a template if it exists, otherwise return a default",
+        arguments: &[
+            ArgumentMetadata {
+                name: "path",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Path to the template file to include",
+            },
+            ArgumentMetadata {
+                name: "default",
+                arg_type: "string",
+                required: false,
+                default: Some("\"\""),
+                description: "Value returned when the file does not exist",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ include_if_exists(path=\"overlays/\" ~ get_env(name=\"ENV\") ~ \".conf\") }}",
+            "{{ include_if_exists(path=\"local.tmpltool\", default=\"# no local overrides\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(context: Arc<TemplateContext>, state: &State, kwargs: Kwargs) -> Result<Value, Error> {
+        let path: String = kwargs.get("path")?;
+        let default: Option<Value> = kwargs.get("default")?;
+
+        if !context.is_trust_mode() {
+            validate_path_security(&path)?;
+        }
+
+        let resolved_path = context.resolve_path(&path);
+        if !resolved_path.is_file() {
+            return Ok(default.unwrap_or_else(|| Value::from("")));
+        }
+
+        let source = fs::read_to_string(&resolved_path).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("Failed to read file '{}': {}", resolved_path.display(), e),
+            )
+        })?;
+
+        // Like {% include %}, the fragment sees the variables of the caller
+        let variables: BTreeMap<String, Value> = state
+            .known_variables()
+            .into_iter()
+            .filter_map(|name| state.lookup(&name).map(|value| (name.into_owned(), value)))
+            .collect();
+
+        let rendered = state.env().render_named_str(&path, &source, variables)?;
+        Ok(Value::from(rendered))
+    }
+}
@@ -6,14 +6,19 @@
 //! - Quantity conversions
 //! - Pod affinity and toleration generation
 //! - Liveness/readiness probe generation
+//! - Recommended `app.kubernetes.io/*` labels
+//! - DNS-1123 resource names built from several parts
+//! - Annotation blocks with quoted values
 //!
 //! Note: k8s_label_safe, k8s_dns_label_safe, k8s_annotation_safe are now in
 //! filter_functions/kubernetes.rs with dual function+filter syntax support.
 
 use super::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
 use super::traits::Function;
-use minijinja::value::Kwargs;
+use crate::filter_functions::kubernetes::{K8sAnnotationSafe, K8sLabelSafe};
+use minijinja::value::{Kwargs, ValueKind};
 use minijinja::{Error, ErrorKind, Value};
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 
 /// Format Kubernetes resource requests
@@ -1040,3 +1045,291 @@ impl Function for K8sProbe {
         Ok(Value::from(output))
     }
 }
+
+/// Generate the recommended `app.kubernetes.io/*` labels as YAML
+pub struct K8sLabels;
+
+impl Function for K8sLabels {
+    const NAME: &'static str = "k8s_labels";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "k8s_labels",
+        category: "kubernetes",
+        description: "Generate the recommended app.kubernetes.io/* labels as YAML, with label-safe values",
+        arguments: &[
+            ArgumentMetadata {
+                name: "name",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Application name (app.kubernetes.io/name)",
+            },
+            ArgumentMetadata {
+                name: "instance",
+                arg_type: "string",
+                required: false,
+                default: None,
+                description: "Unique instance name (app.kubernetes.io/instance)",
+            },
+            ArgumentMetadata {
+                name: "version",
+                arg_type: "string",
+                required: false,
+                default: None,
+                description: "Application version (app.kubernetes.io/version)",
+            },
+            ArgumentMetadata {
+                name: "component",
+                arg_type: "string",
+                required: false,
+                default: None,
+                description: "Component within the architecture (app.kubernetes.io/component)",
+            },
+            ArgumentMetadata {
+                name: "part_of",
+                arg_type: "string",
+                required: false,
+                default: None,
+                description: "Higher-level application this is part of (app.kubernetes.io/part-of)",
+            },
+            ArgumentMetadata {
+                name: "managed_by",
+                arg_type: "string",
+                required: false,
+                default: Some("tmpltool"),
+                description: "Tool managing the resource (app.kubernetes.io/managed-by); empty string omits it",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ k8s_labels(name=\"api\", instance=\"api-prod\", version=\"1.4.2\") }}",
+            "  labels:\n{{ k8s_labels(name=app, component=\"database\", part_of=\"shop\") | indent(4) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        // Values may be numbers (version=1.0), so take their string form
+        let optional = |arg: &str| -> Result<Option<String>, Error> {
+            let value: Option<Value> = kwargs.get(arg)?;
+            Ok(value
+                .filter(|v| !v.is_none())
+                .map(|v| v.to_string())
+                .filter(|v| !v.is_empty()))
+        };
+        let name: Value = kwargs.get("name")?;
+        // managed_by="" (or none) drops the label instead of using the default
+        let managed_by = if kwargs.has("managed_by") {
+            optional("managed_by")?
+        } else {
+            Some("tmpltool".to_string())
+        };
+
+        // In the order the Kubernetes documentation lists them
+        let labels = [
+            ("name", Some(name.to_string())),
+            ("instance", optional("instance")?),
+            ("version", optional("version")?),
+            ("component", optional("component")?),
+            ("part-of", optional("part_of")?),
+            ("managed-by", managed_by),
+        ];
+
+        let lines: Vec<String> = labels
+            .iter()
+            .filter_map(|(label, value)| {
+                // Quoted so values like 1.0 or true stay strings in YAML
+                value.as_ref().map(|value| {
+                    format!(
+                        "app.kubernetes.io/{}: \"{}\"",
+                        label,
+                        K8sLabelSafe::compute(value)
+                    )
+                })
+            })
+            .collect();
+        Ok(Value::from(lines.join("\n")))
+    }
+}
+
+/// Build a DNS-1123 resource name from parts, truncating with a hash suffix
+pub struct K8sName;
+
+impl K8sName {
+    /// Hex characters of the hash appended when a name is truncated
+    const HASH_LENGTH: usize = 8;
+
+    /// Lowercase, replace invalid characters with dashes and collapse them
+    fn sanitize(value: &str) -> String {
+        let replaced: String = value
+            .to_lowercase()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+            .collect();
+        replaced
+            .split('-')
+            .filter(|segment| !segment.is_empty())
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+
+    fn compute(parts: &[String], max_length: usize) -> String {
+        let name = Self::sanitize(&parts.join("-"));
+        if name.len() <= max_length {
+            return name;
+        }
+        // The hash of the full name keeps truncated names with a common prefix distinct
+        let hash = format!("{:x}", Sha256::digest(name.as_bytes()));
+        let prefix = name[..max_length - Self::HASH_LENGTH - 1].trim_end_matches('-');
+        format!("{}-{}", prefix, &hash[..Self::HASH_LENGTH])
+    }
+}
+
+impl Function for K8sName {
+    const NAME: &'static str = "k8s_name";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "k8s_name",
+        category: "kubernetes",
+        description: "Join parts into a DNS-1123 resource name, truncating long names with a short hash suffix",
+        arguments: &[
+            ArgumentMetadata {
+                name: "parts",
+                arg_type: "array",
+                required: true,
+                default: None,
+                description: "Name parts joined with hyphens (empty and none parts are skipped)",
+            },
+            ArgumentMetadata {
+                name: "max_length",
+                arg_type: "integer",
+                required: false,
+                default: Some("63"),
+                description: "Maximum length (at least 10); longer names are truncated and get an 8-character hash suffix",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ k8s_name(parts=[release, \"postgresql\", \"primary\"]) }}",
+            "{{ k8s_name(parts=[\"My App\", \"Worker\", 2]) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let parts: Value = kwargs.get("parts")?;
+        let max_length: usize = kwargs.get::<Option<usize>>("max_length")?.unwrap_or(63);
+
+        if max_length <= Self::HASH_LENGTH + 1 {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                format!(
+                    "k8s_name: max_length must be at least {}, found: {}",
+                    Self::HASH_LENGTH + 2,
+                    max_length
+                ),
+            ));
+        }
+        if parts.kind() != ValueKind::Seq {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                format!("k8s_name requires an array of parts, found: {}", parts),
+            ));
+        }
+
+        let parts: Vec<String> = parts
+            .try_iter()?
+            .filter(|part| !part.is_none() && !part.is_undefined())
+            .map(|part| part.to_string())
+            .collect();
+        let name = Self::compute(&parts, max_length);
+        if name.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                "k8s_name: parts contain no letters or digits",
+            ));
+        }
+        Ok(Value::from(name))
+    }
+}
+
+/// Render an object as a YAML annotations block with quoted values
+pub struct K8sAnnotations;
+
+impl K8sAnnotations {
+    /// Annotation keys made only of these characters need no quoting in YAML
+    fn is_plain_key(key: &str) -> bool {
+        !key.is_empty()
+            && key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_' | '/'))
+    }
+
+    /// JSON string syntax is also a valid double-quoted YAML scalar
+    fn quote(value: &str) -> String {
+        serde_json::Value::String(value.to_string()).to_string()
+    }
+}
+
+impl Function for K8sAnnotations {
+    const NAME: &'static str = "k8s_annotations";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "k8s_annotations",
+        category: "kubernetes",
+        description: "Render an object as a YAML annotations block with every value sanitized and quoted as a string",
+        arguments: &[ArgumentMetadata {
+            name: "object",
+            arg_type: "object",
+            required: true,
+            default: None,
+            description: "Annotation keys and values (numbers and booleans become strings, arrays and objects become JSON)",
+        }],
+        return_type: "string",
+        examples: &[
+            "{{ k8s_annotations(object={\"prometheus.io/scrape\": true, \"prometheus.io/port\": 9090}) }}",
+            "  annotations:\n{{ k8s_annotations(object=annotations) | indent(4) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let object: Value = kwargs.get("object")?;
+        let json = serde_json::to_value(&object).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("Failed to convert object: {}", e),
+            )
+        })?;
+        let serde_json::Value::Object(map) = json else {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                format!("k8s_annotations requires an object, found: {}", object),
+            ));
+        };
+        if map.is_empty() {
+            return Ok(Value::from("{}"));
+        }
+
+        let mut keys: Vec<&String> = map.keys().collect();
+        keys.sort();
+        let lines: Vec<String> = keys
+            .into_iter()
+            .map(|key| {
+                let value = match &map[key] {
+                    serde_json::Value::String(s) => s.clone(),
+                    serde_json::Value::Null => String::new(),
+                    other => other.to_string(),
+                };
+                let key = if Self::is_plain_key(key) {
+                    key.clone()
+                } else {
+                    Self::quote(key)
+                };
+                format!(
+                    "{}: {}",
+                    key,
+                    Self::quote(&K8sAnnotationSafe::compute(&value))
+                )
+            })
+            .collect();
+        Ok(Value::from(lines.join("\n")))
+    }
+}
@@ -0,0 +1,386 @@
+//! Text layout functions for MiniJinja templates
+//!
+//! This module provides functions for plain-text output such as MOTD
+//! banners, help text and terminal listings:
+//! - `columns`: Lay out short strings in aligned columns, like `ls`
+//! - `box`: Draw a Unicode frame around text
+//! - `progress_bar`: Render a proportion as a text bar
+//!
+//! Widths are measured in display columns, so wide CJK characters and
+//! emoji count as 2.
+
+use super::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
+use super::traits::Function;
+use minijinja::value::{Kwargs, ValueKind};
+use minijinja::{Error, ErrorKind, Value};
+use unicode_width::UnicodeWidthStr;
+
+/// Lay out an array of strings in aligned columns
+pub struct Columns;
+
+impl Columns {
+    /// Index of the item shown at `row`/`col` (may be past the end in the last row)
+    fn item_at(row: usize, col: usize, rows: usize, count: usize, column_major: bool) -> usize {
+        if column_major {
+            col * rows + row
+        } else {
+            row * count + col
+        }
+    }
+
+    /// Width of each column for a layout with `count` columns
+    fn column_widths(widths: &[usize], count: usize, column_major: bool) -> Vec<usize> {
+        let rows = widths.len().div_ceil(count);
+        let mut result = vec![0; count];
+        for (index, width) in widths.iter().enumerate() {
+            let col = if column_major {
+                index / rows
+            } else {
+                index % count
+            };
+            result[col] = result[col].max(*width);
+        }
+        result
+    }
+
+    fn layout(items: &[String], count: usize, gap: usize, column_major: bool) -> String {
+        let widths: Vec<usize> = items.iter().map(|item| item.width()).collect();
+        let column_widths = Self::column_widths(&widths, count, column_major);
+        let rows = items.len().div_ceil(count);
+
+        let mut lines = Vec::with_capacity(rows);
+        for row in 0..rows {
+            let mut line = String::new();
+            for (col, column_width) in column_widths.iter().enumerate() {
+                let index = Self::item_at(row, col, rows, count, column_major);
+                let Some(item) = items.get(index) else {
+                    continue;
+                };
+                if col > 0 {
+                    line.push_str(&" ".repeat(gap));
+                }
+                line.push_str(item);
+                line.push_str(&" ".repeat(column_width - widths[index]));
+            }
+            lines.push(line.trim_end().to_string());
+        }
+        lines.join("\n")
+    }
+
+    /// Largest column count whose layout fits in `width`
+    fn fit_count(items: &[String], width: usize, gap: usize, column_major: bool) -> usize {
+        let widths: Vec<usize> = items.iter().map(|item| item.width()).collect();
+        (1..=items.len())
+            .rev()
+            .find(|count| {
+                let columns = Self::column_widths(&widths, *count, column_major);
+                let used = columns.iter().filter(|w| **w > 0).count();
+                columns.iter().sum::<usize>() + gap * used.saturating_sub(1) <= width
+            })
+            .unwrap_or(1)
+    }
+}
+
+impl Function for Columns {
+    const NAME: &'static str = "columns";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "columns",
+        category: "layout",
+        description: "Lay out an array of short strings in aligned text columns",
+        arguments: &[
+            ArgumentMetadata {
+                name: "array",
+                arg_type: "array",
+                required: true,
+                default: None,
+                description: "Items to lay out (non-strings use their string form)",
+            },
+            ArgumentMetadata {
+                name: "count",
+                arg_type: "integer",
+                required: false,
+                default: None,
+                description: "Number of columns (default: as many as fit in width)",
+            },
+            ArgumentMetadata {
+                name: "width",
+                arg_type: "integer",
+                required: false,
+                default: Some("80"),
+                description: "Maximum line width used to choose the column count",
+            },
+            ArgumentMetadata {
+                name: "gap",
+                arg_type: "integer",
+                required: false,
+                default: Some("2"),
+                description: "Spaces between columns",
+            },
+            ArgumentMetadata {
+                name: "order",
+                arg_type: "string",
+                required: false,
+                default: Some("row"),
+                description: "Fill order: \"row\" (left to right) or \"column\" (top to bottom, like ls)",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ columns(array=services, count=3) }}",
+            "{{ columns(array=commands, width=60, order=\"column\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let array: Value = kwargs.get("array")?;
+        let count: Option<usize> = kwargs.get("count")?;
+        let width: usize = kwargs.get::<Option<usize>>("width")?.unwrap_or(80);
+        let gap: usize = kwargs.get::<Option<usize>>("gap")?.unwrap_or(2);
+        let order: Option<String> = kwargs.get("order")?;
+
+        let column_major = match order.as_deref().unwrap_or("row") {
+            "row" => false,
+            "column" => true,
+            other => {
+                return Err(Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!("columns: invalid order '{}'. Use: row, column", other),
+                ));
+            }
+        };
+
+        if array.kind() != ValueKind::Seq {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                format!("columns requires an array, found: {}", array),
+            ));
+        }
+        let items: Vec<String> = array
+            .try_iter()?
+            .map(|item| match item.as_str() {
+                Some(s) => s.to_string(),
+                None => item.to_string(),
+            })
+            .collect();
+        if items.is_empty() {
+            return Ok(Value::from(""));
+        }
+
+        let count = match count {
+            Some(0) => {
+                return Err(Error::new(
+                    ErrorKind::InvalidOperation,
+                    "columns: count must be at least 1",
+                ));
+            }
+            Some(count) => count.min(items.len()),
+            None => Self::fit_count(&items, width, gap, column_major),
+        };
+
+        Ok(Value::from(Self::layout(&items, count, gap, column_major)))
+    }
+}
+
+/// Box-drawing characters: corners (top-left, top-right, bottom-left, bottom-right), horizontal, vertical
+struct BoxStyle {
+    name: &'static str,
+    corners: [char; 4],
+    horizontal: char,
+    vertical: char,
+}
+
+const BOX_STYLES: &[BoxStyle] = &[
+    BoxStyle {
+        name: "single",
+        corners: ['┌', '┐', '└', '┘'],
+        horizontal: '─',
+        vertical: '│',
+    },
+    BoxStyle {
+        name: "double",
+        corners: ['╔', '╗', '╚', '╝'],
+        horizontal: '═',
+        vertical: '║',
+    },
+    BoxStyle {
+        name: "rounded",
+        corners: ['╭', '╮', '╰', '╯'],
+        horizontal: '─',
+        vertical: '│',
+    },
+    BoxStyle {
+        name: "heavy",
+        corners: ['┏', '┓', '┗', '┛'],
+        horizontal: '━',
+        vertical: '┃',
+    },
+    BoxStyle {
+        name: "ascii",
+        corners: ['+', '+', '+', '+'],
+        horizontal: '-',
+        vertical: '|',
+    },
+];
+
+/// Draw a frame around a (possibly multiline) string
+pub struct TextBox;
+
+impl Function for TextBox {
+    const NAME: &'static str = "box";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "box",
+        category: "layout",
+        description: "Draw a Unicode box around a (possibly multiline) string",
+        arguments: &[
+            ArgumentMetadata {
+                name: "string",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Text to frame",
+            },
+            ArgumentMetadata {
+                name: "style",
+                arg_type: "string",
+                required: false,
+                default: Some("single"),
+                description: "Border style: single, double, rounded, heavy or ascii",
+            },
+            ArgumentMetadata {
+                name: "padding",
+                arg_type: "integer",
+                required: false,
+                default: Some("1"),
+                description: "Spaces between the text and the left and right border",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ box(string=\"Deploy complete\") }}",
+            "{{ box(string=\"WARNING\\nThis file is generated\", style=\"double\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let string: String = kwargs.get("string")?;
+        let style: Option<String> = kwargs.get("style")?;
+        let padding: usize = kwargs.get::<Option<usize>>("padding")?.unwrap_or(1);
+
+        let style_name = style.as_deref().unwrap_or("single");
+        let style = BOX_STYLES
+            .iter()
+            .find(|s| s.name == style_name)
+            .ok_or_else(|| {
+                let names: Vec<&str> = BOX_STYLES.iter().map(|s| s.name).collect();
+                Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!(
+                        "box: invalid style '{}'. Use: {}",
+                        style_name,
+                        names.join(", ")
+                    ),
+                )
+            })?;
+
+        let lines: Vec<&str> = string.lines().collect();
+        let lines = if lines.is_empty() { vec![""] } else { lines };
+        let inner = lines.iter().map(|line| line.width()).max().unwrap_or(0) + 2 * padding;
+        let [top_left, top_right, bottom_left, bottom_right] = style.corners;
+        let border = style.horizontal.to_string().repeat(inner);
+
+        let mut out = vec![format!("{}{}{}", top_left, border, top_right)];
+        for line in lines {
+            out.push(format!(
+                "{v}{pad}{line}{fill}{v}",
+                v = style.vertical,
+                pad = " ".repeat(padding),
+                fill = " ".repeat(inner - padding - line.width()),
+            ));
+        }
+        out.push(format!("{}{}{}", bottom_left, border, bottom_right));
+        Ok(Value::from(out.join("\n")))
+    }
+}
+
+/// Render a proportion as a text progress bar
+pub struct ProgressBar;
+
+impl Function for ProgressBar {
+    const NAME: &'static str = "progress_bar";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "progress_bar",
+        category: "layout",
+        description: "Render value/max as a text progress bar",
+        arguments: &[
+            ArgumentMetadata {
+                name: "value",
+                arg_type: "number",
+                required: true,
+                default: None,
+                description: "Current value (clamped to 0..max)",
+            },
+            ArgumentMetadata {
+                name: "max",
+                arg_type: "number",
+                required: false,
+                default: Some("100"),
+                description: "Value of a full bar",
+            },
+            ArgumentMetadata {
+                name: "width",
+                arg_type: "integer",
+                required: false,
+                default: Some("20"),
+                description: "Number of cells in the bar",
+            },
+            ArgumentMetadata {
+                name: "filled",
+                arg_type: "string",
+                required: false,
+                default: Some("█"),
+                description: "Cell used for the filled part",
+            },
+            ArgumentMetadata {
+                name: "empty",
+                arg_type: "string",
+                required: false,
+                default: Some("░"),
+                description: "Cell used for the empty part",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ progress_bar(value=42) }}",
+            "| {{ disk.name }} | {{ progress_bar(value=disk.used, max=disk.size, width=10, filled=\"#\", empty=\".\") }} |",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let value: f64 = kwargs.get("value")?;
+        let max: f64 = kwargs.get::<Option<f64>>("max")?.unwrap_or(100.0);
+        let width: usize = kwargs.get::<Option<usize>>("width")?.unwrap_or(20);
+        let filled: Option<String> = kwargs.get("filled")?;
+        let empty: Option<String> = kwargs.get("empty")?;
+
+        if max <= 0.0 || !max.is_finite() {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                format!(
+                    "progress_bar: max must be a positive number, found: {}",
+                    max
+                ),
+            ));
+        }
+
+        let ratio = (value / max).clamp(0.0, 1.0);
+        let cells = ((ratio * width as f64).round() as usize).min(width);
+        Ok(Value::from(format!(
+            "{}{}",
+            filled.as_deref().unwrap_or("█").repeat(cells),
+            empty.as_deref().unwrap_or("░").repeat(width - cells)
+        )))
+    }
+}
@@ -5,11 +5,13 @@
 //! - `coalesce`: Return first non-null value
 //! - `ternary`: Ternary operator
 //! - `in_range`: Check if value is within range
+//! - `pipe_if`: Apply a named filter only when a condition holds
+//! - `deep_equal`: Compare two values structurally
 
 use super::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
-use super::traits::Function;
+use super::traits::{Function, StateFunction};
 use minijinja::value::Kwargs;
-use minijinja::{Error, ErrorKind, Value};
+use minijinja::{Environment, Error, ErrorKind, State, Value};
 
 /// Return default if value is falsy
 pub struct Default;
@@ -249,3 +251,172 @@ impl Function for InRange {
         Ok(Value::from(num_value >= num_min && num_value <= num_max))
     }
 }
+
+/// Apply a named filter to a value only when a condition is true
+///
+/// Keyword arguments other than `value`, `condition` and `filter` are passed
+/// on to the filter, e.g. `pipe_if(condition=short, filter="truncate", length=10)`.
+pub struct PipeIf;
+
+impl PipeIf {
+    fn apply(state: &State, value: Value, kwargs: Kwargs) -> Result<Value, Error> {
+        let condition: Value = kwargs.get("condition")?;
+        let filter: String = kwargs.get("filter")?;
+
+        let mut filter_kwargs = Vec::new();
+        for name in kwargs.args() {
+            if !matches!(name, "value" | "condition" | "filter") {
+                filter_kwargs.push((name, kwargs.get::<Value>(name)?));
+            }
+        }
+
+        if !condition.is_true() {
+            return Ok(value);
+        }
+
+        let mut args = vec![value];
+        if !filter_kwargs.is_empty() {
+            args.push(Value::from(Kwargs::from_iter(filter_kwargs)));
+        }
+        state.apply_filter(&filter, &args).map_err(|e| {
+            if e.kind() == ErrorKind::UnknownFilter {
+                Error::new(
+                    ErrorKind::UnknownFilter,
+                    format!("pipe_if: unknown filter '{}'", filter),
+                )
+            } else {
+                e
+            }
+        })
+    }
+}
+
+impl StateFunction for PipeIf {
+    const NAME: &'static str = "pipe_if";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "pipe_if",
+        category: "logic",
+        description: "Apply a named filter to a value only when a condition is true, otherwise return it unchanged",
+        arguments: &[
+            ArgumentMetadata {
+                name: "value",
+                arg_type: "any",
+                required: true,
+                default: None,
+                description: "Value to transform",
+            },
+            ArgumentMetadata {
+                name: "condition",
+                arg_type: "boolean",
+                required: true,
+                default: None,
+                description: "Whether to apply the filter",
+            },
+            ArgumentMetadata {
+                name: "filter",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Name of a registered filter (e.g., \"upper\"); other keyword arguments are passed to it",
+            },
+        ],
+        return_type: "any",
+        examples: &[
+            "{{ name | pipe_if(condition=shout, filter=\"upper\") }}",
+            "{{ pipe_if(value=summary, condition=compact, filter=\"truncate\", length=40) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_FILTER,
+    };
+
+    fn call(state: &State, kwargs: Kwargs) -> Result<Value, Error> {
+        let value: Value = kwargs.get("value")?;
+        Self::apply(state, value, kwargs)
+    }
+
+    /// Register as both a function and a filter, since filters are where
+    /// conditional application is otherwise awkward
+    fn register(env: &mut Environment) {
+        env.add_function(Self::NAME, Self::call);
+        env.add_filter(Self::NAME, Self::apply);
+    }
+}
+
+/// Compare two values structurally
+///
+/// Both values are converted to JSON first, so objects compare equal
+/// regardless of key order, arrays must match element by element, and
+/// numbers compare by value (`1 == 1.0`).
+pub struct DeepEqual;
+
+impl DeepEqual {
+    fn json_equal(a: &serde_json::Value, b: &serde_json::Value) -> bool {
+        use serde_json::Value as Json;
+        match (a, b) {
+            (Json::Number(x), Json::Number(y)) => match (x.as_i64(), y.as_i64()) {
+                (Some(x), Some(y)) => x == y,
+                _ => match (x.as_u64(), y.as_u64()) {
+                    (Some(x), Some(y)) => x == y,
+                    _ => x.as_f64() == y.as_f64(),
+                },
+            },
+            (Json::Array(x), Json::Array(y)) => {
+                x.len() == y.len() && x.iter().zip(y).all(|(x, y)| Self::json_equal(x, y))
+            }
+            (Json::Object(x), Json::Object(y)) => {
+                x.len() == y.len()
+                    && x.iter()
+                        .all(|(key, x)| y.get(key).is_some_and(|y| Self::json_equal(x, y)))
+            }
+            _ => a == b,
+        }
+    }
+
+    fn to_json(value: &Value, name: &str) -> Result<serde_json::Value, Error> {
+        serde_json::to_value(value).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("deep_equal: cannot compare '{}': {}", name, e),
+            )
+        })
+    }
+}
+
+impl Function for DeepEqual {
+    const NAME: &'static str = "deep_equal";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "deep_equal",
+        category: "logic",
+        description: "Compare two values structurally (object key order ignored, array order significant)",
+        arguments: &[
+            ArgumentMetadata {
+                name: "a",
+                arg_type: "any",
+                required: true,
+                default: None,
+                description: "First value",
+            },
+            ArgumentMetadata {
+                name: "b",
+                arg_type: "any",
+                required: true,
+                default: None,
+                description: "Second value",
+            },
+        ],
+        return_type: "boolean",
+        examples: &[
+            "{{ deep_equal(a={\"x\": 1, \"y\": [1, 2]}, b={\"y\": [1, 2], \"x\": 1.0}) }}",
+            "{% if not deep_equal(a=current.spec, b=desired.spec) %}changed{% endif %}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let a: Value = kwargs.get("a")?;
+        let b: Value = kwargs.get("b")?;
+
+        let a = Self::to_json(&a, "a")?;
+        let b = Self::to_json(&b, "b")?;
+        Ok(Value::from(Self::json_equal(&a, &b)))
+    }
+}
@@ -4,6 +4,10 @@
 //! - `min`: Return minimum of two values
 //! - `max`: Return maximum of two values
 //! - `percentage`: Calculate percentage
+//! - `percent` / `ratio`: Part of a whole, with a default for an empty whole
+//! - `lerp` / `map_range`: Linear interpolation and rescaling
+//! - `gcd` / `lcm`: Greatest common divisor and least common multiple
+//! - `factorial` / `combinations` / `permutations`: Combinatorics with overflow checks
 //!
 //! Note: abs, round, ceil, floor are now in filter_functions/math.rs
 //! with dual function+filter syntax support.
@@ -238,3 +242,515 @@ impl Function for Percentage {
         }
     }
 }
+
+/// Common arguments of `percent` and `ratio`
+const PART_WHOLE_ARGS: &[ArgumentMetadata] = &[
+    ArgumentMetadata {
+        name: "part",
+        arg_type: "number",
+        required: true,
+        default: None,
+        description: "The part (e.g., used capacity)",
+    },
+    ArgumentMetadata {
+        name: "whole",
+        arg_type: "number",
+        required: true,
+        default: None,
+        description: "The whole (e.g., total capacity)",
+    },
+    ArgumentMetadata {
+        name: "default",
+        arg_type: "any",
+        required: false,
+        default: Some("0"),
+        description: "Value returned when whole is zero",
+    },
+];
+
+/// Read a required numeric keyword argument
+fn number_kwarg(kwargs: &Kwargs, name: &str, fn_name: &str) -> Result<f64, Error> {
+    let value: Value = kwargs.get(name)?;
+    serde_json::to_value(&value)
+        .ok()
+        .and_then(|json| json.as_f64())
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("{} requires numeric {}, found: {}", fn_name, name, value),
+            )
+        })
+}
+
+/// Read a required integer keyword argument
+fn integer_kwarg(kwargs: &Kwargs, name: &str, fn_name: &str) -> Result<i64, Error> {
+    let value: Value = kwargs.get(name)?;
+    i64::try_from(value.clone()).map_err(|_| {
+        Error::new(
+            ErrorKind::InvalidOperation,
+            format!("{} requires integer {}, found: {}", fn_name, name, value),
+        )
+    })
+}
+
+/// Return a number as an integer when it has no fractional part
+fn smart_number(result: f64) -> Value {
+    if result.fract() == 0.0 {
+        Value::from(result as i64)
+    } else {
+        Value::from(result)
+    }
+}
+
+/// Divide `part` by `whole` and multiply by `scale`, returning `default` when whole is zero
+fn part_of_whole(kwargs: Kwargs, scale: f64, fn_name: &str) -> Result<Value, Error> {
+    let part = number_kwarg(&kwargs, "part", fn_name)?;
+    let whole = number_kwarg(&kwargs, "whole", fn_name)?;
+    let default: Option<Value> = kwargs.get("default")?;
+
+    if whole == 0.0 {
+        return Ok(default.unwrap_or_else(|| Value::from(0)));
+    }
+
+    Ok(smart_number(part / whole * scale))
+}
+
+/// Calculate what percentage `part` is of `whole`
+pub struct Percent;
+
+impl Function for Percent {
+    const NAME: &'static str = "percent";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "percent",
+        category: "math",
+        description: "Calculate part / whole * 100, returning a default instead of failing when whole is zero",
+        arguments: PART_WHOLE_ARGS,
+        return_type: "number",
+        examples: &[
+            "{{ percent(part=used, whole=capacity) | round(decimals=1) }}%",
+            "{{ percent(part=done, whole=total, default=\"n/a\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        part_of_whole(kwargs, 100.0, "percent")
+    }
+}
+
+/// Calculate the fraction `part / whole`
+pub struct Ratio;
+
+impl Function for Ratio {
+    const NAME: &'static str = "ratio";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "ratio",
+        category: "math",
+        description: "Calculate part / whole as a fraction, returning a default instead of failing when whole is zero",
+        arguments: PART_WHOLE_ARGS,
+        return_type: "number",
+        examples: &[
+            "{{ ratio(part=hits, whole=requests) }}",
+            "{{ ratio(part=errors, whole=requests, default=0) | round(decimals=3) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        part_of_whole(kwargs, 1.0, "ratio")
+    }
+}
+
+/// Linear interpolation between two numbers
+pub struct Lerp;
+
+impl Function for Lerp {
+    const NAME: &'static str = "lerp";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "lerp",
+        category: "math",
+        description: "Linear interpolation: start + (end - start) * t",
+        arguments: &[
+            ArgumentMetadata {
+                name: "start",
+                arg_type: "number",
+                required: true,
+                default: None,
+                description: "Value at t = 0",
+            },
+            ArgumentMetadata {
+                name: "end",
+                arg_type: "number",
+                required: true,
+                default: None,
+                description: "Value at t = 1",
+            },
+            ArgumentMetadata {
+                name: "t",
+                arg_type: "number",
+                required: true,
+                default: None,
+                description: "Position between start and end (values outside 0..1 extrapolate)",
+            },
+        ],
+        return_type: "number",
+        examples: &[
+            "{{ lerp(start=0, end=100, t=0.25) }}",
+            "{{ lerp(start=min_replicas, end=max_replicas, t=load) | round }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let start = number_kwarg(&kwargs, "start", "lerp")?;
+        let end = number_kwarg(&kwargs, "end", "lerp")?;
+        let t = number_kwarg(&kwargs, "t", "lerp")?;
+        Ok(smart_number(start + (end - start) * t))
+    }
+}
+
+/// Rescale a number from one range to another
+pub struct MapRange;
+
+impl Function for MapRange {
+    const NAME: &'static str = "map_range";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "map_range",
+        category: "math",
+        description: "Rescale a value from the range in_min..in_max to out_min..out_max",
+        arguments: &[
+            ArgumentMetadata {
+                name: "value",
+                arg_type: "number",
+                required: true,
+                default: None,
+                description: "Value to rescale",
+            },
+            ArgumentMetadata {
+                name: "in_min",
+                arg_type: "number",
+                required: true,
+                default: None,
+                description: "Start of the input range",
+            },
+            ArgumentMetadata {
+                name: "in_max",
+                arg_type: "number",
+                required: true,
+                default: None,
+                description: "End of the input range",
+            },
+            ArgumentMetadata {
+                name: "out_min",
+                arg_type: "number",
+                required: true,
+                default: None,
+                description: "Start of the output range",
+            },
+            ArgumentMetadata {
+                name: "out_max",
+                arg_type: "number",
+                required: true,
+                default: None,
+                description: "End of the output range",
+            },
+            ArgumentMetadata {
+                name: "clamp",
+                arg_type: "boolean",
+                required: false,
+                default: Some("false"),
+                description: "Limit the result to the output range",
+            },
+        ],
+        return_type: "number",
+        examples: &[
+            "{{ map_range(value=512, in_min=0, in_max=1024, out_min=0, out_max=100) }}",
+            "{{ map_range(value=cpu, in_min=0, in_max=4, out_min=1, out_max=10, clamp=true) | round }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let value = number_kwarg(&kwargs, "value", "map_range")?;
+        let in_min = number_kwarg(&kwargs, "in_min", "map_range")?;
+        let in_max = number_kwarg(&kwargs, "in_max", "map_range")?;
+        let out_min = number_kwarg(&kwargs, "out_min", "map_range")?;
+        let out_max = number_kwarg(&kwargs, "out_max", "map_range")?;
+        let clamp: Option<bool> = kwargs.get("clamp")?;
+
+        if in_min == in_max {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                "map_range: in_min and in_max must differ",
+            ));
+        }
+
+        let mut result = out_min + (value - in_min) * (out_max - out_min) / (in_max - in_min);
+        if clamp.unwrap_or(false) {
+            // The output range may be descending (out_min > out_max)
+            result = result.clamp(out_min.min(out_max), out_min.max(out_max));
+        }
+        Ok(smart_number(result))
+    }
+}
+
+/// Greatest common divisor of two non-negative values (Euclid)
+fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Greatest common divisor of two integers
+pub struct Gcd;
+
+impl Function for Gcd {
+    const NAME: &'static str = "gcd";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "gcd",
+        category: "math",
+        description: "Greatest common divisor of two integers (always non-negative)",
+        arguments: &[
+            ArgumentMetadata {
+                name: "a",
+                arg_type: "integer",
+                required: true,
+                default: None,
+                description: "First integer",
+            },
+            ArgumentMetadata {
+                name: "b",
+                arg_type: "integer",
+                required: true,
+                default: None,
+                description: "Second integer",
+            },
+        ],
+        return_type: "integer",
+        examples: &[
+            "{{ gcd(a=12, b=18) }}",
+            "{{ width // gcd(a=width, b=height) }}:{{ height // gcd(a=width, b=height) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let a = integer_kwarg(&kwargs, "a", "gcd")?;
+        let b = integer_kwarg(&kwargs, "b", "gcd")?;
+        let result = gcd_u64(a.unsigned_abs(), b.unsigned_abs());
+        i64::try_from(result).map(Value::from).map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("gcd: result {} is too large", result),
+            )
+        })
+    }
+}
+
+/// Least common multiple of two integers
+pub struct Lcm;
+
+impl Function for Lcm {
+    const NAME: &'static str = "lcm";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "lcm",
+        category: "math",
+        description: "Least common multiple of two integers (0 if either is 0)",
+        arguments: &[
+            ArgumentMetadata {
+                name: "a",
+                arg_type: "integer",
+                required: true,
+                default: None,
+                description: "First integer",
+            },
+            ArgumentMetadata {
+                name: "b",
+                arg_type: "integer",
+                required: true,
+                default: None,
+                description: "Second integer",
+            },
+        ],
+        return_type: "integer",
+        examples: &[
+            "{{ lcm(a=4, b=6) }}",
+            "sync_interval: {{ lcm(a=poll_seconds, b=flush_seconds) }}s",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let a = integer_kwarg(&kwargs, "a", "lcm")?;
+        let b = integer_kwarg(&kwargs, "b", "lcm")?;
+        if a == 0 || b == 0 {
+            return Ok(Value::from(0));
+        }
+
+        let (a, b) = (a.unsigned_abs(), b.unsigned_abs());
+        (a / gcd_u64(a, b))
+            .checked_mul(b)
+            .and_then(|result| i64::try_from(result).ok())
+            .map(Value::from)
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!("lcm: result of {} and {} overflows a 64-bit integer", a, b),
+                )
+            })
+    }
+}
+
+/// Read a required non-negative integer keyword argument
+fn count_kwarg(kwargs: &Kwargs, name: &str, fn_name: &str) -> Result<u64, Error> {
+    let value = integer_kwarg(kwargs, name, fn_name)?;
+    u64::try_from(value).map_err(|_| {
+        Error::new(
+            ErrorKind::InvalidOperation,
+            format!(
+                "{}: {} must not be negative, found: {}",
+                fn_name, name, value
+            ),
+        )
+    })
+}
+
+/// Convert a checked combinatorics result to a value, erroring past the i64 range
+fn checked_result(result: Option<u128>, fn_name: &str) -> Result<Value, Error> {
+    result
+        .and_then(|n| i64::try_from(n).ok())
+        .map(Value::from)
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("{}: result overflows a 64-bit integer", fn_name),
+            )
+        })
+}
+
+/// Product of `n - k + 1 ..= n`, or `None` once it leaves the i64 range
+fn falling_factorial(n: u64, k: u64) -> Option<u128> {
+    (0..k).try_fold(1u128, |acc, i| {
+        acc.checked_mul((n - i) as u128)
+            .filter(|product| *product <= i64::MAX as u128)
+    })
+}
+
+/// Factorial of a non-negative integer
+pub struct Factorial;
+
+impl Function for Factorial {
+    const NAME: &'static str = "factorial";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "factorial",
+        category: "math",
+        description: "Factorial n! of a non-negative integer (errors past 20!)",
+        arguments: &[ArgumentMetadata {
+            name: "n",
+            arg_type: "integer",
+            required: true,
+            default: None,
+            description: "Non-negative integer",
+        }],
+        return_type: "integer",
+        examples: &["{{ factorial(n=5) }}"],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let n = count_kwarg(&kwargs, "n", "factorial")?;
+        checked_result(falling_factorial(n, n), "factorial")
+    }
+}
+
+/// Number of ways to choose k items from n, ignoring order
+pub struct Combinations;
+
+impl Function for Combinations {
+    const NAME: &'static str = "combinations";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "combinations",
+        category: "math",
+        description: "Number of ways to choose k of n items, ignoring order (n choose k)",
+        arguments: &[
+            ArgumentMetadata {
+                name: "n",
+                arg_type: "integer",
+                required: true,
+                default: None,
+                description: "Number of items",
+            },
+            ArgumentMetadata {
+                name: "k",
+                arg_type: "integer",
+                required: true,
+                default: None,
+                description: "Number of items chosen",
+            },
+        ],
+        return_type: "integer",
+        examples: &[
+            "{{ combinations(n=5, k=2) }}",
+            "pairs: {{ combinations(n=nodes | length, k=2) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let n = count_kwarg(&kwargs, "n", "combinations")?;
+        let k = count_kwarg(&kwargs, "k", "combinations")?;
+        if k > n {
+            return Ok(Value::from(0));
+        }
+
+        // Multiply and divide step by step; each intermediate is itself a binomial coefficient
+        let k = k.min(n - k);
+        let result = (0..k).try_fold(1u128, |acc, i| {
+            acc.checked_mul((n - i) as u128)
+                .map(|product| product / (i as u128 + 1))
+                .filter(|next| *next <= i64::MAX as u128)
+        });
+        checked_result(result, "combinations")
+    }
+}
+
+/// Number of ordered arrangements of k items out of n
+pub struct Permutations;
+
+impl Function for Permutations {
+    const NAME: &'static str = "permutations";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "permutations",
+        category: "math",
+        description: "Number of ordered arrangements of k of n items (n! / (n - k)!)",
+        arguments: &[
+            ArgumentMetadata {
+                name: "n",
+                arg_type: "integer",
+                required: true,
+                default: None,
+                description: "Number of items",
+            },
+            ArgumentMetadata {
+                name: "k",
+                arg_type: "integer",
+                required: true,
+                default: None,
+                description: "Number of items arranged",
+            },
+        ],
+        return_type: "integer",
+        examples: &["{{ permutations(n=5, k=2) }}"],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let n = count_kwarg(&kwargs, "n", "permutations")?;
+        let k = count_kwarg(&kwargs, "k", "permutations")?;
+        if k > n {
+            return Ok(Value::from(0));
+        }
+        checked_result(falling_factorial(n, k), "permutations")
+    }
+}
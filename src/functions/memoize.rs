@@ -0,0 +1,130 @@
+//! Per-render memoization of expensive functions
+//!
+//! Functions such as `read_file` or `resolve_dns` are often called many times
+//! with the same arguments, e.g. inside a loop. Memoized functions compute
+//! each distinct call once per render; later calls with identical arguments
+//! return the stored result. Only successful results are stored, so errors
+//! are reported every time. Passing `cache=false` bypasses the stored result
+//! for one call, e.g. to re-read a file that changed during the render.
+//!
+//! Functions with side effects (`exec`, `counter`, `set_var`, ...) or that
+//! should differ between calls (`uuid`, `now`, ...) must not be memoized.
+
+use super::metadata::ArgumentMetadata;
+use crate::TemplateContext;
+use minijinja::value::{Kwargs, ValueKind};
+use minijinja::{Environment, Error, State, Value};
+use std::sync::Arc;
+
+/// Functions memoized by `register_all`
+///
+/// These only read data that does not change during a render.
+pub const MEMOIZED_FUNCTIONS: &[&str] = &[
+    "read_file",
+    "read_lines",
+    "file_head",
+    "file_tail",
+    "list_dir",
+    "glob",
+    "resolve_dns",
+    "git",
+    "read_json_file",
+    "read_yaml_file",
+    "read_toml_file",
+];
+
+/// Metadata for the `cache` argument accepted by memoized functions
+pub const CACHE_ARG: ArgumentMetadata = ArgumentMetadata {
+    name: "cache",
+    arg_type: "boolean",
+    required: false,
+    default: Some("true"),
+    description: "Reuse the result of an identical earlier call during this render",
+};
+
+/// Wrap the named functions registered in `env` so repeated calls are served from the cache
+///
+/// The cache lives in `context`, so it is shared by all clones of the context
+/// and lasts for one render. Names that are not registered functions are ignored.
+pub fn memoize(env: &mut Environment, context: &Arc<TemplateContext>, names: &[&str]) {
+    for name in names {
+        let Some(original) = env
+            .globals()
+            .find(|(global, value)| global == name && value.kind() == ValueKind::Plain)
+            .map(|(_, value)| value)
+        else {
+            continue;
+        };
+
+        let context = context.clone();
+        let name = name.to_string();
+        env.add_function(
+            name.clone(),
+            move |state: &State, args: &[Value]| -> Result<Value, Error> {
+                let (use_cache, args) = take_cache_arg(args)?;
+                if !use_cache {
+                    return original.call(state, &args);
+                }
+                cached_call(&context, &name, &args, || original.call(state, &args))
+            },
+        );
+    }
+}
+
+/// Return the stored result of `name(args)`, or compute and store it with `call`
+///
+/// This is what memoized functions do for each call. Functions that can reuse
+/// another function's result call it directly, e.g. `read_json_value` shares
+/// the parsed file with `read_json_file(path=...)`.
+pub fn cached_call<F>(
+    context: &TemplateContext,
+    name: &str,
+    args: &[Value],
+    call: F,
+) -> Result<Value, Error>
+where
+    F: FnOnce() -> Result<Value, Error>,
+{
+    // Arguments that cannot be serialized (e.g. macros) bypass the cache
+    let Some(key) = cache_key(name, args) else {
+        return call();
+    };
+    if let Some(cached) = context.get_memoized(&key) {
+        return Ok(cached);
+    }
+    let result = call()?;
+    context.store_memoized(&key, result.clone());
+    Ok(result)
+}
+
+/// Remove the `cache` keyword argument, returning its value (default: true)
+///
+/// The wrapped function never sees the argument, and it is not part of the
+/// cache key. Results of `cache=false` calls are not stored.
+fn take_cache_arg(args: &[Value]) -> Result<(bool, Vec<Value>), Error> {
+    let Some((last, rest)) = args.split_last().filter(|(last, _)| last.is_kwargs()) else {
+        return Ok((true, args.to_vec()));
+    };
+    let kwargs = Kwargs::try_from(last.clone())?;
+    let use_cache = kwargs.get::<Option<bool>>("cache")?.unwrap_or(true);
+    let remaining: Vec<(&str, Value)> = kwargs
+        .args()
+        .filter(|name| *name != "cache")
+        .map(|name| kwargs.peek::<Value>(name).map(|value| (name, value)))
+        .collect::<Result<_, _>>()?;
+
+    let mut args = rest.to_vec();
+    if !remaining.is_empty() {
+        args.push(Value::from(Kwargs::from_iter(remaining)));
+    }
+    Ok((use_cache, args))
+}
+
+/// Build the cache key from the function name and its arguments
+///
+/// Arguments go through `serde_json::Value`, whose maps are sorted, so the
+/// order of keyword arguments does not matter.
+fn cache_key(name: &str, args: &[Value]) -> Option<String> {
+    let args = serde_json::to_value(args).ok()?;
+    Some(format!("{}({})", name, args))
+}
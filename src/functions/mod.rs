@@ -66,23 +66,34 @@
 //! ```
 
 pub mod array;
+pub mod cache;
+pub mod convert;
+pub mod cron;
 pub mod data_parsing;
 pub mod datetime;
 pub mod debug;
+pub mod diagram;
+pub mod diff;
+pub mod docker;
 pub mod encoding;
 pub mod environment;
 pub mod exec;
 pub mod filesystem;
 pub mod kubernetes;
+pub mod layout;
 pub mod logic;
 pub mod math;
+pub mod memoize;
 pub mod metadata;
 pub mod network;
 pub mod object;
 pub mod predicates;
+pub mod qr;
 pub mod random;
+pub mod state;
 pub mod string;
 pub mod system;
+pub mod template;
 pub mod traits;
 pub mod url;
 pub mod uuid_gen;
@@ -93,7 +104,7 @@ use minijinja::Environment;
 
 // Re-export metadata types for external use
 pub use metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
-pub use traits::{ContextFunction, Function};
+pub use traits::{ContextFunction, ContextStateFunction, Function, StateFunction};
 
 /// Collect all function metadata for IDE integration
 ///
@@ -109,12 +120,21 @@ pub fn get_all_metadata() -> Vec<&'static FunctionMetadata> {
         // Environment functions
         &environment::GetEnv::METADATA,
         &environment::FilterEnv::METADATA,
+        &environment::EnvToObject::METADATA,
+        &environment::ToEnvKeys::METADATA,
+        &environment::Secret::METADATA,
+        &environment::Prompt::METADATA,
         // Random/UUID functions
         &random::GetRandom::METADATA,
         &random::RandomString::METADATA,
         &uuid_gen::UuidGen::METADATA,
+        &uuid_gen::StableId::METADATA,
         // Validation functions
         &validation::MatchesRegex::METADATA,
+        &validation::Luhn::METADATA,
+        &validation::LuhnValid::METADATA,
+        &validation::IsbnValid::METADATA,
+        &validation::EanValid::METADATA,
         // System functions
         &system::GetHostname::METADATA,
         &system::GetUsername::METADATA,
@@ -140,23 +160,36 @@ pub fn get_all_metadata() -> Vec<&'static FunctionMetadata> {
         &debug::Assert::METADATA,
         &debug::Warn::METADATA,
         &debug::Abort::METADATA,
+        &debug::RenderContext::METADATA,
         // Predicate functions
         &predicates::ArrayAny::METADATA,
         &predicates::ArrayAll::METADATA,
         &predicates::ArrayContains::METADATA,
-        &predicates::StartsWith::METADATA,
-        &predicates::EndsWith::METADATA,
         // Logic functions
         &logic::Default::METADATA,
         &logic::Coalesce::METADATA,
         &logic::Ternary::METADATA,
         &logic::InRange::METADATA,
+        &logic::PipeIf::METADATA,
+        &logic::DeepEqual::METADATA,
+        // Template introspection functions
+        &template::TemplateExists::METADATA,
+        &template::HasMacro::METADATA,
+        // Conversion functions
+        &convert::ToInt::METADATA,
+        &convert::ToFloat::METADATA,
+        &convert::ToBool::METADATA,
+        &convert::ToString::METADATA,
         // DateTime functions
         &datetime::Now::METADATA,
         &datetime::ParseDate::METADATA,
         &datetime::DateAdd::METADATA,
         &datetime::DateDiff::METADATA,
         &datetime::TimezoneConvert::METADATA,
+        &datetime::TzConvert::METADATA,
+        &datetime::ParseDuration::METADATA,
+        &datetime::BusinessDaysBetween::METADATA,
+        &cron::CronDescribe::METADATA,
         // Encoding functions
         &encoding::Bcrypt::METADATA,
         &encoding::GenerateSecret::METADATA,
@@ -165,21 +198,33 @@ pub fn get_all_metadata() -> Vec<&'static FunctionMetadata> {
         &math::Min::METADATA,
         &math::Max::METADATA,
         &math::Percentage::METADATA,
+        &math::Percent::METADATA,
+        &math::Ratio::METADATA,
+        &math::Lerp::METADATA,
+        &math::MapRange::METADATA,
+        &math::Gcd::METADATA,
+        &math::Lcm::METADATA,
+        &math::Factorial::METADATA,
+        &math::Combinations::METADATA,
+        &math::Permutations::METADATA,
         // String functions
         &string::RegexMatch::METADATA,
         &string::RegexFindAll::METADATA,
-        &string::Contains::METADATA,
         &string::IndexOf::METADATA,
         &string::CountOccurrences::METADATA,
         &string::SentenceCase::METADATA,
         &string::ToConstantCase::METADATA,
         &string::Pluralize::METADATA,
+        &string::Format::METADATA,
+        &string::Printf::METADATA,
+        &string::GeneratedHeader::METADATA,
         // Array functions
         &array::ArrayCount::METADATA,
         &array::ArrayChunk::METADATA,
         &array::ArrayZip::METADATA,
         &array::ArraySortBy::METADATA,
         &array::ArrayGroupBy::METADATA,
+        &array::ArrayIndexBy::METADATA,
         &array::ArrayTake::METADATA,
         &array::ArrayDrop::METADATA,
         &array::ArrayIndexOf::METADATA,
@@ -190,16 +235,31 @@ pub fn get_all_metadata() -> Vec<&'static FunctionMetadata> {
         &array::ArrayDifference::METADATA,
         &array::ArrayUnion::METADATA,
         &array::ArraySymmetricDifference::METADATA,
+        &array::ArrayFill::METADATA,
+        &array::ArrayZeros::METADATA,
+        &array::Transpose::METADATA,
+        &array::ArrayWindow::METADATA,
         // Object functions
         &object::ObjectMerge::METADATA,
         &object::ObjectGet::METADATA,
+        &object::ObjectGetAll::METADATA,
         &object::ObjectSet::METADATA,
         &object::ObjectHasKey::METADATA,
         &object::JsonPath::METADATA,
+        &object::JsonPatch::METADATA,
+        &object::JsonMergePatch::METADATA,
+        &object::ApplySchemaDefaults::METADATA,
         &object::ObjectPick::METADATA,
         &object::ObjectOmit::METADATA,
         &object::ObjectRenameKeys::METADATA,
         &object::ObjectUnflatten::METADATA,
+        &object::ObjectMapValues::METADATA,
+        &object::ObjectMapKeys::METADATA,
+        &object::ObjectFilter::METADATA,
+        &object::ObjectInvert::METADATA,
+        &object::ObjectKeyOf::METADATA,
+        &object::Dict::METADATA,
+        &object::ObjectFromKwargs::METADATA,
         // Kubernetes functions
         &kubernetes::K8sResourceRequest::METADATA,
         &kubernetes::K8sEnvVarRef::METADATA,
@@ -212,10 +272,27 @@ pub fn get_all_metadata() -> Vec<&'static FunctionMetadata> {
         &kubernetes::K8sPodAffinity::METADATA,
         &kubernetes::K8sToleration::METADATA,
         &kubernetes::K8sProbe::METADATA,
+        &kubernetes::K8sLabels::METADATA,
+        &kubernetes::K8sName::METADATA,
+        &kubernetes::K8sAnnotations::METADATA,
+        // Docker functions
+        &docker::ParseDockerRef::METADATA,
+        &docker::BuildDockerRef::METADATA,
         // URL functions
         &url::BasicAuth::METADATA,
         &url::BuildUrl::METADATA,
         &url::QueryString::METADATA,
+        // Diagram functions
+        &diagram::ToMermaid::METADATA,
+        &diagram::ToDot::METADATA,
+        // QR code functions
+        &qr::QrSvg::METADATA,
+        // Layout functions
+        &layout::Columns::METADATA,
+        &layout::TextBox::METADATA,
+        &layout::ProgressBar::METADATA,
+        // Diff functions
+        &diff::DiffText::METADATA,
         // Filesystem functions (context-aware)
         &filesystem::ReadFile::METADATA,
         &filesystem::FileExists::METADATA,
@@ -224,13 +301,29 @@ pub fn get_all_metadata() -> Vec<&'static FunctionMetadata> {
         &filesystem::FileSize::METADATA,
         &filesystem::FileModified::METADATA,
         &filesystem::ReadLines::METADATA,
+        &filesystem::FileHead::METADATA,
+        &filesystem::FileTail::METADATA,
+        &filesystem::IncludeIfExists::METADATA,
         // Data parsing functions (context-aware)
         &data_parsing::ReadJsonFile::METADATA,
         &data_parsing::ReadYamlFile::METADATA,
         &data_parsing::ReadTomlFile::METADATA,
+        &data_parsing::ReadJsonValue::METADATA,
+        &data_parsing::ReadYamlValue::METADATA,
+        &data_parsing::ReadTomlValue::METADATA,
+        // System functions (context-aware)
+        &system::SystemInfo::METADATA,
         // Exec functions (context-aware)
         &exec::Exec::METADATA,
         &exec::ExecRaw::METADATA,
+        &exec::Git::METADATA,
+        // State functions (context-aware)
+        &state::Counter::METADATA,
+        &state::SetVar::METADATA,
+        &state::GetVar::METADATA,
+        &state::Once::METADATA,
+        // Disk cache (context-aware)
+        &cache::Cache::METADATA,
     ]
 }
 
@@ -257,7 +350,7 @@ pub fn get_all_metadata() -> Vec<&'static FunctionMetadata> {
 /// ```
 pub fn register_all(env: &mut Environment, context: TemplateContext) {
     use std::sync::Arc;
-    use traits::{ContextFunction, Function};
+    use traits::{ContextFunction, ContextStateFunction, Function, StateFunction};
 
     // Register filter-functions (functions that also work as filters)
     crate::filter_functions::register_all(env);
@@ -273,11 +366,16 @@ pub fn register_all(env: &mut Environment, context: TemplateContext) {
     // Environment functions
     environment::GetEnv::register(env);
     environment::FilterEnv::register(env);
+    environment::EnvToObject::register(env);
+    environment::ToEnvKeys::register(env);
+    environment::Secret::register(env, context_arc.clone());
+    environment::Prompt::register(env, context_arc.clone());
 
     // Random/UUID functions
     random::GetRandom::register(env);
     random::RandomString::register(env);
     uuid_gen::UuidGen::register(env);
+    uuid_gen::StableId::register(env);
 
     // DateTime functions
     datetime::Now::register(env);
@@ -285,9 +383,17 @@ pub fn register_all(env: &mut Environment, context: TemplateContext) {
     datetime::DateAdd::register(env);
     datetime::DateDiff::register(env);
     datetime::TimezoneConvert::register(env);
+    datetime::TzConvert::register(env);
+    datetime::ParseDuration::register(env);
+    datetime::BusinessDaysBetween::register(env);
+    cron::CronDescribe::register(env);
 
     // Validation functions
     validation::MatchesRegex::register(env);
+    validation::Luhn::register(env);
+    validation::LuhnValid::register(env);
+    validation::IsbnValid::register(env);
+    validation::EanValid::register(env);
 
     // System functions
     system::GetHostname::register(env);
@@ -319,36 +425,57 @@ pub fn register_all(env: &mut Environment, context: TemplateContext) {
     debug::TypeOf::register(env);
     debug::Inspect::register(env);
     debug::Assert::register(env);
-    debug::Warn::register(env);
     debug::Abort::register(env);
+    debug::RenderContext::register(env);
 
     // Predicate functions
     predicates::ArrayAny::register(env);
     predicates::ArrayAll::register(env);
     predicates::ArrayContains::register(env);
-    predicates::StartsWith::register(env);
-    predicates::EndsWith::register(env);
 
     // Logic functions
     logic::Default::register(env);
     logic::Coalesce::register(env);
     logic::Ternary::register(env);
     logic::InRange::register(env);
+    logic::PipeIf::register(env);
+    logic::DeepEqual::register(env);
+
+    // Template introspection functions
+    template::TemplateExists::register(env);
+    template::HasMacro::register(env);
+
+    // Conversion functions
+    convert::ToInt::register(env);
+    convert::ToFloat::register(env);
+    convert::ToBool::register(env);
+    convert::ToString::register(env);
 
     // Math functions
     math::Min::register(env);
     math::Max::register(env);
     math::Percentage::register(env);
+    math::Percent::register(env);
+    math::Ratio::register(env);
+    math::Lerp::register(env);
+    math::MapRange::register(env);
+    math::Gcd::register(env);
+    math::Lcm::register(env);
+    math::Factorial::register(env);
+    math::Combinations::register(env);
+    math::Permutations::register(env);
 
     // String functions
     string::RegexMatch::register(env);
     string::RegexFindAll::register(env);
-    string::Contains::register(env);
     string::IndexOf::register(env);
     string::CountOccurrences::register(env);
     string::SentenceCase::register(env);
     string::ToConstantCase::register(env);
     string::Pluralize::register(env);
+    string::Format::register(env);
+    string::Printf::register(env);
+    string::GeneratedHeader::register(env);
 
     // Array functions
     array::ArrayCount::register(env);
@@ -356,6 +483,7 @@ pub fn register_all(env: &mut Environment, context: TemplateContext) {
     array::ArrayZip::register(env);
     array::ArraySortBy::register(env);
     array::ArrayGroupBy::register(env);
+    array::ArrayIndexBy::register(env);
     array::ArrayTake::register(env);
     array::ArrayDrop::register(env);
     array::ArrayIndexOf::register(env);
@@ -366,17 +494,32 @@ pub fn register_all(env: &mut Environment, context: TemplateContext) {
     array::ArrayDifference::register(env);
     array::ArrayUnion::register(env);
     array::ArraySymmetricDifference::register(env);
+    array::ArrayFill::register(env);
+    array::ArrayZeros::register(env);
+    array::Transpose::register(env);
+    array::ArrayWindow::register(env);
 
     // Object functions
     object::ObjectMerge::register(env);
     object::ObjectGet::register(env);
+    object::ObjectGetAll::register(env);
     object::ObjectSet::register(env);
     object::ObjectHasKey::register(env);
     object::JsonPath::register(env);
+    object::JsonPatch::register(env);
+    object::JsonMergePatch::register(env);
+    object::ApplySchemaDefaults::register(env);
     object::ObjectPick::register(env);
     object::ObjectOmit::register(env);
     object::ObjectRenameKeys::register(env);
     object::ObjectUnflatten::register(env);
+    object::ObjectMapValues::register(env);
+    object::ObjectMapKeys::register(env);
+    object::ObjectFilter::register(env);
+    object::ObjectInvert::register(env);
+    object::ObjectKeyOf::register(env);
+    object::Dict::register(env);
+    object::ObjectFromKwargs::register(env);
 
     // Kubernetes functions
     kubernetes::K8sResourceRequest::register(env);
@@ -390,12 +533,34 @@ pub fn register_all(env: &mut Environment, context: TemplateContext) {
     kubernetes::K8sPodAffinity::register(env);
     kubernetes::K8sToleration::register(env);
     kubernetes::K8sProbe::register(env);
+    kubernetes::K8sLabels::register(env);
+    kubernetes::K8sName::register(env);
+    kubernetes::K8sAnnotations::register(env);
+
+    // Docker functions
+    docker::ParseDockerRef::register(env);
+    docker::BuildDockerRef::register(env);
 
     // URL functions
     url::BasicAuth::register(env);
     url::BuildUrl::register(env);
     url::QueryString::register(env);
 
+    // Diagram functions
+    diagram::ToMermaid::register(env);
+    diagram::ToDot::register(env);
+
+    // QR code functions
+    qr::QrSvg::register(env);
+
+    // Layout functions
+    layout::Columns::register(env);
+    layout::TextBox::register(env);
+    layout::ProgressBar::register(env);
+
+    // Diff functions
+    diff::DiffText::register(env);
+
     // ===== Context-Aware Functions (need filesystem/trust mode access) =====
 
     // Filesystem functions
@@ -406,13 +571,38 @@ pub fn register_all(env: &mut Environment, context: TemplateContext) {
     filesystem::FileSize::register(env, context_arc.clone());
     filesystem::FileModified::register(env, context_arc.clone());
     filesystem::ReadLines::register(env, context_arc.clone());
+    filesystem::FileHead::register(env, context_arc.clone());
+    filesystem::FileTail::register(env, context_arc.clone());
+    filesystem::IncludeIfExists::register(env, context_arc.clone());
 
     // Data parsing file functions
     data_parsing::ReadJsonFile::register(env, context_arc.clone());
     data_parsing::ReadYamlFile::register(env, context_arc.clone());
     data_parsing::ReadTomlFile::register(env, context_arc.clone());
+    data_parsing::ReadJsonValue::register(env, context_arc.clone());
+    data_parsing::ReadYamlValue::register(env, context_arc.clone());
+    data_parsing::ReadTomlValue::register(env, context_arc.clone());
+
+    // System functions (context-aware for trust mode)
+    system::SystemInfo::register(env, context_arc.clone());
 
     // Execution functions
     exec::Exec::register(env, context_arc.clone());
-    exec::ExecRaw::register(env, context_arc);
+    exec::ExecRaw::register(env, context_arc.clone());
+    exec::Git::register(env, context_arc.clone());
+
+    // Debug functions (context-aware for warning counting)
+    debug::Warn::register(env, context_arc.clone());
+
+    // State functions (context-aware)
+    state::Counter::register(env, context_arc.clone());
+    state::SetVar::register(env, context_arc.clone());
+    state::GetVar::register(env, context_arc.clone());
+    state::Once::register(env, context_arc.clone());
+
+    // Disk cache (context-aware for the base directory and trust mode)
+    cache::Cache::register(env, context_arc.clone());
+
+    // Serve repeated calls with identical arguments from the per-render cache
+    memoize::memoize(env, &context_arc, memoize::MEMOIZED_FUNCTIONS);
 }
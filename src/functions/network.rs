@@ -11,6 +11,7 @@
 //! - `ip_to_int`: Convert IP to integer
 //! - `int_to_ip`: Convert integer to IP
 
+use super::memoize::CACHE_ARG;
 use super::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
 use super::traits::Function;
 use minijinja::value::Kwargs;
@@ -148,13 +149,16 @@ impl Function for ResolveDns {
         name: "resolve_dns",
         category: "network",
         description: "Resolve a hostname to an IP address using DNS",
-        arguments: &[ArgumentMetadata {
-            name: "hostname",
-            arg_type: "string",
-            required: true,
-            default: None,
-            description: "Hostname to resolve (e.g., google.com, localhost)",
-        }],
+        arguments: &[
+            ArgumentMetadata {
+                name: "hostname",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Hostname to resolve (e.g., google.com, localhost)",
+            },
+            CACHE_ARG,
+        ],
         return_type: "string",
         examples: &[
             "{{ resolve_dns(hostname=\"google.com\") }}",
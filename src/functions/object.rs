@@ -3,19 +3,27 @@
 //! This module provides functions for:
 //! - Merging objects
 //! - Getting/setting nested values by path
+//! - Collecting values at wildcard paths
 //! - Checking key existence
 //! - JSONPath queries
+//! - Applying JSON Patch (RFC 6902) and JSON Merge Patch (RFC 7386) documents
+//! - Filling in JSON Schema defaults
 //! - Object picking/omitting keys
 //! - Key renaming
 //! - Unflattening nested objects
+//! - Mapping keys/values through named filters
+//! - Filtering entries by comparison operator
+//! - Inverting keys and values
+//! - Building objects from computed keys (`dict`, `object`)
 //!
 //! Note: object_keys, object_values, object_flatten are now in
 //! filter_functions/object.rs with dual function+filter syntax support.
 
+use super::array::compare_by_op;
 use super::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
-use super::traits::Function;
-use minijinja::value::Kwargs;
-use minijinja::{Error, ErrorKind, Value};
+use super::traits::{Function, StateFunction};
+use minijinja::value::{Kwargs, ValueKind};
+use minijinja::{Environment, Error, ErrorKind, State, Value};
 use serde_json::Map;
 
 /// Deep merge two objects
@@ -42,15 +50,30 @@ impl Function for ObjectMerge {
                 default: None,
                 description: "Second object (overlay, takes precedence)",
             },
+            ArgumentMetadata {
+                name: "list_strategy",
+                arg_type: "string",
+                required: false,
+                default: Some("replace"),
+                description: "How nested arrays are merged: \"replace\", \"append\", or \"unique\"",
+            },
         ],
         return_type: "object",
-        examples: &["{{ object_merge(obj1={\"a\": 1}, obj2={\"b\": 2}) }}"],
+        examples: &[
+            "{{ object_merge(obj1={\"a\": 1}, obj2={\"b\": 2}) }}",
+            "{{ object_merge(obj1=base, obj2=overlay, list_strategy=\"append\") }}",
+        ],
         syntax: SyntaxVariants::FUNCTION_ONLY,
     };
 
     fn call(kwargs: Kwargs) -> Result<Value, Error> {
         let obj1: Value = kwargs.get("obj1")?;
         let obj2: Value = kwargs.get("obj2")?;
+        let list_strategy: Option<String> = kwargs.get("list_strategy")?;
+        let list_strategy = match list_strategy {
+            Some(s) => ListStrategy::parse(&s)?,
+            None => ListStrategy::Replace,
+        };
 
         // Convert to serde_json::Value for easier manipulation
         let json1: serde_json::Value = serde_json::to_value(&obj1).map_err(|e| {
@@ -67,27 +90,72 @@ impl Function for ObjectMerge {
             )
         })?;
 
-        let merged = merge_json_values(json1, json2);
+        let merged = merge_json_values(json1, json2, list_strategy);
 
         Ok(Value::from_serialize(&merged))
     }
 }
 
+/// Strategy for merging arrays found at the same key during a deep merge
+///
+/// Also used by `--context-list-strategy` when layering context files.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum ListStrategy {
+    /// Overlay array replaces the base array
+    #[default]
+    Replace,
+    /// Overlay items are appended to the base array
+    Append,
+    /// Overlay items are appended unless already present in the base array
+    Unique,
+}
+
+impl ListStrategy {
+    /// Parse a strategy name ("replace", "append", "unique")
+    pub fn parse(name: &str) -> Result<Self, Error> {
+        match name {
+            "replace" => Ok(Self::Replace),
+            "append" => Ok(Self::Append),
+            "unique" => Ok(Self::Unique),
+            _ => Err(Error::new(
+                ErrorKind::InvalidOperation,
+                format!(
+                    "Invalid list_strategy '{}'. Use: replace, append, unique",
+                    name
+                ),
+            )),
+        }
+    }
+}
+
 /// Recursively merge two JSON values
-fn merge_json_values(mut base: serde_json::Value, overlay: serde_json::Value) -> serde_json::Value {
-    if let (serde_json::Value::Object(base_map), serde_json::Value::Object(overlay_map)) =
-        (&mut base, &overlay)
-    {
-        for (key, value) in overlay_map {
-            if let Some(base_value) = base_map.get_mut(key) {
-                *base_value = merge_json_values(base_value.clone(), value.clone());
-            } else {
-                base_map.insert(key.clone(), value.clone());
+pub fn merge_json_values(
+    mut base: serde_json::Value,
+    overlay: serde_json::Value,
+    list_strategy: ListStrategy,
+) -> serde_json::Value {
+    match (&mut base, overlay) {
+        (serde_json::Value::Object(base_map), serde_json::Value::Object(overlay_map)) => {
+            for (key, value) in overlay_map {
+                if let Some(base_value) = base_map.get_mut(&key) {
+                    *base_value = merge_json_values(base_value.take(), value, list_strategy);
+                } else {
+                    base_map.insert(key, value);
+                }
+            }
+            base
+        }
+        (serde_json::Value::Array(base_arr), serde_json::Value::Array(overlay_arr))
+            if list_strategy != ListStrategy::Replace =>
+        {
+            for item in overlay_arr {
+                if list_strategy == ListStrategy::Append || !base_arr.contains(&item) {
+                    base_arr.push(item);
+                }
             }
+            base
         }
-        base
-    } else {
-        overlay
+        (_, overlay) => overlay,
     }
 }
 
@@ -163,6 +231,89 @@ impl Function for ObjectGet {
     }
 }
 
+/// Collect all values matching a dot-separated path with `*` wildcards
+pub struct ObjectGetAll;
+
+impl Function for ObjectGetAll {
+    const NAME: &'static str = "object_get_all";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "object_get_all",
+        category: "object",
+        description: "Collect all values at a dot-separated path where \"*\" matches every array item or object value",
+        arguments: &[
+            ArgumentMetadata {
+                name: "object",
+                arg_type: "object",
+                required: true,
+                default: None,
+                description: "Object or array to query",
+            },
+            ArgumentMetadata {
+                name: "path",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Dot-separated path with optional \"*\" segments (e.g., \"servers.*.host\")",
+            },
+        ],
+        return_type: "array",
+        examples: &["{{ object_get_all(object=config, path=\"servers.*.host\") }}"],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let object: Value = kwargs.get("object")?;
+        let path: String = kwargs.get("path")?;
+
+        let json_value: serde_json::Value = serde_json::to_value(&object).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("Failed to convert object: {}", e),
+            )
+        })?;
+
+        let parts: Vec<&str> = path.split('.').collect();
+        let mut results = Vec::new();
+        collect_wildcard_path(&json_value, &parts, &mut results);
+
+        Ok(Value::from_serialize(&results))
+    }
+}
+
+/// Recursively walk a path, expanding `*` segments into every child
+fn collect_wildcard_path(
+    current: &serde_json::Value,
+    parts: &[&str],
+    results: &mut Vec<serde_json::Value>,
+) {
+    let Some((part, rest)) = parts.split_first() else {
+        results.push(current.clone());
+        return;
+    };
+
+    match current {
+        serde_json::Value::Object(map) => {
+            if *part == "*" {
+                for value in map.values() {
+                    collect_wildcard_path(value, rest, results);
+                }
+            } else if let Some(value) = map.get(*part) {
+                collect_wildcard_path(value, rest, results);
+            }
+        }
+        serde_json::Value::Array(arr) => {
+            if *part == "*" {
+                for value in arr {
+                    collect_wildcard_path(value, rest, results);
+                }
+            } else if let Some(value) = part.parse::<usize>().ok().and_then(|i| arr.get(i)) {
+                collect_wildcard_path(value, rest, results);
+            }
+        }
+        _ => {}
+    }
+}
+
 /// Set nested value by path
 pub struct ObjectSet;
 
@@ -455,6 +606,279 @@ fn query_json_path(value: &serde_json::Value, path: &str) -> Result<serde_json::
     Ok(current)
 }
 
+/// Apply an RFC 6902 JSON Patch to an object
+pub struct JsonPatch;
+
+impl Function for JsonPatch {
+    const NAME: &'static str = "json_patch";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "json_patch",
+        category: "object",
+        description: "Apply an RFC 6902 JSON Patch (add/remove/replace/move/copy/test) to an object",
+        arguments: &[
+            ArgumentMetadata {
+                name: "object",
+                arg_type: "object",
+                required: true,
+                default: None,
+                description: "Object (or array) to patch",
+            },
+            ArgumentMetadata {
+                name: "patch",
+                arg_type: "array",
+                required: true,
+                default: None,
+                description: "Array of patch operations, e.g. [{\"op\": \"replace\", \"path\": \"/a\", \"value\": 1}]",
+            },
+        ],
+        return_type: "object",
+        examples: &[
+            "{{ json_patch(object=manifest, patch=[{\"op\": \"replace\", \"path\": \"/spec/replicas\", \"value\": 3}]) }}",
+            "{{ json_patch(object=config, patch=read_json_file(path=\"prod.patch.json\")) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let object: Value = kwargs.get("object")?;
+        let patch: Value = kwargs.get("patch")?;
+
+        let mut json_value: serde_json::Value = serde_json::to_value(&object).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("Failed to convert object: {}", e),
+            )
+        })?;
+        let patch_value: serde_json::Value = serde_json::to_value(&patch).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("Failed to convert patch: {}", e),
+            )
+        })?;
+
+        let patch: json_patch::Patch = serde_json::from_value(patch_value).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("json_patch: invalid patch: {}", e),
+            )
+        })?;
+
+        // Patches are applied atomically: on error the object is left unchanged
+        json_patch::patch(&mut json_value, &patch)
+            .map_err(|e| Error::new(ErrorKind::InvalidOperation, format!("json_patch: {}", e)))?;
+
+        Ok(Value::from_serialize(&json_value))
+    }
+}
+
+/// Apply an RFC 7386 JSON Merge Patch to an object
+pub struct JsonMergePatch;
+
+impl Function for JsonMergePatch {
+    const NAME: &'static str = "json_merge_patch";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "json_merge_patch",
+        category: "object",
+        description: "Apply an RFC 7386 JSON Merge Patch (null values delete keys)",
+        arguments: &[
+            ArgumentMetadata {
+                name: "object",
+                arg_type: "object",
+                required: true,
+                default: None,
+                description: "Object to patch",
+            },
+            ArgumentMetadata {
+                name: "patch",
+                arg_type: "any",
+                required: true,
+                default: None,
+                description: "Merge patch; objects merge recursively, null removes a key, anything else replaces",
+            },
+        ],
+        return_type: "any",
+        examples: &[
+            "{{ json_merge_patch(object=config, patch={\"debug\": none, \"port\": 9090}) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let object: Value = kwargs.get("object")?;
+        let patch: Value = kwargs.get("patch")?;
+
+        let mut json_value: serde_json::Value = serde_json::to_value(&object).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("Failed to convert object: {}", e),
+            )
+        })?;
+        let patch_value: serde_json::Value = serde_json::to_value(&patch).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("Failed to convert patch: {}", e),
+            )
+        })?;
+
+        json_patch::merge(&mut json_value, &patch_value);
+
+        Ok(Value::from_serialize(&json_value))
+    }
+}
+
+/// Fill in `default` values from a JSON Schema for absent properties
+pub struct ApplySchemaDefaults;
+
+impl Function for ApplySchemaDefaults {
+    const NAME: &'static str = "apply_schema_defaults";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "apply_schema_defaults",
+        category: "object",
+        description: "Fill in JSON Schema default values for properties missing from an object",
+        arguments: &[
+            ArgumentMetadata {
+                name: "object",
+                arg_type: "object",
+                required: true,
+                default: None,
+                description: "Object to complete",
+            },
+            ArgumentMetadata {
+                name: "schema",
+                arg_type: "object",
+                required: true,
+                default: None,
+                description: "JSON Schema with \"default\" values (properties, items, allOf and local $ref are followed)",
+            },
+        ],
+        return_type: "object",
+        examples: &[
+            "{{ apply_schema_defaults(object=config, schema=read_json_file(path=\"config.schema.json\")) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let object: Value = kwargs.get("object")?;
+        let schema: Value = kwargs.get("schema")?;
+
+        let mut json_value: serde_json::Value = serde_json::to_value(&object).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("Failed to convert object: {}", e),
+            )
+        })?;
+        let schema: serde_json::Value = serde_json::to_value(&schema).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("Failed to convert schema: {}", e),
+            )
+        })?;
+
+        if !schema.is_object() {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                "apply_schema_defaults: schema must be an object",
+            ));
+        }
+
+        fill_schema_defaults(&mut json_value, &schema, &schema, 0)?;
+        Ok(Value::from_serialize(&json_value))
+    }
+}
+
+/// Maximum `$ref` nesting followed by `apply_schema_defaults` (guards against cycles)
+const MAX_SCHEMA_DEPTH: usize = 64;
+
+/// Recursively fill absent properties of `value` with defaults from `schema`
+fn fill_schema_defaults(
+    value: &mut serde_json::Value,
+    schema: &serde_json::Value,
+    root: &serde_json::Value,
+    depth: usize,
+) -> Result<(), Error> {
+    if depth > MAX_SCHEMA_DEPTH {
+        return Err(Error::new(
+            ErrorKind::InvalidOperation,
+            "apply_schema_defaults: schema nesting too deep (recursive $ref?)",
+        ));
+    }
+
+    if let Some(reference) = schema.get("$ref").and_then(|r| r.as_str()) {
+        let target = resolve_schema_ref(root, reference)?;
+        fill_schema_defaults(value, target, root, depth + 1)?;
+    }
+
+    if let Some(all_of) = schema.get("allOf").and_then(|a| a.as_array()) {
+        for sub_schema in all_of {
+            fill_schema_defaults(value, sub_schema, root, depth + 1)?;
+        }
+    }
+
+    if let (Some(obj), Some(properties)) = (
+        value.as_object_mut(),
+        schema.get("properties").and_then(|p| p.as_object()),
+    ) {
+        for (key, prop_schema) in properties {
+            if !obj.contains_key(key) {
+                match schema_default(prop_schema, root)? {
+                    Some(default) => {
+                        obj.insert(key.clone(), default);
+                    }
+                    None => continue,
+                }
+            }
+            if let Some(prop_value) = obj.get_mut(key) {
+                fill_schema_defaults(prop_value, prop_schema, root, depth + 1)?;
+            }
+        }
+    }
+
+    if let (Some(items), Some(item_schema)) = (
+        value.as_array_mut(),
+        schema.get("items").filter(|i| i.is_object()),
+    ) {
+        for item in items {
+            fill_schema_defaults(item, item_schema, root, depth + 1)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Get the `default` of a schema, following a local `$ref` if the schema has none
+fn schema_default(
+    schema: &serde_json::Value,
+    root: &serde_json::Value,
+) -> Result<Option<serde_json::Value>, Error> {
+    if let Some(default) = schema.get("default") {
+        return Ok(Some(default.clone()));
+    }
+    match schema.get("$ref").and_then(|r| r.as_str()) {
+        Some(reference) => Ok(resolve_schema_ref(root, reference)?.get("default").cloned()),
+        None => Ok(None),
+    }
+}
+
+/// Resolve a local `$ref` (e.g. "#/$defs/port") against the root schema
+fn resolve_schema_ref<'a>(
+    root: &'a serde_json::Value,
+    reference: &str,
+) -> Result<&'a serde_json::Value, Error> {
+    reference
+        .strip_prefix('#')
+        .and_then(|pointer| root.pointer(pointer))
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!(
+                    "apply_schema_defaults: cannot resolve $ref '{}' (only local references are supported)",
+                    reference
+                ),
+            )
+        })
+}
+
 /// Create new object with only specified keys
 pub struct ObjectPick;
 
@@ -782,3 +1206,498 @@ fn set_nested_value_json(
 
     Ok(())
 }
+
+/// Apply a named filter to every value of an object
+pub struct ObjectMapValues;
+
+impl StateFunction for ObjectMapValues {
+    const NAME: &'static str = "object_map_values";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "object_map_values",
+        category: "object",
+        description: "Apply a named filter to every value of an object",
+        arguments: &[
+            ArgumentMetadata {
+                name: "object",
+                arg_type: "object",
+                required: true,
+                default: None,
+                description: "Source object",
+            },
+            ArgumentMetadata {
+                name: "filter",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Name of a registered filter (e.g., \"upper\", \"trim\")",
+            },
+        ],
+        return_type: "object",
+        examples: &["{{ object_map_values(object=config, filter=\"upper\") }}"],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(state: &State, kwargs: Kwargs) -> Result<Value, Error> {
+        let object: Value = kwargs.get("object")?;
+        let filter: String = kwargs.get("filter")?;
+
+        let map = object_entries(&object, "object_map_values")?;
+
+        let mut result = Map::new();
+        for (key, value) in map {
+            let mapped = apply_named_filter(
+                state,
+                &filter,
+                Value::from_serialize(&value),
+                "object_map_values",
+            )?;
+            let mapped: serde_json::Value = serde_json::to_value(&mapped).map_err(|e| {
+                Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!("Failed to convert filter result: {}", e),
+                )
+            })?;
+            result.insert(key, mapped);
+        }
+
+        Ok(Value::from_serialize(serde_json::Value::Object(result)))
+    }
+}
+
+/// Apply a named filter to every key of an object
+pub struct ObjectMapKeys;
+
+impl StateFunction for ObjectMapKeys {
+    const NAME: &'static str = "object_map_keys";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "object_map_keys",
+        category: "object",
+        description: "Apply a named filter to every key of an object (later keys win on collision)",
+        arguments: &[
+            ArgumentMetadata {
+                name: "object",
+                arg_type: "object",
+                required: true,
+                default: None,
+                description: "Source object",
+            },
+            ArgumentMetadata {
+                name: "filter",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Name of a registered filter (e.g., \"to_snake_case\")",
+            },
+        ],
+        return_type: "object",
+        examples: &["{{ object_map_keys(object=response, filter=\"to_snake_case\") }}"],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(state: &State, kwargs: Kwargs) -> Result<Value, Error> {
+        let object: Value = kwargs.get("object")?;
+        let filter: String = kwargs.get("filter")?;
+
+        let map = object_entries(&object, "object_map_keys")?;
+
+        let mut result = Map::new();
+        for (key, value) in map {
+            let mapped = apply_named_filter(state, &filter, Value::from(key), "object_map_keys")?;
+            let new_key = match mapped.as_str() {
+                Some(s) => s.to_string(),
+                None => mapped.to_string(),
+            };
+            result.insert(new_key, value);
+        }
+
+        Ok(Value::from_serialize(serde_json::Value::Object(result)))
+    }
+}
+
+/// Filter object entries by comparison operator
+pub struct ObjectFilter;
+
+impl Function for ObjectFilter {
+    const NAME: &'static str = "object_filter";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "object_filter",
+        category: "object",
+        description: "Keep only object entries whose key or value satisfies a comparison",
+        arguments: &[
+            ArgumentMetadata {
+                name: "object",
+                arg_type: "object",
+                required: true,
+                default: None,
+                description: "Source object",
+            },
+            ArgumentMetadata {
+                name: "op",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Operator: eq, ne, gt, lt, gte, lte, contains",
+            },
+            ArgumentMetadata {
+                name: "value",
+                arg_type: "any",
+                required: true,
+                default: None,
+                description: "Value to compare against",
+            },
+            ArgumentMetadata {
+                name: "by",
+                arg_type: "string",
+                required: false,
+                default: Some("value"),
+                description: "What to compare: \"key\" or \"value\"",
+            },
+        ],
+        return_type: "object",
+        examples: &[
+            "{{ object_filter(object=config, op=\"ne\", value=\"\") }}",
+            "{{ object_filter(object=env_vars, op=\"contains\", value=\"DB_\", by=\"key\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let object: Value = kwargs.get("object")?;
+        let op: String = kwargs.get("op")?;
+        let value: Value = kwargs.get("value")?;
+        let by: Option<String> = kwargs.get("by")?;
+        let by = by.unwrap_or_else(|| "value".to_string());
+
+        let by_key = match by.as_str() {
+            "key" => true,
+            "value" => false,
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!("Invalid by '{}'. Use: key, value", by),
+                ));
+            }
+        };
+
+        let compare_value: serde_json::Value = serde_json::to_value(&value).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("Failed to convert value: {}", e),
+            )
+        })?;
+
+        let map = object_entries(&object, "object_filter")?;
+
+        let mut result = Map::new();
+        for (key, item_val) in map {
+            let matches = if by_key {
+                compare_by_op(&serde_json::Value::from(key.as_str()), &op, &compare_value)?
+            } else {
+                compare_by_op(&item_val, &op, &compare_value)?
+            };
+
+            if matches {
+                result.insert(key, item_val);
+            }
+        }
+
+        Ok(Value::from_serialize(serde_json::Value::Object(result)))
+    }
+}
+
+/// Swap object keys and values
+pub struct ObjectInvert;
+
+impl Function for ObjectInvert {
+    const NAME: &'static str = "object_invert";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "object_invert",
+        category: "object",
+        description: "Swap object keys and values (non-string values become their JSON string form)",
+        arguments: &[
+            ArgumentMetadata {
+                name: "object",
+                arg_type: "object",
+                required: true,
+                default: None,
+                description: "Source object",
+            },
+            ArgumentMetadata {
+                name: "on_duplicate",
+                arg_type: "string",
+                required: false,
+                default: Some("error"),
+                description: "Handling of duplicate resulting keys: \"error\", \"first\", or \"last\"",
+            },
+        ],
+        return_type: "object",
+        examples: &[
+            "{{ object_invert(object={\"a\": \"x\", \"b\": \"y\"}) }}",
+            "{{ object_invert(object=mapping, on_duplicate=\"last\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let object: Value = kwargs.get("object")?;
+        let on_duplicate: Option<String> = kwargs.get("on_duplicate")?;
+        let on_duplicate = on_duplicate.unwrap_or_else(|| "error".to_string());
+
+        if !matches!(on_duplicate.as_str(), "error" | "first" | "last") {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                format!(
+                    "Invalid on_duplicate '{}'. Use: error, first, last",
+                    on_duplicate
+                ),
+            ));
+        }
+
+        let map = object_entries(&object, "object_invert")?;
+
+        let mut result = Map::new();
+        for (key, value) in map {
+            let new_key = match value {
+                serde_json::Value::String(s) => s,
+                other => other.to_string(),
+            };
+
+            if result.contains_key(&new_key) {
+                match on_duplicate.as_str() {
+                    "first" => continue,
+                    "last" => {}
+                    _ => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidOperation,
+                            format!(
+                                "object_invert: duplicate key '{}' (use on_duplicate=\"first\" or \"last\")",
+                                new_key
+                            ),
+                        ));
+                    }
+                }
+            }
+
+            result.insert(new_key, serde_json::Value::String(key));
+        }
+
+        Ok(Value::from_serialize(serde_json::Value::Object(result)))
+    }
+}
+
+/// Reverse lookup: find the first key whose value equals a given value
+pub struct ObjectKeyOf;
+
+impl Function for ObjectKeyOf {
+    const NAME: &'static str = "object_key_of";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "object_key_of",
+        category: "object",
+        description: "Get the first key whose value equals the given value, or null if none does",
+        arguments: &[
+            ArgumentMetadata {
+                name: "object",
+                arg_type: "object",
+                required: true,
+                default: None,
+                description: "Object to search",
+            },
+            ArgumentMetadata {
+                name: "value",
+                arg_type: "any",
+                required: true,
+                default: None,
+                description: "Value to look for",
+            },
+        ],
+        return_type: "string|null",
+        examples: &[
+            "{{ object_key_of(object={\"dev\": 8080, \"prod\": 80}, value=80) }}",
+            "{{ default(value=object_key_of(object=status_codes, value=code), default=\"UNKNOWN\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let object: Value = kwargs.get("object")?;
+        let value: Value = kwargs.get("value")?;
+
+        if object.kind() != ValueKind::Map {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                "object_key_of requires an object",
+            ));
+        }
+
+        // Compare template values directly so 80 and "80" stay distinct
+        for key in object.try_iter()? {
+            if object.get_item(&key)? == value {
+                return Ok(key);
+            }
+        }
+
+        Ok(Value::from(()))
+    }
+}
+
+/// Build an object from parallel key and value arrays, or from keyword arguments
+///
+/// Replaces MiniJinja's builtin `dict`, keeping its behavior for every call
+/// except `dict(keys=[...], values=[...])`, which zips the two arrays.
+pub struct Dict;
+
+impl Dict {
+    fn build(base: Option<Value>, kwargs: Kwargs) -> Result<Value, Error> {
+        let mut entries: Vec<(Value, Value)> = Vec::new();
+        if let Some(base) = base {
+            if base.kind() != ValueKind::Map {
+                return Err(Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!(
+                        "dict: positional argument must be an object, found: {}",
+                        base
+                    ),
+                ));
+            }
+            for key in base.try_iter()? {
+                let value = base.get_item(&key)?;
+                entries.push((key, value));
+            }
+        }
+
+        let names: Vec<&str> = kwargs.args().collect();
+        if names.len() == 2 && names.contains(&"keys") && names.contains(&"values") {
+            let keys: Vec<Value> = kwargs.get("keys")?;
+            let values: Vec<Value> = kwargs.get("values")?;
+            if keys.len() != values.len() {
+                return Err(Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!(
+                        "dict: keys and values must have the same length ({} keys, {} values)",
+                        keys.len(),
+                        values.len()
+                    ),
+                ));
+            }
+            entries.extend(keys.into_iter().zip(values));
+        } else {
+            for name in names {
+                entries.push((Value::from(name), kwargs.get::<Value>(name)?));
+            }
+        }
+
+        // Later entries win, so kwargs override keys of the positional object
+        Ok(Value::from_iter(entries))
+    }
+}
+
+impl Function for Dict {
+    const NAME: &'static str = "dict";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "dict",
+        category: "object",
+        description: "Build an object from parallel keys/values arrays (keys may be computed), or from keyword arguments",
+        arguments: &[
+            ArgumentMetadata {
+                name: "keys",
+                arg_type: "array",
+                required: false,
+                default: None,
+                description: "Keys of the new object (used together with values)",
+            },
+            ArgumentMetadata {
+                name: "values",
+                arg_type: "array",
+                required: false,
+                default: None,
+                description: "Values of the new object, in the same order as keys",
+            },
+        ],
+        return_type: "object",
+        examples: &[
+            "{{ dict(keys=[\"a\", \"b\"], values=[1, 2]) }}",
+            "{{ dict(keys=services | map(attribute=\"name\"), values=services | map(attribute=\"port\")) }}",
+            "{{ dict(name=\"web\", port=80) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        Self::build(None, kwargs)
+    }
+
+    /// Also accept the builtin's optional positional object, e.g. `dict(base, port=81)`
+    fn register(env: &mut Environment) {
+        env.add_function(Self::NAME, Self::build);
+    }
+}
+
+/// Build an object from keyword arguments
+pub struct ObjectFromKwargs;
+
+impl Function for ObjectFromKwargs {
+    const NAME: &'static str = "object";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "object",
+        category: "object",
+        description: "Build an object from keyword arguments; each argument becomes an entry",
+        arguments: &[ArgumentMetadata {
+            name: "<key>",
+            arg_type: "any",
+            required: false,
+            default: None,
+            description: "Any keyword argument, e.g. name=\"web\" becomes the entry \"name\": \"web\"",
+        }],
+        return_type: "object",
+        examples: &[
+            "{{ object(name=\"web\", port=80) }}",
+            "{% set labels = object(app=name, version=version) %}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let mut entries = Vec::new();
+        for name in kwargs.args() {
+            entries.push((name, kwargs.get::<Value>(name)?));
+        }
+        Ok(Value::from_iter(entries))
+    }
+}
+
+/// Apply a registered filter by name, reporting unknown filter names clearly
+fn apply_named_filter(
+    state: &State,
+    filter: &str,
+    value: Value,
+    fn_name: &str,
+) -> Result<Value, Error> {
+    state.apply_filter(filter, &[value]).map_err(|e| {
+        if e.kind() == ErrorKind::UnknownFilter {
+            Error::new(
+                ErrorKind::UnknownFilter,
+                format!("{}: unknown filter '{}'", fn_name, filter),
+            )
+        } else {
+            e
+        }
+    })
+}
+
+/// Convert a value into a JSON map, failing if it is not an object
+fn object_entries(object: &Value, fn_name: &str) -> Result<Map<String, serde_json::Value>, Error> {
+    let json_object: serde_json::Value = serde_json::to_value(object).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidOperation,
+            format!("Failed to convert object: {}", e),
+        )
+    })?;
+
+    match json_object {
+        serde_json::Value::Object(map) => Ok(map),
+        _ => Err(Error::new(
+            ErrorKind::InvalidOperation,
+            format!("{} requires an object", fn_name),
+        )),
+    }
+}
@@ -2,7 +2,9 @@
 //!
 //! This module provides predicate functions for checking conditions:
 //! - Array predicates: `array_any`, `array_all`, `array_contains`
-//! - String predicates: `starts_with`, `ends_with`
+//!
+//! The string predicates `starts_with` and `ends_with` live in
+//! is_functions/string.rs so they can also be used as is-tests.
 
 use super::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
 use super::traits::Function;
@@ -172,83 +174,3 @@ impl Function for ArrayContains {
         Ok(Value::from(false))
     }
 }
-
-/// Check if string starts with a prefix
-pub struct StartsWith;
-
-impl Function for StartsWith {
-    const NAME: &'static str = "starts_with";
-    const METADATA: FunctionMetadata = FunctionMetadata {
-        name: "starts_with",
-        category: "predicate",
-        description: "Check if string starts with a prefix",
-        arguments: &[
-            ArgumentMetadata {
-                name: "string",
-                arg_type: "string",
-                required: true,
-                default: None,
-                description: "The string to check",
-            },
-            ArgumentMetadata {
-                name: "prefix",
-                arg_type: "string",
-                required: true,
-                default: None,
-                description: "The prefix to look for",
-            },
-        ],
-        return_type: "boolean",
-        examples: &[
-            "{% if starts_with(string=\"Hello World\", prefix=\"Hello\") %}Starts with Hello!{% endif %}",
-        ],
-        syntax: SyntaxVariants::FUNCTION_ONLY,
-    };
-
-    fn call(kwargs: Kwargs) -> Result<Value, Error> {
-        let string: String = kwargs.get("string")?;
-        let prefix: String = kwargs.get("prefix")?;
-
-        Ok(Value::from(string.starts_with(&prefix)))
-    }
-}
-
-/// Check if string ends with a suffix
-pub struct EndsWith;
-
-impl Function for EndsWith {
-    const NAME: &'static str = "ends_with";
-    const METADATA: FunctionMetadata = FunctionMetadata {
-        name: "ends_with",
-        category: "predicate",
-        description: "Check if string ends with a suffix",
-        arguments: &[
-            ArgumentMetadata {
-                name: "string",
-                arg_type: "string",
-                required: true,
-                default: None,
-                description: "The string to check",
-            },
-            ArgumentMetadata {
-                name: "suffix",
-                arg_type: "string",
-                required: true,
-                default: None,
-                description: "The suffix to look for",
-            },
-        ],
-        return_type: "boolean",
-        examples: &[
-            "{% if ends_with(string=\"readme.txt\", suffix=\".txt\") %}Text file!{% endif %}",
-        ],
-        syntax: SyntaxVariants::FUNCTION_ONLY,
-    };
-
-    fn call(kwargs: Kwargs) -> Result<Value, Error> {
-        let string: String = kwargs.get("string")?;
-        let suffix: String = kwargs.get("suffix")?;
-
-        Ok(Value::from(string.ends_with(&suffix)))
-    }
-}
@@ -0,0 +1,125 @@
+//! QR code functions for MiniJinja templates
+//!
+//! This module provides:
+//! - `qr_svg`: Render data as an inline SVG QR code
+
+use super::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
+use super::traits::Function;
+use minijinja::value::Kwargs;
+use minijinja::{Error, ErrorKind, Value};
+use qrcode::render::svg;
+use qrcode::{EcLevel, QrCode};
+
+/// Parse an error correction level name
+fn parse_ec_level(level: &str) -> Result<EcLevel, Error> {
+    match level.to_uppercase().as_str() {
+        "L" => Ok(EcLevel::L),
+        "M" => Ok(EcLevel::M),
+        "Q" => Ok(EcLevel::Q),
+        "H" => Ok(EcLevel::H),
+        _ => Err(Error::new(
+            ErrorKind::InvalidOperation,
+            format!(
+                "qr_svg: invalid error_correction '{}'. Use: L, M, Q, H",
+                level
+            ),
+        )),
+    }
+}
+
+/// Render data as an inline SVG QR code
+pub struct QrSvg;
+
+impl Function for QrSvg {
+    const NAME: &'static str = "qr_svg";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "qr_svg",
+        category: "qr",
+        description: "Render a string as an inline SVG QR code",
+        arguments: &[
+            ArgumentMetadata {
+                name: "string",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Data to encode (e.g., a URL)",
+            },
+            ArgumentMetadata {
+                name: "size",
+                arg_type: "integer",
+                required: false,
+                default: Some("200"),
+                description: "Minimum width and height of the SVG in pixels",
+            },
+            ArgumentMetadata {
+                name: "error_correction",
+                arg_type: "string",
+                required: false,
+                default: Some("M"),
+                description: "Error correction level: \"L\", \"M\", \"Q\", or \"H\"",
+            },
+            ArgumentMetadata {
+                name: "dark",
+                arg_type: "string",
+                required: false,
+                default: Some("#000000"),
+                description: "Color of the dark modules",
+            },
+            ArgumentMetadata {
+                name: "light",
+                arg_type: "string",
+                required: false,
+                default: Some("#ffffff"),
+                description: "Background color",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ qr_svg(string=\"https://example.com\") }}",
+            "{{ qr_svg(string=service_url, size=128, error_correction=\"H\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let string: String = kwargs.get("string")?;
+        let size: Option<u32> = kwargs.get("size")?;
+        let error_correction: Option<String> = kwargs.get("error_correction")?;
+        let dark: Option<String> = kwargs.get("dark")?;
+        let light: Option<String> = kwargs.get("light")?;
+
+        let ec_level = parse_ec_level(error_correction.as_deref().unwrap_or("M"))?;
+        let size = size.unwrap_or(200);
+        if size == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                "qr_svg: size must be greater than 0",
+            ));
+        }
+        let dark = dark.unwrap_or_else(|| "#000000".to_string());
+        let light = light.unwrap_or_else(|| "#ffffff".to_string());
+
+        let code =
+            QrCode::with_error_correction_level(string.as_bytes(), ec_level).map_err(|e| {
+                Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!("qr_svg: failed to encode data: {}", e),
+                )
+            })?;
+
+        let image = code
+            .render::<svg::Color>()
+            .min_dimensions(size, size)
+            .dark_color(svg::Color(&dark))
+            .light_color(svg::Color(&light))
+            .build();
+
+        // Drop the XML declaration so the SVG can be embedded in HTML/Markdown
+        let image = match image.find("<svg") {
+            Some(start) => image[start..].to_string(),
+            None => image,
+        };
+
+        Ok(Value::from(image))
+    }
+}
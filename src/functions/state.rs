@@ -0,0 +1,173 @@
+//! Render-scoped state functions for MiniJinja templates
+//!
+//! This module provides functions that keep state across a single render:
+//! - `counter`: Monotonically increasing integer per named counter
+//! - `set_var` / `get_var`: Key-value scratchpad shared across blocks and includes
+//! - `once`: True only the first time a key is seen, for deduplicating output
+//!
+//! State lives on the `TemplateContext`, so it is shared by included
+//! templates and macros but starts fresh for every render invocation.
+
+use super::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
+use super::traits::ContextFunction;
+use crate::TemplateContext;
+use minijinja::value::Kwargs;
+use minijinja::{Error, Value};
+use std::sync::Arc;
+
+/// Return the next value of a named counter
+pub struct Counter;
+
+impl ContextFunction for Counter {
+    const NAME: &'static str = "counter";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "counter",
+        category: "state",
+        description: "Return the next value of a named counter (increments on every call, scoped to one render)",
+        arguments: &[
+            ArgumentMetadata {
+                name: "name",
+                arg_type: "string",
+                required: false,
+                default: Some("default"),
+                description: "Counter name; each name counts independently",
+            },
+            ArgumentMetadata {
+                name: "start",
+                arg_type: "integer",
+                required: false,
+                default: Some("1"),
+                description: "Value returned by the first call for this counter",
+            },
+        ],
+        return_type: "integer",
+        examples: &[
+            "{% for item in items %}{{ counter() }}. {{ item }}{% endfor %}",
+            "Rule {{ counter(name=\"rules\", start=100) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(context: Arc<TemplateContext>, kwargs: Kwargs) -> Result<Value, Error> {
+        let name: Option<String> = kwargs.get("name")?;
+        let start: Option<i64> = kwargs.get("start")?;
+
+        let value = context.next_counter(name.as_deref().unwrap_or("default"), start.unwrap_or(1));
+        Ok(Value::from(value))
+    }
+}
+
+/// Store a value in the render-scoped scratchpad
+pub struct SetVar;
+
+impl ContextFunction for SetVar {
+    const NAME: &'static str = "set_var";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "set_var",
+        category: "state",
+        description: "Store a value under a key for the rest of the render (visible across blocks, includes and macros)",
+        arguments: &[
+            ArgumentMetadata {
+                name: "key",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Key to store the value under",
+            },
+            ArgumentMetadata {
+                name: "value",
+                arg_type: "any",
+                required: true,
+                default: None,
+                description: "Value to store (replaces any previous value)",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ set_var(key=\"title\", value=\"Report\") }}",
+            "{{ set_var(key=\"imports\", value=get_var(key=\"imports\", default=[]) + [\"os\"]) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(context: Arc<TemplateContext>, kwargs: Kwargs) -> Result<Value, Error> {
+        let key: String = kwargs.get("key")?;
+        let value: Value = kwargs.get("value")?;
+
+        context.set_var(&key, value);
+
+        // Empty string so `{{ set_var(...) }}` leaves no trace in the output
+        Ok(Value::from(""))
+    }
+}
+
+/// Read a value from the render-scoped scratchpad
+pub struct GetVar;
+
+impl ContextFunction for GetVar {
+    const NAME: &'static str = "get_var";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "get_var",
+        category: "state",
+        description: "Get a value stored with set_var during this render",
+        arguments: &[
+            ArgumentMetadata {
+                name: "key",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Key to look up",
+            },
+            ArgumentMetadata {
+                name: "default",
+                arg_type: "any",
+                required: false,
+                default: None,
+                description: "Value returned when the key was never set (undefined if omitted)",
+            },
+        ],
+        return_type: "any",
+        examples: &[
+            "{{ get_var(key=\"title\") }}",
+            "{% for module in get_var(key=\"imports\", default=[]) | unique %}import {{ module }}{% endfor %}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(context: Arc<TemplateContext>, kwargs: Kwargs) -> Result<Value, Error> {
+        let key: String = kwargs.get("key")?;
+        let default: Option<Value> = kwargs.get("default")?;
+
+        Ok(context
+            .get_var(&key)
+            .or(default)
+            .unwrap_or(Value::UNDEFINED))
+    }
+}
+
+/// Return true only the first time a key is seen during the render
+pub struct Once;
+
+impl ContextFunction for Once {
+    const NAME: &'static str = "once";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "once",
+        category: "state",
+        description: "Return true the first time a key is seen during this render and false afterwards",
+        arguments: &[ArgumentMetadata {
+            name: "key",
+            arg_type: "string",
+            required: true,
+            default: None,
+            description: "Key identifying the fragment to emit only once",
+        }],
+        return_type: "boolean",
+        examples: &["{% if once(key=\"helpers\") %}{% include \"helpers.tmpl\" %}{% endif %}"],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(context: Arc<TemplateContext>, kwargs: Kwargs) -> Result<Value, Error> {
+        let key: String = kwargs.get("key")?;
+        Ok(Value::from(context.mark_once(&key)))
+    }
+}
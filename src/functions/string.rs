@@ -2,20 +2,26 @@
 //!
 //! This module provides utility functions for working with strings:
 //! - Regex operations: `regex_match`, `regex_find_all`
-//! - String searching: `contains`, `index_of`, `count_occurrences`
+//! - String searching: `index_of`, `count_occurrences`
 //! - Text transformation: `sentence_case`, `to_constant_case`
 //! - Pluralization: `pluralize`
+//! - Formatting: `format`, `printf`
+//! - Provenance banners: `generated_header`
 //!
 //! Note: regex_replace, substring, truncate, word_count, split_lines, wrap,
 //! center, strip_html, strip_ansi, normalize_whitespace, slugify, indent, dedent,
 //! quote, escape_quotes, and case conversion functions are now in
 //! filter_functions/string.rs with dual function+filter syntax support.
+//! `contains` lives in is_functions/logic.rs and also accepts arrays and objects.
 
 use super::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
 use super::traits::Function;
+use crate::filter_functions::string::Comment;
+use chrono::Utc;
 use minijinja::value::Kwargs;
 use minijinja::{Error, ErrorKind, Value};
 use regex::Regex;
+use std::fmt::Write;
 
 /// Check if string matches regex pattern
 pub struct RegexMatch;
@@ -112,44 +118,6 @@ impl Function for RegexFindAll {
     }
 }
 
-/// Check if string contains substring
-pub struct Contains;
-
-impl Function for Contains {
-    const NAME: &'static str = "contains";
-    const METADATA: FunctionMetadata = FunctionMetadata {
-        name: "contains",
-        category: "string",
-        description: "Check if string contains substring",
-        arguments: &[
-            ArgumentMetadata {
-                name: "string",
-                arg_type: "string",
-                required: true,
-                default: None,
-                description: "The input string",
-            },
-            ArgumentMetadata {
-                name: "substring",
-                arg_type: "string",
-                required: true,
-                default: None,
-                description: "Substring to search for",
-            },
-        ],
-        return_type: "boolean",
-        examples: &["{{ contains(string=\"hello world\", substring=\"world\") }}"],
-        syntax: SyntaxVariants::FUNCTION_ONLY,
-    };
-
-    fn call(kwargs: Kwargs) -> Result<Value, Error> {
-        let string: String = kwargs.get("string")?;
-        let substring: String = kwargs.get("substring")?;
-
-        Ok(Value::from(string.contains(&substring)))
-    }
-}
-
 /// Find position of substring
 pub struct IndexOf;
 
@@ -390,3 +358,494 @@ impl Function for Pluralize {
         Ok(Value::from(result))
     }
 }
+
+/// Format a string with positional `{}` / `{N}` placeholders
+pub struct Format;
+
+impl Format {
+    /// Substitute placeholders in `template` with `args`
+    fn substitute(template: &str, args: &[Value]) -> Result<String, Error> {
+        let mut result = String::with_capacity(template.len());
+        let mut chars = template.chars().peekable();
+        let mut next_index = 0;
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    result.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    result.push('}');
+                }
+                '{' => {
+                    let mut spec = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => spec.push(c),
+                            None => {
+                                return Err(Error::new(
+                                    ErrorKind::InvalidOperation,
+                                    "format: unclosed '{' in template (use '{{' for a literal brace)",
+                                ));
+                            }
+                        }
+                    }
+
+                    let index = if spec.is_empty() {
+                        next_index += 1;
+                        next_index - 1
+                    } else {
+                        spec.trim().parse::<usize>().map_err(|_| {
+                            Error::new(
+                                ErrorKind::InvalidOperation,
+                                format!(
+                                    "format: invalid placeholder '{{{}}}', expected '{{}}' or '{{N}}'",
+                                    spec
+                                ),
+                            )
+                        })?
+                    };
+
+                    let arg = args.get(index).ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::InvalidOperation,
+                            format!(
+                                "format: placeholder {} is out of range ({} args given)",
+                                index,
+                                args.len()
+                            ),
+                        )
+                    })?;
+                    match arg.as_str() {
+                        Some(s) => result.push_str(s),
+                        None => result.push_str(&arg.to_string()),
+                    }
+                }
+                '}' => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidOperation,
+                        "format: unmatched '}' in template (use '}}' for a literal brace)",
+                    ));
+                }
+                c => result.push(c),
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl Function for Format {
+    const NAME: &'static str = "format";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "format",
+        category: "string",
+        description: "Substitute positional {} and {N} placeholders in a string ({{ and }} are literal braces)",
+        arguments: &[
+            ArgumentMetadata {
+                name: "template",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Format string with {} or {0}, {1}, ... placeholders",
+            },
+            ArgumentMetadata {
+                name: "args",
+                arg_type: "array",
+                required: false,
+                default: Some("[]"),
+                description: "Values to substitute",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ format(template=\"{}-{}-{}\", args=[app, env, region]) }}",
+            "{{ format(template=\"{1}/{0}\", args=[\"name\", \"namespace\"]) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let template: String = kwargs.get("template")?;
+        let args: Option<Vec<Value>> = kwargs.get("args")?;
+
+        Ok(Value::from(Self::substitute(
+            &template,
+            &args.unwrap_or_default(),
+        )?))
+    }
+}
+
+/// Format a single value with a C-style printf directive
+pub struct Printf;
+
+/// Largest width or precision accepted in a printf directive
+const PRINTF_MAX_FIELD: usize = 1024;
+
+/// A parsed `%[flags][width][.precision]conversion` directive
+#[derive(Debug, Default)]
+struct PrintfSpec {
+    left_align: bool,
+    plus_sign: bool,
+    space_sign: bool,
+    zero_pad: bool,
+    alternate: bool,
+    width: usize,
+    precision: Option<usize>,
+    conversion: char,
+}
+
+impl Printf {
+    /// Split the format into prefix, directive and suffix
+    fn parse(format: &str) -> Result<(String, PrintfSpec, String), Error> {
+        let mut prefix = String::new();
+        let mut suffix = String::new();
+        let mut spec: Option<PrintfSpec> = None;
+        let mut chars = format.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            let target = if spec.is_some() {
+                &mut suffix
+            } else {
+                &mut prefix
+            };
+            if c != '%' {
+                target.push(c);
+                continue;
+            }
+            if chars.peek() == Some(&'%') {
+                chars.next();
+                target.push('%');
+                continue;
+            }
+            if spec.is_some() {
+                return Err(Error::new(
+                    ErrorKind::InvalidOperation,
+                    "printf: format must contain exactly one directive (use %% for a literal %)",
+                ));
+            }
+
+            let mut parsed = PrintfSpec::default();
+            while let Some(&flag) = chars.peek() {
+                match flag {
+                    '-' => parsed.left_align = true,
+                    '+' => parsed.plus_sign = true,
+                    ' ' => parsed.space_sign = true,
+                    '0' => parsed.zero_pad = true,
+                    '#' => parsed.alternate = true,
+                    _ => break,
+                }
+                chars.next();
+            }
+            parsed.width = Self::parse_field(&mut chars, "width")?;
+            if chars.peek() == Some(&'.') {
+                chars.next();
+                parsed.precision = Some(Self::parse_field(&mut chars, "precision")?);
+            }
+            parsed.conversion = match chars.next() {
+                Some(
+                    c @ ('d' | 'i' | 'u' | 'f' | 'F' | 'e' | 'E' | 'x' | 'X' | 'o' | 'b' | 's'),
+                ) => c,
+                Some(c) => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidOperation,
+                        format!(
+                            "printf: unsupported directive '%{}'. Use: d, i, u, f, F, e, E, x, X, o, b, s",
+                            c
+                        ),
+                    ));
+                }
+                None => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidOperation,
+                        "printf: incomplete directive at end of format",
+                    ));
+                }
+            };
+            spec = Some(parsed);
+        }
+
+        let spec = spec.ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                "printf: format must contain a directive such as %d, %.2f or %x",
+            )
+        })?;
+
+        Ok((prefix, spec, suffix))
+    }
+
+    /// Read the digits of a width or precision, rejecting values above the limit
+    fn parse_field(
+        chars: &mut std::iter::Peekable<std::str::Chars<'_>>,
+        what: &str,
+    ) -> Result<usize, Error> {
+        let mut value: usize = 0;
+        while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+            value = value
+                .checked_mul(10)
+                .and_then(|v| v.checked_add(digit as usize))
+                .filter(|v| *v <= PRINTF_MAX_FIELD)
+                .ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::InvalidOperation,
+                        format!("printf: {} must be at most {}", what, PRINTF_MAX_FIELD),
+                    )
+                })?;
+            chars.next();
+        }
+        Ok(value)
+    }
+
+    /// Convert the value to an integer for integer directives
+    fn to_integer(value: &Value, conversion: char) -> Result<i64, Error> {
+        let number = if value.is_integer() {
+            value.as_i64()
+        } else {
+            f64::try_from(value.clone())
+                .ok()
+                .or_else(|| value.as_str().and_then(|s| s.trim().parse::<f64>().ok()))
+                .filter(|f| f.is_finite())
+                .map(|f| f.trunc() as i64)
+        };
+        number.ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!(
+                    "printf: %{} requires a number, found: {}",
+                    conversion, value
+                ),
+            )
+        })
+    }
+
+    /// Convert the value to a float for float directives
+    fn to_float(value: &Value, conversion: char) -> Result<f64, Error> {
+        f64::try_from(value.clone())
+            .ok()
+            .or_else(|| value.as_str().and_then(|s| s.trim().parse::<f64>().ok()))
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!(
+                        "printf: %{} requires a number, found: {}",
+                        conversion, value
+                    ),
+                )
+            })
+    }
+
+    /// Format C-style scientific notation (exponent with sign and at least 2 digits)
+    fn scientific(number: f64, precision: usize, upper: bool) -> String {
+        let formatted = format!("{:.*e}", precision, number);
+        let (mantissa, exponent) = formatted.split_once('e').unwrap_or((&formatted, "0"));
+        let exponent: i32 = exponent.parse().unwrap_or(0);
+        let sign = if exponent < 0 { '-' } else { '+' };
+        let result = format!("{}e{}{:02}", mantissa, sign, exponent.abs());
+        if upper { result.to_uppercase() } else { result }
+    }
+
+    /// Render the directive for the given value
+    fn render(spec: &PrintfSpec, value: &Value) -> Result<String, Error> {
+        let conversion = spec.conversion;
+
+        // (negative, prefix such as 0x, digits)
+        let (negative, radix_prefix, digits) = match conversion {
+            's' => {
+                let text = match value.as_str() {
+                    Some(s) => s.to_string(),
+                    None => value.to_string(),
+                };
+                let text: String = match spec.precision {
+                    Some(p) => text.chars().take(p).collect(),
+                    None => text,
+                };
+                return Ok(Self::pad(spec, "", "", &text, false));
+            }
+            'd' | 'i' | 'u' => {
+                let n = Self::to_integer(value, conversion)?;
+                if conversion == 'u' && n < 0 {
+                    return Err(Error::new(
+                        ErrorKind::InvalidOperation,
+                        format!("printf: %u requires a non-negative number, found: {}", n),
+                    ));
+                }
+                (n < 0, "", n.unsigned_abs().to_string())
+            }
+            'x' | 'X' | 'o' | 'b' => {
+                let n = Self::to_integer(value, conversion)?;
+                if n < 0 {
+                    return Err(Error::new(
+                        ErrorKind::InvalidOperation,
+                        format!(
+                            "printf: %{} requires a non-negative number, found: {}",
+                            conversion, n
+                        ),
+                    ));
+                }
+                let (digits, prefix) = match conversion {
+                    'x' => (format!("{:x}", n), "0x"),
+                    'X' => (format!("{:X}", n), "0X"),
+                    // C's alternate octal form is a single leading zero
+                    'o' if n == 0 => (format!("{:o}", n), ""),
+                    'o' => (format!("{:o}", n), "0"),
+                    _ => (format!("{:b}", n), "0b"),
+                };
+                (false, if spec.alternate { prefix } else { "" }, digits)
+            }
+            _ => {
+                let f = Self::to_float(value, conversion)?;
+                let precision = spec.precision.unwrap_or(6);
+                let digits = match conversion {
+                    'e' => Self::scientific(f.abs(), precision, false),
+                    'E' => Self::scientific(f.abs(), precision, true),
+                    _ => format!("{:.*}", precision, f.abs()),
+                };
+                (f.is_sign_negative() && f != 0.0, "", digits)
+            }
+        };
+
+        let sign = if negative {
+            "-"
+        } else if spec.plus_sign {
+            "+"
+        } else if spec.space_sign {
+            " "
+        } else {
+            ""
+        };
+
+        Ok(Self::pad(spec, sign, radix_prefix, &digits, true))
+    }
+
+    /// Apply width padding (zeros go between sign/prefix and digits)
+    fn pad(spec: &PrintfSpec, sign: &str, prefix: &str, body: &str, numeric: bool) -> String {
+        let len = sign.len() + prefix.len() + body.chars().count();
+        let fill = spec.width.saturating_sub(len);
+
+        if spec.left_align {
+            format!("{}{}{}{}", sign, prefix, body, " ".repeat(fill))
+        } else if spec.zero_pad && numeric {
+            format!("{}{}{}{}", sign, prefix, "0".repeat(fill), body)
+        } else {
+            format!("{}{}{}{}", " ".repeat(fill), sign, prefix, body)
+        }
+    }
+}
+
+impl Function for Printf {
+    const NAME: &'static str = "printf";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "printf",
+        category: "string",
+        description: "Format a value with a C-style directive (%d, %05.2f, %x, %o, %e, ...)",
+        arguments: &[
+            ArgumentMetadata {
+                name: "format",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Format string containing exactly one directive (%% for a literal %)",
+            },
+            ArgumentMetadata {
+                name: "value",
+                arg_type: "any",
+                required: true,
+                default: None,
+                description: "Value to format",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ printf(format=\"%05.2f\", value=3.14159) }}",
+            "{{ printf(format=\"0x%08X\", value=48879) }}",
+            "{{ printf(format=\"%-10s|\", value=\"name\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let format: String = kwargs.get("format")?;
+        let value: Value = kwargs.get("value")?;
+
+        let (prefix, spec, suffix) = Self::parse(&format)?;
+        let rendered = Self::render(&spec, &value)?;
+
+        Ok(Value::from(format!("{}{}{}", prefix, rendered, suffix)))
+    }
+}
+
+/// Build a "DO NOT EDIT" provenance banner in a given comment style
+pub struct GeneratedHeader;
+
+impl GeneratedHeader {
+    /// Default timestamp format, matching `now(format=...)` conventions
+    const DEFAULT_FORMAT: &'static str = "%Y-%m-%d %H:%M:%S UTC";
+}
+
+impl Function for GeneratedHeader {
+    const NAME: &'static str = "generated_header";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "generated_header",
+        category: "string",
+        description: "Build a \"DO NOT EDIT\" banner naming the source template and generation time, as a comment",
+        arguments: &[
+            ArgumentMetadata {
+                name: "source",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Name of the file the output was generated from",
+            },
+            ArgumentMetadata {
+                name: "style",
+                arg_type: "string",
+                required: false,
+                default: Some("#"),
+                description: "Comment syntax: \"//\", \"#\", \"/* */\", or \"<!-- -->\"",
+            },
+            ArgumentMetadata {
+                name: "format",
+                arg_type: "string",
+                required: false,
+                default: Some("%Y-%m-%d %H:%M:%S UTC"),
+                description: "Timestamp format (UTC, same syntax as now(format=...))",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ generated_header(source=\"config.yaml.tmpl\") }}",
+            "{{ generated_header(source=\"schema.sql.tmpl\", style=\"/* */\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let source: String = kwargs.get("source")?;
+        let style: Option<String> = kwargs.get("style")?;
+        let format: Option<String> = kwargs.get("format")?;
+
+        let format = format.as_deref().unwrap_or(Self::DEFAULT_FORMAT);
+        let mut timestamp = String::new();
+        // Invalid specifiers surface as a fmt error rather than a panic
+        write!(timestamp, "{}", Utc::now().format(format)).map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("generated_header: invalid timestamp format '{}'", format),
+            )
+        })?;
+        let banner = format!(
+            "DO NOT EDIT \u{2014} generated from {} at {}",
+            source, timestamp
+        );
+
+        Ok(Value::from(Comment::compute(
+            &banner,
+            style.as_deref().unwrap_or("#"),
+            None,
+        )?))
+    }
+}
@@ -8,12 +8,27 @@
 //! - `get_os`: Operating system name
 //! - `get_arch`: CPU architecture
 //! - `get_cwd`: Current working directory
+//! - `system_info`: Machine facts (hostname, os, arch, cpus), trust mode only
 
-use super::metadata::{FunctionMetadata, SyntaxVariants};
-use super::traits::Function;
+use super::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
+use super::traits::{ContextFunction, Function};
+use crate::TemplateContext;
 use minijinja::value::Kwargs;
 use minijinja::{Error, ErrorKind, Value};
+use std::collections::BTreeMap;
 use std::env;
+use std::sync::Arc;
+
+/// Read the system hostname
+fn read_hostname() -> Result<String, Error> {
+    let hostname = hostname::get().map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidOperation,
+            format!("Failed to get hostname: {}", e),
+        )
+    })?;
+    Ok(hostname.to_string_lossy().to_string())
+}
 
 /// Get the system hostname
 pub struct GetHostname;
@@ -31,17 +46,7 @@ impl Function for GetHostname {
     };
 
     fn call(_kwargs: Kwargs) -> Result<Value, Error> {
-        let hostname = hostname::get()
-            .map_err(|e| {
-                Error::new(
-                    ErrorKind::InvalidOperation,
-                    format!("Failed to get hostname: {}", e),
-                )
-            })?
-            .to_string_lossy()
-            .to_string();
-
-        Ok(Value::from(hostname))
+        Ok(Value::from(read_hostname()?))
     }
 }
 
@@ -184,3 +189,78 @@ impl Function for GetCwd {
         Ok(Value::from(cwd.to_string_lossy().to_string()))
     }
 }
+
+/// Fields supported by `system_info`
+const SYSTEM_INFO_FIELDS: [&str; 4] = ["hostname", "os", "arch", "cpus"];
+
+/// Read a single machine fact
+fn system_info_field(field: &str) -> Result<Value, Error> {
+    match field {
+        "hostname" => read_hostname().map(Value::from),
+        "os" => Ok(Value::from(env::consts::OS)),
+        "arch" => Ok(Value::from(env::consts::ARCH)),
+        "cpus" => std::thread::available_parallelism()
+            .map(|n| Value::from(n.get()))
+            .map_err(|e| {
+                Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!("Failed to get CPU count: {}", e),
+                )
+            }),
+        _ => Err(Error::new(
+            ErrorKind::InvalidOperation,
+            format!(
+                "system_info: invalid field '{}'. Use: {}",
+                field,
+                SYSTEM_INFO_FIELDS.join(", ")
+            ),
+        )),
+    }
+}
+
+/// Get basic machine facts (requires trust mode)
+pub struct SystemInfo;
+
+impl ContextFunction for SystemInfo {
+    const NAME: &'static str = "system_info";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "system_info",
+        category: "system",
+        description: "Get machine facts: hostname, os, arch, or cpus (requires trust mode)",
+        arguments: &[ArgumentMetadata {
+            name: "field",
+            arg_type: "string",
+            required: false,
+            default: None,
+            description: "Field to read: \"hostname\", \"os\", \"arch\", or \"cpus\" (omit for an object with all fields)",
+        }],
+        return_type: "string|integer|object",
+        examples: &[
+            "workers: {{ system_info(field=\"cpus\") * 2 }}",
+            "{% set info = system_info() %}{{ info.hostname }} ({{ info.os }}/{{ info.arch }})",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(context: Arc<TemplateContext>, kwargs: Kwargs) -> Result<Value, Error> {
+        // Security check: machine facts are only exposed in trust mode
+        if !context.is_trust_mode() {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                "Security: system_info() function requires trust mode. Use --trust flag to enable it.",
+            ));
+        }
+
+        let field: Option<String> = kwargs.get("field")?;
+        match field {
+            Some(field) => system_info_field(&field),
+            None => {
+                let mut info = BTreeMap::new();
+                for field in SYSTEM_INFO_FIELDS {
+                    info.insert(field, system_info_field(field)?);
+                }
+                Ok(Value::from_serialize(&info))
+            }
+        }
+    }
+}
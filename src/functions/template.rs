@@ -0,0 +1,112 @@
+//! Template introspection functions for MiniJinja templates
+//!
+//! This module provides functions for templates that pull in optional parts:
+//! - `template_exists`: Check whether a template can be loaded
+//! - `has_macro`: Check whether a template defines a macro
+//!
+//! Names are resolved like `{% include %}` and `{% import %}`: partials
+//! preloaded with `--partials-dir`, then files relative to the template and
+//! in the `--include-path` directories. A missing template is not an error.
+
+use super::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
+use super::traits::StateFunction;
+use minijinja::value::Kwargs;
+use minijinja::{Error, ErrorKind, State, Template, Value};
+
+/// Whether a value is a macro
+///
+/// Macros are map-like objects with no public type; they render as "<macro name>".
+pub(crate) fn is_macro(value: &Value) -> bool {
+    value.as_object().is_some() && value.to_string().starts_with("<macro ")
+}
+
+/// Load a template by name, or `None` when no such template exists
+///
+/// Other errors, such as syntax errors or paths rejected by the security
+/// checks, are returned as is.
+fn load<'env>(state: &State<'_, 'env>, name: &str) -> Result<Option<Template<'env, 'env>>, Error> {
+    match state.env().get_template(name) {
+        Ok(tmpl) => Ok(Some(tmpl)),
+        Err(e) if e.kind() == ErrorKind::TemplateNotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Check whether a template can be loaded
+pub struct TemplateExists;
+
+impl StateFunction for TemplateExists {
+    const NAME: &'static str = "template_exists";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "template_exists",
+        category: "template",
+        description: "Check whether a template can be included or imported by name",
+        arguments: &[ArgumentMetadata {
+            name: "name",
+            arg_type: "string",
+            required: true,
+            default: None,
+            description: "Template name, as used with {% include %}",
+        }],
+        return_type: "boolean",
+        examples: &[
+            "{% if template_exists(name=\"partials/\" ~ env_name ~ \".tmpltool\") %}{% include \"partials/\" ~ env_name ~ \".tmpltool\" %}{% endif %}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(state: &State, kwargs: Kwargs) -> Result<Value, Error> {
+        let name: String = kwargs.get("name")?;
+        Ok(Value::from(load(state, &name)?.is_some()))
+    }
+}
+
+/// Check whether a template defines a macro
+pub struct HasMacro;
+
+impl StateFunction for HasMacro {
+    const NAME: &'static str = "has_macro";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "has_macro",
+        category: "template",
+        description: "Check whether a template defines a macro with the given name",
+        arguments: &[
+            ArgumentMetadata {
+                name: "template",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Template name, as used with {% import %}",
+            },
+            ArgumentMetadata {
+                name: "name",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "Macro name",
+            },
+        ],
+        return_type: "boolean",
+        examples: &[
+            "{% if has_macro(template=\"helpers.tmpltool\", name=\"banner\") %}{% from \"helpers.tmpltool\" import banner %}{{ banner() }}{% endif %}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(state: &State, kwargs: Kwargs) -> Result<Value, Error> {
+        let template: String = kwargs.get("template")?;
+        let name: String = kwargs.get("name")?;
+
+        let Some(tmpl) = load(state, &template)? else {
+            return Ok(Value::from(false));
+        };
+        // Evaluated like {% import %}: top-level code runs without the caller's variables
+        let module = tmpl.render_captured(())?;
+        Ok(Value::from(
+            module
+                .state()
+                .lookup(&name)
+                .is_some_and(|value| is_macro(&value)),
+        ))
+    }
+}
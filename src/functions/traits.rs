@@ -7,6 +7,8 @@
 //!
 //! - `Function` - Simple functions that don't need context (e.g., `get_env`, `uuid`)
 //! - `ContextFunction` - Functions that need `TemplateContext` for filesystem access
+//! - `StateFunction` - Functions that need the MiniJinja `State` (e.g., to apply filters by name)
+//! - `ContextStateFunction` - Functions that need both (e.g., to render a file as a template)
 //!
 //! # Example
 //!
@@ -40,7 +42,7 @@
 use super::metadata::FunctionMetadata;
 use crate::TemplateContext;
 use minijinja::value::Kwargs;
-use minijinja::{Environment, Error, Value};
+use minijinja::{Environment, Error, State, Value};
 use std::sync::Arc;
 
 /// Trait for simple functions that don't require context.
@@ -126,3 +128,81 @@ pub trait ContextFunction: 'static {
         });
     }
 }
+
+/// Trait for functions that require the MiniJinja `State` of the running template.
+///
+/// Use this for functions that:
+/// - Need to apply registered filters or tests by name
+/// - Need to look up variables of the current render
+///
+/// # Example
+///
+/// ```rust,ignore
+/// pub struct ObjectMapValues;
+///
+/// impl StateFunction for ObjectMapValues {
+///     const NAME: &'static str = "object_map_values";
+///     const METADATA: FunctionMetadata = FunctionMetadata { ... };
+///
+///     fn call(state: &State, kwargs: Kwargs) -> Result<Value, Error> {
+///         let filter: String = kwargs.get("filter")?;
+///         state.apply_filter(&filter, &[Value::from("hello")])
+///     }
+/// }
+/// ```
+pub trait StateFunction: 'static {
+    /// Function name used for registration.
+    const NAME: &'static str;
+
+    /// Metadata describing this function (required for IDE integration).
+    const METADATA: FunctionMetadata;
+
+    /// Handle function calls with access to the template state.
+    fn call(state: &State, kwargs: Kwargs) -> Result<Value, Error>;
+
+    /// Register this function with the MiniJinja environment.
+    fn register(env: &mut Environment) {
+        env.add_function(Self::NAME, Self::call);
+    }
+}
+
+/// Trait for functions that require both `TemplateContext` and the MiniJinja `State`.
+///
+/// Use this for functions that:
+/// - Read files under the security rules and render them with the running environment
+///
+/// # Example
+///
+/// ```rust,ignore
+/// pub struct IncludeIfExists;
+///
+/// impl ContextStateFunction for IncludeIfExists {
+///     const NAME: &'static str = "include_if_exists";
+///     const METADATA: FunctionMetadata = FunctionMetadata { ... };
+///
+///     fn call(context: Arc<TemplateContext>, state: &State, kwargs: Kwargs) -> Result<Value, Error> {
+///         let path: String = kwargs.get("path")?;
+///         let source = std::fs::read_to_string(context.resolve_path(&path)).unwrap_or_default();
+///         state.env().render_str(&source, ()).map(Value::from)
+///     }
+/// }
+/// ```
+pub trait ContextStateFunction: 'static {
+    /// Function name used for registration.
+    const NAME: &'static str;
+
+    /// Metadata describing this function (required for IDE integration).
+    const METADATA: FunctionMetadata;
+
+    /// Handle function calls with context and template state access.
+    fn call(context: Arc<TemplateContext>, state: &State, kwargs: Kwargs) -> Result<Value, Error>;
+
+    /// Register this function with the MiniJinja environment.
+    ///
+    /// Creates a closure that captures the context for use in the function.
+    fn register(env: &mut Environment, context: Arc<TemplateContext>) {
+        env.add_function(Self::NAME, move |state: &State, kwargs: Kwargs| {
+            Self::call(context.clone(), state, kwargs)
+        });
+    }
+}
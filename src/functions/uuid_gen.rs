@@ -2,11 +2,13 @@
 //!
 //! This module provides UUID generation with configurable versions:
 //! - `uuid`: Generate UUID v4 (random) or v7 (time-ordered)
+//! - `stable_id`: Derive a deterministic UUID v5 or short hash from inputs
 
 use super::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
 use super::traits::Function;
 use minijinja::value::Kwargs;
 use minijinja::{Error, ErrorKind, Value};
+use sha2::{Digest, Sha256};
 use uuid::Uuid;
 
 /// Generate UUID with configurable version
@@ -56,4 +58,111 @@ impl Function for UuidGen {
     }
 }
 
+/// Derive a deterministic identifier from a list of inputs
+pub struct StableId;
+
+impl StableId {
+    /// Separator placed between inputs so ["a", "bc"] and ["ab", "c"] differ
+    const SEPARATOR: char = '\u{1f}';
+
+    /// Resolve the namespace: a UUID string is used as-is, anything else is
+    /// hashed into a UUID under the URL namespace
+    fn namespace_uuid(namespace: &str) -> Uuid {
+        Uuid::parse_str(namespace)
+            .unwrap_or_else(|_| Uuid::new_v5(&Uuid::NAMESPACE_URL, namespace.as_bytes()))
+    }
+
+    /// Join inputs into the name that gets hashed
+    fn join_inputs(inputs: &[Value]) -> String {
+        inputs
+            .iter()
+            .map(|v| match v.as_str() {
+                Some(s) => s.to_string(),
+                None => v.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(&Self::SEPARATOR.to_string())
+    }
+}
+
+impl Function for StableId {
+    const NAME: &'static str = "stable_id";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "stable_id",
+        category: "random",
+        description: "Derive a deterministic ID (UUID v5 or short hash) from inputs, identical across runs",
+        arguments: &[
+            ArgumentMetadata {
+                name: "inputs",
+                arg_type: "array",
+                required: true,
+                default: None,
+                description: "Values that identify the resource",
+            },
+            ArgumentMetadata {
+                name: "namespace",
+                arg_type: "string",
+                required: false,
+                default: Some("tmpltool"),
+                description: "Namespace UUID or any string scoping the IDs",
+            },
+            ArgumentMetadata {
+                name: "format",
+                arg_type: "string",
+                required: false,
+                default: Some("uuid"),
+                description: "Output format: uuid (UUID v5) or short (hex hash prefix)",
+            },
+            ArgumentMetadata {
+                name: "length",
+                arg_type: "integer",
+                required: false,
+                default: Some("8"),
+                description: "Number of hex characters for format=short (1-64)",
+            },
+        ],
+        return_type: "string",
+        examples: &[
+            "{{ stable_id(inputs=[app, env]) }}",
+            "{{ stable_id(inputs=[app, env, region], namespace=\"deployments\") }}",
+            "{{ stable_id(inputs=[app, env], format=\"short\", length=10) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let inputs: Vec<Value> = kwargs.get("inputs")?;
+        let namespace: Option<String> = kwargs.get("namespace")?;
+        let format: Option<String> = kwargs.get("format")?;
+        let length: Option<usize> = kwargs.get("length")?;
+
+        let namespace = Self::namespace_uuid(namespace.as_deref().unwrap_or("tmpltool"));
+        let name = Self::join_inputs(&inputs);
+
+        match format.as_deref().unwrap_or("uuid") {
+            "uuid" => Ok(Value::from(
+                Uuid::new_v5(&namespace, name.as_bytes()).to_string(),
+            )),
+            "short" => {
+                let length = length.unwrap_or(8);
+                if !(1..=64).contains(&length) {
+                    return Err(Error::new(
+                        ErrorKind::InvalidOperation,
+                        format!("stable_id length must be between 1 and 64, got {}", length),
+                    ));
+                }
+                let mut hasher = Sha256::new();
+                hasher.update(namespace.as_bytes());
+                hasher.update(name.as_bytes());
+                let digest = hex::encode(hasher.finalize());
+                Ok(Value::from(&digest[..length]))
+            }
+            other => Err(Error::new(
+                ErrorKind::InvalidOperation,
+                format!("Invalid stable_id format '{}'. Use: uuid, short", other),
+            )),
+        }
+    }
+}
+
 // Legacy function export for backward compatibility during migration
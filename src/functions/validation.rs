@@ -7,7 +7,11 @@
 //! - `is_ip` / `{% if x is ip %}`
 //! - `is_uuid` / `{% if x is uuid %}`
 //!
-//! This module contains `matches_regex` which has a different pattern (takes 2 args).
+//! This module contains `matches_regex` which has a different pattern (takes 2 args),
+//! and check digit helpers for identifiers:
+//! - `luhn` / `luhn_valid`: Luhn (mod 10) check digits, e.g. card or account numbers
+//! - `isbn_valid`: ISBN-10 and ISBN-13
+//! - `ean_valid`: EAN-8, UPC-A, EAN-13 and GTIN-14
 
 use super::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
 use super::traits::Function;
@@ -64,3 +68,216 @@ impl Function for MatchesRegex {
 }
 
 // Legacy function export for backward compatibility during migration
+
+/// Metadata for the `number` argument of the check digit functions
+const NUMBER_ARG: ArgumentMetadata = ArgumentMetadata {
+    name: "number",
+    arg_type: "string|integer",
+    required: true,
+    default: None,
+    description: "Identifier digits (spaces and hyphens are ignored)",
+};
+
+/// Read the `number` argument as a string with spaces and hyphens removed
+fn get_number(kwargs: &Kwargs) -> Result<String, Error> {
+    let number: Value = kwargs.get("number")?;
+    let number = match number.as_str() {
+        Some(s) => s.to_string(),
+        None => number.to_string(),
+    };
+    Ok(number
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect())
+}
+
+/// Convert a string of ASCII digits to their values
+fn to_digits(number: &str) -> Option<Vec<u32>> {
+    if number.is_empty() {
+        return None;
+    }
+    number.chars().map(|c| c.to_digit(10)).collect()
+}
+
+/// Compute the Luhn check digit for a payload (without check digit)
+fn luhn_check_digit(payload: &[u32]) -> u32 {
+    // Double every second digit starting from the rightmost payload digit
+    let sum: u32 = payload
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| {
+            if i % 2 == 0 {
+                let doubled = d * 2;
+                if doubled > 9 { doubled - 9 } else { doubled }
+            } else {
+                d
+            }
+        })
+        .sum();
+    (10 - sum % 10) % 10
+}
+
+/// Compute the GS1 (EAN/UPC) check digit for a payload (without check digit)
+fn gs1_check_digit(payload: &[u32]) -> u32 {
+    // Weights alternate 3, 1, 3, ... starting from the rightmost payload digit
+    let sum: u32 = payload
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| if i % 2 == 0 { d * 3 } else { d })
+        .sum();
+    (10 - sum % 10) % 10
+}
+
+/// Check whether the last digit is a valid GS1 check digit
+fn is_valid_gs1(digits: &[u32]) -> bool {
+    match digits.split_last() {
+        Some((&check, payload)) => gs1_check_digit(payload) == check,
+        None => false,
+    }
+}
+
+/// Check whether a string is a valid ISBN-10 (last character may be 'X')
+fn is_valid_isbn10(number: &str) -> bool {
+    let chars: Vec<char> = number.chars().collect();
+    if chars.len() != 10 {
+        return false;
+    }
+    let mut sum = 0;
+    for (i, c) in chars.iter().enumerate() {
+        let value = match c {
+            'X' | 'x' if i == 9 => 10,
+            _ => match c.to_digit(10) {
+                Some(d) => d,
+                None => return false,
+            },
+        };
+        sum += value * (10 - i as u32);
+    }
+    sum % 11 == 0
+}
+
+/// Compute the Luhn check digit for a number
+pub struct Luhn;
+
+impl Function for Luhn {
+    const NAME: &'static str = "luhn";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "luhn",
+        category: "validation",
+        description: "Compute the Luhn (mod 10) check digit for a number",
+        arguments: &[NUMBER_ARG],
+        return_type: "integer",
+        examples: &[
+            "{{ luhn(number=\"7992739871\") }}",
+            "{{ account_id }}{{ luhn(number=account_id) }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let number = get_number(&kwargs)?;
+        let digits = to_digits(&number).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("luhn: '{}' is not a number", number),
+            )
+        })?;
+
+        Ok(Value::from(luhn_check_digit(&digits)))
+    }
+}
+
+/// Validate a number whose last digit is a Luhn check digit
+pub struct LuhnValid;
+
+impl Function for LuhnValid {
+    const NAME: &'static str = "luhn_valid";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "luhn_valid",
+        category: "validation",
+        description: "Check if the last digit of a number is a valid Luhn check digit",
+        arguments: &[NUMBER_ARG],
+        return_type: "boolean",
+        examples: &[
+            "{{ luhn_valid(number=\"79927398713\") }}",
+            "{% if not luhn_valid(number=card) %}invalid{% endif %}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let number = get_number(&kwargs)?;
+        let valid = match to_digits(&number) {
+            Some(digits) if digits.len() >= 2 => {
+                let (&check, payload) = digits.split_last().unwrap();
+                luhn_check_digit(payload) == check
+            }
+            _ => false,
+        };
+
+        Ok(Value::from(valid))
+    }
+}
+
+/// Validate an ISBN-10 or ISBN-13
+pub struct IsbnValid;
+
+impl Function for IsbnValid {
+    const NAME: &'static str = "isbn_valid";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "isbn_valid",
+        category: "validation",
+        description: "Check if a string is a valid ISBN-10 or ISBN-13",
+        arguments: &[NUMBER_ARG],
+        return_type: "boolean",
+        examples: &[
+            "{{ isbn_valid(number=\"978-0-306-40615-7\") }}",
+            "{{ isbn_valid(number=\"0-8044-2957-X\") }}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let number = get_number(&kwargs)?;
+        let valid = match number.len() {
+            10 => is_valid_isbn10(&number),
+            13 if number.starts_with("978") || number.starts_with("979") => {
+                to_digits(&number).is_some_and(|d| is_valid_gs1(&d))
+            }
+            _ => false,
+        };
+
+        Ok(Value::from(valid))
+    }
+}
+
+/// Validate an EAN/UPC/GTIN barcode number
+pub struct EanValid;
+
+impl Function for EanValid {
+    const NAME: &'static str = "ean_valid";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "ean_valid",
+        category: "validation",
+        description: "Check if a number is a valid EAN-8, UPC-A, EAN-13 or GTIN-14 barcode",
+        arguments: &[NUMBER_ARG],
+        return_type: "boolean",
+        examples: &[
+            "{{ ean_valid(number=\"4006381333931\") }}",
+            "{% if ean_valid(number=product.ean) %}{{ product.ean }}{% endif %}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_ONLY,
+    };
+
+    fn call(kwargs: Kwargs) -> Result<Value, Error> {
+        let number = get_number(&kwargs)?;
+        let valid = match to_digits(&number) {
+            Some(digits) if matches!(digits.len(), 8 | 12 | 13 | 14) => is_valid_gs1(&digits),
+            _ => false,
+        };
+
+        Ok(Value::from(valid))
+    }
+}
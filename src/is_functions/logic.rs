@@ -0,0 +1,492 @@
+//! Logic is-functions for tmpltool
+//!
+//! This module provides value checks that work with both syntaxes:
+//! - Function syntax: `{{ is_empty(value=items) }}`
+//! - Is-test syntax: `{% if items is empty %}`
+//!
+//! # Available Logic Functions
+//!
+//! - `is_empty` / `empty` - Check if a value is undefined, null, or has no elements
+//! - `is_defined` / `defined` - Check if a value was supplied (null counts as supplied)
+//! - `is_undefined` / `undefined` - Check if a value was not supplied at all
+//! - `contains` / `contains(needle)` - Check substring, array membership, or object key
+//! - `between` / `between(min, max)` - Check if a number falls within a range
+//!
+//! # Example Usage
+//!
+//! ```jinja
+//! {# Function syntax #}
+//! {% if is_empty(value=servers) %}no servers{% endif %}
+//!
+//! {# Is-test syntax (preferred for readability) #}
+//! {% if servers is empty %}no servers{% endif %}
+//! ```
+
+use crate::functions::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
+use crate::is_functions::IsFunction;
+use minijinja::value::{Kwargs, ValueKind};
+use minijinja::{Environment, Error, ErrorKind, Value};
+
+/// Emptiness check is-function
+///
+/// A value is empty when it is:
+/// - undefined or null
+/// - an empty string
+/// - an empty array
+/// - an empty object
+///
+/// Numbers and booleans are never empty (`0` and `false` are values).
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ is_empty(value="") }}
+/// {% if is_empty(value=config.servers) %}...{% endif %}
+/// ```
+///
+/// # Is-Test Syntax
+/// ```jinja
+/// {% if config.servers is empty %}no servers{% endif %}
+/// {% if name is not empty %}Hello {{ name }}{% endif %}
+/// ```
+pub struct Empty;
+
+impl Empty {
+    /// Check if a value is empty
+    pub fn check(value: &Value) -> bool {
+        match value.kind() {
+            ValueKind::Undefined | ValueKind::None => true,
+            ValueKind::String | ValueKind::Bytes | ValueKind::Seq | ValueKind::Map => {
+                value.len() == Some(0)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl IsFunction for Empty {
+    const FUNCTION_NAME: &'static str = "is_empty";
+    const IS_NAME: &'static str = "empty";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "is_empty",
+        category: "logic",
+        description: "Check if a value is undefined, null, an empty string, an empty array, or an empty object",
+        arguments: &[ArgumentMetadata {
+            name: "value",
+            arg_type: "any",
+            required: true,
+            default: None,
+            description: "The value to check",
+        }],
+        return_type: "boolean",
+        examples: &[
+            "{{ is_empty(value=\"\") }}",
+            "{% if servers is empty %}no servers{% endif %}",
+            "{% if name is not empty %}Hello {{ name }}{% endif %}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_TEST,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let value: Value = kwargs.get("value")?;
+        Ok(Value::from(Self::check(&value)))
+    }
+
+    fn call_as_is(value: &Value) -> bool {
+        Self::check(value)
+    }
+}
+
+/// Definedness check is-function
+///
+/// A value is defined when it was supplied at all, even if it is `null` or
+/// empty. This distinguishes a missing config key from one set to `null`.
+/// The check behaves the same in strict and lenient undefined modes.
+///
+/// The `defined` test is MiniJinja's own and is not replaced.
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ is_defined(value=config.port) }}
+/// ```
+///
+/// # Is-Test Syntax
+/// ```jinja
+/// {% if config.port is defined %}port: {{ config.port }}{% endif %}
+/// ```
+pub struct Defined;
+
+impl IsFunction for Defined {
+    const FUNCTION_NAME: &'static str = "is_defined";
+    const IS_NAME: &'static str = "defined";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "is_defined",
+        category: "logic",
+        description: "Check if a value was supplied (null and empty values count as defined)",
+        arguments: &[ArgumentMetadata {
+            name: "value",
+            arg_type: "any",
+            required: true,
+            default: None,
+            description: "The value to check",
+        }],
+        return_type: "boolean",
+        examples: &[
+            "{{ is_defined(value=config.port) }}",
+            "{% if config.port is defined %}port: {{ config.port }}{% endif %}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_TEST,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let value: Value = kwargs.get("value")?;
+        Ok(Value::from(!value.is_undefined()))
+    }
+
+    fn call_as_is(value: &Value) -> bool {
+        !value.is_undefined()
+    }
+
+    /// Only the function is added; `defined` stays MiniJinja's built-in test
+    fn register(env: &mut Environment) {
+        env.add_function(Self::FUNCTION_NAME, Self::call_as_function);
+    }
+}
+
+/// Undefinedness check is-function
+///
+/// The inverse of [`Defined`]: true only when the value was not supplied.
+/// A value explicitly set to `null` is not undefined.
+///
+/// The `undefined` test is MiniJinja's own and is not replaced.
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ is_undefined(value=config.port) }}
+/// ```
+///
+/// # Is-Test Syntax
+/// ```jinja
+/// {% if config.port is undefined %}port: 8080{% endif %}
+/// ```
+pub struct Undefined;
+
+impl IsFunction for Undefined {
+    const FUNCTION_NAME: &'static str = "is_undefined";
+    const IS_NAME: &'static str = "undefined";
+    const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "is_undefined",
+        category: "logic",
+        description: "Check if a value was not supplied at all (null counts as supplied)",
+        arguments: &[ArgumentMetadata {
+            name: "value",
+            arg_type: "any",
+            required: true,
+            default: None,
+            description: "The value to check",
+        }],
+        return_type: "boolean",
+        examples: &[
+            "{{ is_undefined(value=config.port) }}",
+            "{% if config.port is undefined %}port: 8080{% endif %}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_TEST,
+    };
+
+    fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let value: Value = kwargs.get("value")?;
+        Ok(Value::from(value.is_undefined()))
+    }
+
+    fn call_as_is(value: &Value) -> bool {
+        value.is_undefined()
+    }
+
+    /// Only the function is added; `undefined` stays MiniJinja's built-in test
+    fn register(env: &mut Environment) {
+        env.add_function(Self::FUNCTION_NAME, Self::call_as_function);
+    }
+}
+
+/// Unified membership check
+///
+/// Works uniformly across container types:
+/// - strings: substring search
+/// - arrays: element membership
+/// - objects: key presence
+///
+/// Unlike the other is-functions this test takes an argument, so it is
+/// registered directly rather than through [`IsFunction`].
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ contains(container="hello world", value="world") }}
+/// {{ contains(container=["a", "b"], value="a") }}
+/// {{ contains(container={"port": 80}, value="port") }}
+/// ```
+///
+/// # Is-Test Syntax
+/// ```jinja
+/// {% if roles is contains("admin") %}...{% endif %}
+/// {% if config is contains "port" %}...{% endif %}
+/// ```
+pub struct Contains;
+
+impl Contains {
+    pub const FUNCTION_NAME: &'static str = "contains";
+    pub const IS_NAME: &'static str = "contains";
+    pub const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "contains",
+        category: "logic",
+        description: "Check if a string contains a substring, an array contains an element, or an object contains a key",
+        arguments: &[
+            ArgumentMetadata {
+                name: "container",
+                arg_type: "string|array|object",
+                required: true,
+                default: None,
+                description: "The string, array, or object to search (alias: string)",
+            },
+            ArgumentMetadata {
+                name: "value",
+                arg_type: "any",
+                required: true,
+                default: None,
+                description: "Substring, element, or key to look for (alias: substring)",
+            },
+        ],
+        return_type: "boolean",
+        examples: &[
+            "{{ contains(container=\"hello world\", value=\"world\") }}",
+            "{{ contains(container=[1, 2, 3], value=2) }}",
+            "{% if config is contains(\"port\") %}has port{% endif %}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_TEST,
+    };
+
+    /// Check membership, returning `None` when the container type is not supported
+    pub fn check(container: &Value, value: &Value) -> Option<bool> {
+        match container.kind() {
+            ValueKind::String => {
+                let haystack = container.as_str()?;
+                let needle = value.as_str()?;
+                Some(haystack.contains(needle))
+            }
+            ValueKind::Seq => Some(container.try_iter().ok()?.any(|item| &item == value)),
+            ValueKind::Map => Some(container.try_iter().ok()?.any(|key| &key == value)),
+            _ => None,
+        }
+    }
+
+    /// Handle function-style calls: `contains(container=..., value=...)`
+    pub fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        // `string`/`substring` are accepted for compatibility with the
+        // original string-only version of this function
+        let container: Value = if kwargs.has("string") {
+            kwargs.get("string")?
+        } else {
+            kwargs.get("container")?
+        };
+        let value: Value = if kwargs.has("substring") {
+            kwargs.get("substring")?
+        } else {
+            kwargs.get("value")?
+        };
+
+        match container.kind() {
+            ValueKind::String if value.as_str().is_none() => Err(Error::new(
+                ErrorKind::InvalidOperation,
+                format!(
+                    "contains: value must be a string when searching a string, got {}",
+                    value.kind()
+                ),
+            )),
+            _ => Self::check(&container, &value)
+                .map(Value::from)
+                .ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::InvalidOperation,
+                        format!(
+                            "contains: container must be a string, array, or object, got {}",
+                            container.kind()
+                        ),
+                    )
+                }),
+        }
+    }
+
+    /// Handle is-style calls: `{% if container is contains(value) %}`
+    pub fn call_as_is(container: &Value, value: &Value) -> bool {
+        Self::check(container, value).unwrap_or(false)
+    }
+
+    /// Register `contains` as both a function and a test
+    pub fn register(env: &mut Environment) {
+        env.add_function(Self::FUNCTION_NAME, Self::call_as_function);
+        env.add_test(Self::IS_NAME, Self::call_as_is);
+    }
+}
+
+/// Numeric range check
+///
+/// Bounds are inclusive by default; `inclusive=false` excludes both bounds.
+/// Like the other tests that take arguments, this one is registered directly
+/// rather than through [`IsFunction`].
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ between(value=5, min=1, max=10) }}
+/// {{ between(value=10, min=1, max=10, inclusive=false) }}
+/// ```
+///
+/// # Is-Test Syntax
+/// ```jinja
+/// {% if replicas is between(min=1, max=10) %}...{% endif %}
+/// {% if port is between(1024, 65535) %}...{% endif %}
+/// ```
+pub struct Between;
+
+impl Between {
+    pub const FUNCTION_NAME: &'static str = "between";
+    pub const IS_NAME: &'static str = "between";
+    pub const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "between",
+        category: "logic",
+        description: "Check if a number falls within a range (bounds included unless inclusive=false)",
+        arguments: &[
+            ArgumentMetadata {
+                name: "value",
+                arg_type: "number",
+                required: true,
+                default: None,
+                description: "The number to check",
+            },
+            ArgumentMetadata {
+                name: "min",
+                arg_type: "number",
+                required: true,
+                default: None,
+                description: "Lower bound",
+            },
+            ArgumentMetadata {
+                name: "max",
+                arg_type: "number",
+                required: true,
+                default: None,
+                description: "Upper bound",
+            },
+            ArgumentMetadata {
+                name: "inclusive",
+                arg_type: "boolean",
+                required: false,
+                default: Some("true"),
+                description: "Whether the bounds themselves are in the range",
+            },
+        ],
+        return_type: "boolean",
+        examples: &[
+            "{{ between(value=cpu, min=0, max=80) }}",
+            "{% if replicas is between(min=1, max=10) %}ok{% endif %}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_TEST,
+    };
+
+    /// Read a number, returning `None` for non-numeric values
+    fn number(value: &Value) -> Option<f64> {
+        if value.kind() == ValueKind::Number {
+            f64::try_from(value.clone()).ok()
+        } else {
+            None
+        }
+    }
+
+    /// Read a required numeric bound
+    fn bound(value: Option<Value>, name: &str) -> Result<f64, Error> {
+        let value = value.ok_or_else(|| {
+            Error::new(
+                ErrorKind::MissingArgument,
+                format!("between: missing argument '{}'", name),
+            )
+        })?;
+        Self::number(&value).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("between: {} must be a number, found: {}", name, value),
+            )
+        })
+    }
+
+    /// Check `value` against the bounds, validating that the range is not inverted
+    pub fn check(value: f64, min: f64, max: f64, inclusive: bool) -> Result<bool, Error> {
+        if min > max {
+            return Err(Error::new(
+                ErrorKind::InvalidOperation,
+                format!(
+                    "between: min ({}) must not be greater than max ({})",
+                    min, max
+                ),
+            ));
+        }
+        Ok(if inclusive {
+            min <= value && value <= max
+        } else {
+            min < value && value < max
+        })
+    }
+
+    /// Handle function-style calls: `between(value=..., min=..., max=...)`
+    pub fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let value: Value = kwargs.get("value")?;
+        let value = Self::number(&value).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("between: value must be a number, found: {}", value),
+            )
+        })?;
+        let min = Self::bound(kwargs.get("min")?, "min")?;
+        let max = Self::bound(kwargs.get("max")?, "max")?;
+        let inclusive: Option<bool> = kwargs.get("inclusive")?;
+
+        Self::check(value, min, max, inclusive.unwrap_or(true)).map(Value::from)
+    }
+
+    /// Handle is-style calls: `{% if value is between(min, max) %}`
+    ///
+    /// Bounds may be given positionally or as `min=` / `max=`. Non-numeric
+    /// values never match.
+    pub fn call_as_is(
+        value: &Value,
+        min: Option<Value>,
+        max: Option<Value>,
+        kwargs: Kwargs,
+    ) -> Result<bool, Error> {
+        let min = Self::bound(
+            min.map_or_else(|| kwargs.get("min"), |v| Ok(Some(v)))?,
+            "min",
+        )?;
+        let max = Self::bound(
+            max.map_or_else(|| kwargs.get("max"), |v| Ok(Some(v)))?,
+            "max",
+        )?;
+        let inclusive: Option<bool> = kwargs.get("inclusive")?;
+
+        match Self::number(value) {
+            Some(value) => Self::check(value, min, max, inclusive.unwrap_or(true)),
+            None => Ok(false),
+        }
+    }
+
+    /// Register `between` as both a function and a test
+    pub fn register(env: &mut Environment) {
+        env.add_function(Self::FUNCTION_NAME, Self::call_as_function);
+        env.add_test(Self::IS_NAME, Self::call_as_is);
+    }
+}
+
+/// Register all logic is-functions with the MiniJinja environment
+pub fn register_all(env: &mut Environment) {
+    Empty::register(env);
+    Defined::register(env);
+    Undefined::register(env);
+    Contains::register(env);
+    Between::register(env);
+}
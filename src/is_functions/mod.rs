@@ -18,6 +18,17 @@
 //! ## DateTime
 //! - `is_leap_year` / `{% if year is leap_year %}` - Check if year is a leap year
 //!
+//! ## Logic
+//! - `is_empty` / `{% if x is empty %}` - Check if value is undefined, null, or empty
+//! - `is_defined` / `{% if x is defined %}` - Check if value was supplied
+//! - `is_undefined` / `{% if x is undefined %}` - Check if value was not supplied
+//! - `contains` / `{% if x is contains(y) %}` - Substring, array element, or object key
+//! - `between` / `{% if x is between(1, 10) %}` - Number within a range
+//!
+//! ## Predicate
+//! - `starts_with` / `{% if x is starts_with("v") %}` - Check string prefix
+//! - `ends_with` / `{% if x is ends_with(".yaml") %}` - Check string suffix
+//!
 //! ## Network
 //! - `is_port_available` / `{% if port is port_available %}` - Check if port is available
 //!
@@ -47,7 +58,9 @@
 
 pub mod datetime;
 pub mod filesystem;
+pub mod logic;
 pub mod network;
+pub mod string;
 pub mod traits;
 pub mod validation;
 
@@ -66,8 +79,18 @@ pub fn get_all_metadata() -> Vec<&'static FunctionMetadata> {
         &validation::Url::METADATA,
         &validation::Ip::METADATA,
         &validation::Uuid::METADATA,
+        &validation::Phone::METADATA,
         // DateTime functions
         &datetime::LeapYear::METADATA,
+        // Logic functions
+        &logic::Empty::METADATA,
+        &logic::Defined::METADATA,
+        &logic::Undefined::METADATA,
+        &logic::Contains::METADATA,
+        &logic::Between::METADATA,
+        // Predicate functions
+        &string::StartsWith::METADATA,
+        &string::EndsWith::METADATA,
         // Network functions
         &network::PortAvailable::METADATA,
         // Filesystem functions
@@ -108,6 +131,12 @@ pub fn register_all(env: &mut Environment, context: Arc<TemplateContext>) {
     // Phase 3: DateTime functions (leap_year)
     datetime::register_all(env);
 
+    // Logic functions (empty, defined, undefined, contains)
+    logic::register_all(env);
+
+    // Predicate functions (starts_with, ends_with)
+    string::register_all(env);
+
     // Phase 4: Network functions (port_available)
     network::register_all(env);
 
@@ -0,0 +1,227 @@
+//! String is-functions for tmpltool
+//!
+//! This module provides string predicates that work with both syntaxes:
+//! - Function syntax: `{{ starts_with(string=tag, prefix="v") }}`
+//! - Is-test syntax: `{% if tag is starts_with("v") %}`
+//!
+//! # Available String Functions
+//!
+//! - `starts_with` / `starts_with(prefix)` - Check if a string starts with a prefix
+//! - `ends_with` / `ends_with(suffix)` - Check if a string ends with a suffix
+//!
+//! Both accept `case_insensitive=true` to ignore case.
+//!
+//! # Example Usage
+//!
+//! ```jinja
+//! {# Function syntax #}
+//! {% if starts_with(string=image_tag, prefix="v") %}release{% endif %}
+//!
+//! {# Is-test syntax (preferred for readability) #}
+//! {% if path is ends_with(".yaml") %}yaml file{% endif %}
+//! {% if name is ends_with(".PNG", case_insensitive=true) %}image{% endif %}
+//! ```
+
+use crate::functions::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
+use minijinja::value::Kwargs;
+use minijinja::{Environment, Error, Value};
+
+/// Shared `case_insensitive` argument metadata
+const CASE_INSENSITIVE_ARG: ArgumentMetadata = ArgumentMetadata {
+    name: "case_insensitive",
+    arg_type: "boolean",
+    required: false,
+    default: Some("false"),
+    description: "Ignore case when comparing",
+};
+
+/// Check whether `string` starts (or ends) with `affix`
+fn matches_affix(string: &str, affix: &str, case_insensitive: bool, at_end: bool) -> bool {
+    if case_insensitive {
+        let string = string.to_lowercase();
+        let affix = affix.to_lowercase();
+        if at_end {
+            string.ends_with(&affix)
+        } else {
+            string.starts_with(&affix)
+        }
+    } else if at_end {
+        string.ends_with(affix)
+    } else {
+        string.starts_with(affix)
+    }
+}
+
+/// Read the optional `case_insensitive` kwarg of an is-test, defaulting to false
+fn test_case_insensitive(kwargs: &Kwargs) -> bool {
+    kwargs
+        .get::<Option<bool>>("case_insensitive")
+        .ok()
+        .flatten()
+        .unwrap_or(false)
+}
+
+/// Prefix check
+///
+/// Like [`super::logic::Contains`], this test takes an argument and is
+/// registered directly rather than through [`super::IsFunction`].
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ starts_with(string="v1.2.3", prefix="v") }}
+/// {{ starts_with(string="Release-1", prefix="release", case_insensitive=true) }}
+/// ```
+///
+/// # Is-Test Syntax
+/// ```jinja
+/// {% if image_tag is starts_with("v") %}release{% endif %}
+/// ```
+pub struct StartsWith;
+
+impl StartsWith {
+    pub const FUNCTION_NAME: &'static str = "starts_with";
+    pub const IS_NAME: &'static str = "starts_with";
+    pub const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "starts_with",
+        category: "predicate",
+        description: "Check if a string starts with a prefix",
+        arguments: &[
+            ArgumentMetadata {
+                name: "string",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "The string to check",
+            },
+            ArgumentMetadata {
+                name: "prefix",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "The prefix to look for",
+            },
+            CASE_INSENSITIVE_ARG,
+        ],
+        return_type: "boolean",
+        examples: &[
+            "{{ starts_with(string=\"v1.2.3\", prefix=\"v\") }}",
+            "{% if image_tag is starts_with(\"v\") %}release{% endif %}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_TEST,
+    };
+
+    /// Handle function-style calls: `starts_with(string=..., prefix=...)`
+    pub fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let string: String = kwargs.get("string")?;
+        let prefix: String = kwargs.get("prefix")?;
+        let case_insensitive: Option<bool> = kwargs.get("case_insensitive")?;
+
+        Ok(Value::from(matches_affix(
+            &string,
+            &prefix,
+            case_insensitive.unwrap_or(false),
+            false,
+        )))
+    }
+
+    /// Handle is-style calls: `{% if value is starts_with(prefix) %}`
+    ///
+    /// Non-string values never match.
+    pub fn call_as_is(value: &Value, prefix: &str, kwargs: Kwargs) -> bool {
+        let case_insensitive = test_case_insensitive(&kwargs);
+        value
+            .as_str()
+            .map(|s| matches_affix(s, prefix, case_insensitive, false))
+            .unwrap_or(false)
+    }
+
+    /// Register `starts_with` as both a function and a test
+    pub fn register(env: &mut Environment) {
+        env.add_function(Self::FUNCTION_NAME, Self::call_as_function);
+        env.add_test(Self::IS_NAME, Self::call_as_is);
+    }
+}
+
+/// Suffix check
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ ends_with(string="config.yaml", suffix=".yaml") }}
+/// {{ ends_with(string="IMAGE.PNG", suffix=".png", case_insensitive=true) }}
+/// ```
+///
+/// # Is-Test Syntax
+/// ```jinja
+/// {% if path is ends_with(".yaml") %}yaml file{% endif %}
+/// ```
+pub struct EndsWith;
+
+impl EndsWith {
+    pub const FUNCTION_NAME: &'static str = "ends_with";
+    pub const IS_NAME: &'static str = "ends_with";
+    pub const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "ends_with",
+        category: "predicate",
+        description: "Check if a string ends with a suffix",
+        arguments: &[
+            ArgumentMetadata {
+                name: "string",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "The string to check",
+            },
+            ArgumentMetadata {
+                name: "suffix",
+                arg_type: "string",
+                required: true,
+                default: None,
+                description: "The suffix to look for",
+            },
+            CASE_INSENSITIVE_ARG,
+        ],
+        return_type: "boolean",
+        examples: &[
+            "{{ ends_with(string=\"config.yaml\", suffix=\".yaml\") }}",
+            "{% if path is ends_with(\".yaml\") %}yaml file{% endif %}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_TEST,
+    };
+
+    /// Handle function-style calls: `ends_with(string=..., suffix=...)`
+    pub fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let string: String = kwargs.get("string")?;
+        let suffix: String = kwargs.get("suffix")?;
+        let case_insensitive: Option<bool> = kwargs.get("case_insensitive")?;
+
+        Ok(Value::from(matches_affix(
+            &string,
+            &suffix,
+            case_insensitive.unwrap_or(false),
+            true,
+        )))
+    }
+
+    /// Handle is-style calls: `{% if value is ends_with(suffix) %}`
+    ///
+    /// Non-string values never match.
+    pub fn call_as_is(value: &Value, suffix: &str, kwargs: Kwargs) -> bool {
+        let case_insensitive = test_case_insensitive(&kwargs);
+        value
+            .as_str()
+            .map(|s| matches_affix(s, suffix, case_insensitive, true))
+            .unwrap_or(false)
+    }
+
+    /// Register `ends_with` as both a function and a test
+    pub fn register(env: &mut Environment) {
+        env.add_function(Self::FUNCTION_NAME, Self::call_as_function);
+        env.add_test(Self::IS_NAME, Self::call_as_is);
+    }
+}
+
+/// Register all string is-functions with the MiniJinja environment
+pub fn register_all(env: &mut Environment) {
+    StartsWith::register(env);
+    EndsWith::register(env);
+}
@@ -10,6 +10,7 @@
 //! - `is_url` / `url` - Validate URL format
 //! - `is_ip` / `ip` - Validate IP address (IPv4 or IPv6)
 //! - `is_uuid` / `uuid` - Validate UUID format
+//! - `is_phone` / `phone` - Validate a phone number for a region
 //!
 //! # Example Usage
 //!
@@ -21,6 +22,7 @@
 //! {% if user_input is email %}valid{% endif %}
 //! ```
 
+use crate::filter_functions::formatting::PhoneFormat;
 use crate::functions::metadata::{ArgumentMetadata, FunctionMetadata, SyntaxVariants};
 use crate::is_functions::IsFunction;
 use minijinja::value::Kwargs;
@@ -253,10 +255,87 @@ impl IsFunction for Uuid {
     }
 }
 
+/// Phone number validation is-function
+///
+/// Takes an optional region argument, so like [`super::string::StartsWith`]
+/// it is registered directly rather than through [`IsFunction`].
+///
+/// # Function Syntax
+/// ```jinja
+/// {{ is_phone(string="(202) 555-0143") }}
+/// {{ is_phone(string="020 7946 0958", country="GB") }}
+/// ```
+///
+/// # Is-Test Syntax
+/// ```jinja
+/// {% if contact.phone is phone %}valid{% endif %}
+/// {% if contact.phone is phone("GB") %}valid{% endif %}
+/// ```
+pub struct Phone;
+
+impl Phone {
+    pub const FUNCTION_NAME: &'static str = "is_phone";
+    pub const IS_NAME: &'static str = "phone";
+    pub const METADATA: FunctionMetadata = FunctionMetadata {
+        name: "is_phone",
+        category: "validation",
+        description: "Validate a phone number using libphonenumber metadata",
+        arguments: &[
+            STRING_ARG,
+            ArgumentMetadata {
+                name: "country",
+                arg_type: "string",
+                required: false,
+                default: Some("US"),
+                description: "Region used for numbers without a +country code prefix",
+            },
+        ],
+        return_type: "boolean",
+        examples: &[
+            "{{ is_phone(string=\"(202) 555-0143\") }}",
+            "{% if contact.phone is phone(\"GB\") %}valid{% endif %}",
+        ],
+        syntax: SyntaxVariants::FUNCTION_AND_TEST,
+    };
+
+    /// Validate a phone number; unknown regions are an error
+    pub fn validate(s: &str, country: &str) -> Result<bool, Error> {
+        let country = PhoneFormat::country(country, Self::FUNCTION_NAME)?;
+        Ok(PhoneFormat::parse_valid(s, country).is_some())
+    }
+
+    /// Handle function-style calls: `is_phone(string=..., country=...)`
+    pub fn call_as_function(kwargs: Kwargs) -> Result<Value, Error> {
+        let string: String = kwargs.get("string")?;
+        let country: Option<String> = kwargs.get("country")?;
+        Ok(Value::from(Self::validate(
+            &string,
+            country.as_deref().unwrap_or("US"),
+        )?))
+    }
+
+    /// Handle is-style calls: `{% if value is phone %}` / `{% if value is phone("GB") %}`
+    ///
+    /// Non-string values never match.
+    pub fn call_as_is(value: &Value, country: Option<String>) -> Result<bool, Error> {
+        match value.as_str() {
+            Some(s) => Self::validate(s, country.as_deref().unwrap_or("US")),
+            None => Ok(false),
+        }
+    }
+
+    /// Register `is_phone` as a function and `phone` as a test
+    pub fn register(env: &mut Environment) {
+        env.add_function(Self::FUNCTION_NAME, Self::call_as_function);
+        env.add_test(Self::IS_NAME, Self::call_as_is);
+    }
+}
+
 /// Register all validation is-functions with the MiniJinja environment
 pub fn register_all(env: &mut Environment) {
     Email::register(env);
     Url::register(env);
     Ip::register(env);
     Uuid::register(env);
+    Phone::register(env);
 }
@@ -14,17 +14,29 @@
 //! See the [`functions`] module for more details on available functions.
 
 pub mod cli;
+pub mod color;
 pub mod context;
+pub mod context_file;
 pub mod filter_functions;
+pub mod format_output;
 pub mod functions;
 pub mod is_functions;
+pub mod logger;
+pub mod output_path;
+pub mod partials;
+pub mod profiler;
 pub mod renderer;
+pub mod self_test;
+#[cfg(feature = "strict-parse")]
+pub mod strict_parse;
+pub mod strip_comments;
+pub mod suggest;
 pub mod validator;
 
 pub use cli::Cli;
 pub use context::TemplateContext;
 pub use functions::metadata::FunctionMetadata;
-pub use renderer::render_template;
+pub use renderer::{RenderOptions, dump_context, render_template, render_template_with_options};
 
 /// Get all function metadata for IDE integration
 ///
@@ -44,3 +56,24 @@ pub fn get_all_metadata() -> Vec<&'static FunctionMetadata> {
 
     all
 }
+
+/// Look up the metadata of a function, filter or is-test by name
+///
+/// # Example
+///
+/// ```
+/// let metadata = tmpltool::get_metadata_by_name("to_json").unwrap();
+/// assert_eq!(metadata.category, "serialization");
+/// assert!(tmpltool::get_metadata_by_name("no_such_function").is_none());
+/// ```
+pub fn get_metadata_by_name(name: &str) -> Option<&'static FunctionMetadata> {
+    get_all_metadata().into_iter().find(|m| m.name == name)
+}
+
+/// Names of all functions, filters and is-tests, sorted and without duplicates
+pub fn all_function_names() -> Vec<&'static str> {
+    let mut names: Vec<&'static str> = get_all_metadata().iter().map(|m| m.name).collect();
+    names.sort_unstable();
+    names.dedup();
+    names
+}
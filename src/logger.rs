@@ -0,0 +1,60 @@
+//! Lightweight stderr logger for `--verbose` output
+//!
+//! This module provides a minimal phase timer used to report how long each
+//! step of rendering takes (reading, registration, parsing, rendering, writing).
+//! When disabled, all calls are no-ops so the default output stays quiet.
+
+use std::time::{Duration, Instant};
+
+/// Logs rendering phases with elapsed times to stderr
+#[derive(Debug)]
+pub struct PhaseLogger {
+    /// Whether messages are printed at all
+    enabled: bool,
+    /// When the logger was created (start of the whole run)
+    started: Instant,
+    /// When the current phase started
+    phase_started: Instant,
+}
+
+impl PhaseLogger {
+    /// Create a new logger; when `enabled` is false nothing is printed
+    pub fn new(enabled: bool) -> Self {
+        let now = Instant::now();
+        Self {
+            enabled,
+            started: now,
+            phase_started: now,
+        }
+    }
+
+    /// Print a free-form message
+    pub fn log(&self, message: &str) {
+        if self.enabled {
+            eprintln!("[VERBOSE] {}", message);
+        }
+    }
+
+    /// Finish the current phase, printing its name and elapsed time
+    ///
+    /// The next phase starts timing immediately after this call.
+    pub fn phase(&mut self, name: &str) {
+        let elapsed = self.phase_started.elapsed();
+        self.log(&format!("{:<22} {}", name, format_duration(elapsed)));
+        self.phase_started = Instant::now();
+    }
+
+    /// Print the total time since the logger was created
+    pub fn finish(&self) {
+        self.log(&format!(
+            "{:<22} {}",
+            "total",
+            format_duration(self.started.elapsed())
+        ));
+    }
+}
+
+/// Format a duration in milliseconds with microsecond precision
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+}
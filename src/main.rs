@@ -1,9 +1,14 @@
 use clap::Parser;
 use serde::Serialize;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process;
 use tmpltool::cli::IdeFormat;
-use tmpltool::{Cli, FunctionMetadata, get_all_metadata, render_template};
+use tmpltool::color;
+use tmpltool::logger::PhaseLogger;
+use tmpltool::{
+    Cli, FunctionMetadata, RenderOptions, TemplateContext, dump_context, get_all_metadata,
+    render_template_with_options, self_test, suggest,
+};
 
 /// Wrapper for TOML output (TOML requires a table at the root)
 #[derive(Serialize)]
@@ -29,18 +34,62 @@ fn load_env_files(env_files: &[String]) -> Result<(), String> {
     Ok(())
 }
 
+/// Keep only metadata in the given categories (all of it when none are given)
+fn filter_by_category(
+    metadata: Vec<&'static FunctionMetadata>,
+    categories: &[String],
+) -> Result<Vec<&'static FunctionMetadata>, String> {
+    if categories.is_empty() {
+        return Ok(metadata);
+    }
+    let mut known: Vec<&str> = metadata.iter().map(|m| m.category).collect();
+    known.sort_unstable();
+    known.dedup();
+    if let Some(unknown) = categories.iter().find(|c| !known.contains(&c.as_str())) {
+        let hint = suggest::did_you_mean(unknown, known.iter().copied())
+            .map(|name| format!(" (did you mean '{}'?)", name))
+            .unwrap_or_default();
+        return Err(format!(
+            "Unknown category '{}'{}. Available categories: {}",
+            unknown,
+            hint,
+            known.join(", ")
+        ));
+    }
+    Ok(metadata
+        .into_iter()
+        .filter(|m| categories.iter().any(|c| c == m.category))
+        .collect())
+}
+
+/// Print an error to stderr, colored if enabled, and exit with code 1
+fn fail(message: &str, use_color: bool) -> ! {
+    eprintln!(
+        "{}",
+        color::paint_error(&format!("Error: {}", message), use_color)
+    );
+    process::exit(1);
+}
+
 fn main() {
     let cli = Cli::parse();
+    let use_color = color::use_color(cli.color_choice());
 
     // Load environment files first (before any other processing)
+    let mut logger = PhaseLogger::new(cli.verbose);
     if let Err(e) = load_env_files(&cli.env_files) {
-        eprintln!("Error: {}", e);
-        process::exit(1);
+        fail(&e, use_color);
+    }
+    if !cli.env_files.is_empty() {
+        logger.phase("load env files");
     }
 
     // Handle --ide <format> early exit
     if let Some(format) = cli.ide {
-        let metadata = get_all_metadata();
+        let metadata = match filter_by_category(get_all_metadata(), &cli.categories) {
+            Ok(metadata) => metadata,
+            Err(e) => fail(&e, use_color),
+        };
         let result = match format {
             IdeFormat::Json => serde_json::to_string_pretty(&metadata).map_err(|e| e.to_string()),
             IdeFormat::Yaml => serde_yaml::to_string(&metadata).map_err(|e| e.to_string()),
@@ -63,13 +112,68 @@ fn main() {
         }
     }
 
-    if let Err(e) = render_template(
-        cli.template.as_deref(),
-        cli.output.as_deref(),
-        cli.trust,
-        cli.validate,
-    ) {
-        eprintln!("Error: {}", e);
-        process::exit(1);
+    // Handle --self-test early exit
+    if cli.self_test {
+        let context = std::env::current_dir()
+            .map(|dir| TemplateContext::new(dir, cli.trust))
+            .unwrap_or_else(|e| {
+                fail(
+                    &format!("Failed to get current directory: {}", e),
+                    use_color,
+                )
+            });
+        let report = self_test::run(context);
+        for failure in &report.failures {
+            eprintln!("FAIL {}: {}", failure.function, failure.example);
+            eprintln!("     {}", failure.error);
+        }
+        println!("Self-test: {}", report.summary());
+        if !report.failures.is_empty() {
+            fail(
+                &format!("{} example(s) failed", report.failures.len()),
+                use_color,
+            );
+        }
+        process::exit(0);
+    }
+
+    let options = RenderOptions {
+        trust_mode: cli.trust,
+        validate_format: cli.validate,
+        verbose: cli.verbose,
+        profile: cli.profile,
+        max_output_size: cli.max_output_size,
+        timeout: cli.timeout,
+        fail_on_warn: cli.fail_on_warn,
+        context_files: cli.context_files,
+        context_format: cli.context_format,
+        context_list_strategy: cli.context_list_strategy,
+        env_key: cli.env_key,
+        env_top_level: cli.env_top_level,
+        include_paths: cli.include_paths.iter().map(PathBuf::from).collect(),
+        partials_dir: cli.partials_dir.as_deref().map(PathBuf::from),
+        base_dir: cli.base_dir.as_deref().map(PathBuf::from),
+        strict_parse: cli.strict_parse,
+        interactive: cli.interactive,
+        manifest: cli.manifest.as_deref().map(PathBuf::from),
+        check: cli.check,
+        line_ending: cli.line_ending,
+        bom: cli.bom,
+        strip_comments: cli.strip_comments.then_some(cli.comment_syntax),
+        format_output: cli.format_output,
+        args: cli.args,
+    };
+
+    if let Some(path) = &cli.dump_context {
+        if let Err(e) = dump_context(&options, path) {
+            fail(&e.to_string(), use_color);
+        }
+        process::exit(0);
+    }
+
+    if let Err(e) =
+        render_template_with_options(cli.template.as_deref(), cli.output.as_deref(), &options)
+    {
+        fail(&e.to_string(), use_color);
     }
 }
@@ -0,0 +1,97 @@
+//! Templated output paths (`-o "dist/{{ name }}.yaml"`)
+//!
+//! An `--output` value containing template syntax is rendered against the
+//! template variables before anything is written. The path is rendered in a
+//! minimal environment with MiniJinja's builtins only, so it cannot read
+//! files or run commands. Environment variables are available as `env`
+//! unless the context already defines that name.
+//!
+//! Unless `--trust` is given, the rendered path may not climb out of the
+//! directory it names: a `..` component, or an absolute path produced by a
+//! relative template, is an error.
+
+use minijinja::{Environment, UndefinedBehavior};
+use serde_json::Value;
+use std::path::{Component, Path};
+
+/// Whether an output path contains template syntax
+///
+/// # Example
+///
+/// ```
+/// use tmpltool::output_path::is_templated;
+///
+/// assert!(is_templated("dist/{{ name }}.yaml"));
+/// assert!(!is_templated("dist/app.yaml"));
+/// ```
+pub fn is_templated(path: &str) -> bool {
+    path.contains("{{") || path.contains("{%")
+}
+
+/// Render a templated output path against the template variables
+///
+/// Paths without template syntax are returned unchanged.
+///
+/// # Arguments
+///
+/// * `path` - The `--output` value
+/// * `context` - Template variables (a JSON object)
+/// * `trust_mode` - If true, skips the path traversal checks
+pub fn resolve_output_path(
+    path: &str,
+    context: &Value,
+    trust_mode: bool,
+) -> Result<String, String> {
+    if !is_templated(path) {
+        return Ok(path.to_string());
+    }
+
+    let mut variables = match context {
+        Value::Object(map) => map.clone(),
+        _ => serde_json::Map::new(),
+    };
+    if !variables.contains_key("env") {
+        let env_vars = std::env::vars()
+            .map(|(name, value)| (name, Value::String(value)))
+            .collect();
+        variables.insert("env".to_string(), Value::Object(env_vars));
+    }
+
+    let mut env = Environment::new();
+    env.set_undefined_behavior(UndefinedBehavior::Strict);
+    let resolved = env
+        .render_str(path, Value::Object(variables))
+        .map_err(|e| format!("Failed to render output path '{}': {}", path, e))?;
+
+    if resolved.trim().is_empty() {
+        return Err(format!(
+            "Output path '{}' rendered to an empty string",
+            path
+        ));
+    }
+    if resolved.contains(['\n', '\r', '\0']) {
+        return Err(format!(
+            "Output path '{}' rendered to '{}', which contains control characters",
+            path,
+            resolved.escape_debug()
+        ));
+    }
+
+    if !trust_mode {
+        let rendered = Path::new(&resolved);
+        if rendered.components().any(|c| c == Component::ParentDir) {
+            return Err(format!(
+                "Security: Output path '{}' rendered to '{}', which contains parent directory (..) traversal. Use --trust to bypass this restriction.",
+                path, resolved
+            ));
+        }
+        if rendered.is_absolute() && !Path::new(path).is_absolute() {
+            return Err(format!(
+                "Security: Output path '{}' rendered to absolute path '{}'. Use --trust to bypass this restriction.",
+                path, resolved
+            ));
+        }
+    }
+
+    Ok(resolved)
+}
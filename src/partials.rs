@@ -0,0 +1,130 @@
+//! Preloading of shared partials for `--partials-dir`
+//!
+//! Every file in the partials directory (recursively) is registered with the
+//! MiniJinja environment under its relative path (`forms/inputs.j2`) and under
+//! the same path without its last extension (`forms/inputs`), so templates can
+//! `{% import %}` shared macros by name.
+//!
+//! A top-level `_globals` template (any extension) is imported automatically:
+//! its public macros become available to the main template without an
+//! explicit `{% from ... import ... %}` line.
+
+use minijinja::Environment;
+use regex::Regex;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+/// Name (without extension) of the template imported automatically
+pub const GLOBALS_NAME: &str = "_globals";
+
+/// Matches macro definitions: `{% macro name(` / `{%- macro name(`
+static MACRO_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{%-?\s*macro\s+([A-Za-z_][A-Za-z0-9_]*)\s*\(").unwrap());
+
+/// Register all partials in `dir` with the environment
+///
+/// # Returns
+///
+/// The import statement to prepend to the main template when a `_globals`
+/// template with public macros exists, or `None` otherwise.
+pub fn load_partials(
+    env: &mut Environment<'_>,
+    dir: &Path,
+) -> Result<Option<String>, Box<dyn std::error::Error>> {
+    if !dir.is_dir() {
+        return Err(format!("Partials directory '{}' does not exist", dir.display()).into());
+    }
+
+    let mut files = Vec::new();
+    collect_files(dir, &mut files).map_err(|e| {
+        format!(
+            "Failed to read partials directory '{}': {}",
+            dir.display(),
+            e
+        )
+    })?;
+    files.sort();
+
+    let mut globals_import = None;
+    let mut registered = HashSet::new();
+    for path in files {
+        let content = fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read partial '{}': {}", path.display(), e))?;
+
+        let name = partial_name(dir, &path);
+        let short_name = strip_extension(&name);
+
+        if short_name == GLOBALS_NAME && globals_import.is_none() {
+            globals_import = globals_import_statement(&content);
+        }
+
+        env.add_template_owned(name.clone(), content.clone())
+            .map_err(|e| format!("Failed to parse partial '{}': {}", path.display(), e))?;
+        registered.insert(name.clone());
+
+        // The first file wins the short alias; full names always work
+        if short_name != name && registered.insert(short_name.clone()) {
+            env.add_template_owned(short_name, content)
+                .map_err(|e| format!("Failed to parse partial '{}': {}", path.display(), e))?;
+        }
+    }
+
+    Ok(globals_import)
+}
+
+/// Recursively collect all regular files below `dir`
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(&path, files)?;
+        } else if path.is_file() {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Template name of a partial: its path relative to the partials directory, with `/` separators
+fn partial_name(dir: &Path, path: &Path) -> String {
+    let relative = path.strip_prefix(dir).unwrap_or(path);
+    relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Remove the last extension from a template name (`forms/inputs.j2` -> `forms/inputs`)
+fn strip_extension(name: &str) -> String {
+    let file_start = name.rfind('/').map(|i| i + 1).unwrap_or(0);
+    match name[file_start..].rfind('.') {
+        Some(dot) if dot > 0 => name[..file_start + dot].to_string(),
+        _ => name.to_string(),
+    }
+}
+
+/// Build `{% from "_globals" import a, b %}` for the public macros of the globals template
+///
+/// Macros starting with an underscore are private in MiniJinja and are skipped.
+/// The statement has no trailing newline so line numbers in the main template
+/// stay unchanged.
+fn globals_import_statement(content: &str) -> Option<String> {
+    let mut seen = HashSet::new();
+    let names: Vec<&str> = MACRO_RE
+        .captures_iter(content)
+        .filter_map(|c| c.get(1).map(|m| m.as_str()))
+        .filter(|name| !name.starts_with('_') && seen.insert(*name))
+        .collect();
+
+    if names.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "{{% from \"{}\" import {} %}}",
+        GLOBALS_NAME,
+        names.join(", ")
+    ))
+}
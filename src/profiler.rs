@@ -0,0 +1,99 @@
+//! Function call profiling for `--profile`
+//!
+//! The profiler wraps every function registered in a MiniJinja environment so
+//! that each invocation is counted and timed. After rendering, a summary sorted
+//! by total time is printed to stderr, revealing functions that are called far
+//! more often than expected (e.g., a file read inside a loop).
+
+use minijinja::value::ValueKind;
+use minijinja::{Environment, Error, State, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Call statistics for a single function
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CallStats {
+    /// Number of times the function was called
+    pub calls: u64,
+    /// Total time spent inside the function
+    pub total: Duration,
+}
+
+/// Collects call counts and timings for template functions
+#[derive(Debug, Clone, Default)]
+pub struct Profiler {
+    stats: Arc<Mutex<HashMap<String, CallStats>>>,
+}
+
+impl Profiler {
+    /// Create an empty profiler
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wrap every function currently registered in `env` with call instrumentation
+    ///
+    /// Must be called after all functions have been registered; functions
+    /// added afterwards are not profiled.
+    pub fn instrument(&self, env: &mut Environment) {
+        let functions: Vec<(String, Value)> = env
+            .globals()
+            .filter(|(_, value)| value.kind() == ValueKind::Plain)
+            .map(|(name, value)| (name.to_string(), value))
+            .collect();
+
+        for (name, original) in functions {
+            let stats = self.stats.clone();
+            let key = name.clone();
+            env.add_function(
+                name,
+                move |state: &State, args: &[Value]| -> Result<Value, Error> {
+                    let started = Instant::now();
+                    let result = original.call(state, args);
+                    let elapsed = started.elapsed();
+
+                    let mut stats = stats.lock().unwrap_or_else(|e| e.into_inner());
+                    let entry = stats.entry(key.clone()).or_default();
+                    entry.calls += 1;
+                    entry.total += elapsed;
+
+                    result
+                },
+            );
+        }
+    }
+
+    /// Get the collected statistics sorted by total time (slowest first)
+    pub fn summary(&self) -> Vec<(String, CallStats)> {
+        let stats = self.stats.lock().unwrap_or_else(|e| e.into_inner());
+        let mut entries: Vec<(String, CallStats)> = stats
+            .iter()
+            .map(|(name, stats)| (name.clone(), *stats))
+            .collect();
+        entries.sort_by(|a, b| b.1.total.cmp(&a.1.total).then_with(|| a.0.cmp(&b.0)));
+        entries
+    }
+
+    /// Print the summary table to stderr
+    pub fn report(&self) {
+        let entries = self.summary();
+        if entries.is_empty() {
+            eprintln!("[PROFILE] no functions were called");
+            return;
+        }
+
+        eprintln!(
+            "[PROFILE] {:<28} {:>8} {:>12} {:>12}",
+            "function", "calls", "total", "avg"
+        );
+        for (name, stats) in entries {
+            let total_ms = stats.total.as_secs_f64() * 1000.0;
+            let avg_ms = total_ms / stats.calls as f64;
+            eprintln!(
+                "[PROFILE] {:<28} {:>8} {:>10.3}ms {:>10.3}ms",
+                name, stats.calls, total_ms, avg_ms
+            );
+        }
+    }
+}
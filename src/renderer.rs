@@ -1,8 +1,95 @@
-use crate::{TemplateContext, cli::ValidateFormat, functions, validator};
+use crate::cli::{CommentSyntax, ContextFormat, LineEnding, OutputFormat, ValidateFormat};
+use crate::functions::object::ListStrategy;
+use crate::logger::PhaseLogger;
+use crate::profiler::Profiler;
+use crate::{
+    TemplateContext, context_file, format_output, functions, output_path, partials, strip_comments,
+    suggest, validator,
+};
 use minijinja::Environment;
 use serde::Serialize;
+use sha2::{Digest, Sha256};
+use similar::TextDiff;
 use std::fs;
 use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// Options controlling how a template is rendered
+///
+/// Use `RenderOptions::default()` and override the fields you need:
+///
+/// ```
+/// use tmpltool::RenderOptions;
+///
+/// let options = RenderOptions {
+///     trust_mode: true,
+///     ..Default::default()
+/// };
+/// assert!(!options.verbose);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RenderOptions {
+    /// If true, disables filesystem security restrictions
+    pub trust_mode: bool,
+    /// Optional format to validate output against (JSON, YAML, or TOML)
+    pub validate_format: Option<ValidateFormat>,
+    /// If true, logs rendering phases with elapsed times to stderr
+    pub verbose: bool,
+    /// If true, counts and times every function call and prints a summary to stderr
+    pub profile: bool,
+    /// Abort rendering once the output grows beyond this many bytes
+    pub max_output_size: Option<u64>,
+    /// Abort rendering if it takes longer than this (best-effort watchdog)
+    pub timeout: Option<Duration>,
+    /// If true, fails when any warnings were emitted during rendering
+    pub fail_on_warn: bool,
+    /// JSON, YAML or TOML files whose top-level keys become template
+    /// variables (`-` reads stdin); later files are deep-merged over earlier ones
+    pub context_files: Vec<String>,
+    /// Format of the context files (None infers it from each extension)
+    pub context_format: Option<ContextFormat>,
+    /// How arrays are combined when merging context files
+    pub context_list_strategy: ListStrategy,
+    /// Expose all environment variables as an object under this variable name
+    pub env_key: Option<String>,
+    /// If true, exposes all environment variables as top-level variables
+    pub env_top_level: bool,
+    /// Extra directories searched by `{% include %}` / `{% import %}`,
+    /// in order, after the template's own directory
+    pub include_paths: Vec<PathBuf>,
+    /// Directory whose templates are preloaded so their macros can be
+    /// imported by name; a `_globals` template in it is imported automatically
+    pub partials_dir: Option<PathBuf>,
+    /// Root for filesystem functions (relative paths and security checks)
+    /// instead of the template's directory; includes are unaffected
+    pub base_dir: Option<PathBuf>,
+    /// If true, fails before rendering when the template references an
+    /// unknown function, filter or test
+    pub strict_parse: bool,
+    /// If true, `prompt()` asks for missing values on stdin
+    pub interactive: bool,
+    /// Write a JSON listing of the generated output (path, size, hash) here
+    /// once rendering has succeeded
+    pub manifest: Option<PathBuf>,
+    /// If true, compares the rendered output with the existing output file
+    /// instead of writing it, failing (with a diff) when they differ
+    pub check: bool,
+    /// Convert all line endings of the output (None keeps them as rendered)
+    pub line_ending: Option<LineEnding>,
+    /// If true, prepends a UTF-8 byte order mark to the output
+    pub bom: bool,
+    /// Remove comment lines with this marker from the output before it is
+    /// validated and written
+    pub strip_comments: Option<CommentSyntax>,
+    /// Parse and re-serialize the output in this format (after comments are
+    /// stripped, before it is validated)
+    pub format_output: Option<OutputFormat>,
+    /// Positional arguments given after `--`, exposed as the `args` variable
+    pub args: Vec<String>,
+}
 
 /// Renders a template with environment variables
 ///
@@ -22,37 +109,208 @@ pub fn render_template(
     trust_mode: bool,
     validate_format: Option<ValidateFormat>,
 ) -> Result<(), Box<dyn std::error::Error>> {
+    let options = RenderOptions {
+        trust_mode,
+        validate_format,
+        ..Default::default()
+    };
+    render_template_with_options(template_source, output_file, &options)
+}
+
+/// Renders a template with the given options
+///
+/// # Arguments
+///
+/// * `template_source` - Optional path to template file. If None, reads from stdin
+/// * `output_file` - Optional path to output file. If None, prints to stdout
+/// * `options` - Rendering options (trust mode, validation, verbosity, ...)
+///
+/// # Returns
+///
+/// Returns Ok(()) on success, or an error message on failure
+pub fn render_template_with_options(
+    template_source: Option<&str>,
+    output_file: Option<&str>,
+    options: &RenderOptions,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut logger = PhaseLogger::new(options.verbose);
+
+    let reads_stdin = options
+        .context_files
+        .iter()
+        .any(|path| path == context_file::STDIN);
+    if template_source.is_none() && reads_stdin {
+        return Err(
+            "Cannot read both the template and the context from stdin; pass the template as a file"
+                .into(),
+        );
+    }
+
     // Read template from file or stdin
     let template_content = read_template(template_source)?;
+    logger.phase("read template");
 
     // Create template context for resolving file paths
     let template_context = match template_source {
-        Some(file_path) => TemplateContext::from_template_file(file_path, trust_mode)?,
-        None => TemplateContext::from_stdin(trust_mode)?,
-    };
+        Some(file_path) => TemplateContext::from_template_file(file_path, options.trust_mode)?,
+        None => TemplateContext::from_stdin(options.trust_mode)?,
+    }
+    .with_interactive(options.interactive);
+    let warnings = template_context.clone();
 
-    // Create empty context - env vars only accessible via env() function
-    let context = serde_json::json!({});
+    let context = build_context(options)?;
+    logger.phase("build context");
+
+    // A templated --output is resolved before rendering so a bad path fails fast
+    let output_file = output_file
+        .map(|path| output_path::resolve_output_path(path, &context, options.trust_mode))
+        .transpose()?;
+    let output_file = output_file.as_deref();
 
     // Render the template
-    let rendered = render(
-        template_source,
-        &template_content,
-        &context,
-        template_context,
-    )?;
+    let profiler = options.profile.then(Profiler::new);
+    let rendered = match options.timeout {
+        Some(timeout) => render_with_timeout(
+            timeout,
+            template_source,
+            template_content,
+            context,
+            template_context,
+            &mut logger,
+            profiler.clone(),
+            options.clone(),
+        ),
+        None => render(
+            template_source,
+            &template_content,
+            &context,
+            template_context,
+            &mut logger,
+            profiler.as_ref(),
+            options,
+        ),
+    };
+    if let Some(profiler) = &profiler {
+        profiler.report();
+    }
+    let rendered = rendered?;
+    if options.validate_format == Some(ValidateFormat::Template) {
+        logger.finish();
+        return Ok(());
+    }
+
+    // Escalate warnings to an error if requested
+    let warning_count = warnings.warning_count();
+    if options.fail_on_warn && warning_count > 0 {
+        return Err(format!(
+            "{} warning(s) emitted during rendering (--fail-on-warn)",
+            warning_count
+        )
+        .into());
+    }
+
+    let rendered = match options.strip_comments {
+        Some(syntax) => {
+            let stripped = strip_comments::strip_comments(&rendered, syntax);
+            logger.phase("strip comments");
+            stripped
+        }
+        None => rendered,
+    };
+
+    let rendered = match options.format_output {
+        Some(format) => {
+            let formatted = format_output::format_output(&rendered, format)?;
+            logger.phase("format");
+            formatted
+        }
+        None => rendered,
+    };
 
     // Validate output if requested
-    if let Some(format) = validate_format {
+    if let Some(format) = options.validate_format {
         validator::validate_output(&rendered, format)?;
+        logger.phase("validate");
+    }
+
+    // Line endings and BOM apply to exactly what is written (or checked)
+    let rendered = encode_output(rendered, options.line_ending, options.bom);
+
+    if options.check {
+        check_output(&rendered, output_file)?;
+        logger.phase("check");
+        logger.finish();
+        return Ok(());
     }
 
     // Write output to file or stdout
     write_output(&rendered, output_file)?;
+    logger.phase("write");
+
+    if let Some(manifest) = &options.manifest {
+        write_manifest(manifest, &rendered, output_file)?;
+        logger.phase("manifest");
+    }
+    logger.finish();
+
+    Ok(())
+}
 
+/// Writes the template variables a render would use as JSON (`--dump-context`)
+///
+/// The context is assembled exactly as for rendering (see `build_context`),
+/// without reading or rendering a template.
+///
+/// # Arguments
+///
+/// * `options` - Rendering options naming the context sources
+/// * `path` - File to write, or `-` for stdout
+pub fn dump_context(options: &RenderOptions, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let context = build_context(options)?;
+    let json = serde_json::to_string_pretty(&context)? + "\n";
+    if path == context_file::STDIN {
+        print!("{}", json);
+        io::stdout().flush()?;
+    } else {
+        fs::write(path, json)
+            .map_err(|e| format!("Failed to write context dump '{}': {}", path, e))?;
+    }
     Ok(())
 }
 
+/// Builds the template variables
+///
+/// Later sources take precedence: environment variables (`--env-top-level`),
+/// then `--context` files, then the `--env-key` object, and finally `args`
+/// when any were passed after `--`.
+/// Without these flags env vars are only accessible via the get_env() function.
+fn build_context(options: &RenderOptions) -> Result<serde_json::Value, String> {
+    let env_vars = || {
+        std::env::vars()
+            .map(|(name, value)| (name, serde_json::Value::String(value)))
+            .collect::<serde_json::Map<_, _>>()
+    };
+
+    let mut variables = if options.env_top_level {
+        env_vars()
+    } else {
+        serde_json::Map::new()
+    };
+    variables.extend(context_file::load_contexts(
+        &options.context_files,
+        options.context_format,
+        options.context_list_strategy,
+    )?);
+    if let Some(key) = &options.env_key {
+        variables.insert(key.clone(), serde_json::Value::Object(env_vars()));
+    }
+    // An `args` key from a source above is only replaced by actual `-- ARGS`
+    if !options.args.is_empty() || !variables.contains_key("args") {
+        variables.insert("args".to_string(), serde_json::json!(options.args));
+    }
+    Ok(serde_json::Value::Object(variables))
+}
+
 /// Reads the template content from file or stdin
 fn read_template(template_source: Option<&str>) -> Result<String, Box<dyn std::error::Error>> {
     match template_source {
@@ -80,20 +338,80 @@ fn read_template(template_source: Option<&str>) -> Result<String, Box<dyn std::e
     }
 }
 
+/// Renders the template on a worker thread, giving up after `timeout`
+///
+/// MiniJinja has no cooperative cancellation, so this is a best-effort
+/// watchdog: on timeout the worker thread is abandoned and an error is
+/// returned. The CLI exits right after, which terminates the thread.
+#[allow(clippy::too_many_arguments)]
+fn render_with_timeout(
+    timeout: Duration,
+    template_source: Option<&str>,
+    template_content: String,
+    context: serde_json::Value,
+    template_context: TemplateContext,
+    logger: &mut PhaseLogger,
+    profiler: Option<Profiler>,
+    options: RenderOptions,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let template_source = template_source.map(str::to_string);
+    let mut worker_logger = std::mem::replace(logger, PhaseLogger::new(false));
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        let result = render(
+            template_source.as_deref(),
+            &template_content,
+            &context,
+            template_context,
+            &mut worker_logger,
+            profiler.as_ref(),
+            &options,
+        )
+        .map_err(|e| e.to_string());
+        // The receiver is gone if the render already timed out
+        sender.send((result, worker_logger)).ok();
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok((result, worker_logger)) => {
+            *logger = worker_logger;
+            result.map_err(Into::into)
+        }
+        Err(mpsc::RecvTimeoutError::Timeout) => Err(format!(
+            "Rendering timed out after {} (--timeout)",
+            format_timeout(timeout)
+        )
+        .into()),
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            Err("Rendering thread terminated unexpectedly".into())
+        }
+    }
+}
+
+/// Formats a timeout for error messages (e.g., "2s" or "0.5s")
+fn format_timeout(timeout: Duration) -> String {
+    format!("{}s", timeout.as_secs_f64())
+}
+
 /// Renders the template with the given context
 fn render(
     template_source: Option<&str>,
     template_content: &str,
-    context: &impl Serialize,
+    context: &serde_json::Value,
     template_context: TemplateContext,
+    logger: &mut PhaseLogger,
+    profiler: Option<&Profiler>,
+    options: &RenderOptions,
 ) -> Result<String, Box<dyn std::error::Error>> {
     let mut env = Environment::new();
 
     // Set strict undefined behavior - fail on undefined variables (like Tera)
     env.set_undefined_behavior(minijinja::UndefinedBehavior::Strict);
 
-    // Clone template_context for use in the loader closure
+    // Clone template_context and search paths for use in the loader closure
     let loader_context = template_context.clone();
+    let include_paths = options.include_paths.clone();
 
     // Set up lazy template loader for includes
     env.set_loader(move |name: &str| -> Result<Option<String>, minijinja::Error> {
@@ -119,8 +437,10 @@ fn render(
             }
         }
 
-        // Resolve the template path relative to the base directory
+        // Resolve the template path relative to the base directory,
+        // falling back to the --include-path directories in order
         let resolved_path = loader_context.resolve_path(name);
+        let resolved_path = find_in_include_paths(&resolved_path, name, &include_paths);
 
         // Read the template file
         match fs::read_to_string(&resolved_path) {
@@ -135,18 +455,146 @@ fn render(
         }
     });
 
+    // Filesystem functions resolve against --base-dir when given, while
+    // includes keep resolving relative to the template's own directory
+    let function_context = match &options.base_dir {
+        Some(dir) => {
+            let dir = fs::canonicalize(dir).map_err(|e| {
+                format!(
+                    "Failed to resolve base directory '{}': {}",
+                    dir.display(),
+                    e
+                )
+            })?;
+            template_context.with_base_dir(dir)
+        }
+        None => template_context,
+    };
+
     // Register all custom functions (includes filter-functions via register_all)
-    functions::register_all(&mut env, template_context);
+    functions::register_all(&mut env, function_context);
+    if let Some(profiler) = profiler {
+        profiler.instrument(&mut env);
+    }
+    logger.phase("register functions");
+
+    // Preload shared partials; the `_globals` import is prepended on the
+    // first line so line numbers in error messages stay unchanged
+    let template_content = match &options.partials_dir {
+        Some(dir) => match partials::load_partials(&mut env, dir)? {
+            Some(globals_import) => format!("{}{}", globals_import, template_content),
+            None => template_content.to_string(),
+        },
+        None => template_content.to_string(),
+    };
 
     // Use full file path as template name if it's a file, otherwise use "template"
     let template_name = template_source.unwrap_or("template");
 
-    env.add_template(template_name, template_content)
+    // `--validate template` lints the template instead of rendering it
+    if options.validate_format == Some(ValidateFormat::Template) {
+        validator::lint_template(&env, template_name, &template_content)?;
+        logger.phase("lint");
+        return Ok(String::new());
+    }
+
+    env.add_template_owned(template_name.to_string(), template_content)
         .map_err(|e| format_minijinja_error("Failed to parse template", &e))?;
+    logger.phase("parse");
 
     let tmpl = env.get_template(template_name)?;
-    tmpl.render(context)
-        .map_err(|e| format_minijinja_error("Failed to render template", &e).into())
+    if options.strict_parse {
+        check_strict_parse(&env, &tmpl)?;
+        logger.phase("strict parse");
+    }
+    let rendered = match options.max_output_size {
+        Some(limit) => {
+            let mut writer = LimitedWriter::new(limit);
+            let result = tmpl.render_captured_to(context, &mut writer).map(|_| ());
+            if writer.exceeded {
+                return Err(format!(
+                    "Rendered output exceeds --max-output-size limit of {} bytes",
+                    limit
+                )
+                .into());
+            }
+            result.map_err(|e| format_render_error(&env, context, &e))?;
+            String::from_utf8(writer.buffer)
+                .map_err(|e| format!("Rendered output is not valid UTF-8: {}", e))?
+        }
+        None => tmpl
+            .render(context)
+            .map_err(|e| format_render_error(&env, context, &e))?,
+    };
+    logger.phase("render");
+
+    Ok(rendered)
+}
+
+/// Reject unknown functions, filters and tests before rendering (`--strict-parse`)
+#[cfg(feature = "strict-parse")]
+fn check_strict_parse(env: &Environment, tmpl: &minijinja::Template) -> Result<(), String> {
+    crate::strict_parse::check_references(env, tmpl)
+}
+
+#[cfg(not(feature = "strict-parse"))]
+fn check_strict_parse(_env: &Environment, _tmpl: &minijinja::Template) -> Result<(), String> {
+    Err(
+        "--strict-parse is not available: tmpltool was built without the 'strict-parse' feature \
+         (install with: cargo install tmpltool --features strict-parse)"
+            .to_string(),
+    )
+}
+
+/// Pick the path a template name is loaded from
+///
+/// The template's own directory wins; otherwise the first include path
+/// containing `name` is used. Falls back to `resolved` so the caller reports
+/// the primary location when the template is missing everywhere.
+fn find_in_include_paths(resolved: &Path, name: &str, include_paths: &[PathBuf]) -> PathBuf {
+    if resolved.is_file() {
+        return resolved.to_path_buf();
+    }
+    include_paths
+        .iter()
+        .map(|dir| dir.join(name))
+        .find(|candidate| candidate.is_file())
+        .unwrap_or_else(|| resolved.to_path_buf())
+}
+
+/// In-memory writer that fails once more than `limit` bytes are written
+///
+/// Used for `--max-output-size` so runaway templates are stopped as soon as
+/// they cross the limit instead of after the whole output is built.
+struct LimitedWriter {
+    buffer: Vec<u8>,
+    limit: u64,
+    exceeded: bool,
+}
+
+impl LimitedWriter {
+    fn new(limit: u64) -> Self {
+        Self {
+            buffer: Vec::new(),
+            limit,
+            exceeded: false,
+        }
+    }
+}
+
+impl Write for LimitedWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.buffer.len() as u64 + buf.len() as u64 > self.limit {
+            self.exceeded = true;
+            return Err(io::Error::other("output size limit exceeded"));
+        }
+        self.buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
 }
 
 /// Formats MiniJinja errors with detailed information
@@ -167,6 +615,28 @@ fn format_minijinja_error(prefix: &str, error: &minijinja::Error) -> String {
     msg
 }
 
+/// Formats a render error, adding a hint for unknown or undefined names
+///
+/// Unknown functions, filters and tests get a "did you mean" suggestion;
+/// undefined values list the top-level variables in the context.
+fn format_render_error(
+    env: &Environment<'_>,
+    context: &serde_json::Value,
+    error: &minijinja::Error,
+) -> String {
+    let mut msg = format_minijinja_error("Failed to render template", error);
+    let variables: Vec<&str> = context
+        .as_object()
+        .map(|map| map.keys().map(String::as_str).collect())
+        .unwrap_or_default();
+    if let Some(hint) = suggest::hint(env, error, &variables) {
+        msg.push('\n');
+        msg.push_str(&hint);
+        msg.push('\n');
+    }
+    msg
+}
+
 /// Writes the rendered content to file or stdout
 fn write_output(
     rendered: &str,
@@ -185,3 +655,91 @@ fn write_output(
     }
     Ok(())
 }
+
+/// Applies `--line-ending` and `--bom` to the final output
+fn encode_output(rendered: String, line_ending: Option<LineEnding>, bom: bool) -> String {
+    let mut output = match line_ending {
+        None => rendered,
+        Some(ending) => {
+            let unix = rendered.replace("\r\n", "\n");
+            match ending {
+                LineEnding::Lf => unix,
+                LineEnding::Crlf => unix.replace('\n', "\r\n"),
+            }
+        }
+    };
+    if bom && !output.starts_with('\u{feff}') {
+        output.insert(0, '\u{feff}');
+    }
+    output
+}
+
+/// Compares the rendered output with the existing output file (`--check`)
+///
+/// Prints a unified diff to stdout and fails when they differ; nothing is written.
+fn check_output(
+    rendered: &str,
+    output_file: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let path = output_file.ok_or("--check requires --output to name the file to compare")?;
+    let current = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            return Err(format!("'{}' does not exist (--check)", path).into());
+        }
+        Err(e) => return Err(format!("Failed to read output file '{}': {}", path, e).into()),
+    };
+
+    if current == rendered {
+        eprintln!("'{}' is up to date", path);
+        return Ok(());
+    }
+
+    let diff = TextDiff::from_lines(current.as_str(), rendered);
+    print!(
+        "{}",
+        diff.unified_diff()
+            .header(path, &format!("{} (rendered)", path))
+    );
+    io::stdout().flush()?;
+    Err(format!("'{}' is out of date (--check)", path).into())
+}
+
+/// One generated file in the `--manifest` listing
+#[derive(Serialize)]
+struct ManifestEntry<'a> {
+    /// Output path as given, or "-" for stdout
+    path: &'a str,
+    size: usize,
+    sha256: String,
+}
+
+/// Root of the `--manifest` JSON document
+#[derive(Serialize)]
+struct Manifest<'a> {
+    files: Vec<ManifestEntry<'a>>,
+}
+
+/// Writes the `--manifest` JSON listing for the rendered output
+fn write_manifest(
+    manifest_path: &Path,
+    rendered: &str,
+    output_file: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let manifest = Manifest {
+        files: vec![ManifestEntry {
+            path: output_file.unwrap_or("-"),
+            size: rendered.len(),
+            sha256: hex::encode(Sha256::digest(rendered.as_bytes())),
+        }],
+    };
+    let json = serde_json::to_string_pretty(&manifest)?;
+    fs::write(manifest_path, json + "\n").map_err(|e| {
+        format!(
+            "Failed to write manifest '{}': {}",
+            manifest_path.display(),
+            e
+        )
+    })?;
+    Ok(())
+}
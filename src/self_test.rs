@@ -0,0 +1,122 @@
+//! Render every example in the metadata registry (`--self-test`)
+//!
+//! Each `FunctionMetadata` lists example snippets for the docs and IDE
+//! integration. Rendering them all is a quick smoke test that the installed
+//! binary's functions behave as documented.
+//!
+//! Examples that need input the self-test cannot provide are skipped: those
+//! using variables the reader is expected to supply (`{{ user.name | upper }}`)
+//! and those calling functions that depend on files, commands, the network,
+//! secrets or user input.
+
+use crate::{TemplateContext, functions, get_all_metadata};
+use minijinja::{Environment, UndefinedBehavior};
+use std::collections::HashSet;
+
+/// Functions whose examples cannot run without outside input, or fail on purpose
+const SKIPPED_FUNCTIONS: &[&str] = &[
+    "abort",
+    "cache",
+    "env_to_object",
+    "exec",
+    "exec_raw",
+    "file_head",
+    "file_modified",
+    "file_size",
+    "file_tail",
+    "git",
+    "include_if_exists",
+    "list_dir",
+    "prompt",
+    "read_file",
+    "read_json_file",
+    "read_json_value",
+    "read_lines",
+    "read_toml_file",
+    "read_toml_value",
+    "read_yaml_file",
+    "read_yaml_value",
+    "resolve_dns",
+    "secret",
+    "system_info",
+];
+
+/// An example that failed to render
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExampleFailure {
+    /// Name of the function the example belongs to
+    pub function: &'static str,
+    pub example: &'static str,
+    pub error: String,
+}
+
+/// Outcome of rendering all examples
+#[derive(Debug, Default)]
+pub struct SelfTestReport {
+    pub passed: usize,
+    pub skipped: usize,
+    pub failures: Vec<ExampleFailure>,
+}
+
+impl SelfTestReport {
+    /// One-line summary, e.g. "412 passed, 131 skipped, 0 failed"
+    pub fn summary(&self) -> String {
+        format!(
+            "{} passed, {} skipped, {} failed",
+            self.passed,
+            self.skipped,
+            self.failures.len()
+        )
+    }
+}
+
+/// Render every example in the metadata registry
+///
+/// Includes resolve to empty templates, so examples that include helpers
+/// still render.
+///
+/// # Arguments
+///
+/// * `context` - Context for the registered functions (base directory, trust mode)
+pub fn run(context: TemplateContext) -> SelfTestReport {
+    let mut env = Environment::new();
+    env.set_undefined_behavior(UndefinedBehavior::Strict);
+    env.set_loader(|_| Ok(Some(String::new())));
+    functions::register_all(&mut env, context);
+    let globals: HashSet<&str> = env.globals().map(|(name, _)| name).collect();
+
+    let mut report = SelfTestReport::default();
+    for metadata in get_all_metadata() {
+        for example in metadata.examples {
+            let tmpl = match env.template_from_str(example) {
+                Ok(tmpl) => tmpl,
+                Err(e) => {
+                    report.failures.push(ExampleFailure {
+                        function: metadata.name,
+                        example,
+                        error: e.to_string(),
+                    });
+                    continue;
+                }
+            };
+
+            let needs_input = tmpl.undeclared_variables(false).iter().any(|name| {
+                !globals.contains(name.as_str()) || SKIPPED_FUNCTIONS.contains(&name.as_str())
+            });
+            if needs_input {
+                report.skipped += 1;
+                continue;
+            }
+
+            match tmpl.render(()) {
+                Ok(_) => report.passed += 1,
+                Err(e) => report.failures.push(ExampleFailure {
+                    function: metadata.name,
+                    example,
+                    error: e.to_string(),
+                }),
+            }
+        }
+    }
+    report
+}
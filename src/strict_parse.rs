@@ -0,0 +1,241 @@
+//! Parse-time reference checking for `--strict-parse` and `--validate template`
+//!
+//! MiniJinja only notices an unknown function, filter or test when the
+//! expression using it is evaluated, so a typo in a rarely taken branch
+//! slips through until that branch runs. This module walks the compiled
+//! instructions of a template and reports every referenced name that is
+//! not registered with the environment, before anything is rendered.
+//! Functions are looked up in the environment's globals; filters and tests
+//! in the names tmpltool registers (see [`suggest::filter_names`]).
+//!
+//! Only the main template is checked; included and imported templates are
+//! loaded lazily during rendering.
+//!
+//! This module uses MiniJinja's `unstable_machinery` API (compiled
+//! instructions and the lexer), which is outside MiniJinja's semver
+//! guarantees. It is only built with the `strict-parse` cargo feature.
+
+use crate::suggest;
+use crate::validator::LintIssue;
+use minijinja::machinery::{
+    Instruction, Span, Token, WhitespaceConfig, get_compiled_template, tokenize,
+};
+use minijinja::syntax::SyntaxConfig;
+use minijinja::{Environment, Template};
+use std::collections::HashSet;
+
+/// Names that templates may call without them being globals
+const IMPLICIT_CALLABLES: &[&str] = &["caller", "super", "loop"];
+
+/// Tags that open a block closed by `end<tag>`
+const BLOCK_TAGS: &[&str] = &[
+    "for",
+    "if",
+    "macro",
+    "call",
+    "filter",
+    "with",
+    "autoescape",
+    "block",
+    "set",
+];
+
+/// A function, filter or test used by a template but not registered
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownReference {
+    /// "function", "filter" or "test"
+    pub kind: &'static str,
+    pub name: String,
+    /// Template name and 1-based line, when known
+    pub template: String,
+    pub line: Option<usize>,
+    /// 1-based column, when known
+    pub column: Option<usize>,
+    /// Closest registered name, when one looks like a typo of `name`
+    pub suggestion: Option<String>,
+}
+
+impl UnknownReference {
+    /// " (did you mean 'upper'?)" when there is a suggestion, otherwise empty
+    pub fn hint(&self) -> String {
+        match &self.suggestion {
+            Some(suggestion) => format!(" (did you mean '{}'?)", suggestion),
+            None => String::new(),
+        }
+    }
+}
+
+/// Collect every function, filter and test used by `tmpl` that is missing from `env`
+///
+/// Names bound inside the template (macros, `{% set %}`, loop variables,
+/// imported macros) are accepted as functions. Each name is reported once
+/// per location.
+pub fn unknown_references(env: &Environment<'_>, tmpl: &Template<'_, '_>) -> Vec<UnknownReference> {
+    let compiled = get_compiled_template(tmpl);
+    let mut all = vec![&compiled.instructions];
+    all.extend(compiled.blocks.values());
+
+    let mut local_names = HashSet::new();
+    for instructions in &all {
+        for idx in 0..instructions.len() as u32 {
+            if let Some(Instruction::StoreLocal(name) | Instruction::Enclose(name)) =
+                instructions.get(idx)
+            {
+                local_names.insert(*name);
+            }
+        }
+    }
+
+    let globals: HashSet<&str> = env.globals().map(|(name, _)| name).collect();
+    let filters: HashSet<&str> = suggest::filter_names().into_iter().collect();
+    let tests: HashSet<&str> = suggest::test_names().into_iter().collect();
+
+    let mut found = Vec::new();
+    for instructions in &all {
+        for idx in 0..instructions.len() as u32 {
+            let (kind, name) = match instructions.get(idx) {
+                Some(Instruction::CallFunction(name, _)) => {
+                    if globals.contains(name)
+                        || local_names.contains(name)
+                        || IMPLICIT_CALLABLES.contains(name)
+                    {
+                        continue;
+                    }
+                    ("function", *name)
+                }
+                Some(Instruction::ApplyFilter(name, _, _)) if !filters.contains(name) => {
+                    ("filter", *name)
+                }
+                Some(Instruction::PerformTest(name, _, _)) if !tests.contains(name) => {
+                    ("test", *name)
+                }
+                _ => continue,
+            };
+
+            let span = instructions.get_span(idx);
+            let mut reference = UnknownReference {
+                kind,
+                name: name.to_string(),
+                template: instructions.name().to_string(),
+                line: instructions.get_line(idx),
+                column: span.map(|span| span.start_col as usize + 1),
+                suggestion: None,
+            };
+            // Report each name once per line, whatever its column
+            if !found.iter().any(|r: &UnknownReference| {
+                r.kind == reference.kind && r.name == reference.name && r.line == reference.line
+            }) {
+                reference.suggestion = suggest::suggest_name(env, kind, name);
+                found.push(reference);
+            }
+        }
+    }
+    found
+}
+
+/// Check that every function, filter and test used by `tmpl` exists in `env`
+///
+/// Names bound inside the template (macros, `{% set %}`, loop variables,
+/// imported macros) are accepted as functions.
+///
+/// # Returns
+///
+/// Returns `Ok(())` when all references resolve, or an error listing each
+/// unknown name with its template location
+pub fn check_references(env: &Environment<'_>, tmpl: &Template<'_, '_>) -> Result<(), String> {
+    let problems: Vec<String> = unknown_references(env, tmpl)
+        .iter()
+        .map(|r| {
+            let location = match r.line {
+                Some(line) => format!("{}:{}", r.template, line),
+                None => r.template.clone(),
+            };
+            format!(
+                "unknown {} '{}' at {}{}",
+                r.kind,
+                r.name,
+                location,
+                r.hint()
+            )
+        })
+        .collect();
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Strict parse failed (--strict-parse):\n  {}",
+            problems.join("\n  ")
+        ))
+    }
+}
+
+/// Find unclosed blocks and stray end tags by matching block tags
+///
+/// Used by `--validate template`, which reports these at the tag that
+/// opened the block rather than where MiniJinja's parser gives up.
+pub fn unbalanced_blocks(source: &str) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+    let mut open: Vec<(&str, Span)> = Vec::new();
+    let tokens: Vec<(Token<'_>, Span)> =
+        match tokenize(source, false, SyntaxConfig, WhitespaceConfig::default())
+            .collect::<Result<_, _>>()
+        {
+            Ok(tokens) => tokens,
+            // Lexer errors are reported when the template is compiled
+            Err(_) => return issues,
+        };
+
+    for (index, window) in tokens.windows(2).enumerate() {
+        let (Token::BlockStart, _) = window[0] else {
+            continue;
+        };
+        let (Token::Ident(tag), span) = &window[1] else {
+            continue;
+        };
+
+        if BLOCK_TAGS.contains(tag) {
+            // `{% set x = ... %}` is a statement; only `{% set x %}...{% endset %}` is a block
+            if *tag == "set" && is_assignment(&tokens[index + 2..]) {
+                continue;
+            }
+            open.push((tag, *span));
+        } else if let Some(closed) = tag.strip_prefix("end") {
+            match open.iter().rposition(|(tag, _)| *tag == closed) {
+                Some(position) => {
+                    for (tag, span) in open.drain(position + 1..) {
+                        issues.push(unclosed(tag, span));
+                    }
+                    open.pop();
+                }
+                None => issues.push(LintIssue {
+                    line: span.start_line as usize,
+                    column: span.start_col as usize + 1,
+                    message: format!(
+                        "unexpected {{% {} %}} without an open {{% {} %}}",
+                        tag, closed
+                    ),
+                }),
+            }
+        }
+    }
+
+    issues.extend(open.into_iter().map(|(tag, span)| unclosed(tag, span)));
+    issues
+}
+
+/// Whether a `set` tag assigns with `=` before the end of the tag
+fn is_assignment(tokens: &[(Token<'_>, Span)]) -> bool {
+    tokens
+        .iter()
+        .take_while(|(token, _)| !matches!(token, Token::BlockEnd))
+        .any(|(token, _)| matches!(token, Token::Assign))
+}
+
+fn unclosed(tag: &str, span: Span) -> LintIssue {
+    LintIssue {
+        line: span.start_line as usize,
+        column: span.start_col as usize + 1,
+        message: format!("unclosed {{% {} %}} (missing {{% end{} %}})", tag, tag),
+    }
+}
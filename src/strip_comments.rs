@@ -0,0 +1,145 @@
+//! Comment line removal for rendered output (`--strip-comments`)
+//!
+//! Templates often carry authoring notes that should not reach the generated
+//! file. Lines whose first non-blank text is the comment marker are removed;
+//! everything else, including comments after content on the same line, is
+//! kept as rendered.
+//!
+//! Lines inside multi-line strings are content, not comments, so they are
+//! kept where the string can be recognized line by line:
+//! - `"""` and `'''` strings (TOML, Python)
+//! - YAML block scalars (`key: |` / `key: >`) with `#` comments
+//! - backtick template literals with `//` comments
+//!
+//! A `#!` shebang on the first line is also kept.
+
+use crate::cli::CommentSyntax;
+
+/// Multi-line string the scanner is currently inside
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Block {
+    /// Inside a string closed by this delimiter
+    Quoted(&'static str),
+    /// Inside a YAML block scalar whose key line has this indentation
+    YamlScalar(usize),
+}
+
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+/// Whether a line ends with a YAML block scalar indicator (`|`, `>-`, `|2+`, ...)
+fn starts_yaml_block_scalar(line: &str) -> bool {
+    let trimmed = line.trim_end();
+    let Some(indicator_start) = trimmed.rfind(['|', '>']) else {
+        return false;
+    };
+    let (before, indicator) = trimmed.split_at(indicator_start);
+    let before = before.trim_end();
+    (before.ends_with(':') || before == "-" || before.ends_with(" -"))
+        && indicator[1..]
+            .chars()
+            .all(|c| c == '-' || c == '+' || c.is_ascii_digit())
+        && indicator[1..].len() <= 2
+}
+
+/// The delimiters that open multi-line strings for this comment syntax
+fn string_delimiters(syntax: CommentSyntax) -> &'static [&'static str] {
+    match syntax {
+        CommentSyntax::Slash => &["\"\"\"", "'''", "`"],
+        _ => &["\"\"\"", "'''"],
+    }
+}
+
+/// Track string delimiters on a line, returning the block still open at its end
+fn scan_strings(
+    line: &str,
+    mut open: Option<&'static str>,
+    delimiters: &[&'static str],
+) -> Option<&'static str> {
+    let mut rest = line;
+    loop {
+        match open {
+            Some(delimiter) => match rest.find(delimiter) {
+                Some(i) => {
+                    rest = &rest[i + delimiter.len()..];
+                    open = None;
+                }
+                None => return open,
+            },
+            None => {
+                let next = delimiters
+                    .iter()
+                    .filter_map(|d| rest.find(d).map(|i| (i, *d)))
+                    .min_by_key(|(i, d)| (*i, std::cmp::Reverse(d.len())));
+                match next {
+                    Some((i, delimiter)) => {
+                        rest = &rest[i + delimiter.len()..];
+                        open = Some(delimiter);
+                    }
+                    None => return None,
+                }
+            }
+        }
+    }
+}
+
+/// Remove comment lines from rendered output
+///
+/// Line endings of the remaining lines are preserved.
+///
+/// # Example
+///
+/// ```
+/// use tmpltool::cli::CommentSyntax;
+/// use tmpltool::strip_comments::strip_comments;
+///
+/// let output = "# generated\nport: 8080\n  # note\nhost: db # primary\n";
+/// assert_eq!(
+///     strip_comments(output, CommentSyntax::Hash),
+///     "port: 8080\nhost: db # primary\n"
+/// );
+/// ```
+pub fn strip_comments(text: &str, syntax: CommentSyntax) -> String {
+    let marker = syntax.marker();
+    let delimiters = string_delimiters(syntax);
+    let mut block: Option<Block> = None;
+    let mut out = String::with_capacity(text.len());
+
+    for (index, line) in text.split_inclusive('\n').enumerate() {
+        let content = line.trim_end_matches(['\n', '\r']);
+
+        // A block scalar ends at the first non-blank line indented no deeper than its key
+        if let Some(Block::YamlScalar(indent)) = block
+            && !content.trim().is_empty()
+            && indentation(content) <= indent
+        {
+            block = None;
+        }
+
+        match block {
+            Some(Block::YamlScalar(_)) => {
+                out.push_str(line);
+                continue;
+            }
+            Some(Block::Quoted(delimiter)) => {
+                block = scan_strings(content, Some(delimiter), delimiters).map(Block::Quoted);
+                out.push_str(line);
+                continue;
+            }
+            None => {}
+        }
+
+        let is_shebang = index == 0 && syntax == CommentSyntax::Hash && content.starts_with("#!");
+        if content.trim_start().starts_with(marker) && !is_shebang {
+            continue;
+        }
+
+        block = scan_strings(content, None, delimiters).map(Block::Quoted);
+        if block.is_none() && syntax == CommentSyntax::Hash && starts_yaml_block_scalar(content) {
+            block = Some(Block::YamlScalar(indentation(content)));
+        }
+        out.push_str(line);
+    }
+    out
+}
@@ -0,0 +1,207 @@
+//! "Did you mean" hints for unknown names in error messages
+//!
+//! MiniJinja reports unknown functions, filters and tests by name only. This
+//! module finds the closest registered name by edit distance, and for
+//! undefined values lists the variables that are available instead.
+
+use minijinja::{Environment, Error, ErrorKind};
+
+/// Filters built into MiniJinja (not in the tmpltool metadata registry)
+const BUILTIN_FILTERS: &[&str] = &[
+    "abs",
+    "attr",
+    "batch",
+    "bool",
+    "capitalize",
+    "chain",
+    "count",
+    "default",
+    "dictsort",
+    "escape",
+    "first",
+    "float",
+    "format",
+    "groupby",
+    "indent",
+    "int",
+    "items",
+    "join",
+    "last",
+    "length",
+    "lines",
+    "list",
+    "lower",
+    "map",
+    "max",
+    "min",
+    "pprint",
+    "reject",
+    "rejectattr",
+    "replace",
+    "reverse",
+    "round",
+    "safe",
+    "select",
+    "selectattr",
+    "slice",
+    "sort",
+    "split",
+    "string",
+    "sum",
+    "title",
+    "tojson",
+    "trim",
+    "unique",
+    "upper",
+    "urlencode",
+    "zip",
+];
+
+/// Tests built into MiniJinja (not in the tmpltool metadata registry)
+const BUILTIN_TESTS: &[&str] = &[
+    "boolean",
+    "defined",
+    "divisibleby",
+    "endingwith",
+    "eq",
+    "escaped",
+    "even",
+    "false",
+    "filter",
+    "float",
+    "ge",
+    "gt",
+    "in",
+    "integer",
+    "iterable",
+    "le",
+    "lower",
+    "lt",
+    "mapping",
+    "ne",
+    "none",
+    "number",
+    "odd",
+    "sameas",
+    "sequence",
+    "startingwith",
+    "string",
+    "test",
+    "true",
+    "undefined",
+    "upper",
+];
+
+/// At most this many variable names are listed for an undefined value
+const MAX_LISTED_VARIABLES: usize = 20;
+
+/// Levenshtein distance between two strings, counted in characters
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// The candidate closest to `name`, if it is close enough to be a likely typo
+///
+/// # Example
+///
+/// ```
+/// use tmpltool::suggest::did_you_mean;
+///
+/// assert_eq!(did_you_mean("uppr", ["upper", "lower"]), Some("upper".to_string()));
+/// assert_eq!(did_you_mean("xyz", ["upper", "lower"]), None);
+/// ```
+pub fn did_you_mean<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<String> {
+    let limit = (name.chars().count() / 3).max(1);
+    candidates
+        .into_iter()
+        .filter(|candidate| *candidate != name)
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= limit)
+        .min()
+        .map(|(_, candidate)| candidate.to_string())
+}
+
+/// Names of every filter in a tmpltool environment
+///
+/// MiniJinja cannot list the filters of an environment, so this is built
+/// from its builtins and the filter-capable entries of the metadata registry.
+pub fn filter_names() -> Vec<&'static str> {
+    let registered = crate::get_all_metadata()
+        .into_iter()
+        .filter(|m| m.syntax.filter)
+        .map(|m| m.name);
+    BUILTIN_FILTERS.iter().copied().chain(registered).collect()
+}
+
+/// Names of every test in a tmpltool environment (see [`filter_names`])
+///
+/// Is-functions are registered as tests without their `is_` prefix
+/// (`is_email` is used as `x is email`).
+pub fn test_names() -> Vec<&'static str> {
+    let registered = crate::get_all_metadata()
+        .into_iter()
+        .filter(|m| m.syntax.is_test)
+        .map(|m| m.name.strip_prefix("is_").unwrap_or(m.name));
+    BUILTIN_TESTS.iter().copied().chain(registered).collect()
+}
+
+/// Suggest a registered name for an unknown function, filter or test
+///
+/// `kind` is "function", "filter" or "test".
+pub fn suggest_name(env: &Environment<'_>, kind: &str, name: &str) -> Option<String> {
+    match kind {
+        "function" => did_you_mean(name, env.globals().map(|(global, _)| global)),
+        "filter" => did_you_mean(name, filter_names()),
+        "test" => did_you_mean(name, test_names()),
+        _ => None,
+    }
+}
+
+/// Build a hint for a render error, if one applies
+///
+/// Unknown functions, filters and tests get the closest registered name;
+/// undefined values list the available top-level variables.
+pub fn hint(env: &Environment<'_>, error: &Error, variables: &[&str]) -> Option<String> {
+    let kind = match error.kind() {
+        ErrorKind::UnknownFunction => "function",
+        ErrorKind::UnknownFilter => "filter",
+        ErrorKind::UnknownTest => "test",
+        ErrorKind::UndefinedError => return Some(list_variables(variables)),
+        _ => return None,
+    };
+    // The detail is "uppr is unknown", "filter uppr is unknown" or "test evn is unknown"
+    let detail = error.detail()?;
+    let name = detail.strip_suffix(" is unknown")?.rsplit(' ').next()?;
+    suggest_name(env, kind, name).map(|suggestion| format!("Did you mean '{}'?", suggestion))
+}
+
+fn list_variables(variables: &[&str]) -> String {
+    if variables.is_empty() {
+        return "No variables are defined; use get_env() for environment variables".to_string();
+    }
+    let mut names = variables.to_vec();
+    names.sort_unstable();
+    let listed = names
+        .iter()
+        .take(MAX_LISTED_VARIABLES)
+        .copied()
+        .collect::<Vec<_>>()
+        .join(", ");
+    match names.len().checked_sub(MAX_LISTED_VARIABLES) {
+        Some(more) if more > 0 => format!("Available variables: {} (and {} more)", listed, more),
+        _ => format!("Available variables: {}", listed),
+    }
+}
@@ -1,9 +1,14 @@
-//! Output format validation
+//! Output format validation and template linting
 //!
 //! This module provides validation for rendered template output to ensure
-//! it conforms to the expected format (JSON, YAML, or TOML).
+//! it conforms to the expected format (JSON, YAML, or TOML), and a linter
+//! for templates themselves (`--validate template`) that reports every
+//! problem it finds with its line and column instead of stopping at the first.
 
 use crate::cli::ValidateFormat;
+#[cfg(feature = "strict-parse")]
+use crate::strict_parse;
+use minijinja::{Environment, Template};
 
 /// Validate output string against the specified format
 ///
@@ -33,6 +38,8 @@ pub fn validate_output(output: &str, format: ValidateFormat) -> Result<(), Strin
         ValidateFormat::Json => validate_json(output),
         ValidateFormat::Yaml => validate_yaml(output),
         ValidateFormat::Toml => validate_toml(output),
+        // Templates are checked before rendering, see lint_template
+        ValidateFormat::Template => Ok(()),
     }
 }
 
@@ -84,3 +91,109 @@ fn validate_toml(output: &str) -> Result<(), String> {
     })?;
     Ok(())
 }
+
+/// A problem found by [`lint_template`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintIssue {
+    /// 1-based line
+    pub line: usize,
+    /// 1-based column
+    pub column: usize,
+    pub message: String,
+}
+
+/// Lint a template without rendering it
+///
+/// Reports, each with line and column:
+/// - unclosed blocks (at the tag that opened them) and unexpected end tags
+/// - other syntax errors
+/// - unknown functions, filters and tests, including calls to undefined macros
+///
+/// Blocks and names are only checked with the `strict-parse` feature.
+///
+/// # Returns
+///
+/// Returns `Ok(())` when no issues were found, or an error listing all of them
+pub fn lint_template(env: &Environment<'_>, name: &str, source: &str) -> Result<(), String> {
+    let mut issues = block_issues(source);
+
+    match env.template_from_named_str(name, source) {
+        Ok(tmpl) => issues.extend(reference_issues(env, &tmpl)),
+        // An unbalanced block is already reported where it was opened
+        Err(_) if !issues.is_empty() => {}
+        Err(e) => {
+            let (line, column) = match e.range() {
+                Some(range) => line_column(source, range.start),
+                None => (e.line().unwrap_or(1), 1),
+            };
+            let message = match e.detail() {
+                Some(detail) => format!("{}: {}", e.kind(), detail),
+                None => e.kind().to_string(),
+            };
+            issues.push(LintIssue {
+                line,
+                column,
+                message,
+            });
+        }
+    }
+
+    if issues.is_empty() {
+        return Ok(());
+    }
+    issues.sort_by_key(|issue| (issue.line, issue.column));
+    let lines: Vec<String> = issues
+        .iter()
+        .map(|issue| {
+            format!(
+                "{}:{}:{}: {}",
+                name, issue.line, issue.column, issue.message
+            )
+        })
+        .collect();
+    Err(format!(
+        "Template validation failed with {} issue(s):\n  {}",
+        issues.len(),
+        lines.join("\n  ")
+    ))
+}
+
+/// Unclosed blocks and stray end tags
+#[cfg(feature = "strict-parse")]
+fn block_issues(source: &str) -> Vec<LintIssue> {
+    strict_parse::unbalanced_blocks(source)
+}
+
+/// Block matching needs the `strict-parse` feature; without it an unbalanced
+/// block is reported as the syntax error MiniJinja raises for it
+#[cfg(not(feature = "strict-parse"))]
+fn block_issues(_source: &str) -> Vec<LintIssue> {
+    Vec::new()
+}
+
+/// Unknown functions, filters and tests
+#[cfg(feature = "strict-parse")]
+fn reference_issues(env: &Environment<'_>, tmpl: &Template<'_, '_>) -> Vec<LintIssue> {
+    strict_parse::unknown_references(env, tmpl)
+        .into_iter()
+        .map(|r| LintIssue {
+            line: r.line.unwrap_or(1),
+            column: r.column.unwrap_or(1),
+            message: format!("unknown {} '{}'{}", r.kind, r.name, r.hint()),
+        })
+        .collect()
+}
+
+/// Reference checking needs the `strict-parse` feature
+#[cfg(not(feature = "strict-parse"))]
+fn reference_issues(_env: &Environment<'_>, _tmpl: &Template<'_, '_>) -> Vec<LintIssue> {
+    Vec::new()
+}
+
+/// 1-based line and column of a byte offset
+fn line_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset.min(source.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+    (line, column)
+}
@@ -1,7 +1,7 @@
 use minijinja::Value;
 use minijinja::value::Kwargs;
 use tmpltool::functions::Function;
-use tmpltool::functions::array::{ArrayGroupBy, ArraySortBy};
+use tmpltool::functions::array::{ArrayGroupBy, ArrayIndexBy, ArraySortBy};
 
 // ============================================================================
 // Array Sort By Tests
@@ -113,6 +113,99 @@ fn test_array_sort_by_missing_key_param() {
 // Array Group By Tests
 // ============================================================================
 
+#[test]
+fn test_array_index_by_basic() {
+    let services = serde_json::json!([
+        {"name": "api", "port": 8080},
+        {"name": "db", "port": 5432}
+    ]);
+
+    let result = ArrayIndexBy::call(Kwargs::from_iter(vec![
+        ("array", Value::from_serialize(&services)),
+        ("key", Value::from("name")),
+    ]))
+    .unwrap();
+
+    let json_result: serde_json::Value = serde_json::to_value(&result).unwrap();
+    assert_eq!(
+        json_result,
+        serde_json::json!({
+            "api": {"name": "api", "port": 8080},
+            "db": {"name": "db", "port": 5432}
+        })
+    );
+}
+
+#[test]
+fn test_array_index_by_duplicates_keep_last_and_first() {
+    let items = serde_json::json!([
+        {"env": "prod", "version": 1},
+        {"env": "prod", "version": 2}
+    ]);
+
+    let last = ArrayIndexBy::call(Kwargs::from_iter(vec![
+        ("array", Value::from_serialize(&items)),
+        ("key", Value::from("env")),
+    ]))
+    .unwrap();
+    let last: serde_json::Value = serde_json::to_value(&last).unwrap();
+    assert_eq!(last["prod"]["version"], 2);
+
+    let first = ArrayIndexBy::call(Kwargs::from_iter(vec![
+        ("array", Value::from_serialize(&items)),
+        ("key", Value::from("env")),
+        ("keep", Value::from("first")),
+    ]))
+    .unwrap();
+    let first: serde_json::Value = serde_json::to_value(&first).unwrap();
+    assert_eq!(first["prod"]["version"], 1);
+}
+
+#[test]
+fn test_array_index_by_nested_key_skips_missing() {
+    let items = serde_json::json!([
+        {"meta": {"id": 7}},
+        {"meta": {}},
+        {"other": true},
+        {"meta": {"id": null}}
+    ]);
+
+    let result = ArrayIndexBy::call(Kwargs::from_iter(vec![
+        ("array", Value::from_serialize(&items)),
+        ("key", Value::from("meta.id")),
+    ]))
+    .unwrap();
+
+    let json_result: serde_json::Value = serde_json::to_value(&result).unwrap();
+    assert_eq!(json_result, serde_json::json!({"7": {"meta": {"id": 7}}}));
+}
+
+#[test]
+fn test_array_index_by_invalid_keep() {
+    let result = ArrayIndexBy::call(Kwargs::from_iter(vec![
+        ("array", Value::from_serialize(serde_json::json!([]))),
+        ("key", Value::from("name")),
+        ("keep", Value::from("middle")),
+    ]));
+
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("invalid keep 'middle'")
+    );
+}
+
+#[test]
+fn test_array_index_by_requires_array() {
+    let result = ArrayIndexBy::call(Kwargs::from_iter(vec![
+        ("array", Value::from("not an array")),
+        ("key", Value::from("name")),
+    ]));
+
+    assert!(result.is_err());
+}
+
 #[test]
 fn test_array_group_by_basic() {
     let users = serde_json::json!([
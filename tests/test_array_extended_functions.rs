@@ -11,6 +11,15 @@ fn render_template(template: &str) -> String {
     tmpl.render(()).unwrap()
 }
 
+fn render_template_err(template: &str) -> String {
+    let mut env = Environment::new();
+    let ctx = TemplateContext::new(PathBuf::from("."), false);
+    register_all(&mut env, ctx);
+
+    let tmpl = env.template_from_str(template).unwrap();
+    tmpl.render(()).unwrap_err().to_string()
+}
+
 // ==================== array_take Tests ====================
 
 #[test]
@@ -818,3 +827,156 @@ fn test_array_symmetric_difference_empty_second() {
         render_template(r#"{{ array_symmetric_difference(array1=[1,2], array2=[]) | tojson }}"#);
     assert_eq!(result, "[1,2]");
 }
+
+// ==================== array_fill Tests ====================
+
+#[test]
+fn test_array_fill_basic() {
+    let result = render_template(r#"{{ array_fill(value="x", count=3) | tojson }}"#);
+    assert_eq!(result, r#"["x","x","x"]"#);
+}
+
+#[test]
+fn test_array_fill_object_value() {
+    let result = render_template(r#"{{ array_fill(value={"a": 1}, count=2) | tojson }}"#);
+    assert_eq!(result, r#"[{"a":1},{"a":1}]"#);
+}
+
+#[test]
+fn test_array_fill_zero_count() {
+    let result = render_template(r#"{{ array_fill(value=1, count=0) | tojson }}"#);
+    assert_eq!(result, "[]");
+}
+
+#[test]
+fn test_array_fill_negative_count() {
+    let err = render_template_err(r#"{{ array_fill(value=1, count=-1) }}"#);
+    assert!(err.contains("count must be non-negative"));
+}
+
+#[test]
+fn test_array_fill_count_limit() {
+    let result = render_template(r#"{{ array_fill(value=1, count=100000) | length }}"#);
+    assert_eq!(result, "100000");
+    let err = render_template_err(r#"{{ array_fill(value=1, count=100001) }}"#);
+    assert!(err.contains("array_fill count must be at most 100000, got 100001"));
+}
+
+// ==================== array_zeros Tests ====================
+
+#[test]
+fn test_array_zeros_basic() {
+    let result = render_template(r#"{{ array_zeros(count=4) | tojson }}"#);
+    assert_eq!(result, "[0,0,0,0]");
+}
+
+#[test]
+fn test_array_zeros_sum() {
+    let result = render_template(r#"{{ array_zeros(count=3) | sum }}"#);
+    assert_eq!(result, "0");
+}
+
+#[test]
+fn test_array_zeros_negative_count() {
+    let err = render_template_err(r#"{{ array_zeros(count=-5) }}"#);
+    assert!(err.contains("array_zeros count must be non-negative, got -5"));
+}
+
+#[test]
+fn test_array_zeros_count_limit() {
+    let err = render_template_err(r#"{{ array_zeros(count=9223372036854775807) }}"#);
+    assert!(err.contains("array_zeros count must be at most 100000"));
+}
+
+// ==================== transpose Tests ====================
+
+#[test]
+fn test_transpose_basic() {
+    let result = render_template(r#"{{ transpose(matrix=[[1, 2, 3], [4, 5, 6]]) | tojson }}"#);
+    assert_eq!(result, "[[1,4],[2,5],[3,6]]");
+}
+
+#[test]
+fn test_transpose_empty() {
+    let result = render_template(r#"{{ transpose(matrix=[]) | tojson }}"#);
+    assert_eq!(result, "[]");
+}
+
+#[test]
+fn test_transpose_ragged_errors() {
+    let err = render_template_err(r#"{{ transpose(matrix=[[1, 2], [3]]) }}"#);
+    assert!(err.contains("row 1 has 1 items, expected 2"));
+}
+
+#[test]
+fn test_transpose_ragged_pad_none() {
+    let result = render_template(r#"{{ transpose(matrix=[[1, 2], [3]], pad=none) | tojson }}"#);
+    assert_eq!(result, "[[1,3],[2,null]]");
+}
+
+#[test]
+fn test_transpose_ragged_pad_value() {
+    let result = render_template(r#"{{ transpose(matrix=[[1], [2, 3]], pad=0) | tojson }}"#);
+    assert_eq!(result, "[[1,2],[0,3]]");
+}
+
+#[test]
+fn test_transpose_non_array_row() {
+    let err = render_template_err(r#"{{ transpose(matrix=[[1], 2]) }}"#);
+    assert!(err.contains("every row to be an array"));
+}
+
+// ==================== array_window Tests ====================
+
+#[test]
+fn test_array_window_overlapping() {
+    let result = render_template(r#"{{ array_window(array=[1, 2, 3, 4], size=2) | tojson }}"#);
+    assert_eq!(result, "[[1,2],[2,3],[3,4]]");
+}
+
+#[test]
+fn test_array_window_step_equal_to_size() {
+    let result =
+        render_template(r#"{{ array_window(array=[1, 2, 3, 4, 5], size=2, step=2) | tojson }}"#);
+    assert_eq!(result, "[[1,2],[3,4]]");
+}
+
+#[test]
+fn test_array_window_strided() {
+    let result = render_template(
+        r#"{{ array_window(array=[1, 2, 3, 4, 5, 6, 7], size=2, step=3) | tojson }}"#,
+    );
+    assert_eq!(result, "[[1,2],[4,5]]");
+}
+
+#[test]
+fn test_array_window_shorter_than_size() {
+    let result = render_template(r#"{{ array_window(array=[1, 2], size=3) | tojson }}"#);
+    assert_eq!(result, "[]");
+}
+
+#[test]
+fn test_array_window_adjacent_pairs_in_loop() {
+    let result = render_template(
+        r#"{% for a, b in array_window(array=["v1", "v2", "v3"], size=2) %}{{ a }}->{{ b }} {% endfor %}"#,
+    );
+    assert_eq!(result, "v1->v2 v2->v3 ");
+}
+
+#[test]
+fn test_array_window_zero_size() {
+    let err = render_template_err(r#"{{ array_window(array=[1, 2], size=0) }}"#);
+    assert!(err.contains("size must be greater than 0"));
+}
+
+#[test]
+fn test_array_window_zero_step() {
+    let err = render_template_err(r#"{{ array_window(array=[1, 2], size=1, step=0) }}"#);
+    assert!(err.contains("step must be greater than 0"));
+}
+
+#[test]
+fn test_array_window_not_array() {
+    let err = render_template_err(r#"{{ array_window(array="abc", size=1) }}"#);
+    assert!(err.contains("requires an array"));
+}
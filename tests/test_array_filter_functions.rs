@@ -2,13 +2,17 @@
 //!
 //! Tests both function and filter syntax for:
 //! - array_sum, array_avg, array_median, array_min, array_max
+//! - array_variance, array_stddev, array_percentile, array_stats
+//! - array_running_total, array_scan
 //! - array_unique, array_flatten
+//! - filter_glob
 
 use minijinja::Value;
 use minijinja::value::Kwargs;
 use tmpltool::filter_functions::FilterFunction;
 use tmpltool::filter_functions::array::{
-    ArrayAvg, ArrayFlatten, ArrayMax, ArrayMedian, ArrayMin, ArraySum, ArrayUnique,
+    ArrayAvg, ArrayFlatten, ArrayMax, ArrayMedian, ArrayMin, ArrayPercentile, ArrayRunningTotal,
+    ArrayScan, ArrayStats, ArrayStddev, ArraySum, ArrayUnique, ArrayVariance, FilterGlob,
 };
 
 /// Helper to create empty kwargs
@@ -225,6 +229,269 @@ fn test_array_max_error_not_array() {
     assert!(result.is_err());
 }
 
+// ============================================
+// ArrayVariance tests
+// ============================================
+
+#[test]
+fn test_array_variance_population() {
+    let array = make_array(vec![2, 4, 4, 4, 5, 5, 7, 9]);
+    let result = ArrayVariance::call_as_filter(&array, empty_kwargs()).unwrap();
+    assert_eq!(f64::try_from(result).unwrap(), 4.0);
+}
+
+#[test]
+fn test_array_variance_sample() {
+    let array = make_array(vec![1, 2, 3, 4]);
+    let kwargs = Kwargs::from_iter(vec![("array", array), ("sample", Value::from(true))]);
+    let result = ArrayVariance::call_as_function(kwargs).unwrap();
+    let variance = f64::try_from(result).unwrap();
+    assert!((variance - 5.0 / 3.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_array_variance_sample_single_value() {
+    let kwargs = Kwargs::from_iter(vec![("sample", Value::from(true))]);
+    let result = ArrayVariance::call_as_filter(&make_array(vec![1]), kwargs);
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("at least 2 values")
+    );
+}
+
+#[test]
+fn test_array_variance_empty() {
+    let result = ArrayVariance::call_as_filter(&make_array(vec![]), empty_kwargs());
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("requires a non-empty array")
+    );
+}
+
+// ============================================
+// ArrayStddev tests
+// ============================================
+
+#[test]
+fn test_array_stddev_filter_syntax() {
+    let array = make_array(vec![2, 4, 4, 4, 5, 5, 7, 9]);
+    let result = ArrayStddev::call_as_filter(&array, empty_kwargs()).unwrap();
+    assert_eq!(f64::try_from(result).unwrap(), 2.0);
+}
+
+#[test]
+fn test_array_stddev_function_syntax_returns_float() {
+    let kwargs = Kwargs::from_iter(vec![("array", make_array(vec![5, 5, 5]))]);
+    let result = ArrayStddev::call_as_function(kwargs).unwrap();
+    assert_eq!(result.to_string(), "0.0");
+}
+
+#[test]
+fn test_array_stddev_error_not_array() {
+    let result = ArrayStddev::call_as_filter(&Value::from("abc"), empty_kwargs());
+    assert!(result.is_err());
+}
+
+// ============================================
+// ArrayPercentile tests
+// ============================================
+
+fn percentile(items: Vec<i64>, p: f64) -> f64 {
+    let kwargs = Kwargs::from_iter(vec![("p", Value::from(p))]);
+    let result = ArrayPercentile::call_as_filter(&make_array(items), kwargs).unwrap();
+    f64::try_from(result).unwrap()
+}
+
+#[test]
+fn test_array_percentile_interpolates() {
+    assert_eq!(percentile(vec![1, 2, 3, 4], 50.0), 2.5);
+    assert!((percentile(vec![10, 20, 30, 40, 50], 95.0) - 48.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_array_percentile_bounds() {
+    assert_eq!(percentile(vec![3, 1, 2], 0.0), 1.0);
+    assert_eq!(percentile(vec![3, 1, 2], 100.0), 3.0);
+}
+
+#[test]
+fn test_array_percentile_single_value() {
+    assert_eq!(percentile(vec![7], 99.0), 7.0);
+}
+
+#[test]
+fn test_array_percentile_function_syntax() {
+    let kwargs = Kwargs::from_iter(vec![
+        ("array", make_array(vec![1, 2, 3, 4, 5])),
+        ("p", Value::from(25)),
+    ]);
+    let result = ArrayPercentile::call_as_function(kwargs).unwrap();
+    assert_eq!(f64::try_from(result).unwrap(), 2.0);
+}
+
+#[test]
+fn test_array_percentile_out_of_range() {
+    let kwargs = Kwargs::from_iter(vec![("p", Value::from(101))]);
+    let result = ArrayPercentile::call_as_filter(&make_array(vec![1, 2]), kwargs);
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("must be between 0 and 100")
+    );
+}
+
+#[test]
+fn test_array_percentile_missing_p() {
+    let result = ArrayPercentile::call_as_filter(&make_array(vec![1, 2]), empty_kwargs());
+    assert!(result.is_err());
+}
+
+// ============================================
+// ArrayStats tests
+// ============================================
+
+#[test]
+fn test_array_stats_filter_syntax() {
+    let array = make_array(vec![2, 4, 4, 4, 5, 5, 7, 9]);
+    let stats = ArrayStats::call_as_filter(&array, empty_kwargs()).unwrap();
+    assert_eq!(stats.get_attr("count").unwrap(), Value::from(8));
+    assert_eq!(stats.get_attr("sum").unwrap(), Value::from(40));
+    assert_eq!(stats.get_attr("min").unwrap(), Value::from(2));
+    assert_eq!(stats.get_attr("max").unwrap(), Value::from(9));
+    assert_eq!(stats.get_attr("mean").unwrap(), Value::from(5));
+    assert_eq!(stats.get_attr("median").unwrap(), Value::from(4.5));
+    assert_eq!(stats.get_attr("stddev").unwrap(), Value::from(2.0));
+}
+
+#[test]
+fn test_array_stats_matches_individual_functions() {
+    let array = Value::from_iter([1.5, 3.25, 8.0, 2.75, 10.5].map(Value::from));
+    let stats = ArrayStats::call_as_filter(&array, empty_kwargs()).unwrap();
+    assert_eq!(
+        stats.get_attr("mean").unwrap(),
+        ArrayAvg::call_as_filter(&array, empty_kwargs()).unwrap()
+    );
+    assert_eq!(
+        stats.get_attr("median").unwrap(),
+        ArrayMedian::call_as_filter(&array, empty_kwargs()).unwrap()
+    );
+
+    let sample = || Kwargs::from_iter(vec![("sample", Value::from(true))]);
+    let stddev = f64::try_from(stats.get_attr("stddev").unwrap()).unwrap();
+    let expected =
+        f64::try_from(ArrayStddev::call_as_filter(&array, empty_kwargs()).unwrap()).unwrap();
+    assert!((stddev - expected).abs() < 1e-12);
+
+    let stats = ArrayStats::call_as_filter(&array, sample()).unwrap();
+    let stddev = f64::try_from(stats.get_attr("stddev").unwrap()).unwrap();
+    let expected = f64::try_from(ArrayStddev::call_as_filter(&array, sample()).unwrap()).unwrap();
+    assert!((stddev - expected).abs() < 1e-12);
+}
+
+#[test]
+fn test_array_stats_function_syntax() {
+    let kwargs = Kwargs::from_iter(vec![("array", make_array(vec![3, 1, 2]))]);
+    let stats = ArrayStats::call_as_function(kwargs).unwrap();
+    assert_eq!(stats.get_attr("count").unwrap(), Value::from(3));
+    assert_eq!(stats.get_attr("median").unwrap(), Value::from(2));
+}
+
+#[test]
+fn test_array_stats_empty() {
+    let stats = ArrayStats::call_as_filter(&make_array(vec![]), empty_kwargs()).unwrap();
+    assert_eq!(stats.get_attr("count").unwrap(), Value::from(0));
+    assert_eq!(stats.get_attr("sum").unwrap(), Value::from(0));
+    assert!(stats.get_attr("mean").unwrap().is_none());
+    assert!(stats.get_attr("stddev").unwrap().is_none());
+}
+
+#[test]
+fn test_array_stats_errors() {
+    let result = ArrayStats::call_as_filter(&Value::from("nope"), empty_kwargs());
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("requires an array")
+    );
+
+    let mixed = Value::from_iter([Value::from(1), Value::from("x")]);
+    let result = ArrayStats::call_as_filter(&mixed, empty_kwargs());
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("requires numeric values")
+    );
+}
+
+// ============================================
+// ArrayRunningTotal / ArrayScan tests
+// ============================================
+
+#[test]
+fn test_array_running_total_filter_syntax() {
+    let array = make_array(vec![10, 20, 5, 15]);
+    let result = ArrayRunningTotal::call_as_filter(&array, empty_kwargs()).unwrap();
+    assert_eq!(result.to_string(), "[10, 30, 35, 50]");
+}
+
+#[test]
+fn test_array_running_total_function_syntax() {
+    let kwargs = Kwargs::from_iter(vec![(
+        "array",
+        Value::from_iter([Value::from(0.5), Value::from(1), Value::from(1.5)]),
+    )]);
+    let result = ArrayRunningTotal::call_as_function(kwargs).unwrap();
+    assert_eq!(result.to_string(), "[0.5, 1.5, 3]");
+}
+
+#[test]
+fn test_array_running_total_empty() {
+    let result = ArrayRunningTotal::call_as_filter(&make_array(vec![]), empty_kwargs()).unwrap();
+    assert_eq!(result.to_string(), "[]");
+}
+
+#[test]
+fn test_array_scan_ops() {
+    let array = make_array(vec![3, 1, 4, 1, 5]);
+    let scan = |op: &str| {
+        ArrayScan::call_as_filter(&array, Kwargs::from_iter(vec![("op", Value::from(op))]))
+            .unwrap()
+            .to_string()
+    };
+    assert_eq!(scan("sum"), "[3, 4, 8, 9, 14]");
+    assert_eq!(scan("product"), "[3, 3, 12, 12, 60]");
+    assert_eq!(scan("max"), "[3, 3, 4, 4, 5]");
+    assert_eq!(scan("min"), "[3, 1, 1, 1, 1]");
+}
+
+#[test]
+fn test_array_scan_defaults_to_sum() {
+    let kwargs = Kwargs::from_iter(vec![("array", make_array(vec![1, 2, 3]))]);
+    let result = ArrayScan::call_as_function(kwargs).unwrap();
+    assert_eq!(result.to_string(), "[1, 3, 6]");
+}
+
+#[test]
+fn test_array_scan_errors() {
+    let kwargs = Kwargs::from_iter(vec![("op", Value::from("avg"))]);
+    let err = ArrayScan::call_as_filter(&make_array(vec![1]), kwargs).unwrap_err();
+    assert!(err.to_string().contains("invalid op 'avg'"));
+
+    let mixed = Value::from_iter([Value::from(1), Value::from("x")]);
+    let err = ArrayScan::call_as_filter(&mixed, empty_kwargs()).unwrap_err();
+    assert!(err.to_string().contains("requires numeric values"));
+
+    let err = ArrayRunningTotal::call_as_filter(&Value::from(5), empty_kwargs()).unwrap_err();
+    assert!(err.to_string().contains("requires an array"));
+}
+
 // ============================================
 // ArrayUnique tests
 // ============================================
@@ -328,3 +595,58 @@ fn test_array_flatten_error_not_array() {
     let result = ArrayFlatten::call_as_filter(&Value::from(123), empty_kwargs());
     assert!(result.is_err());
 }
+
+// ============================================
+// FilterGlob tests
+// ============================================
+
+/// Helper to create an array of strings
+fn make_strings(items: &[&str]) -> Value {
+    Value::from_iter(items.iter().map(|s| Value::from(*s)))
+}
+
+#[test]
+fn test_filter_glob_filter_syntax() {
+    let files = make_strings(&["app.yaml", "db.yml", "README.md", "prod.yaml"]);
+    let kwargs = Kwargs::from_iter(vec![("pattern", Value::from("*.yaml"))]);
+    let result = FilterGlob::call_as_filter(&files, kwargs).unwrap();
+    assert_eq!(result.to_string(), r#"["app.yaml", "prod.yaml"]"#);
+}
+
+#[test]
+fn test_filter_glob_function_syntax_invert() {
+    let kwargs = Kwargs::from_iter(vec![
+        ("array", make_strings(&["a.txt", "a.bak", "b.bak"])),
+        ("pattern", Value::from("*.bak")),
+        ("invert", Value::from(true)),
+    ]);
+    let result = FilterGlob::call_as_function(kwargs).unwrap();
+    assert_eq!(result.to_string(), r#"["a.txt"]"#);
+}
+
+#[test]
+fn test_filter_glob_separator_handling() {
+    let paths = make_strings(&["main.rs", "src/lib.rs", "src/bin/cli.rs"]);
+    let kwargs = Kwargs::from_iter(vec![("pattern", Value::from("src/*.rs"))]);
+    let result = FilterGlob::call_as_filter(&paths, kwargs).unwrap();
+    assert_eq!(result.to_string(), r#"["src/lib.rs"]"#);
+
+    let kwargs = Kwargs::from_iter(vec![("pattern", Value::from("src/**/*.rs"))]);
+    let result = FilterGlob::call_as_filter(&paths, kwargs).unwrap();
+    assert_eq!(result.to_string(), r#"["src/lib.rs", "src/bin/cli.rs"]"#);
+}
+
+#[test]
+fn test_filter_glob_errors() {
+    let kwargs = Kwargs::from_iter(vec![("pattern", Value::from("[a"))]);
+    let err = FilterGlob::call_as_filter(&make_strings(&["a"]), kwargs).unwrap_err();
+    assert!(err.to_string().contains("invalid pattern '[a'"));
+
+    let kwargs = Kwargs::from_iter(vec![("pattern", Value::from("*"))]);
+    let err = FilterGlob::call_as_filter(&make_array(vec![1, 2]), kwargs).unwrap_err();
+    assert!(err.to_string().contains("requires an array of strings"));
+
+    let kwargs = Kwargs::from_iter(vec![("pattern", Value::from("*"))]);
+    let err = FilterGlob::call_as_filter(&Value::from("a"), kwargs).unwrap_err();
+    assert!(err.to_string().contains("requires an array"));
+}
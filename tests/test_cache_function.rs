@@ -0,0 +1,142 @@
+//! Tests for the cache() disk cache
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    Command::cargo_bin("tmpltool").unwrap()
+}
+
+fn render(dir: &TempDir, template: &str, trust: bool) -> Command {
+    let path = dir.path().join("main.tmpltool");
+    fs::write(&path, template).unwrap();
+    let mut cmd = tmpltool();
+    cmd.arg(&path);
+    if trust {
+        cmd.arg("--trust");
+    }
+    cmd
+}
+
+fn cache_files(dir: &TempDir) -> Vec<std::path::PathBuf> {
+    match fs::read_dir(dir.path().join(".tmpltool-cache")) {
+        Ok(entries) => entries.map(|e| e.unwrap().path()).collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+#[test]
+fn test_cache_computes_and_stores_in_trust_mode() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("data.json"), r#"{"region": "eu"}"#).unwrap();
+
+    render(
+        &dir,
+        r#"{{ cache(compute="read_json_file", path="data.json").region }}"#,
+        true,
+    )
+    .assert()
+    .success()
+    .stdout("eu");
+
+    let files = cache_files(&dir);
+    assert_eq!(files.len(), 1);
+    let entry: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&files[0]).unwrap()).unwrap();
+    assert_eq!(entry["key"], r#"read_json_file({"path":"data.json"})"#);
+    assert_eq!(entry["value"]["region"], "eu");
+}
+
+#[test]
+fn test_cache_hit_does_not_recompute() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("data.json"), r#"{"region": "eu"}"#).unwrap();
+    let template = r#"{{ cache(key="data", compute="read_json_file", path="data.json").region }}"#;
+
+    render(&dir, template, true).assert().success().stdout("eu");
+    fs::write(dir.path().join("data.json"), r#"{"region": "us"}"#).unwrap();
+    render(&dir, template, true).assert().success().stdout("eu");
+}
+
+#[test]
+fn test_cache_hit_without_trust() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("data.json"), r#"{"region": "eu"}"#).unwrap();
+    let template = r#"{{ cache(key="data", compute="read_json_file", path="data.json").region }}"#;
+
+    render(&dir, template, true).assert().success();
+    render(&dir, template, false)
+        .assert()
+        .success()
+        .stdout("eu");
+}
+
+#[test]
+fn test_cache_miss_without_trust_errors() {
+    let dir = TempDir::new().unwrap();
+
+    render(
+        &dir,
+        r#"{{ cache(key="ip", compute="resolve_dns", hostname="localhost") }}"#,
+        false,
+    )
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains("no fresh cached value for 'ip'"))
+    .stderr(predicate::str::contains("--trust"));
+
+    assert!(cache_files(&dir).is_empty());
+}
+
+#[test]
+fn test_cache_expired_value_is_recomputed() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("data.json"), r#"{"region": "eu"}"#).unwrap();
+    let template =
+        r#"{{ cache(key="data", compute="read_json_file", path="data.json", ttl=60).region }}"#;
+    render(&dir, template, true).assert().success().stdout("eu");
+
+    // Age the entry past its TTL
+    let file = &cache_files(&dir)[0];
+    let mut entry: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(file).unwrap()).unwrap();
+    entry["created"] = serde_json::json!(0);
+    fs::write(file, entry.to_string()).unwrap();
+
+    fs::write(dir.path().join("data.json"), r#"{"region": "us"}"#).unwrap();
+    render(&dir, template, false)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no fresh cached value"));
+    render(&dir, template, true).assert().success().stdout("us");
+}
+
+#[test]
+fn test_cache_default_key_depends_on_arguments() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("a.json"), r#"{"v": 1}"#).unwrap();
+    fs::write(dir.path().join("b.json"), r#"{"v": 2}"#).unwrap();
+
+    render(
+        &dir,
+        r#"{{ cache(compute="read_json_file", path="a.json").v }}{{ cache(compute="read_json_file", path="b.json").v }}"#,
+        true,
+    )
+    .assert()
+    .success()
+    .stdout("12");
+    assert_eq!(cache_files(&dir).len(), 2);
+}
+
+#[test]
+fn test_cache_unknown_function() {
+    let dir = TempDir::new().unwrap();
+
+    render(&dir, r#"{{ cache(compute="nope") }}"#, true)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cache: unknown function 'nope'"));
+}
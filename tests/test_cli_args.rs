@@ -0,0 +1,68 @@
+//! Integration tests for positional arguments after `--` (the `args` variable)
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    Command::cargo_bin("tmpltool").unwrap()
+}
+
+#[test]
+fn test_args_after_separator_are_exposed() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let template = temp_dir.path().join("gen.tmpltool");
+    fs::write(&template, "{% for name in args %}[{{ name }}]{% endfor %}").unwrap();
+
+    tmpltool()
+        .arg(&template)
+        .args(["--", "users", "order items"])
+        .assert()
+        .success()
+        .stdout("[users][order items]");
+}
+
+#[test]
+fn test_args_is_empty_list_without_separator() {
+    tmpltool()
+        .write_stdin("{{ args | length }}")
+        .assert()
+        .success()
+        .stdout("0");
+}
+
+#[test]
+fn test_args_with_stdin_template() {
+    tmpltool()
+        .args(["--", "a", "b"])
+        .write_stdin("{{ args | join(\",\") }}")
+        .assert()
+        .success()
+        .stdout("a,b");
+}
+
+#[test]
+fn test_flags_after_separator_are_plain_args() {
+    tmpltool()
+        .args(["--", "--verbose", "-o"])
+        .write_stdin("{{ args | join(\" \") }}")
+        .assert()
+        .success()
+        .stdout("--verbose -o")
+        .stderr("");
+}
+
+#[test]
+fn test_extra_positional_without_separator_is_rejected() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let template = temp_dir.path().join("gen.tmpltool");
+    fs::write(&template, "{{ args }}").unwrap();
+
+    tmpltool()
+        .arg(&template)
+        .arg("users")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unexpected argument 'users'"));
+}
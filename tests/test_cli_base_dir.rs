@@ -0,0 +1,99 @@
+//! Integration tests for the --base-dir CLI flag
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    Command::cargo_bin("tmpltool").unwrap()
+}
+
+#[test]
+fn test_filesystem_functions_use_base_dir() {
+    let dir = tempfile::tempdir().unwrap();
+    let templates = dir.path().join("templates");
+    let project = dir.path().join("project");
+    fs::create_dir_all(&templates).unwrap();
+    fs::create_dir_all(project.join("data")).unwrap();
+    fs::write(project.join("data/config.json"), r#"{"name": "api"}"#).unwrap();
+    let template = templates.join("main.tmpltool");
+    fs::write(
+        &template,
+        r#"{{ read_json_file(path="data/config.json").name }} {{ file_exists(path="main.tmpltool") }}"#,
+    )
+    .unwrap();
+
+    tmpltool()
+        .arg("--base-dir")
+        .arg(&project)
+        .arg(&template)
+        .assert()
+        .success()
+        .stdout("api false");
+}
+
+#[test]
+fn test_includes_stay_relative_to_template() {
+    let dir = tempfile::tempdir().unwrap();
+    let templates = dir.path().join("templates");
+    let project = dir.path().join("project");
+    fs::create_dir_all(&templates).unwrap();
+    fs::create_dir_all(&project).unwrap();
+    fs::write(templates.join("header.txt"), "header").unwrap();
+    fs::write(project.join("value.txt"), "value").unwrap();
+    let template = templates.join("main.tmpltool");
+    fs::write(
+        &template,
+        r#"{% include "header.txt" %} {{ read_file(path="value.txt") }}"#,
+    )
+    .unwrap();
+
+    tmpltool()
+        .arg("--base-dir")
+        .arg(&project)
+        .arg(&template)
+        .assert()
+        .success()
+        .stdout("header value");
+}
+
+#[test]
+fn test_base_dir_with_stdin() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("name.txt"), "from base dir").unwrap();
+
+    tmpltool()
+        .arg("--base-dir")
+        .arg(dir.path())
+        .write_stdin(r#"{{ read_file(path="name.txt") }}"#)
+        .assert()
+        .success()
+        .stdout("from base dir");
+}
+
+#[test]
+fn test_base_dir_keeps_security_checks() {
+    let dir = tempfile::tempdir().unwrap();
+
+    tmpltool()
+        .arg("--base-dir")
+        .arg(dir.path())
+        .write_stdin(r#"{{ read_file(path="../secret.txt") }}"#)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Security"));
+}
+
+#[test]
+fn test_missing_base_dir_fails() {
+    tmpltool()
+        .arg("--base-dir")
+        .arg("/nonexistent/project")
+        .write_stdin("hello")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Failed to resolve base directory '/nonexistent/project'",
+        ));
+}
@@ -0,0 +1,76 @@
+//! Integration tests for the --check CLI flag
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    Command::cargo_bin("tmpltool").unwrap()
+}
+
+#[test]
+fn test_check_passes_when_output_is_up_to_date() {
+    let dir = TempDir::new().unwrap();
+    let output = dir.path().join("out.txt");
+    fs::write(&output, "a\nb\n2").unwrap();
+
+    tmpltool()
+        .arg("-o")
+        .arg(&output)
+        .arg("--check")
+        .write_stdin("a\nb\n{{ 1 + 1 }}")
+        .assert()
+        .success()
+        .stdout("")
+        .stderr(predicate::str::contains("is up to date"));
+}
+
+#[test]
+fn test_check_fails_with_diff_when_output_is_stale() {
+    let dir = TempDir::new().unwrap();
+    let output = dir.path().join("out.txt");
+    fs::write(&output, "a\nX\n2").unwrap();
+
+    tmpltool()
+        .arg("-o")
+        .arg(&output)
+        .arg("--check")
+        .write_stdin("a\nb\n{{ 1 + 1 }}")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("-X\n"))
+        .stdout(predicate::str::contains("+b\n"))
+        .stderr(predicate::str::contains("is out of date (--check)"));
+
+    // The stale file is left untouched
+    assert_eq!(fs::read_to_string(&output).unwrap(), "a\nX\n2");
+}
+
+#[test]
+fn test_check_fails_when_output_is_missing() {
+    let dir = TempDir::new().unwrap();
+    let output = dir.path().join("out.txt");
+
+    tmpltool()
+        .arg("-o")
+        .arg(&output)
+        .arg("--check")
+        .write_stdin("hello")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("does not exist (--check)"));
+
+    assert!(!output.exists());
+}
+
+#[test]
+fn test_check_requires_output() {
+    tmpltool()
+        .arg("--check")
+        .write_stdin("hello")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--output"));
+}
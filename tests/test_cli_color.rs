@@ -0,0 +1,105 @@
+//! Tests for colored diagnostics (--color / --no-color)
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tmpltool::cli::ColorChoice;
+use tmpltool::color::{paint_error, use_color};
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    Command::cargo_bin("tmpltool").unwrap()
+}
+
+#[test]
+fn test_color_auto_is_plain_when_not_a_terminal() {
+    tmpltool()
+        .write_stdin("{{ undefined_var }}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Error: Failed to render template"))
+        .stderr(predicate::str::contains("\x1b[").not());
+}
+
+#[test]
+fn test_color_always_colors_errors() {
+    tmpltool()
+        .arg("--color")
+        .arg("always")
+        .env("NO_COLOR", "1")
+        .write_stdin("{{ undefined_var }}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("\x1b[1;31mError:\x1b[0m"))
+        .stderr(predicate::str::contains("\x1b[36m(in template:1)\x1b[0m"));
+}
+
+#[test]
+fn test_color_never_is_plain() {
+    tmpltool()
+        .arg("--color")
+        .arg("never")
+        .write_stdin("{{ undefined_var }}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("\x1b[").not());
+}
+
+#[test]
+fn test_no_color_flag_is_plain() {
+    tmpltool()
+        .arg("--no-color")
+        .write_stdin("{{ undefined_var }}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("\x1b[").not());
+}
+
+#[test]
+fn test_no_color_conflicts_with_color() {
+    tmpltool()
+        .arg("--no-color")
+        .arg("--color")
+        .arg("always")
+        .write_stdin("hello")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_color_invalid_value() {
+    tmpltool()
+        .arg("--color")
+        .arg("sometimes")
+        .write_stdin("hello")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value 'sometimes'"));
+}
+
+#[test]
+fn test_use_color_explicit_choices() {
+    assert!(use_color(ColorChoice::Always));
+    assert!(!use_color(ColorChoice::Never));
+}
+
+#[test]
+fn test_paint_error_disabled_returns_message() {
+    let message = "Error: undefined value (in template:1)";
+    assert_eq!(paint_error(message, false), message);
+}
+
+#[test]
+fn test_paint_error_highlights_quoted_names() {
+    let painted = paint_error("Error: unknown function 'foo'", true);
+    assert_eq!(
+        painted,
+        "\x1b[1;31mError:\x1b[0m unknown function \x1b[33m'foo'\x1b[0m"
+    );
+}
+
+#[test]
+fn test_paint_error_highlights_carets() {
+    let painted = paint_error("{{ foo }}\n   ^^^", true);
+    assert_eq!(painted, "{{ foo }}\n\x1b[1;31m   ^^^\x1b[0m");
+}
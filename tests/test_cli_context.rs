@@ -0,0 +1,213 @@
+//! Integration tests for the --context, --context-format and --context-list-strategy flags
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    Command::cargo_bin("tmpltool").unwrap()
+}
+
+#[test]
+fn test_context_formats_inferred_from_extension() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let files = [
+        ("values.json", r#"{"app": {"name": "api", "port": 8080}}"#),
+        ("values.yaml", "app:\n  name: api\n  port: 8080\n"),
+        ("values.yml", "app:\n  name: api\n  port: 8080\n"),
+        ("values.toml", "[app]\nname = \"api\"\nport = 8080\n"),
+    ];
+
+    for (name, content) in files {
+        let path = temp_dir.path().join(name);
+        fs::write(&path, content).unwrap();
+        tmpltool()
+            .arg("--context")
+            .arg(&path)
+            .write_stdin("{{ app.name }}:{{ app.port }}")
+            .assert()
+            .success()
+            .stdout("api:8080");
+    }
+}
+
+#[test]
+fn test_context_format_overrides_extension() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("values.conf");
+    fs::write(&path, "name: api\n").unwrap();
+
+    tmpltool()
+        .arg("--context")
+        .arg(&path)
+        .write_stdin("{{ name }}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("pass --context-format"));
+
+    tmpltool()
+        .arg("--context")
+        .arg(&path)
+        .args(["--context-format", "yaml"])
+        .write_stdin("{{ name }}")
+        .assert()
+        .success()
+        .stdout("api");
+}
+
+#[test]
+fn test_context_from_stdin() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let template = temp_dir.path().join("gen.tmpltool");
+    fs::write(&template, "{% for u in users %}{{ u }};{% endfor %}").unwrap();
+
+    tmpltool()
+        .arg(&template)
+        .args(["--context", "-", "--context-format", "json"])
+        .write_stdin(r#"{"users": ["ann", "bob"]}"#)
+        .assert()
+        .success()
+        .stdout("ann;bob;");
+
+    tmpltool()
+        .arg(&template)
+        .args(["--context", "-"])
+        .write_stdin(r#"{"users": []}"#)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("context read from stdin"));
+}
+
+#[test]
+fn test_context_and_template_cannot_both_use_stdin() {
+    tmpltool()
+        .args(["--context", "-", "--context-format", "json"])
+        .write_stdin("{}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Cannot read both the template and the context from stdin",
+        ));
+}
+
+#[test]
+fn test_context_errors() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let invalid = temp_dir.path().join("bad.json");
+    fs::write(&invalid, "{\"a\": }").unwrap();
+    tmpltool()
+        .arg("--context")
+        .arg(&invalid)
+        .write_stdin("x")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("as JSON"));
+
+    let list = temp_dir.path().join("list.yaml");
+    fs::write(&list, "- a\n- b\n").unwrap();
+    tmpltool()
+        .arg("--context")
+        .arg(&list)
+        .write_stdin("x")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("must contain an object"));
+
+    tmpltool()
+        .args(["--context", "missing.json"])
+        .write_stdin("x")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Failed to read context file 'missing.json'",
+        ));
+}
+
+#[test]
+fn test_args_take_precedence_over_context() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("values.json");
+    fs::write(&path, r#"{"args": "from file", "name": "api"}"#).unwrap();
+
+    tmpltool()
+        .arg("--context")
+        .arg(&path)
+        .args(["--", "x"])
+        .write_stdin("{{ name }} {{ args | join(\",\") }}")
+        .assert()
+        .success()
+        .stdout("api x");
+}
+
+#[test]
+fn test_context_args_kept_without_trailing_args() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("values.json");
+    fs::write(&path, r#"{"args": ["a", "b"]}"#).unwrap();
+
+    tmpltool()
+        .arg("--context")
+        .arg(&path)
+        .write_stdin("{{ args | join(\",\") }}")
+        .assert()
+        .success()
+        .stdout("a,b");
+}
+
+#[test]
+fn test_multiple_contexts_are_deep_merged_in_order() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let base = temp_dir.path().join("base.yaml");
+    let prod = temp_dir.path().join("prod.json");
+    fs::write(
+        &base,
+        "app:\n  name: api\n  replicas: 1\n  hosts: [a]\nregion: eu\n",
+    )
+    .unwrap();
+    fs::write(&prod, r#"{"app": {"replicas": 3, "hosts": ["b"]}}"#).unwrap();
+
+    let template = "{{ app.name }} {{ app.replicas }} {{ app.hosts | join(\",\") }} {{ region }}";
+    tmpltool()
+        .arg("--context")
+        .arg(&base)
+        .arg("--context")
+        .arg(&prod)
+        .write_stdin(template)
+        .assert()
+        .success()
+        .stdout("api 3 b eu");
+
+    tmpltool()
+        .arg("--context")
+        .arg(&base)
+        .arg("--context")
+        .arg(&prod)
+        .args(["--context-list-strategy", "append"])
+        .write_stdin(template)
+        .assert()
+        .success()
+        .stdout("api 3 a,b eu");
+}
+
+#[test]
+fn test_stdin_context_only_once() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let template = temp_dir.path().join("gen.tmpltool");
+    fs::write(&template, "x").unwrap();
+
+    tmpltool()
+        .arg(&template)
+        .args([
+            "--context",
+            "-",
+            "--context",
+            "-",
+            "--context-format",
+            "json",
+        ])
+        .write_stdin("{}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("only once"));
+}
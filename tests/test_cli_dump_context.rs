@@ -0,0 +1,113 @@
+//! Integration tests for the --dump-context CLI flag
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    Command::cargo_bin("tmpltool").unwrap()
+}
+
+fn read_json(path: &std::path::Path) -> serde_json::Value {
+    serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap()
+}
+
+#[test]
+fn test_dump_context_writes_merged_context_files() {
+    let dir = TempDir::new().unwrap();
+    let base = dir.path().join("base.yaml");
+    let prod = dir.path().join("prod.json");
+    let dump = dir.path().join("context.json");
+    fs::write(&base, "db:\n  host: localhost\n  port: 5432\nname: app\n").unwrap();
+    fs::write(&prod, r#"{"db": {"host": "db.prod"}}"#).unwrap();
+
+    tmpltool()
+        .arg("--context")
+        .arg(&base)
+        .arg("--context")
+        .arg(&prod)
+        .arg("--dump-context")
+        .arg(&dump)
+        .assert()
+        .success()
+        .stdout("");
+
+    let context = read_json(&dump);
+    assert_eq!(context["db"]["host"], "db.prod");
+    assert_eq!(context["db"]["port"], 5432);
+    assert_eq!(context["name"], "app");
+    assert_eq!(context["args"], serde_json::json!([]));
+}
+
+#[test]
+fn test_dump_context_does_not_render() {
+    let dir = TempDir::new().unwrap();
+    let template = dir.path().join("broken.tmpltool");
+    let output = dir.path().join("out.txt");
+    let dump = dir.path().join("context.json");
+    fs::write(&template, "{{ undefined_variable }}").unwrap();
+
+    tmpltool()
+        .arg(&template)
+        .arg("-o")
+        .arg(&output)
+        .arg("--dump-context")
+        .arg(&dump)
+        .assert()
+        .success();
+
+    assert!(dump.exists());
+    assert!(!output.exists());
+}
+
+#[test]
+fn test_dump_context_env_key_and_args() {
+    tmpltool()
+        .env("DUMP_CONTEXT_TEST", "yes")
+        .arg("--env-key")
+        .arg("env")
+        .arg("--dump-context")
+        .arg("-")
+        .arg("--")
+        .arg("first")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"DUMP_CONTEXT_TEST\": \"yes\""))
+        .stdout(predicate::str::contains("\"first\""));
+}
+
+#[test]
+fn test_dump_context_env_key_overrides_context_file() {
+    let dir = TempDir::new().unwrap();
+    let values = dir.path().join("values.json");
+    fs::write(&values, r#"{"env": "from-file"}"#).unwrap();
+
+    let output = tmpltool()
+        .env("DUMP_CONTEXT_TEST", "yes")
+        .arg("--context")
+        .arg(&values)
+        .arg("--env-key")
+        .arg("env")
+        .arg("--dump-context")
+        .arg("-")
+        .output()
+        .unwrap();
+    let context: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(context["env"]["DUMP_CONTEXT_TEST"], "yes");
+}
+
+#[test]
+fn test_dump_context_reports_context_errors() {
+    tmpltool()
+        .arg("--context")
+        .arg("missing.json")
+        .arg("--dump-context")
+        .arg("-")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Failed to read context file 'missing.json'",
+        ));
+}
@@ -0,0 +1,96 @@
+//! Integration tests for the --env-key and --env-top-level flags
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    Command::cargo_bin("tmpltool").unwrap()
+}
+
+#[test]
+fn test_env_vars_are_not_variables_by_default() {
+    tmpltool()
+        .env("TMPLTOOL_ENV_KEY_TEST", "value")
+        .write_stdin("{{ TMPLTOOL_ENV_KEY_TEST }}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("undefined"));
+}
+
+#[test]
+fn test_env_key_exposes_env_object() {
+    tmpltool()
+        .env("TMPLTOOL_ENV_KEY_TEST", "value")
+        .args(["--env-key", "env"])
+        .write_stdin("{{ env.TMPLTOOL_ENV_KEY_TEST }}")
+        .assert()
+        .success()
+        .stdout("value");
+
+    // Only under the key, not at the top level
+    tmpltool()
+        .env("TMPLTOOL_ENV_KEY_TEST", "value")
+        .args(["--env-key", "env"])
+        .write_stdin("{{ TMPLTOOL_ENV_KEY_TEST }}")
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_env_top_level_and_both() {
+    tmpltool()
+        .env("TMPLTOOL_ENV_KEY_TEST", "value")
+        .arg("--env-top-level")
+        .write_stdin("{{ TMPLTOOL_ENV_KEY_TEST }}")
+        .assert()
+        .success()
+        .stdout("value");
+
+    tmpltool()
+        .env("TMPLTOOL_ENV_KEY_TEST", "value")
+        .args(["--env-top-level", "--env-key", "sys"])
+        .write_stdin("{{ TMPLTOOL_ENV_KEY_TEST }}={{ sys.TMPLTOOL_ENV_KEY_TEST }}")
+        .assert()
+        .success()
+        .stdout("value=value");
+}
+
+#[test]
+fn test_env_precedence_with_context_files() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let context = temp_dir.path().join("values.json");
+    fs::write(
+        &context,
+        r#"{"TMPLTOOL_ENV_KEY_TEST": "from file", "env": "from file"}"#,
+    )
+    .unwrap();
+
+    // Context files override top-level env vars; the --env-key object overrides context keys
+    tmpltool()
+        .env("TMPLTOOL_ENV_KEY_TEST", "from env")
+        .arg("--context")
+        .arg(&context)
+        .args(["--env-top-level", "--env-key", "env"])
+        .write_stdin("{{ TMPLTOOL_ENV_KEY_TEST }} / {{ env.TMPLTOOL_ENV_KEY_TEST }}")
+        .assert()
+        .success()
+        .stdout("from file / from env");
+}
+
+#[test]
+fn test_env_key_includes_env_files() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let env_file = temp_dir.path().join(".env");
+    fs::write(&env_file, "TMPLTOOL_ENV_FILE_KEY=loaded\n").unwrap();
+
+    tmpltool()
+        .arg("--env")
+        .arg(&env_file)
+        .args(["--env-key", "env"])
+        .write_stdin("{{ env.TMPLTOOL_ENV_FILE_KEY }}")
+        .assert()
+        .success()
+        .stdout("loaded");
+}
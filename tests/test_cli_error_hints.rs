@@ -0,0 +1,77 @@
+//! Integration tests for the hints added to render errors
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    Command::cargo_bin("tmpltool").unwrap()
+}
+
+#[test]
+fn test_unknown_filter_suggests_similar_name() {
+    tmpltool()
+        .write_stdin("{{ \"a\" | uppr }}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Did you mean 'upper'?"));
+}
+
+#[test]
+fn test_unknown_function_suggests_similar_name() {
+    tmpltool()
+        .write_stdin("{{ to_jsn(object=1) }}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Did you mean 'to_json'?"));
+}
+
+#[test]
+fn test_unknown_test_suggests_similar_name() {
+    tmpltool()
+        .write_stdin("{% if 2 is evn %}x{% endif %}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Did you mean 'even'?"));
+}
+
+#[test]
+fn test_unrelated_name_gets_no_suggestion() {
+    tmpltool()
+        .write_stdin("{{ \"a\" | qwertyuiop }}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Did you mean").not());
+}
+
+#[test]
+fn test_undefined_variable_lists_available_variables() {
+    let dir = TempDir::new().unwrap();
+    let context = dir.path().join("values.json");
+    fs::write(&context, r#"{"version": "1.0", "app": "web"}"#).unwrap();
+
+    tmpltool()
+        .arg("--context")
+        .arg(&context)
+        .write_stdin("{{ verison }}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Available variables: app, args, version",
+        ));
+}
+
+#[test]
+#[cfg(feature = "strict-parse")]
+fn test_strict_parse_suggests_similar_name() {
+    tmpltool()
+        .arg("--strict-parse")
+        .write_stdin("{% if false %}{{ \"a\" | uppr }}{% endif %}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "unknown filter 'uppr' at template:1 (did you mean 'upper'?)",
+        ));
+}
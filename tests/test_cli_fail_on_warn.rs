@@ -0,0 +1,69 @@
+//! Integration tests for the --fail-on-warn CLI flag
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    Command::cargo_bin("tmpltool").unwrap()
+}
+
+#[test]
+fn test_warnings_do_not_fail_by_default() {
+    tmpltool()
+        .write_stdin("{{ warn(message=\"using defaults\") }}ok")
+        .assert()
+        .success()
+        .stdout("ok")
+        .stderr(predicate::str::contains("[WARNING] using defaults"));
+}
+
+#[test]
+fn test_fail_on_warn_fails_when_warning_emitted() {
+    tmpltool()
+        .arg("--fail-on-warn")
+        .write_stdin("{{ warn(message=\"using defaults\") }}{{ warn(message=\"again\") }}ok")
+        .assert()
+        .failure()
+        .stdout("")
+        .stderr(predicate::str::contains("[WARNING] using defaults"))
+        .stderr(predicate::str::contains(
+            "2 warning(s) emitted during rendering (--fail-on-warn)",
+        ));
+}
+
+#[test]
+fn test_fail_on_warn_succeeds_without_warnings() {
+    tmpltool()
+        .arg("--fail-on-warn")
+        .write_stdin("Hello {{ \"World\" }}")
+        .assert()
+        .success()
+        .stdout("Hello World");
+}
+
+#[test]
+fn test_fail_on_warn_does_not_write_output_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = dir.path().join("out.txt");
+    tmpltool()
+        .arg("--fail-on-warn")
+        .arg("-o")
+        .arg(&output)
+        .write_stdin("{{ warn(message=\"careful\") }}content")
+        .assert()
+        .failure();
+    assert!(!output.exists());
+}
+
+#[test]
+fn test_fail_on_warn_with_timeout() {
+    tmpltool()
+        .arg("--fail-on-warn")
+        .arg("--timeout")
+        .arg("10")
+        .write_stdin("{{ warn(message=\"careful\") }}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("1 warning(s) emitted"));
+}
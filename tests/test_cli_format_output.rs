@@ -0,0 +1,55 @@
+//! Integration tests for the --format-output CLI flag
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    Command::cargo_bin("tmpltool").unwrap()
+}
+
+#[test]
+fn test_format_output_yaml() {
+    tmpltool()
+        .arg("--format-output")
+        .arg("yaml")
+        .write_stdin("{% for i in range(2) %}\nitem{{ i }}:    {{ i }}\n{% endfor %}\n")
+        .assert()
+        .success()
+        .stdout("item0: 0\nitem1: 1\n");
+}
+
+#[test]
+fn test_format_output_json() {
+    tmpltool()
+        .arg("--format-output")
+        .arg("json")
+        .write_stdin("{\"name\": \"{{ 'app' }}\",   \"count\": 2}")
+        .assert()
+        .success()
+        .stdout("{\n  \"count\": 2,\n  \"name\": \"app\"\n}\n");
+}
+
+#[test]
+fn test_format_output_parse_error() {
+    tmpltool()
+        .arg("--format-output")
+        .arg("json")
+        .write_stdin("{\n\"a\": 1,\n}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to format output as JSON"))
+        .stderr(predicate::str::contains("rendered output line 3"));
+}
+
+#[test]
+fn test_format_output_after_strip_comments() {
+    tmpltool()
+        .arg("--strip-comments")
+        .arg("--format-output")
+        .arg("yaml")
+        .write_stdin("# note\nb: 1\na: 2")
+        .assert()
+        .success()
+        .stdout("a: 2\nb: 1\n");
+}
@@ -202,3 +202,62 @@ fn test_ide_json_categories_present() {
         );
     }
 }
+
+#[test]
+fn test_ide_category_filters_output() {
+    let output = tmpltool()
+        .args(["--ide", "json", "--category", "kubernetes"])
+        .output()
+        .expect("Failed to execute command");
+    assert!(output.status.success(), "Command should succeed");
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let functions = json.as_array().unwrap();
+    assert!(!functions.is_empty());
+    assert!(functions.iter().all(|f| f["category"] == "kubernetes"));
+}
+
+#[test]
+fn test_ide_multiple_categories() {
+    let output = tmpltool()
+        .args([
+            "--ide",
+            "json",
+            "--category",
+            "hash",
+            "--category",
+            "layout",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let categories: std::collections::HashSet<&str> = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| f["category"].as_str().unwrap())
+        .collect();
+    assert_eq!(categories, ["hash", "layout"].into_iter().collect());
+}
+
+#[test]
+fn test_ide_unknown_category_lists_available() {
+    tmpltool()
+        .args(["--ide", "json", "--category", "kubernets"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Unknown category 'kubernets' (did you mean 'kubernetes'?)",
+        ))
+        .stderr(predicate::str::contains("kubernetes"));
+}
+
+#[test]
+fn test_category_requires_ide() {
+    tmpltool()
+        .args(["--category", "hash"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--ide"));
+}
@@ -0,0 +1,128 @@
+//! Integration tests for the --include-path CLI flag
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    Command::cargo_bin("tmpltool").unwrap()
+}
+
+#[test]
+fn test_include_from_include_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let shared = dir.path().join("shared");
+    let project = dir.path().join("project");
+    fs::create_dir_all(&shared).unwrap();
+    fs::create_dir_all(&project).unwrap();
+    fs::write(shared.join("header.txt"), "# shared header").unwrap();
+    let template = project.join("main.tmpltool");
+    fs::write(&template, "{% include \"header.txt\" %}body").unwrap();
+
+    tmpltool()
+        .arg("--include-path")
+        .arg(&shared)
+        .arg(&template)
+        .assert()
+        .success()
+        .stdout("# shared headerbody");
+}
+
+#[test]
+fn test_import_macro_from_include_path() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(
+        dir.path().join("macros.j2"),
+        "{% macro greet(name) %}Hello {{ name }}{% endmacro %}",
+    )
+    .unwrap();
+
+    tmpltool()
+        .arg("--include-path")
+        .arg(dir.path())
+        .write_stdin("{% import \"macros.j2\" as m %}{{ m.greet(\"World\") }}")
+        .assert()
+        .success()
+        .stdout("Hello World");
+}
+
+#[test]
+fn test_template_directory_takes_precedence() {
+    let dir = tempfile::tempdir().unwrap();
+    let shared = dir.path().join("shared");
+    let project = dir.path().join("project");
+    fs::create_dir_all(&shared).unwrap();
+    fs::create_dir_all(&project).unwrap();
+    fs::write(shared.join("part.txt"), "shared").unwrap();
+    fs::write(project.join("part.txt"), "local").unwrap();
+    let template = project.join("main.tmpltool");
+    fs::write(&template, "{% include \"part.txt\" %}").unwrap();
+
+    tmpltool()
+        .arg("--include-path")
+        .arg(&shared)
+        .arg(&template)
+        .assert()
+        .success()
+        .stdout("local");
+}
+
+#[test]
+fn test_include_paths_searched_in_order() {
+    let dir = tempfile::tempdir().unwrap();
+    let first = dir.path().join("first");
+    let second = dir.path().join("second");
+    let project = dir.path().join("project");
+    for d in [&first, &second, &project] {
+        fs::create_dir_all(d).unwrap();
+    }
+    fs::write(first.join("only_first.txt"), "1").unwrap();
+    fs::write(second.join("only_second.txt"), "2").unwrap();
+    fs::write(first.join("both.txt"), "first").unwrap();
+    fs::write(second.join("both.txt"), "second").unwrap();
+    let template = project.join("main.tmpltool");
+    fs::write(
+        &template,
+        "{% include \"only_first.txt\" %}{% include \"only_second.txt\" %}{% include \"both.txt\" %}",
+    )
+    .unwrap();
+
+    tmpltool()
+        .arg("--include-path")
+        .arg(&first)
+        .arg("--include-path")
+        .arg(&second)
+        .arg(&template)
+        .assert()
+        .success()
+        .stdout("12first");
+}
+
+#[test]
+fn test_missing_template_still_fails() {
+    let dir = tempfile::tempdir().unwrap();
+    let template = dir.path().join("main.tmpltool");
+    fs::write(&template, "{% include \"missing.txt\" %}").unwrap();
+
+    tmpltool()
+        .arg("--include-path")
+        .arg(dir.path().join("partials"))
+        .arg(&template)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("missing.txt"));
+}
+
+#[test]
+fn test_include_path_keeps_security_checks() {
+    let dir = tempfile::tempdir().unwrap();
+
+    tmpltool()
+        .arg("--include-path")
+        .arg(dir.path())
+        .write_stdin("{% include \"../secret.txt\" %}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Parent directory (..) traversal"));
+}
@@ -0,0 +1,92 @@
+//! Integration tests for the --interactive CLI flag and prompt()
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    let mut cmd = Command::cargo_bin("tmpltool").unwrap();
+    cmd.env_remove("TMPLTOOL_PROMPT_NAME")
+        .env_remove("TMPLTOOL_PROMPT_LICENSE");
+    cmd
+}
+
+fn write_template(dir: &TempDir, content: &str) -> PathBuf {
+    let path = dir.path().join("wizard.tmpltool");
+    fs::write(&path, content).unwrap();
+    path
+}
+
+const WIZARD: &str = "name={{ prompt(name=\"TMPLTOOL_PROMPT_NAME\", message=\"Project name\") }}\n\
+                      again={{ prompt(name=\"TMPLTOOL_PROMPT_NAME\") }}\n\
+                      license={{ prompt(name=\"TMPLTOOL_PROMPT_LICENSE\", message=\"License\", default=\"MIT\") }}";
+
+#[test]
+fn test_interactive_reads_answers_from_stdin() {
+    let dir = TempDir::new().unwrap();
+    let template = write_template(&dir, WIZARD);
+
+    tmpltool()
+        .arg("--interactive")
+        .arg(&template)
+        .write_stdin("demo\n\n")
+        .assert()
+        .success()
+        .stdout("name=demo\nagain=demo\nlicense=MIT")
+        .stderr("Project name: License [MIT]: ");
+}
+
+#[test]
+fn test_environment_variable_skips_question() {
+    let dir = TempDir::new().unwrap();
+    let template = write_template(&dir, WIZARD);
+
+    tmpltool()
+        .arg("--interactive")
+        .arg(&template)
+        .env("TMPLTOOL_PROMPT_NAME", "from-env")
+        .write_stdin("Apache-2.0\n")
+        .assert()
+        .success()
+        .stdout("name=from-env\nagain=from-env\nlicense=Apache-2.0")
+        .stderr("License [MIT]: ");
+}
+
+#[test]
+fn test_non_interactive_uses_default() {
+    tmpltool()
+        .write_stdin("{{ prompt(name=\"TMPLTOOL_PROMPT_LICENSE\", default=\"MIT\") }}")
+        .assert()
+        .success()
+        .stdout("MIT");
+}
+
+#[test]
+fn test_non_interactive_without_default_fails() {
+    tmpltool()
+        .write_stdin("{{ prompt(name=\"TMPLTOOL_PROMPT_NAME\") }}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "no value for 'TMPLTOOL_PROMPT_NAME' (set the TMPLTOOL_PROMPT_NAME environment variable or use --interactive)",
+        ));
+}
+
+#[test]
+fn test_interactive_with_closed_stdin_fails() {
+    let dir = TempDir::new().unwrap();
+    let template = write_template(&dir, "{{ prompt(name=\"TMPLTOOL_PROMPT_NAME\") }}");
+
+    tmpltool()
+        .arg("--interactive")
+        .arg(&template)
+        .write_stdin("")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "stdin was closed before an answer was given",
+        ));
+}
@@ -0,0 +1,67 @@
+//! Integration tests for the --line-ending and --bom CLI flags
+
+use assert_cmd::Command;
+use std::fs;
+use tempfile::TempDir;
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    Command::cargo_bin("tmpltool").unwrap()
+}
+
+#[test]
+fn test_line_endings_kept_by_default() {
+    tmpltool()
+        .write_stdin("a\r\nb\nc")
+        .assert()
+        .success()
+        .stdout("a\r\nb\nc");
+}
+
+#[test]
+fn test_line_ending_crlf_converts_every_newline() {
+    tmpltool()
+        .args(["--line-ending", "crlf"])
+        .write_stdin("a\r\nb\n{% for i in [1, 2] %}{{ i }}\n{% endfor %}")
+        .assert()
+        .success()
+        .stdout("a\r\nb\r\n1\r\n2\r\n");
+}
+
+#[test]
+fn test_line_ending_lf_normalizes_crlf() {
+    tmpltool()
+        .args(["--line-ending", "lf"])
+        .write_stdin("a\r\nb\r\nc")
+        .assert()
+        .success()
+        .stdout("a\nb\nc");
+}
+
+#[test]
+fn test_bom_is_prepended_to_output_file() {
+    let dir = TempDir::new().unwrap();
+    let output = dir.path().join("script.ps1");
+
+    tmpltool()
+        .arg("-o")
+        .arg(&output)
+        .args(["--bom", "--line-ending", "crlf"])
+        .write_stdin("Write-Host hi\nexit 0")
+        .assert()
+        .success();
+
+    assert_eq!(
+        fs::read(&output).unwrap(),
+        b"\xEF\xBB\xBFWrite-Host hi\r\nexit 0"
+    );
+}
+
+#[test]
+fn test_invalid_line_ending_is_rejected() {
+    tmpltool()
+        .args(["--line-ending", "cr"])
+        .write_stdin("a")
+        .assert()
+        .failure();
+}
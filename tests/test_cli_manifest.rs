@@ -0,0 +1,75 @@
+//! Integration tests for the --manifest CLI flag
+
+use assert_cmd::Command;
+use std::fs;
+use tempfile::TempDir;
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    Command::cargo_bin("tmpltool").unwrap()
+}
+
+fn read_manifest(path: &std::path::Path) -> serde_json::Value {
+    serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap()
+}
+
+#[test]
+fn test_manifest_lists_output_file() {
+    let dir = TempDir::new().unwrap();
+    let output = dir.path().join("out.txt");
+    let manifest = dir.path().join("manifest.json");
+
+    tmpltool()
+        .arg("-o")
+        .arg(&output)
+        .arg("--manifest")
+        .arg(&manifest)
+        .write_stdin("hi {{ 1 + 1 }}")
+        .assert()
+        .success();
+
+    let manifest = read_manifest(&manifest);
+    let files = manifest["files"].as_array().unwrap();
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0]["path"], output.to_str().unwrap());
+    assert_eq!(files[0]["size"], 4);
+    assert_eq!(
+        files[0]["sha256"],
+        "1bd6b91d3984e9a53c6381c2a9beb0ae94630b9163166fc01e15f43f3f3c376b"
+    );
+}
+
+#[test]
+fn test_manifest_for_stdout_uses_dash() {
+    let dir = TempDir::new().unwrap();
+    let manifest = dir.path().join("manifest.json");
+
+    tmpltool()
+        .arg("--manifest")
+        .arg(&manifest)
+        .write_stdin("hi")
+        .assert()
+        .success()
+        .stdout("hi");
+
+    let manifest = read_manifest(&manifest);
+    assert_eq!(manifest["files"][0]["path"], "-");
+    assert_eq!(manifest["files"][0]["size"], 2);
+}
+
+#[test]
+fn test_manifest_not_written_on_failure() {
+    let dir = TempDir::new().unwrap();
+    let manifest = dir.path().join("manifest.json");
+
+    tmpltool()
+        .arg("--manifest")
+        .arg(&manifest)
+        .arg("--validate")
+        .arg("json")
+        .write_stdin("not json")
+        .assert()
+        .failure();
+
+    assert!(!manifest.exists());
+}
@@ -0,0 +1,80 @@
+//! Integration tests for the --max-output-size CLI flag
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+use tmpltool::{RenderOptions, render_template_with_options};
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    Command::cargo_bin("tmpltool").unwrap()
+}
+
+#[test]
+fn test_max_output_size_within_limit() {
+    tmpltool()
+        .arg("--max-output-size")
+        .arg("100")
+        .write_stdin("Hello {{ \"World\" }}")
+        .assert()
+        .success()
+        .stdout("Hello World");
+}
+
+#[test]
+fn test_max_output_size_exact_limit() {
+    tmpltool()
+        .arg("--max-output-size")
+        .arg("5")
+        .write_stdin("{{ \"x\" * 5 }}")
+        .assert()
+        .success()
+        .stdout("xxxxx");
+}
+
+#[test]
+fn test_max_output_size_exceeded() {
+    tmpltool()
+        .arg("--max-output-size")
+        .arg("1000")
+        .write_stdin("{% for i in range(10000) %}line {{ i }}\n{% endfor %}")
+        .assert()
+        .failure()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "exceeds --max-output-size limit of 1000 bytes",
+        ));
+}
+
+#[test]
+fn test_max_output_size_does_not_write_output_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let template = temp_dir.path().join("big.tmpl");
+    let output = temp_dir.path().join("out.txt");
+    fs::write(&template, "{{ \"a\" * 50 }}").unwrap();
+
+    let options = RenderOptions {
+        max_output_size: Some(10),
+        ..Default::default()
+    };
+    let result = render_template_with_options(
+        Some(template.to_str().unwrap()),
+        Some(output.to_str().unwrap()),
+        &options,
+    );
+
+    assert!(result.is_err());
+    assert!(!output.exists());
+}
+
+#[test]
+fn test_max_output_size_template_error_still_reported() {
+    tmpltool()
+        .arg("--max-output-size")
+        .arg("1000")
+        .write_stdin("{{ undefined_var }}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to render template"));
+}
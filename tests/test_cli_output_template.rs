@@ -0,0 +1,132 @@
+//! Integration tests for templated -o/--output paths
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    Command::cargo_bin("tmpltool").unwrap()
+}
+
+#[test]
+fn test_output_path_uses_context_variables() {
+    let dir = TempDir::new().unwrap();
+    let context = dir.path().join("values.json");
+    fs::write(&context, r#"{"app": "billing"}"#).unwrap();
+
+    tmpltool()
+        .current_dir(dir.path())
+        .arg("--context")
+        .arg(&context)
+        .arg("-o")
+        .arg("{{ app }}.yaml")
+        .write_stdin("name: {{ app }}")
+        .assert()
+        .success();
+
+    assert_eq!(
+        fs::read_to_string(dir.path().join("billing.yaml")).unwrap(),
+        "name: billing"
+    );
+}
+
+#[test]
+fn test_output_path_uses_env() {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir(dir.path().join("dist")).unwrap();
+
+    tmpltool()
+        .current_dir(dir.path())
+        .env("APP_NAME", "api")
+        .arg("-o")
+        .arg("dist/{{ env.APP_NAME | lower }}.yaml")
+        .write_stdin("ok")
+        .assert()
+        .success();
+
+    assert_eq!(
+        fs::read_to_string(dir.path().join("dist/api.yaml")).unwrap(),
+        "ok"
+    );
+}
+
+#[test]
+fn test_output_path_rejects_parent_traversal() {
+    let dir = TempDir::new().unwrap();
+
+    tmpltool()
+        .current_dir(dir.path())
+        .env("APP_NAME", "../escape")
+        .arg("-o")
+        .arg("{{ env.APP_NAME }}.yaml")
+        .write_stdin("ok")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("parent directory (..) traversal"));
+
+    assert!(!dir.path().parent().unwrap().join("escape.yaml").exists());
+}
+
+#[test]
+fn test_output_path_rejects_rendered_absolute_path() {
+    let dir = TempDir::new().unwrap();
+
+    tmpltool()
+        .current_dir(dir.path())
+        .env("OUT_DIR", "/tmp")
+        .arg("-o")
+        .arg("{{ env.OUT_DIR }}/out.yaml")
+        .write_stdin("ok")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("rendered to absolute path"));
+}
+
+#[test]
+fn test_output_path_trust_allows_traversal() {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir(dir.path().join("work")).unwrap();
+
+    tmpltool()
+        .current_dir(dir.path().join("work"))
+        .env("TARGET", "../out")
+        .arg("--trust")
+        .arg("-o")
+        .arg("{{ env.TARGET }}.txt")
+        .write_stdin("ok")
+        .assert()
+        .success();
+
+    assert!(dir.path().join("out.txt").exists());
+}
+
+#[test]
+fn test_output_path_undefined_variable_fails() {
+    let dir = TempDir::new().unwrap();
+
+    tmpltool()
+        .current_dir(dir.path())
+        .arg("-o")
+        .arg("{{ missing }}.yaml")
+        .write_stdin("ok")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to render output path"));
+}
+
+#[test]
+fn test_output_path_without_template_syntax_is_unchanged() {
+    let dir = TempDir::new().unwrap();
+
+    tmpltool()
+        .current_dir(dir.path())
+        .arg("-o")
+        .arg("plain.txt")
+        .write_stdin("ok")
+        .assert()
+        .success();
+
+    assert!(dir.path().join("plain.txt").exists());
+}
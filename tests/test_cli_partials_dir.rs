@@ -0,0 +1,113 @@
+//! Integration tests for the --partials-dir CLI flag
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    Command::cargo_bin("tmpltool").unwrap()
+}
+
+#[test]
+fn test_import_partial_by_name() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(
+        dir.path().join("forms.j2"),
+        "{% macro input(name) %}<input name=\"{{ name }}\">{% endmacro %}",
+    )
+    .unwrap();
+
+    tmpltool()
+        .arg("--partials-dir")
+        .arg(dir.path())
+        .write_stdin("{% import \"forms\" as forms %}{{ forms.input(\"email\") }}")
+        .assert()
+        .success()
+        .stdout("<input name=\"email\">");
+}
+
+#[test]
+fn test_import_partial_by_full_and_nested_name() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("ui")).unwrap();
+    fs::write(
+        dir.path().join("ui/buttons.j2"),
+        "{% macro button(label) %}[{{ label }}]{% endmacro %}",
+    )
+    .unwrap();
+
+    tmpltool()
+        .arg("--partials-dir")
+        .arg(dir.path())
+        .write_stdin(
+            "{% from \"ui/buttons.j2\" import button %}{% from \"ui/buttons\" import button as b %}{{ button(\"a\") }}{{ b(\"b\") }}",
+        )
+        .assert()
+        .success()
+        .stdout("[a][b]");
+}
+
+#[test]
+fn test_globals_are_imported_automatically() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(
+        dir.path().join("_globals.j2"),
+        "{% macro shout(text) %}{{ text | upper }}!{% endmacro %}\n{% macro _private() %}x{% endmacro %}",
+    )
+    .unwrap();
+
+    tmpltool()
+        .arg("--partials-dir")
+        .arg(dir.path())
+        .write_stdin("{{ shout(\"hello\") }}")
+        .assert()
+        .success()
+        .stdout("HELLO!");
+}
+
+#[test]
+fn test_globals_import_keeps_line_numbers() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(
+        dir.path().join("_globals.j2"),
+        "{% macro shout(text) %}{{ text }}{% endmacro %}",
+    )
+    .unwrap();
+
+    tmpltool()
+        .arg("--partials-dir")
+        .arg(dir.path())
+        .write_stdin("line one\nline two\n{{ undefined_var.field }}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("template:3"));
+}
+
+#[test]
+fn test_missing_partials_dir_fails() {
+    tmpltool()
+        .arg("--partials-dir")
+        .arg("/nonexistent/partials")
+        .write_stdin("hello")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Partials directory '/nonexistent/partials' does not exist",
+        ));
+}
+
+#[test]
+fn test_invalid_partial_fails() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("broken.j2"), "{% macro oops( %}").unwrap();
+
+    tmpltool()
+        .arg("--partials-dir")
+        .arg(dir.path())
+        .write_stdin("hello")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to parse partial"))
+        .stderr(predicate::str::contains("broken.j2"));
+}
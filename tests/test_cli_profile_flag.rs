@@ -0,0 +1,77 @@
+//! Integration tests for the --profile CLI flag
+
+use assert_cmd::Command;
+use minijinja::Environment;
+use predicates::prelude::*;
+use std::fs;
+use std::path::PathBuf;
+use tempfile::TempDir;
+use tmpltool::profiler::Profiler;
+use tmpltool::{TemplateContext, functions::register_all};
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    Command::cargo_bin("tmpltool").unwrap()
+}
+
+#[test]
+fn test_profiler_counts_calls() {
+    let mut env = Environment::new();
+    register_all(&mut env, TemplateContext::new(PathBuf::from("."), false));
+    let profiler = Profiler::new();
+    profiler.instrument(&mut env);
+
+    let result = env
+        .render_str(
+            r#"{% for i in range(3) %}{{ to_snake_case(string="HelloWorld") }}{% endfor %}{{ sha256(string="x") | length }}"#,
+            (),
+        )
+        .unwrap();
+    assert_eq!(result, "hello_worldhello_worldhello_world64");
+
+    let summary = profiler.summary();
+    let snake = summary.iter().find(|(n, _)| n == "to_snake_case").unwrap();
+    assert_eq!(snake.1.calls, 3);
+    let sha = summary.iter().find(|(n, _)| n == "sha256").unwrap();
+    assert_eq!(sha.1.calls, 1);
+}
+
+#[test]
+fn test_profiler_preserves_kwargs_errors() {
+    let mut env = Environment::new();
+    register_all(&mut env, TemplateContext::new(PathBuf::from("."), false));
+    let profiler = Profiler::new();
+    profiler.instrument(&mut env);
+
+    let result = env.render_str(r#"{{ sha256() }}"#, ());
+    assert!(result.is_err());
+    assert_eq!(profiler.summary()[0].1.calls, 1);
+}
+
+#[test]
+fn test_profile_flag_prints_summary() {
+    let dir = TempDir::new().unwrap();
+    let template = dir.path().join("test.tmpltool");
+    fs::write(&template, r#"{{ md5(string="a") }}{{ md5(string="b") }}"#).unwrap();
+
+    tmpltool()
+        .args(["--profile", template.to_str().unwrap()])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("[PROFILE] function"))
+        .stderr(predicate::str::is_match(r"\[PROFILE\] md5\s+2\s").unwrap());
+}
+
+#[test]
+fn test_profile_flag_no_calls() {
+    let dir = TempDir::new().unwrap();
+    let template = dir.path().join("test.tmpltool");
+    fs::write(&template, "static").unwrap();
+
+    tmpltool()
+        .args(["--profile", template.to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout("static")
+        .stderr(predicate::str::contains("no functions were called"));
+}
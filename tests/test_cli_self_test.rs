@@ -0,0 +1,45 @@
+//! Integration tests for the --self-test CLI flag
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tmpltool::TemplateContext;
+use tmpltool::self_test;
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    Command::cargo_bin("tmpltool").unwrap()
+}
+
+#[test]
+fn test_self_test_passes() {
+    tmpltool()
+        .arg("--self-test")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Self-test: "))
+        .stdout(predicate::str::contains(" 0 failed"));
+}
+
+#[test]
+fn test_self_test_does_not_render_stdin() {
+    // --self-test exits before reading a template from stdin
+    tmpltool()
+        .arg("--self-test")
+        .write_stdin("{{ broken")
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_self_test_report_covers_all_examples() {
+    let context = TemplateContext::new(std::env::temp_dir(), false);
+    let report = self_test::run(context);
+    let examples: usize = tmpltool::get_all_metadata()
+        .iter()
+        .map(|m| m.examples.len())
+        .sum();
+
+    assert!(report.failures.is_empty(), "{:?}", report.failures);
+    assert!(report.passed > 0);
+    assert_eq!(report.passed + report.skipped, examples);
+}
@@ -0,0 +1,128 @@
+//! Integration tests for the --strict-parse CLI flag
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    Command::cargo_bin("tmpltool").unwrap()
+}
+
+#[test]
+fn test_unknown_function_in_dead_branch_passes_without_flag() {
+    tmpltool()
+        .write_stdin("{% if false %}{{ no_such_function() }}{% endif %}ok")
+        .assert()
+        .success()
+        .stdout("ok");
+}
+
+#[test]
+#[cfg(feature = "strict-parse")]
+fn test_strict_parse_rejects_unknown_function() {
+    tmpltool()
+        .arg("--strict-parse")
+        .write_stdin("line one\n{% if false %}{{ no_such_function() }}{% endif %}ok")
+        .assert()
+        .failure()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "unknown function 'no_such_function' at template:2",
+        ));
+}
+
+#[test]
+#[cfg(feature = "strict-parse")]
+fn test_strict_parse_rejects_unknown_filter_and_test() {
+    tmpltool()
+        .arg("--strict-parse")
+        .write_stdin("{% if false %}{{ \"a\" | uppr }}{{ 1 is evn }}{% endif %}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "unknown filter 'uppr' at template:1",
+        ))
+        .stderr(predicate::str::contains("unknown test 'evn' at template:1"));
+}
+
+#[test]
+#[cfg(feature = "strict-parse")]
+fn test_strict_parse_suggests_is_test_name() {
+    tmpltool()
+        .arg("--strict-parse")
+        .write_stdin("{% if false %}{{ \"a@b.c\" is emal }}{% endif %}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "unknown test 'emal' at template:1 (did you mean 'email'?)",
+        ));
+}
+
+#[test]
+#[cfg(feature = "strict-parse")]
+fn test_strict_parse_accepts_known_names() {
+    tmpltool()
+        .arg("--strict-parse")
+        .write_stdin(
+            "{{ \"a\" | upper }}{{ 2 is even }}{{ \"abc\" is starts_with(\"a\") }}\
+             {{ \"x\" is email }}{{ [1] | array_sum }}\
+             {{ get_env(name=\"TMPLTOOL_STRICT_PARSE_UNSET\", default=\"d\") }}\
+             {% for i in range(1) %}{{ loop.index }}{% endfor %}",
+        )
+        .assert()
+        .success()
+        .stdout("Atruetruefalse1d1");
+}
+
+#[test]
+#[cfg(feature = "strict-parse")]
+fn test_strict_parse_accepts_macros_and_locals() {
+    tmpltool()
+        .arg("--strict-parse")
+        .write_stdin(
+            "{% macro wrap(x) %}[{{ x }}{{ caller() }}]{% endmacro %}\
+             {% call wrap(\"a\") %}b{% endcall %}\
+             {% set ns = namespace(n=1) %}{{ ns.n }}",
+        )
+        .assert()
+        .success()
+        .stdout("[ab]1");
+}
+
+#[test]
+#[cfg(feature = "strict-parse")]
+fn test_strict_parse_accepts_imported_macros() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("macros.j2"),
+        "{% macro greet(name) %}Hi {{ name }}{% endmacro %}",
+    )
+    .unwrap();
+    let template = dir.path().join("main.j2");
+    std::fs::write(
+        &template,
+        "{% from \"macros.j2\" import greet %}{{ greet(\"Bob\") }}",
+    )
+    .unwrap();
+
+    tmpltool()
+        .arg("--strict-parse")
+        .arg(&template)
+        .assert()
+        .success()
+        .stdout("Hi Bob");
+}
+
+#[test]
+#[cfg(not(feature = "strict-parse"))]
+fn test_strict_parse_requires_feature() {
+    tmpltool()
+        .arg("--strict-parse")
+        .write_stdin("ok")
+        .assert()
+        .failure()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "built without the 'strict-parse' feature",
+        ));
+}
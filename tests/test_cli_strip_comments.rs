@@ -0,0 +1,64 @@
+//! Integration tests for the --strip-comments CLI flag
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    Command::cargo_bin("tmpltool").unwrap()
+}
+
+#[test]
+fn test_strip_comments_removes_hash_lines() {
+    tmpltool()
+        .arg("--strip-comments")
+        .write_stdin("# authoring note\nname: {{ \"app\" }}\n")
+        .assert()
+        .success()
+        .stdout("name: app");
+}
+
+#[test]
+fn test_strip_comments_with_comment_syntax() {
+    tmpltool()
+        .arg("--strip-comments")
+        .arg("--comment-syntax")
+        .arg("slash")
+        .write_stdin("// note\n{\"a\": 1}\n")
+        .assert()
+        .success()
+        .stdout("{\"a\": 1}");
+}
+
+#[test]
+fn test_strip_comments_runs_before_validation() {
+    tmpltool()
+        .arg("--strip-comments")
+        .arg("--comment-syntax")
+        .arg("slash")
+        .arg("--validate")
+        .arg("json")
+        .write_stdin("// not valid JSON on its own\n{\"a\": 1}\n")
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_comment_syntax_requires_strip_comments() {
+    tmpltool()
+        .arg("--comment-syntax")
+        .arg("slash")
+        .write_stdin("x")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--strip-comments"));
+}
+
+#[test]
+fn test_without_strip_comments_output_is_unchanged() {
+    tmpltool()
+        .write_stdin("# kept\nx\n")
+        .assert()
+        .success()
+        .stdout("# kept\nx");
+}
@@ -0,0 +1,82 @@
+//! Integration tests for the --timeout CLI flag
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::time::{Duration, Instant};
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    Command::cargo_bin("tmpltool").unwrap()
+}
+
+#[test]
+fn test_timeout_fast_render_succeeds() {
+    tmpltool()
+        .arg("--timeout")
+        .arg("10")
+        .write_stdin("Hello {{ \"World\" }}")
+        .assert()
+        .success()
+        .stdout("Hello World");
+}
+
+#[test]
+fn test_timeout_slow_render_aborts() {
+    let started = Instant::now();
+    tmpltool()
+        .arg("--timeout")
+        .arg("0.5")
+        .write_stdin(
+            "{% for i in range(100000) %}{% for j in range(100000) %}{% endfor %}{% endfor %}",
+        )
+        .assert()
+        .failure()
+        .stdout("")
+        .stderr(predicate::str::contains("Rendering timed out after 0.5s"));
+    assert!(started.elapsed() < Duration::from_secs(30));
+}
+
+#[test]
+fn test_timeout_render_error_still_reported() {
+    tmpltool()
+        .arg("--timeout")
+        .arg("10")
+        .write_stdin("{{ undefined_var }}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to render template"));
+}
+
+#[test]
+fn test_timeout_rejects_non_positive() {
+    tmpltool()
+        .arg("--timeout")
+        .arg("0")
+        .write_stdin("x")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("positive number of seconds"));
+}
+
+#[test]
+fn test_timeout_rejects_non_number() {
+    tmpltool()
+        .arg("--timeout")
+        .arg("soon")
+        .write_stdin("x")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not a number of seconds"));
+}
+
+#[test]
+fn test_timeout_rejects_oversized() {
+    tmpltool()
+        .arg("--timeout")
+        .arg("1e300")
+        .write_stdin("x")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("too large"));
+}
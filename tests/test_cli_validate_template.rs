@@ -0,0 +1,74 @@
+//! Integration tests for `--validate template` (template linting)
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    Command::cargo_bin("tmpltool").unwrap()
+}
+
+#[test]
+fn test_validate_template_passes_without_rendering() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let output = temp_dir.path().join("out.txt");
+
+    // `missing` is undefined, so rendering would fail; linting does not evaluate it
+    tmpltool()
+        .args(["--validate", "template", "-o"])
+        .arg(&output)
+        .write_stdin("{{ missing | upper }}")
+        .assert()
+        .success()
+        .stdout("");
+    assert!(!output.exists());
+}
+
+#[test]
+#[cfg(feature = "strict-parse")]
+fn test_validate_template_lists_all_issues() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let template = temp_dir.path().join("page.tmpltool");
+    std::fs::write(
+        &template,
+        "{% for x in items %}\n{{ x | uppr }}\n{% endfor %}\n{{ x is evn }}\n",
+    )
+    .unwrap();
+
+    tmpltool()
+        .arg(&template)
+        .args(["--validate", "template"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("2 issue(s)"))
+        .stderr(predicate::str::contains(
+            "page.tmpltool:2:8: unknown filter 'uppr'",
+        ))
+        .stderr(predicate::str::contains(
+            "page.tmpltool:4:9: unknown test 'evn'",
+        ));
+}
+
+#[test]
+#[cfg(feature = "strict-parse")]
+fn test_validate_template_unclosed_block() {
+    tmpltool()
+        .args(["--validate", "template"])
+        .write_stdin("line\n  {% if ready %}\nyes\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "template:2:6: unclosed {% if %} (missing {% endif %})",
+        ));
+}
+
+#[test]
+#[cfg(not(feature = "strict-parse"))]
+fn test_validate_template_reports_syntax_error_without_feature() {
+    tmpltool()
+        .args(["--validate", "template"])
+        .write_stdin("{% for x in items %}\n{{ x }}\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("syntax error"));
+}
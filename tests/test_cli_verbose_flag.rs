@@ -0,0 +1,79 @@
+//! Integration tests for the --verbose CLI flag
+//!
+//! These tests verify that rendering phases are logged to stderr only when requested.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    Command::cargo_bin("tmpltool").unwrap()
+}
+
+/// Helper to create a temporary template file
+fn create_template_file(dir: &TempDir, name: &str, content: &str) -> String {
+    let path = dir.path().join(name);
+    fs::write(&path, content).unwrap();
+    path.to_str().unwrap().to_string()
+}
+
+#[test]
+fn test_verbose_flag_logs_phases() {
+    let dir = TempDir::new().unwrap();
+    let template = create_template_file(&dir, "test.tmpltool", "Hello");
+
+    tmpltool()
+        .args(["--verbose", &template])
+        .assert()
+        .success()
+        .stdout("Hello")
+        .stderr(predicate::str::contains("[VERBOSE] read template"))
+        .stderr(predicate::str::contains("[VERBOSE] build context"))
+        .stderr(predicate::str::contains("env snapshot").not())
+        .stderr(predicate::str::contains("[VERBOSE] register functions"))
+        .stderr(predicate::str::contains("[VERBOSE] parse"))
+        .stderr(predicate::str::contains("[VERBOSE] render"))
+        .stderr(predicate::str::contains("[VERBOSE] write"))
+        .stderr(predicate::str::contains("[VERBOSE] total"));
+}
+
+#[test]
+fn test_verbose_short_flag() {
+    let dir = TempDir::new().unwrap();
+    let template = create_template_file(&dir, "test.tmpltool", "Hello");
+
+    tmpltool()
+        .args(["-v", &template])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("[VERBOSE]"));
+}
+
+#[test]
+fn test_default_output_is_quiet() {
+    let dir = TempDir::new().unwrap();
+    let template = create_template_file(&dir, "test.tmpltool", "Hello");
+
+    tmpltool()
+        .arg(&template)
+        .assert()
+        .success()
+        .stdout("Hello")
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn test_verbose_logs_env_file_loading() {
+    let dir = TempDir::new().unwrap();
+    let env_file = create_template_file(&dir, ".env", "A=1\n");
+    let template = create_template_file(&dir, "test.tmpltool", "{{ get_env(name=\"A\") }}");
+
+    tmpltool()
+        .args(["--verbose", "--env", &env_file, &template])
+        .assert()
+        .success()
+        .stdout("1")
+        .stderr(predicate::str::contains("[VERBOSE] load env files"));
+}
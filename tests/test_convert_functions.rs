@@ -0,0 +1,180 @@
+use minijinja::Environment;
+use std::path::PathBuf;
+use tmpltool::{TemplateContext, functions::register_all};
+
+fn render_template(template: &str) -> String {
+    let mut env = Environment::new();
+    let ctx = TemplateContext::new(PathBuf::from("."), false);
+    register_all(&mut env, ctx);
+
+    let tmpl = env.template_from_str(template).unwrap();
+    tmpl.render(()).unwrap()
+}
+
+fn render_template_err(template: &str) -> String {
+    let mut env = Environment::new();
+    let ctx = TemplateContext::new(PathBuf::from("."), false);
+    register_all(&mut env, ctx);
+
+    let tmpl = env.template_from_str(template).unwrap();
+    tmpl.render(()).unwrap_err().to_string()
+}
+
+// ==================== to_int Tests ====================
+
+#[test]
+fn test_to_int_from_string() {
+    assert_eq!(render_template(r#"{{ to_int(value="42") }}"#), "42");
+    assert_eq!(render_template(r#"{{ to_int(value=" -7 ") }}"#), "-7");
+}
+
+#[test]
+fn test_to_int_truncates_floats() {
+    assert_eq!(render_template(r#"{{ to_int(value=3.9) }}"#), "3");
+    assert_eq!(render_template(r#"{{ to_int(value="-3.9") }}"#), "-3");
+}
+
+#[test]
+fn test_to_int_from_bool() {
+    assert_eq!(
+        render_template(r#"{{ to_int(value=true) }}-{{ to_int(value=false) }}"#),
+        "1-0"
+    );
+}
+
+#[test]
+fn test_to_int_result_is_number() {
+    assert_eq!(render_template(r#"{{ to_int(value="2") + 3 }}"#), "5");
+}
+
+#[test]
+fn test_to_int_invalid_errors() {
+    let err = render_template_err(r#"{{ to_int(value="abc") }}"#);
+    assert!(err.contains("to_int: cannot convert 'abc' to an integer"));
+}
+
+#[test]
+fn test_to_int_invalid_uses_default() {
+    assert_eq!(
+        render_template(r#"{{ to_int(value="abc", default=8) }}"#),
+        "8"
+    );
+    assert_eq!(
+        render_template(r#"{{ to_int(value=[1], default=0) }}"#),
+        "0"
+    );
+}
+
+// ==================== to_float Tests ====================
+
+#[test]
+fn test_to_float_from_string() {
+    assert_eq!(render_template(r#"{{ to_float(value="3.14") }}"#), "3.14");
+}
+
+#[test]
+fn test_to_float_from_int() {
+    assert_eq!(render_template(r#"{{ to_float(value=2) }}"#), "2.0");
+}
+
+#[test]
+fn test_to_float_from_bool() {
+    assert_eq!(render_template(r#"{{ to_float(value=true) }}"#), "1.0");
+}
+
+#[test]
+fn test_to_float_rejects_nan() {
+    let err = render_template_err(r#"{{ to_float(value="NaN") }}"#);
+    assert!(err.contains("cannot convert"));
+}
+
+#[test]
+fn test_to_float_invalid_uses_default() {
+    assert_eq!(
+        render_template(r#"{{ to_float(value="n/a", default=0.5) }}"#),
+        "0.5"
+    );
+}
+
+// ==================== to_bool Tests ====================
+
+#[test]
+fn test_to_bool_truthy_strings() {
+    for s in ["true", "TRUE", "1", "yes", "Y", "on"] {
+        let result = render_template(&format!(r#"{{{{ to_bool(value="{}") }}}}"#, s));
+        assert_eq!(result, "true", "expected '{}' to be true", s);
+    }
+}
+
+#[test]
+fn test_to_bool_falsy_strings() {
+    for s in ["false", "0", "no", "N", "off", ""] {
+        let result = render_template(&format!(r#"{{{{ to_bool(value="{}") }}}}"#, s));
+        assert_eq!(result, "false", "expected '{}' to be false", s);
+    }
+}
+
+#[test]
+fn test_to_bool_numbers_and_none() {
+    assert_eq!(
+        render_template(
+            r#"{{ to_bool(value=0) }}-{{ to_bool(value=2.5) }}-{{ to_bool(value=none) }}"#
+        ),
+        "false-true-false"
+    );
+}
+
+#[test]
+fn test_to_bool_invalid_errors() {
+    let err = render_template_err(r#"{{ to_bool(value="maybe") }}"#);
+    assert!(err.contains("to_bool: cannot convert 'maybe' to a boolean"));
+}
+
+#[test]
+fn test_to_bool_invalid_uses_default() {
+    assert_eq!(
+        render_template(r#"{{ to_bool(value="maybe", default=true) }}"#),
+        "true"
+    );
+}
+
+// ==================== to_string Tests ====================
+
+#[test]
+fn test_to_string_scalars() {
+    assert_eq!(
+        render_template(
+            r#"{{ to_string(value=42) ~ to_string(value=true) ~ to_string(value="x") }}"#
+        ),
+        "42truex"
+    );
+}
+
+#[test]
+fn test_to_string_collections_as_json() {
+    assert_eq!(
+        render_template(r#"{{ to_string(value=[1, "a"]) }}"#),
+        r#"[1,"a"]"#
+    );
+    assert_eq!(
+        render_template(r#"{{ to_string(value={"k": 1}) }}"#),
+        r#"{"k":1}"#
+    );
+}
+
+#[test]
+fn test_to_string_none_uses_default() {
+    assert_eq!(render_template(r#"[{{ to_string(value=none) }}]"#), "[]");
+    assert_eq!(
+        render_template(r#"{{ to_string(value=none, default="n/a") }}"#),
+        "n/a"
+    );
+}
+
+#[test]
+fn test_to_string_result_is_string() {
+    assert_eq!(
+        render_template(r#"{{ to_string(value=5) is string }}"#),
+        "true"
+    );
+}
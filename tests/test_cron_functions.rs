@@ -0,0 +1,96 @@
+use minijinja::Environment;
+use std::path::PathBuf;
+use tmpltool::{TemplateContext, functions::register_all};
+
+fn describe(expression: &str) -> Result<String, String> {
+    let mut env = Environment::new();
+    let ctx = TemplateContext::new(PathBuf::from("."), false);
+    register_all(&mut env, ctx);
+
+    let tmpl = env
+        .template_from_str("{{ cron_describe(expression=expression) }}")
+        .unwrap();
+    tmpl.render(minijinja::context! { expression })
+        .map_err(|e| e.to_string())
+}
+
+#[test]
+fn test_cron_describe_daily_time() {
+    assert_eq!(describe("30 2 * * *").unwrap(), "At 02:30 every day");
+    assert_eq!(
+        describe("0 9,17 * * *").unwrap(),
+        "At 09:00 and 17:00 every day"
+    );
+}
+
+#[test]
+fn test_cron_describe_intervals() {
+    assert_eq!(describe("* * * * *").unwrap(), "Every minute");
+    assert_eq!(describe("*/15 * * * *").unwrap(), "Every 15 minutes");
+    assert_eq!(describe("0 * * * *").unwrap(), "Every hour");
+    assert_eq!(describe("0 */6 * * *").unwrap(), "Every 6 hours");
+    assert_eq!(describe("5 * * * *").unwrap(), "At 5 minutes past the hour");
+}
+
+#[test]
+fn test_cron_describe_hour_window_and_weekdays() {
+    assert_eq!(
+        describe("*/15 9-17 * * 1-5").unwrap(),
+        "Every 15 minutes, between 09:00 and 17:59, Monday through Friday"
+    );
+    assert_eq!(
+        describe("0 12 * * MON,wed,FRI").unwrap(),
+        "At 12:00, only on Monday, Wednesday and Friday"
+    );
+    assert_eq!(describe("0 0 * * 7").unwrap(), "At 00:00, only on Sunday");
+}
+
+#[test]
+fn test_cron_describe_days_and_months() {
+    assert_eq!(
+        describe("0 0 1,15 * *").unwrap(),
+        "At 00:00, on days 1 and 15 of the month"
+    );
+    assert_eq!(
+        describe("0 6 * JAN-MAR *").unwrap(),
+        "At 06:00, January through March"
+    );
+    assert_eq!(describe("0 0 */3 * *").unwrap(), "At 00:00, every 3 days");
+    assert_eq!(
+        describe("0 8 1 * 1").unwrap(),
+        "At 08:00, on day 1 of the month or on Monday"
+    );
+}
+
+#[test]
+fn test_cron_describe_macros() {
+    assert_eq!(describe("@daily").unwrap(), "At 00:00 every day");
+    assert_eq!(describe("@hourly").unwrap(), "Every hour");
+    assert_eq!(
+        describe("@yearly").unwrap(),
+        "At 00:00, on day 1 of the month, only in January"
+    );
+    assert_eq!(describe("@reboot").unwrap(), "At system startup");
+}
+
+#[test]
+fn test_cron_describe_invalid() {
+    for (expression, message) in [
+        ("1 2 3 4", "expected 5 fields"),
+        ("61 * * * *", "minute value 61 is out of range 0-59"),
+        ("0 5-2 * * *", "hour range 5-2 is reversed"),
+        ("*/0 * * * *", "invalid minute step '0'"),
+        ("0 0 L * *", "invalid day-of-month value 'L'"),
+        ("@often", "unknown macro '@often'"),
+    ] {
+        let err = describe(expression).unwrap_err();
+        assert!(
+            err.contains(&format!(
+                "cron_describe: invalid expression '{}': {}",
+                expression, message
+            )),
+            "{}",
+            err
+        );
+    }
+}
@@ -6,7 +6,9 @@ use tmpltool::TemplateContext;
 use tmpltool::filter_functions::FilterFunction;
 use tmpltool::filter_functions::serialization::{ParseJson, ParseToml, ParseYaml};
 use tmpltool::functions::ContextFunction;
-use tmpltool::functions::data_parsing::{ReadJsonFile, ReadTomlFile, ReadYamlFile};
+use tmpltool::functions::data_parsing::{
+    ReadJsonFile, ReadJsonValue, ReadTomlFile, ReadTomlValue, ReadYamlFile, ReadYamlValue,
+};
 
 // Helper to create kwargs for testing
 fn create_kwargs(args: Vec<(&str, &str)>) -> Kwargs {
@@ -1367,3 +1369,162 @@ fn test_read_toml_file_with_inline_table() {
     // Cleanup
     fs::remove_file(&toml_file).unwrap();
 }
+
+// ========== read_*_value tests ==========
+
+// Helper to create kwargs for single-value readers
+fn value_kwargs(path: &str, key: &str, default: Option<minijinja::Value>) -> Kwargs {
+    let mut args = vec![
+        ("path", minijinja::Value::from(path)),
+        ("key", minijinja::Value::from(key)),
+    ];
+    if let Some(default) = default {
+        args.push(("default", default));
+    }
+    Kwargs::from_iter(args)
+}
+
+#[test]
+fn test_read_toml_value_nested_key() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    fs::write(
+        temp_dir.path().join("Cargo.toml"),
+        "[package]\nname = \"myapp\"\nversion = \"1.2.3\"\n",
+    )
+    .unwrap();
+    let context = Arc::new(TemplateContext::new(temp_dir.path().to_path_buf(), false));
+
+    let result =
+        ReadTomlValue::call(context, value_kwargs("Cargo.toml", "package.version", None)).unwrap();
+    assert_eq!(result.as_str(), Some("1.2.3"));
+}
+
+#[test]
+fn test_read_toml_value_missing_key_returns_default() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    fs::write(temp_dir.path().join("config.toml"), "[server]\nport = 80\n").unwrap();
+    let context = Arc::new(TemplateContext::new(temp_dir.path().to_path_buf(), false));
+
+    let result = ReadTomlValue::call(
+        context,
+        value_kwargs(
+            "config.toml",
+            "server.host",
+            Some(minijinja::Value::from("localhost")),
+        ),
+    )
+    .unwrap();
+    assert_eq!(result.as_str(), Some("localhost"));
+}
+
+#[test]
+fn test_read_toml_value_missing_key_without_default_is_undefined() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    fs::write(temp_dir.path().join("config.toml"), "[server]\nport = 80\n").unwrap();
+    let context = Arc::new(TemplateContext::new(temp_dir.path().to_path_buf(), false));
+
+    let result =
+        ReadTomlValue::call(context, value_kwargs("config.toml", "server.host", None)).unwrap();
+    assert!(result.is_undefined());
+}
+
+#[test]
+fn test_read_json_value_array_index() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    fs::write(
+        temp_dir.path().join("data.json"),
+        r#"{"servers": [{"name": "a"}, {"name": "b"}]}"#,
+    )
+    .unwrap();
+    let context = Arc::new(TemplateContext::new(temp_dir.path().to_path_buf(), false));
+
+    let result =
+        ReadJsonValue::call(context, value_kwargs("data.json", "servers.1.name", None)).unwrap();
+    assert_eq!(result.as_str(), Some("b"));
+}
+
+#[test]
+fn test_read_json_value_returns_object() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    fs::write(
+        temp_dir.path().join("data.json"),
+        r#"{"db": {"host": "x", "port": 5432}}"#,
+    )
+    .unwrap();
+    let context = Arc::new(TemplateContext::new(temp_dir.path().to_path_buf(), false));
+
+    let result = ReadJsonValue::call(context, value_kwargs("data.json", "db", None)).unwrap();
+    assert_eq!(result.get_attr("port").unwrap().as_i64(), Some(5432));
+}
+
+#[test]
+fn test_read_json_value_null_is_returned_not_default() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    fs::write(temp_dir.path().join("data.json"), r#"{"value": null}"#).unwrap();
+    let context = Arc::new(TemplateContext::new(temp_dir.path().to_path_buf(), false));
+
+    let result = ReadJsonValue::call(
+        context,
+        value_kwargs("data.json", "value", Some(minijinja::Value::from(1))),
+    )
+    .unwrap();
+    assert!(result.is_none());
+}
+
+#[test]
+fn test_read_yaml_value_nested_key() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    fs::write(
+        temp_dir.path().join("values.yaml"),
+        "image:\n  repository: nginx\n  tag: \"1.25\"\n",
+    )
+    .unwrap();
+    let context = Arc::new(TemplateContext::new(temp_dir.path().to_path_buf(), false));
+
+    let result =
+        ReadYamlValue::call(context, value_kwargs("values.yaml", "image.tag", None)).unwrap();
+    assert_eq!(result.as_str(), Some("1.25"));
+}
+
+#[test]
+fn test_read_yaml_value_key_through_scalar_returns_default() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    fs::write(temp_dir.path().join("values.yaml"), "replicas: 3\n").unwrap();
+    let context = Arc::new(TemplateContext::new(temp_dir.path().to_path_buf(), false));
+
+    let result = ReadYamlValue::call(
+        context,
+        value_kwargs(
+            "values.yaml",
+            "replicas.count",
+            Some(minijinja::Value::from(1)),
+        ),
+    )
+    .unwrap();
+    assert_eq!(result.as_i64(), Some(1));
+}
+
+#[test]
+fn test_read_json_value_missing_file_errors() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let context = Arc::new(TemplateContext::new(temp_dir.path().to_path_buf(), false));
+
+    let result = ReadJsonValue::call(
+        context,
+        value_kwargs("missing.json", "a", Some(minijinja::Value::from(1))),
+    );
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("Failed to read file")
+    );
+}
+
+#[test]
+fn test_read_toml_value_security_absolute_path() {
+    let context = Arc::new(TemplateContext::new(PathBuf::from("."), false));
+
+    let result = ReadTomlValue::call(context, value_kwargs("/etc/config.toml", "a", None));
+    assert!(result.unwrap_err().to_string().contains("Security"));
+}
@@ -2,12 +2,15 @@
 //!
 //! Tests both function and filter syntax for:
 //! - format_date, get_year, get_month, get_day, get_hour, get_minute, get_second
+//! - day_of_week, week_of_year, day_of_year, quarter
+//! - time_ago
 
 use minijinja::Value;
 use minijinja::value::Kwargs;
 use tmpltool::filter_functions::FilterFunction;
 use tmpltool::filter_functions::datetime::{
-    FormatDate, GetDay, GetHour, GetMinute, GetMonth, GetSecond, GetYear,
+    DayOfWeek, DayOfYear, FormatDate, GetDay, GetHour, GetMinute, GetMonth, GetSecond, GetYear,
+    Quarter, TimeAgo, WeekOfYear,
 };
 
 /// Helper to create empty kwargs
@@ -250,3 +253,136 @@ fn test_get_second_error_not_number() {
     let result = GetSecond::call_as_filter(&Value::from("not a number"), empty_kwargs());
     assert!(result.is_err());
 }
+
+// ============================================
+// DayOfWeek / WeekOfYear / DayOfYear / Quarter tests
+// ============================================
+
+// 2024-12-31 00:00:00 UTC (Tuesday, ISO week 1 of 2025, leap year)
+const NEW_YEARS_EVE_2024: i64 = 1735603200;
+
+#[test]
+fn test_day_of_week_filter_syntax() {
+    // 2024-01-01 was a Monday
+    let result = DayOfWeek::call_as_filter(&Value::from(TEST_TIMESTAMP), empty_kwargs()).unwrap();
+    assert_eq!(result.as_i64().unwrap(), 0);
+
+    let result =
+        DayOfWeek::call_as_filter(&Value::from(NEW_YEARS_EVE_2024), empty_kwargs()).unwrap();
+    assert_eq!(result.as_i64().unwrap(), 1);
+}
+
+#[test]
+fn test_day_of_week_name() {
+    let kwargs = Kwargs::from_iter(vec![
+        ("timestamp", Value::from(TEST_TIMESTAMP + 6 * 86400)),
+        ("name", Value::from(true)),
+    ]);
+    let result = DayOfWeek::call_as_function(kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "Sunday");
+}
+
+#[test]
+fn test_week_of_year_iso() {
+    let result = WeekOfYear::call_as_filter(&Value::from(TEST_TIMESTAMP), empty_kwargs()).unwrap();
+    assert_eq!(result.as_i64().unwrap(), 1);
+
+    // 2024-12-31 belongs to ISO week 1 of 2025
+    let result =
+        WeekOfYear::call_as_filter(&Value::from(NEW_YEARS_EVE_2024), empty_kwargs()).unwrap();
+    assert_eq!(result.as_i64().unwrap(), 1);
+
+    // 2024-03-15 is in week 11
+    let kwargs = Kwargs::from_iter(vec![("timestamp", Value::from(1710460800))]);
+    assert_eq!(
+        WeekOfYear::call_as_function(kwargs).unwrap().as_i64(),
+        Some(11)
+    );
+}
+
+#[test]
+fn test_day_of_year() {
+    let result = DayOfYear::call_as_filter(&Value::from(TEST_TIMESTAMP), empty_kwargs()).unwrap();
+    assert_eq!(result.as_i64().unwrap(), 1);
+
+    let kwargs = Kwargs::from_iter(vec![("timestamp", Value::from(NEW_YEARS_EVE_2024))]);
+    assert_eq!(
+        DayOfYear::call_as_function(kwargs).unwrap().as_i64(),
+        Some(366)
+    );
+}
+
+#[test]
+fn test_quarter() {
+    for (timestamp, expected) in [
+        (TEST_TIMESTAMP, 1),
+        (1711929600, 2), // 2024-04-01
+        (1719791999, 2), // 2024-06-30 23:59:59
+        (1719792000, 3), // 2024-07-01
+        (NEW_YEARS_EVE_2024, 4),
+    ] {
+        let result = Quarter::call_as_filter(&Value::from(timestamp), empty_kwargs()).unwrap();
+        assert_eq!(
+            result.as_i64().unwrap(),
+            expected,
+            "timestamp {}",
+            timestamp
+        );
+    }
+}
+
+#[test]
+fn test_calendar_extractors_error_not_number() {
+    assert!(DayOfWeek::call_as_filter(&Value::from("x"), empty_kwargs()).is_err());
+    assert!(WeekOfYear::call_as_filter(&Value::from("x"), empty_kwargs()).is_err());
+    assert!(DayOfYear::call_as_filter(&Value::from("x"), empty_kwargs()).is_err());
+    assert!(Quarter::call_as_filter(&Value::from("x"), empty_kwargs()).is_err());
+}
+
+// ============================================
+// TimeAgo tests
+// ============================================
+
+fn time_ago(offset: i64) -> String {
+    let kwargs = Kwargs::from_iter(vec![("now", Value::from(TEST_TIMESTAMP))]);
+    TimeAgo::call_as_filter(&Value::from(TEST_TIMESTAMP + offset), kwargs)
+        .unwrap()
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn test_time_ago_past() {
+    assert_eq!(time_ago(-90), "1 minute ago");
+    assert_eq!(time_ago(-3 * 3600), "3 hours ago");
+    assert_eq!(time_ago(-3 * 86400 - 100), "3 days ago");
+    assert_eq!(time_ago(-14 * 86400), "2 weeks ago");
+    assert_eq!(time_ago(-400 * 86400), "1 year ago");
+}
+
+#[test]
+fn test_time_ago_future() {
+    assert_eq!(time_ago(2 * 3600), "in 2 hours");
+    assert_eq!(time_ago(61 * 86400), "in 2 months");
+}
+
+#[test]
+fn test_time_ago_just_now() {
+    assert_eq!(time_ago(0), "just now");
+    assert_eq!(time_ago(-59), "just now");
+    assert_eq!(time_ago(30), "just now");
+}
+
+#[test]
+fn test_time_ago_function_syntax_defaults_to_current_time() {
+    let kwargs = Kwargs::from_iter(vec![("timestamp", Value::from(TEST_TIMESTAMP))]);
+    let result = TimeAgo::call_as_function(kwargs).unwrap();
+    assert!(result.as_str().unwrap().ends_with(" ago"));
+}
+
+#[test]
+fn test_time_ago_error_not_number() {
+    let result = TimeAgo::call_as_filter(&Value::from("yesterday"), empty_kwargs());
+    assert!(result.is_err());
+}
@@ -705,8 +705,228 @@ fn test_timezone_convert_invalid_to_tz() {
     assert!(result.unwrap_err().to_string().contains("Invalid timezone"));
 }
 
+// Tests for tz_convert
+#[test]
+fn test_tz_convert_epoch_default_format() {
+    let env = create_env();
+    let result = render_template(
+        &env,
+        "{{ tz_convert(timestamp=1704067200, to=\"Asia/Tokyo\") }}",
+    )
+    .unwrap();
+    assert_eq!(result, "2024-01-01T09:00:00+09:00");
+}
+
+#[test]
+fn test_tz_convert_naive_string_uses_from_zone() {
+    let env = create_env();
+    let result = render_template(
+        &env,
+        "{{ tz_convert(timestamp=\"2024-06-01 09:00\", from=\"Europe/Warsaw\", to=\"America/New_York\", format=\"%H:%M %Z\") }}",
+    )
+    .unwrap();
+    assert_eq!(result, "03:00 EDT");
+}
+
+#[test]
+fn test_tz_convert_offset_string_ignores_from() {
+    let env = create_env();
+    let result = render_template(
+        &env,
+        "{{ tz_convert(timestamp=\"2024-01-01T12:00:00+01:00\", from=\"Asia/Tokyo\", to=\"UTC\", format=\"%H:%M\") }}",
+    )
+    .unwrap();
+    assert_eq!(result, "11:00");
+}
+
+#[test]
+fn test_tz_convert_date_only() {
+    let env = create_env();
+    let result = render_template(
+        &env,
+        "{{ tz_convert(timestamp=\"2024-01-01\", to=\"America/Los_Angeles\") }}",
+    )
+    .unwrap();
+    assert_eq!(result, "2023-12-31T16:00:00-08:00");
+}
+
+#[test]
+fn test_tz_convert_errors() {
+    let env = create_env();
+    for (args, message) in [
+        (
+            "timestamp=0, to=\"Mars/Base\"",
+            "tz_convert: unknown timezone 'Mars/Base'",
+        ),
+        (
+            "timestamp=0, from=\"Nowhere\", to=\"UTC\"",
+            "tz_convert: unknown timezone 'Nowhere'",
+        ),
+        (
+            "timestamp=\"yesterday\", to=\"UTC\"",
+            "must be a Unix timestamp or an ISO 8601 date-time",
+        ),
+        (
+            "timestamp=\"2024-03-31 02:30\", from=\"Europe/Warsaw\", to=\"UTC\"",
+            "does not exist in Europe/Warsaw",
+        ),
+        (
+            "timestamp=0, to=\"UTC\", format=\"%Q\"",
+            "invalid format string '%Q'",
+        ),
+    ] {
+        let template = format!("{{{{ tz_convert({}) }}}}", args);
+        let err = render_template(&env, &template).unwrap_err().to_string();
+        assert!(err.contains(message), "{}", err);
+    }
+}
+
+// Tests for parse_duration
+#[test]
+fn test_parse_duration_compound() {
+    let env = create_env();
+    let result = render_template(&env, "{{ parse_duration(string=\"1h30m\") }}").unwrap();
+    assert_eq!(result, "5400");
+}
+
+#[test]
+fn test_parse_duration_single_units() {
+    let env = create_env();
+    for (input, expected) in [
+        ("90s", "90"),
+        ("2d", "172800"),
+        ("1w", "604800"),
+        ("15m", "900"),
+    ] {
+        let template = format!("{{{{ parse_duration(string=\"{}\") }}}}", input);
+        assert_eq!(render_template(&env, &template).unwrap(), expected);
+    }
+}
+
+#[test]
+fn test_parse_duration_long_units_and_spaces() {
+    let env = create_env();
+    let result = render_template(
+        &env,
+        "{{ parse_duration(string=\"1 day, 2 hours 30 minutes\") }}",
+    )
+    .unwrap();
+    assert_eq!(result, "95400");
+}
+
+#[test]
+fn test_parse_duration_fractional() {
+    let env = create_env();
+    assert_eq!(
+        render_template(&env, "{{ parse_duration(string=\"1.5h\") }}").unwrap(),
+        "5400"
+    );
+    assert_eq!(
+        render_template(&env, "{{ parse_duration(string=\"250ms\") }}").unwrap(),
+        "0.25"
+    );
+}
+
+#[test]
+fn test_parse_duration_invalid() {
+    let env = create_env();
+    for (input, message) in [
+        ("", "duration is empty"),
+        ("10", "missing unit after '10'"),
+        ("5x", "unknown unit 'x'"),
+        ("soon", "expected a number before 'soon'"),
+        ("-5s", "unexpected character '-'"),
+    ] {
+        let template = format!("{{{{ parse_duration(string=\"{}\") }}}}", input);
+        let err = render_template(&env, &template).unwrap_err().to_string();
+        assert!(
+            err.contains(&format!("invalid duration '{}': {}", input, message)),
+            "{}",
+            err
+        );
+    }
+}
+
 // ============ Direct Function Tests ============
 
+// Tests for business_days_between
+#[test]
+fn test_business_days_between_two_weeks() {
+    let env = create_env();
+    let result = render_template(
+        &env,
+        "{{ business_days_between(start=\"2024-01-01\", end=\"2024-01-15\") }}",
+    )
+    .unwrap();
+    assert_eq!(result, "10");
+}
+
+#[test]
+fn test_business_days_between_skips_holidays() {
+    let env = create_env();
+    // 2024-12-28 is a Saturday, so it does not reduce the count twice
+    let result = render_template(
+        &env,
+        "{{ business_days_between(start=\"2024-12-20\", end=\"2025-01-03\", \
+         holidays=[\"2024-12-25\", \"2024-12-26\", \"2025-01-01\", \"2024-12-28\"]) }}",
+    )
+    .unwrap();
+    assert_eq!(result, "7");
+}
+
+#[test]
+fn test_business_days_between_reversed_is_negative() {
+    let env = create_env();
+    let result = render_template(
+        &env,
+        "{{ business_days_between(start=\"2024-01-15\", end=\"2024-01-01\") }}",
+    )
+    .unwrap();
+    assert_eq!(result, "-10");
+}
+
+#[test]
+fn test_business_days_between_timestamps_and_weekend() {
+    let env = create_env();
+    // 1704067200 = 2024-01-01 (Monday)
+    assert_eq!(
+        render_template(
+            &env,
+            "{{ business_days_between(start=1704067200, end=\"2024-01-08T10:00:00Z\") }}"
+        )
+        .unwrap(),
+        "5"
+    );
+    assert_eq!(
+        render_template(
+            &env,
+            "{{ business_days_between(start=\"2024-01-06\", end=\"2024-01-08\") }}"
+        )
+        .unwrap(),
+        "0"
+    );
+}
+
+#[test]
+fn test_business_days_between_errors() {
+    let env = create_env();
+    let err = render_template(
+        &env,
+        "{{ business_days_between(start=\"soon\", end=\"2024-01-01\") }}",
+    )
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains("start must be a YYYY-MM-DD date"), "{}", err);
+
+    let err = render_template(
+        &env,
+        "{{ business_days_between(start=\"2024-01-01\", end=\"2024-01-02\", holidays=\"2024-01-01\") }}",
+    )
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains("holidays must be an array"), "{}", err);
+}
+
 mod unit_tests {
     use minijinja::Value;
     use minijinja::value::Kwargs;
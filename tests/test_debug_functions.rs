@@ -1,31 +1,19 @@
 use minijinja::Value;
 use minijinja::value::Kwargs;
-use tmpltool::functions::Function;
-use tmpltool::functions::debug::{Abort, Assert, Debug, Inspect, TypeOf, Warn};
-
-#[test]
-fn test_debug_returns_value() {
-    let result = Debug::call(Kwargs::from_iter(vec![("value", Value::from("test"))])).unwrap();
-    assert_eq!(result.as_str().unwrap(), "test");
+use std::path::PathBuf;
+use std::sync::Arc;
+use tmpltool::TemplateContext;
+use tmpltool::functions::debug::{Abort, Assert, Debug, Inspect, TypeOf, Warn, describe_value};
+use tmpltool::functions::{ContextFunction, Function};
+
+fn create_context() -> Arc<TemplateContext> {
+    Arc::new(TemplateContext::new(PathBuf::from("."), false))
 }
 
 #[test]
-fn test_debug_with_number() {
-    let result = Debug::call(Kwargs::from_iter(vec![("value", Value::from(42))])).unwrap();
-    assert_eq!(result.as_i64(), Some(42));
-}
-
-#[test]
-fn test_debug_with_array() {
-    let arr = vec![1, 2, 3];
-    let result = Debug::call(Kwargs::from_iter(vec![("value", Value::from(arr.clone()))])).unwrap();
-
-    let result_vec: Vec<i64> = result
-        .try_iter()
-        .unwrap()
-        .map(|v: Value| v.as_i64().unwrap())
-        .collect();
-    assert_eq!(result_vec, arr);
+fn test_debug_returns_empty_string() {
+    let result = Debug::call(Kwargs::from_iter(vec![("value", Value::from("test"))])).unwrap();
+    assert_eq!(result.as_str(), Some(""));
 }
 
 #[test]
@@ -37,7 +25,32 @@ fn test_debug_with_object() {
     )]))
     .unwrap();
 
-    assert!(result.is_true());
+    assert_eq!(result.as_str(), Some(""));
+}
+
+#[test]
+fn test_debug_with_undefined() {
+    let result = Debug::call(Kwargs::from_iter(vec![("value", Value::UNDEFINED)])).unwrap();
+    assert_eq!(result.as_str(), Some(""));
+}
+
+#[test]
+fn test_describe_value_scalars() {
+    assert_eq!(describe_value(&Value::from("hi")), "string \"hi\"\n");
+    assert_eq!(describe_value(&Value::from(42)), "number 42\n");
+    assert_eq!(describe_value(&Value::from(true)), "boolean true\n");
+    assert_eq!(describe_value(&Value::from(())), "null\n");
+    assert_eq!(describe_value(&Value::UNDEFINED), "undefined\n");
+}
+
+#[test]
+fn test_describe_value_nested_structure() {
+    let obj = serde_json::json!({"name": "app", "ports": [80, 443]});
+    let description = describe_value(&Value::from_serialize(&obj));
+    assert_eq!(
+        description,
+        "object (2 keys)\n  name: string \"app\"\n  ports: array (2 items)\n    [0]: number 80\n    [1]: number 443\n"
+    );
 }
 
 #[test]
@@ -55,7 +68,7 @@ fn test_type_of_number() {
 #[test]
 fn test_type_of_bool() {
     let result = TypeOf::call(Kwargs::from_iter(vec![("value", Value::from(true))])).unwrap();
-    assert_eq!(result.as_str().unwrap(), "bool");
+    assert_eq!(result.as_str().unwrap(), "boolean");
 }
 
 #[test]
@@ -82,6 +95,26 @@ fn test_type_of_undefined() {
     assert_eq!(result.as_str().unwrap(), "undefined");
 }
 
+#[test]
+fn test_type_of_null() {
+    let result = TypeOf::call(Kwargs::from_iter(vec![("value", Value::from(()))])).unwrap();
+    assert_eq!(result.as_str().unwrap(), "null");
+}
+
+#[test]
+fn test_type_of_float() {
+    let result = TypeOf::call(Kwargs::from_iter(vec![("value", Value::from(1.5))])).unwrap();
+    assert_eq!(result.as_str().unwrap(), "number");
+}
+
+#[test]
+fn test_type_of_parsed_json_null() {
+    let obj = serde_json::json!({"field": null});
+    let value = Value::from_serialize(&obj).get_attr("field").unwrap();
+    let result = TypeOf::call(Kwargs::from_iter(vec![("value", value)])).unwrap();
+    assert_eq!(result.as_str().unwrap(), "null");
+}
+
 #[test]
 fn test_inspect_returns_value() {
     let result = Inspect::call(Kwargs::from_iter(vec![("value", Value::from("test"))])).unwrap();
@@ -109,7 +142,28 @@ fn test_assert_passes() {
     ]))
     .unwrap();
 
-    assert!(result.is_true());
+    assert_eq!(result.as_str(), Some(""));
+}
+
+#[test]
+fn test_assert_truthy_values_pass() {
+    for value in [Value::from(1), Value::from("x"), Value::from(vec![1])] {
+        let result = Assert::call(Kwargs::from_iter(vec![("condition", value)])).unwrap();
+        assert_eq!(result.as_str(), Some(""));
+    }
+}
+
+#[test]
+fn test_assert_falsy_values_fail() {
+    for value in [
+        Value::from(0),
+        Value::from(""),
+        Value::from(Vec::<i32>::new()),
+        Value::from(()),
+    ] {
+        let result = Assert::call(Kwargs::from_iter(vec![("condition", value)]));
+        assert!(result.is_err());
+    }
 }
 
 #[test]
@@ -138,22 +192,38 @@ fn test_assert_fails_with_default_message() {
 
 #[test]
 fn test_warn_returns_empty_string() {
-    let result = Warn::call(Kwargs::from_iter(vec![(
-        "message",
-        Value::from("Test warning"),
-    )]))
+    let result = Warn::call(
+        create_context(),
+        Kwargs::from_iter(vec![("message", Value::from("Test warning"))]),
+    )
     .unwrap();
 
     assert_eq!(result.as_str().unwrap(), "");
 }
 
+#[test]
+fn test_warn_records_warning_on_context() {
+    let context = create_context();
+    assert_eq!(context.warning_count(), 0);
+
+    for _ in 0..2 {
+        Warn::call(
+            context.clone(),
+            Kwargs::from_iter(vec![("message", Value::from("careful"))]),
+        )
+        .unwrap();
+    }
+
+    assert_eq!(context.warning_count(), 2);
+}
+
 #[test]
 fn test_warn_with_long_message() {
     let long_message = "This is a very long warning message that contains important information about something that might be wrong or needs attention";
-    let result = Warn::call(Kwargs::from_iter(vec![(
-        "message",
-        Value::from(long_message),
-    )]))
+    let result = Warn::call(
+        create_context(),
+        Kwargs::from_iter(vec![("message", Value::from(long_message))]),
+    )
     .unwrap();
 
     assert_eq!(result.as_str().unwrap(), "");
@@ -198,7 +268,10 @@ fn test_debug_missing_value() {
 
 #[test]
 fn test_warn_missing_message() {
-    let result = Warn::call(Kwargs::from_iter(Vec::<(&str, Value)>::new()));
+    let result = Warn::call(
+        create_context(),
+        Kwargs::from_iter(Vec::<(&str, Value)>::new()),
+    );
     assert!(result.is_err());
 }
 
@@ -207,3 +280,36 @@ fn test_abort_missing_message() {
     let result = Abort::call(Kwargs::from_iter(Vec::<(&str, Value)>::new()));
     assert!(result.is_err());
 }
+
+fn render_with_context(template: &str, context: serde_json::Value) -> String {
+    let mut env = minijinja::Environment::new();
+    tmpltool::functions::register_all(&mut env, TemplateContext::new(PathBuf::from("."), false));
+    env.render_str(template, context).unwrap()
+}
+
+#[test]
+fn test_context_returns_render_context() {
+    let result = render_with_context(
+        "{{ context() | tojson }}",
+        serde_json::json!({"port": 8080, "app": {"name": "api"}}),
+    );
+    assert_eq!(result, r#"{"app":{"name":"api"},"port":8080}"#);
+}
+
+#[test]
+fn test_context_includes_set_and_loop_variables() {
+    let result = render_with_context(
+        "{% set x = 1 %}{% for item in ['a'] %}{{ context() | list | join(',') }}{% endfor %}",
+        serde_json::json!({"port": 8080}),
+    );
+    assert_eq!(result, "item,loop,port,x");
+}
+
+#[test]
+fn test_context_omits_functions_and_macros() {
+    let result = render_with_context(
+        "{% macro greet() %}hi{% endmacro %}{{ context() | tojson }}",
+        serde_json::json!({}),
+    );
+    assert_eq!(result, "{}");
+}
@@ -0,0 +1,106 @@
+use minijinja::Environment;
+use std::path::PathBuf;
+use tmpltool::{TemplateContext, functions::register_all};
+
+fn render_template(template: &str) -> String {
+    let mut env = Environment::new();
+    let ctx = TemplateContext::new(PathBuf::from("."), false);
+    register_all(&mut env, ctx);
+
+    let tmpl = env.template_from_str(template).unwrap();
+    tmpl.render(()).unwrap()
+}
+
+fn render_template_err(template: &str) -> String {
+    let mut env = Environment::new();
+    let ctx = TemplateContext::new(PathBuf::from("."), false);
+    register_all(&mut env, ctx);
+
+    let tmpl = env.template_from_str(template).unwrap();
+    tmpl.render(()).unwrap_err().to_string()
+}
+
+// ==================== to_mermaid Tests ====================
+
+#[test]
+fn test_to_mermaid_basic() {
+    let result = render_template(
+        r#"{{ to_mermaid(nodes=["api", {"id": "db", "label": "Database"}], edges=[{"from": "api", "to": "db", "label": "reads"}]) }}"#,
+    );
+    assert_eq!(
+        result,
+        "flowchart TD\n    api[\"api\"]\n    db[\"Database\"]\n    api -->|\"reads\"| db"
+    );
+}
+
+#[test]
+fn test_to_mermaid_edges_only_with_direction() {
+    let result =
+        render_template(r#"{{ to_mermaid(edges=[{"from": "a", "to": "b"}], direction="lr") }}"#);
+    assert_eq!(result, "flowchart LR\n    a --> b");
+}
+
+#[test]
+fn test_to_mermaid_sanitizes_ids_and_escapes_labels() {
+    let result = render_template(
+        r#"{{ to_mermaid(nodes=[{"id": "db", "label": "Main \"DB\""}], edges=[{"from": "user-api", "to": "db"}]) }}"#,
+    );
+    assert_eq!(
+        result,
+        "flowchart TD\n    db[\"Main #quot;DB#quot;\"]\n    user_api[\"user-api\"]\n    user_api --> db"
+    );
+}
+
+#[test]
+fn test_to_mermaid_edge_missing_to() {
+    let err = render_template_err(r#"{{ to_mermaid(edges=[{"from": "a"}]) }}"#);
+    assert!(err.contains("to_mermaid: edge at index 0 is missing 'to'"));
+}
+
+#[test]
+fn test_to_mermaid_invalid_direction() {
+    let err = render_template_err(r#"{{ to_mermaid(edges=[], direction="up") }}"#);
+    assert!(err.contains("invalid direction 'UP'"));
+}
+
+#[test]
+fn test_to_mermaid_edges_must_be_array() {
+    let err = render_template_err(r#"{{ to_mermaid(edges="a->b") }}"#);
+    assert!(err.contains("to_mermaid: edges must be an array"));
+}
+
+// ==================== to_dot Tests ====================
+
+#[test]
+fn test_to_dot_basic() {
+    let result = render_template(
+        r#"{{ to_dot(nodes=[{"id": "db", "label": "Database"}], edges=[{"from": "api", "to": "db", "label": "reads"}]) }}"#,
+    );
+    assert_eq!(
+        result,
+        "digraph \"G\" {\n    rankdir=TB;\n    \"db\" [label=\"Database\"];\n    \"api\" -> \"db\" [label=\"reads\"];\n}"
+    );
+}
+
+#[test]
+fn test_to_dot_undirected_named() {
+    let result = render_template(
+        r#"{{ to_dot(edges=[{"from": "a", "to": "b"}], directed=false, name="net", direction="LR") }}"#,
+    );
+    assert_eq!(
+        result,
+        "graph \"net\" {\n    rankdir=LR;\n    \"a\" -- \"b\";\n}"
+    );
+}
+
+#[test]
+fn test_to_dot_escapes_quotes() {
+    let result = render_template(r#"{{ to_dot(edges=[{"from": "say \"hi\"", "to": "b"}]) }}"#);
+    assert!(result.contains(r#""say \"hi\"" -> "b";"#));
+}
+
+#[test]
+fn test_to_dot_node_missing_id() {
+    let err = render_template_err(r#"{{ to_dot(nodes=[{"label": "x"}], edges=[]) }}"#);
+    assert!(err.contains("to_dot: node at index 0 is missing 'id'"));
+}
@@ -0,0 +1,43 @@
+use minijinja::Environment;
+use std::path::PathBuf;
+use tmpltool::{TemplateContext, functions::register_all};
+
+fn render_template(template: &str) -> String {
+    let mut env = Environment::new();
+    let ctx = TemplateContext::new(PathBuf::from("."), false);
+    register_all(&mut env, ctx);
+
+    let tmpl = env.template_from_str(template).unwrap();
+    tmpl.render(()).unwrap()
+}
+
+#[test]
+fn test_diff_text_unified() {
+    let result = render_template(r#"{{ diff_text(a="a\nb\nc\n", b="a\nB\nc\nd\n") }}"#);
+    assert_eq!(
+        result,
+        "--- a\n+++ b\n@@ -1,3 +1,4 @@\n a\n-b\n+B\n c\n+d\n"
+    );
+}
+
+#[test]
+fn test_diff_text_equal_is_empty() {
+    let result = render_template(r#"{{ diff_text(a="same\n", b="same\n") }}"#);
+    assert_eq!(result, "");
+}
+
+#[test]
+fn test_diff_text_context_and_names() {
+    let template = r#"{% set old = "1\n2\n3\n4\n5\n6\n7\n" %}{% set new = "1\n2\n3\nfour\n5\n6\n7\n" %}{{ diff_text(a=old, b=new, context=1, a_name="old.yaml", b_name="new.yaml") }}"#;
+    let result = render_template(template);
+    assert_eq!(
+        result,
+        "--- old.yaml\n+++ new.yaml\n@@ -3,3 +3,3 @@\n 3\n-4\n+four\n 5\n"
+    );
+}
+
+#[test]
+fn test_diff_text_missing_newline() {
+    let result = render_template(r#"{{ diff_text(a="x", b="y") }}"#);
+    assert!(result.contains("-x\n\\ No newline at end of file\n+y\n"));
+}
@@ -0,0 +1,182 @@
+use minijinja::Value;
+use minijinja::value::Kwargs;
+use tmpltool::functions::Function;
+use tmpltool::functions::docker::{BuildDockerRef, ParseDockerRef};
+
+const DIGEST: &str = "sha256:4c5f1ad2b1e7a7d7f3f6bb6a3b4e2a0e4f1c9e8d7b6a5f4e3d2c1b0a9f8e7d6c";
+
+fn parse(reference: &str) -> Value {
+    ParseDockerRef::call(Kwargs::from_iter(vec![("string", Value::from(reference))])).unwrap()
+}
+
+fn field(value: &Value, name: &str) -> Value {
+    value.get_attr(name).unwrap()
+}
+
+// ============================================================================
+// parse_docker_ref Tests
+// ============================================================================
+
+#[test]
+fn test_parse_docker_ref_official_image() {
+    let result = parse("nginx");
+    assert_eq!(field(&result, "registry").to_string(), "docker.io");
+    assert_eq!(field(&result, "repository").to_string(), "library/nginx");
+    assert_eq!(field(&result, "tag").to_string(), "latest");
+    assert!(field(&result, "digest").is_none());
+}
+
+#[test]
+fn test_parse_docker_ref_hub_namespace_with_tag() {
+    let result = parse("bitnami/redis:7.2");
+    assert_eq!(field(&result, "registry").to_string(), "docker.io");
+    assert_eq!(field(&result, "repository").to_string(), "bitnami/redis");
+    assert_eq!(field(&result, "tag").to_string(), "7.2");
+}
+
+#[test]
+fn test_parse_docker_ref_full_reference() {
+    let result = parse(&format!("registry.example.com/ns/app:1.2@{}", DIGEST));
+    assert_eq!(
+        field(&result, "registry").to_string(),
+        "registry.example.com"
+    );
+    assert_eq!(field(&result, "repository").to_string(), "ns/app");
+    assert_eq!(field(&result, "tag").to_string(), "1.2");
+    assert_eq!(field(&result, "digest").to_string(), DIGEST);
+}
+
+#[test]
+fn test_parse_docker_ref_registry_port_is_not_a_tag() {
+    let result = parse("localhost:5000/app");
+    assert_eq!(field(&result, "registry").to_string(), "localhost:5000");
+    assert_eq!(field(&result, "repository").to_string(), "app");
+    assert_eq!(field(&result, "tag").to_string(), "latest");
+}
+
+#[test]
+fn test_parse_docker_ref_localhost_registry() {
+    let result = parse("localhost/tools/builder:dev");
+    assert_eq!(field(&result, "registry").to_string(), "localhost");
+    assert_eq!(field(&result, "repository").to_string(), "tools/builder");
+    assert_eq!(field(&result, "tag").to_string(), "dev");
+}
+
+#[test]
+fn test_parse_docker_ref_digest_only_has_no_tag() {
+    let result = parse(&format!("ghcr.io/org/app@{}", DIGEST));
+    assert_eq!(field(&result, "registry").to_string(), "ghcr.io");
+    assert!(field(&result, "tag").is_none());
+    assert_eq!(field(&result, "digest").to_string(), DIGEST);
+}
+
+#[test]
+fn test_parse_docker_ref_malformed() {
+    for reference in [
+        "",
+        "Nginx",
+        "app:",
+        "registry.example.com/",
+        "ns//app",
+        "app:bad/tag",
+        "app@sha256:xyz",
+        "app@nodigest",
+        ":1.0",
+    ] {
+        let result =
+            ParseDockerRef::call(Kwargs::from_iter(vec![("string", Value::from(reference))]));
+        assert!(result.is_err(), "expected an error for '{}'", reference);
+    }
+}
+
+#[test]
+fn test_parse_docker_ref_error_message() {
+    let err = ParseDockerRef::call(Kwargs::from_iter(vec![("string", Value::from("My/App"))]))
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("malformed image reference 'My/App'")
+    );
+}
+
+// ============================================================================
+// build_docker_ref Tests
+// ============================================================================
+
+fn build(args: Vec<(&str, Value)>) -> String {
+    BuildDockerRef::call(Kwargs::from_iter(args))
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn test_build_docker_ref_omits_docker_hub() {
+    let result = build(vec![
+        ("registry", Value::from("docker.io")),
+        ("repository", Value::from("library/nginx")),
+        ("tag", Value::from("1.25")),
+    ]);
+    assert_eq!(result, "nginx:1.25");
+}
+
+#[test]
+fn test_build_docker_ref_hub_namespace() {
+    let result = build(vec![("repository", Value::from("bitnami/redis"))]);
+    assert_eq!(result, "bitnami/redis");
+}
+
+#[test]
+fn test_build_docker_ref_custom_registry_keeps_library() {
+    let result = build(vec![
+        ("registry", Value::from("mirror.internal:5000")),
+        ("repository", Value::from("library/nginx")),
+        ("tag", Value::from("latest")),
+    ]);
+    assert_eq!(result, "mirror.internal:5000/library/nginx:latest");
+}
+
+#[test]
+fn test_build_docker_ref_prefers_digest() {
+    let result = build(vec![
+        ("registry", Value::from("ghcr.io")),
+        ("repository", Value::from("org/app")),
+        ("tag", Value::from("v2")),
+        ("digest", Value::from(DIGEST)),
+    ]);
+    assert_eq!(result, format!("ghcr.io/org/app@{}", DIGEST));
+}
+
+#[test]
+fn test_build_docker_ref_prefer_tag() {
+    let result = build(vec![
+        ("registry", Value::from("ghcr.io")),
+        ("repository", Value::from("org/app")),
+        ("tag", Value::from("v2")),
+        ("digest", Value::from(DIGEST)),
+        ("prefer_tag", Value::from(true)),
+    ]);
+    assert_eq!(result, "ghcr.io/org/app:v2");
+}
+
+#[test]
+fn test_build_docker_ref_round_trip() {
+    let reference = format!("registry.example.com/ns/app@{}", DIGEST);
+    let parsed = parse(&reference);
+    let result = build(vec![
+        ("registry", field(&parsed, "registry")),
+        ("repository", field(&parsed, "repository")),
+        ("tag", field(&parsed, "tag")),
+        ("digest", field(&parsed, "digest")),
+    ]);
+    assert_eq!(result, reference);
+}
+
+#[test]
+fn test_build_docker_ref_invalid_component() {
+    let result = BuildDockerRef::call(Kwargs::from_iter(vec![
+        ("repository", Value::from("Org/App")),
+        ("tag", Value::from("v1")),
+    ]));
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("build_docker_ref"));
+}
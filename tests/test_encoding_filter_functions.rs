@@ -4,12 +4,14 @@
 //! - base64_encode, base64_decode
 //! - hex_encode, hex_decode
 //! - escape_html, escape_xml, escape_shell
+//! - json_escape, yaml_quote
 
 use minijinja::Value;
 use minijinja::value::Kwargs;
 use tmpltool::filter_functions::FilterFunction;
 use tmpltool::filter_functions::encoding::{
     Base64Decode, Base64Encode, EscapeHtml, EscapeShell, EscapeXml, HexDecode, HexEncode,
+    JsonEscape, YamlQuote,
 };
 
 /// Helper to create empty kwargs
@@ -313,3 +315,147 @@ fn test_escape_shell_error_not_string() {
             .contains("requires a string")
     );
 }
+
+// ============================================
+// JsonEscape tests
+// ============================================
+
+#[test]
+fn test_json_escape_filter_syntax() {
+    let result = JsonEscape::call_as_filter(&Value::from("say \"hi\"\\n"), empty_kwargs()).unwrap();
+    assert_eq!(result.as_str().unwrap(), "say \\\"hi\\\"\\\\n");
+}
+
+#[test]
+fn test_json_escape_function_syntax() {
+    let kwargs = Kwargs::from_iter(vec![("string", Value::from("a\nb\tc"))]);
+    let result = JsonEscape::call_as_function(kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "a\\nb\\tc");
+}
+
+#[test]
+fn test_json_escape_control_chars() {
+    let result =
+        JsonEscape::call_as_filter(&Value::from("\u{01}\u{08}\u{0c}\r"), empty_kwargs()).unwrap();
+    assert_eq!(result.as_str().unwrap(), "\\u0001\\b\\f\\r");
+}
+
+#[test]
+fn test_json_escape_unicode() {
+    let result = JsonEscape::call_as_filter(&Value::from("é 😀"), empty_kwargs()).unwrap();
+    assert_eq!(result.as_str().unwrap(), "é 😀");
+
+    let ascii = Kwargs::from_iter(vec![("ascii", Value::from(true))]);
+    let result = JsonEscape::call_as_filter(&Value::from("é 😀"), ascii).unwrap();
+    assert_eq!(result.as_str().unwrap(), "\\u00e9 \\ud83d\\ude00");
+}
+
+#[test]
+fn test_json_escape_round_trip() {
+    let input = "quote \" slash \\ line\nbreak \u{2028} ünïcødé";
+    let escaped = JsonEscape::call_as_filter(&Value::from(input), empty_kwargs()).unwrap();
+    let parsed: String =
+        serde_json::from_str(&format!("\"{}\"", escaped.as_str().unwrap())).unwrap();
+    assert_eq!(parsed, input);
+}
+
+#[test]
+fn test_json_escape_error_not_string() {
+    let result = JsonEscape::call_as_filter(&Value::from(123), empty_kwargs());
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("json_escape requires a string")
+    );
+}
+
+// ============================================
+// YamlQuote tests
+// ============================================
+
+fn yaml_quote(input: &str) -> String {
+    YamlQuote::call_as_filter(&Value::from(input), empty_kwargs())
+        .unwrap()
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn test_yaml_quote_function_syntax() {
+    let kwargs = Kwargs::from_iter(vec![("string", Value::from("yes"))]);
+    let result = YamlQuote::call_as_function(kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "'yes'");
+}
+
+#[test]
+fn test_yaml_quote_leaves_safe_scalars() {
+    for input in [
+        "hello",
+        "hello world",
+        "a:b",
+        "v1.2.3",
+        "user@example.com",
+        "nan",
+    ] {
+        assert_eq!(yaml_quote(input), input);
+    }
+}
+
+#[test]
+fn test_yaml_quote_booleans_and_null() {
+    for input in ["yes", "No", "ON", "off", "y", "true", "False", "null", "~"] {
+        assert_eq!(yaml_quote(input), format!("'{}'", input));
+    }
+}
+
+#[test]
+fn test_yaml_quote_numbers_and_dates() {
+    for input in [
+        "1",
+        "-42",
+        "1.0",
+        "1e5",
+        "1_000",
+        "0x1F",
+        "0o17",
+        ".inf",
+        "1:30",
+        "2024-01-05",
+    ] {
+        assert_eq!(yaml_quote(input), format!("'{}'", input));
+    }
+}
+
+#[test]
+fn test_yaml_quote_syntax_characters() {
+    assert_eq!(yaml_quote("a: b"), "'a: b'");
+    assert_eq!(yaml_quote("key:"), "'key:'");
+    assert_eq!(yaml_quote("x #comment"), "'x #comment'");
+    assert_eq!(yaml_quote("-dash"), "'-dash'");
+    assert_eq!(yaml_quote("*alias"), "'*alias'");
+    assert_eq!(yaml_quote(" padded"), "' padded'");
+    assert_eq!(yaml_quote(""), "''");
+}
+
+#[test]
+fn test_yaml_quote_escapes_single_quotes() {
+    assert_eq!(yaml_quote("it's: here"), "'it''s: here'");
+}
+
+#[test]
+fn test_yaml_quote_control_chars_use_double_quotes() {
+    assert_eq!(yaml_quote("line\nbreak"), "\"line\\nbreak\"");
+}
+
+#[test]
+fn test_yaml_quote_error_not_string() {
+    let result = YamlQuote::call_as_filter(&Value::from(123), empty_kwargs());
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("yaml_quote requires a string")
+    );
+}
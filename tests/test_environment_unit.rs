@@ -141,3 +141,307 @@ fn test_env_fn_empty_string_value() {
         std::env::remove_var("TEST_EMPTY_VAR");
     }
 }
+
+// ==================== env_to_object Tests ====================
+
+fn env_to_object(
+    args: Vec<(&str, minijinja::Value)>,
+) -> Result<minijinja::Value, minijinja::Error> {
+    tmpltool::functions::environment::EnvToObject::call(Kwargs::from_iter(args))
+}
+
+#[test]
+fn test_env_to_object_builds_nested_object() {
+    unsafe {
+        std::env::set_var("ETO_NEST__DB__HOST", "localhost");
+        std::env::set_var("ETO_NEST__DB__PORT", "5432");
+        std::env::set_var("ETO_NEST__NAME", "app");
+    }
+
+    let result = env_to_object(vec![("prefix", "ETO_NEST".into())]).unwrap();
+    let db = result.get_attr("db").unwrap();
+    assert_eq!(db.get_attr("host").unwrap().as_str(), Some("localhost"));
+    assert_eq!(db.get_attr("port").unwrap().as_str(), Some("5432"));
+    assert_eq!(result.get_attr("name").unwrap().as_str(), Some("app"));
+
+    unsafe {
+        std::env::remove_var("ETO_NEST__DB__HOST");
+        std::env::remove_var("ETO_NEST__DB__PORT");
+        std::env::remove_var("ETO_NEST__NAME");
+    }
+}
+
+#[test]
+fn test_env_to_object_prefix_with_trailing_delimiter() {
+    unsafe {
+        std::env::set_var("ETO_TRAIL__KEY", "v");
+    }
+
+    let result = env_to_object(vec![("prefix", "ETO_TRAIL__".into())]).unwrap();
+    assert_eq!(result.get_attr("key").unwrap().as_str(), Some("v"));
+
+    unsafe {
+        std::env::remove_var("ETO_TRAIL__KEY");
+    }
+}
+
+#[test]
+fn test_env_to_object_ignores_similar_prefixes() {
+    unsafe {
+        std::env::set_var("ETO_SIMX__KEY", "other");
+    }
+
+    let result = env_to_object(vec![("prefix", "ETO_SIM".into())]).unwrap();
+    assert_eq!(result.len(), Some(0));
+
+    unsafe {
+        std::env::remove_var("ETO_SIMX__KEY");
+    }
+}
+
+#[test]
+fn test_env_to_object_custom_delimiter_and_case() {
+    unsafe {
+        std::env::set_var("ETO_CASE_Server_Port", "80");
+    }
+
+    let result = env_to_object(vec![
+        ("prefix", "ETO_CASE".into()),
+        ("delimiter", "_".into()),
+        ("lowercase", false.into()),
+    ])
+    .unwrap();
+    let server = result.get_attr("Server").unwrap();
+    assert_eq!(server.get_attr("Port").unwrap().as_str(), Some("80"));
+
+    unsafe {
+        std::env::remove_var("ETO_CASE_Server_Port");
+    }
+}
+
+#[test]
+fn test_env_to_object_conflict_errors() {
+    unsafe {
+        std::env::set_var("ETO_CONFLICT__DB", "x");
+        std::env::set_var("ETO_CONFLICT__DB__HOST", "y");
+    }
+
+    let result = env_to_object(vec![("prefix", "ETO_CONFLICT".into())]);
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("'ETO_CONFLICT__DB__HOST' conflicts")
+    );
+
+    unsafe {
+        std::env::remove_var("ETO_CONFLICT__DB");
+        std::env::remove_var("ETO_CONFLICT__DB__HOST");
+    }
+}
+
+#[test]
+fn test_env_to_object_empty_delimiter_errors() {
+    let result = env_to_object(vec![("prefix", "ETO".into()), ("delimiter", "".into())]);
+    assert!(result.is_err());
+}
+
+// ==================== to_env_keys Tests ====================
+
+fn to_env_keys(args: Vec<(&str, minijinja::Value)>) -> Result<serde_json::Value, minijinja::Error> {
+    tmpltool::functions::environment::ToEnvKeys::call(Kwargs::from_iter(args))
+        .map(|v| serde_json::to_value(&v).unwrap())
+}
+
+#[test]
+fn test_to_env_keys_flattens_with_prefix() {
+    let object = minijinja::Value::from_serialize(serde_json::json!({
+        "db": {"host": "localhost", "port": 5432},
+        "debug": true,
+        "name": "api"
+    }));
+    let result = to_env_keys(vec![("object", object), ("prefix", "APP".into())]).unwrap();
+    assert_eq!(
+        result,
+        serde_json::json!({
+            "APP_DB_HOST": "localhost",
+            "APP_DB_PORT": "5432",
+            "APP_DEBUG": "true",
+            "APP_NAME": "api"
+        })
+    );
+}
+
+#[test]
+fn test_to_env_keys_array_indices_and_sanitizing() {
+    let object = minijinja::Value::from_serialize(serde_json::json!({
+        "allowed-hosts": ["a.example.com", "b.example.com"],
+        "cache": {"ttl": null}
+    }));
+    let result = to_env_keys(vec![("object", object)]).unwrap();
+    assert_eq!(
+        result,
+        serde_json::json!({
+            "ALLOWED_HOSTS_0": "a.example.com",
+            "ALLOWED_HOSTS_1": "b.example.com",
+            "CACHE_TTL": ""
+        })
+    );
+}
+
+#[test]
+fn test_to_env_keys_custom_delimiter_round_trips_with_env_to_object() {
+    let object = minijinja::Value::from_serialize(serde_json::json!({"db": {"host": "h"}}));
+    let result = to_env_keys(vec![
+        ("object", object),
+        ("prefix", "APP__".into()),
+        ("delimiter", "__".into()),
+    ])
+    .unwrap();
+    assert_eq!(result, serde_json::json!({"APP__DB__HOST": "h"}));
+}
+
+#[test]
+fn test_to_env_keys_duplicate_key_errors() {
+    let object = minijinja::Value::from_serialize(serde_json::json!({
+        "db_host": "a",
+        "db": {"host": "b"}
+    }));
+    let err = to_env_keys(vec![("object", object)]).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("key 'DB_HOST' is produced more than once")
+    );
+}
+
+#[test]
+fn test_to_env_keys_requires_object() {
+    let err = to_env_keys(vec![("object", minijinja::Value::from(vec![1, 2]))]).unwrap_err();
+    assert!(err.to_string().contains("to_env_keys requires an object"));
+}
+
+// ==================== secret Tests ====================
+
+fn secret(
+    base_dir: &std::path::Path,
+    args: Vec<(&str, minijinja::Value)>,
+) -> Result<minijinja::Value, minijinja::Error> {
+    use tmpltool::functions::ContextFunction;
+    let context = std::sync::Arc::new(tmpltool::TemplateContext::new(
+        base_dir.to_path_buf(),
+        false,
+    ));
+    tmpltool::functions::environment::Secret::call(context, Kwargs::from_iter(args))
+}
+
+#[test]
+fn test_secret_env_backend() {
+    unsafe {
+        std::env::set_var("TEST_SECRET_DB_PASSWORD", "hunter2");
+    }
+    let dir = tempfile::tempdir().unwrap();
+
+    let plain = secret(dir.path(), vec![("name", "TEST_SECRET_DB_PASSWORD".into())]).unwrap();
+    assert_eq!(plain.as_str(), Some("hunter2"));
+
+    let keyed = secret(
+        dir.path(),
+        vec![
+            ("name", "test-secret.db".into()),
+            ("key", "password".into()),
+        ],
+    )
+    .unwrap();
+    assert_eq!(keyed.as_str(), Some("hunter2"));
+
+    unsafe {
+        std::env::remove_var("TEST_SECRET_DB_PASSWORD");
+    }
+}
+
+#[test]
+fn test_secret_file_backend_strips_trailing_newline() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir(dir.path().join("db")).unwrap();
+    std::fs::write(dir.path().join("db/password"), "s3cret\n").unwrap();
+
+    let result = secret(
+        dir.path(),
+        vec![
+            ("name", "db".into()),
+            ("key", "password".into()),
+            ("backend", "file".into()),
+        ],
+    )
+    .unwrap();
+    assert_eq!(result.as_str(), Some("s3cret"));
+}
+
+#[test]
+fn test_secret_absent_returns_none_unless_required() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let optional = secret(
+        dir.path(),
+        vec![("name", "TEST_SECRET_MISSING_12345".into())],
+    )
+    .unwrap();
+    assert!(optional.is_none());
+
+    let env_err = secret(
+        dir.path(),
+        vec![
+            ("name", "TEST_SECRET_MISSING_12345".into()),
+            ("required", true.into()),
+        ],
+    )
+    .unwrap_err();
+    assert!(
+        env_err.to_string().contains(
+            "required secret not found in environment variable 'TEST_SECRET_MISSING_12345'"
+        )
+    );
+
+    let file_err = secret(
+        dir.path(),
+        vec![
+            ("name", "db".into()),
+            ("key", "password".into()),
+            ("backend", "file".into()),
+            ("required", true.into()),
+        ],
+    )
+    .unwrap_err();
+    assert!(
+        file_err
+            .to_string()
+            .contains("required secret not found in file 'db/password'")
+    );
+}
+
+#[test]
+fn test_secret_file_backend_security() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let result = secret(
+        dir.path(),
+        vec![("name", "/etc/passwd".into()), ("backend", "file".into())],
+    );
+    assert!(result.unwrap_err().to_string().contains("Security"));
+}
+
+#[test]
+fn test_secret_unknown_backend() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let result = secret(
+        dir.path(),
+        vec![("name", "x".into()), ("backend", "vault".into())],
+    );
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("secret: unknown backend 'vault'")
+    );
+}
@@ -1,10 +1,11 @@
 use minijinja::Environment;
 use minijinja::Value;
 use minijinja::value::Kwargs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::process::Command;
 use std::sync::Arc;
 use tmpltool::functions::ContextFunction;
-use tmpltool::functions::exec::{Exec, ExecRaw};
+use tmpltool::functions::exec::{Exec, ExecRaw, Git};
 use tmpltool::{TemplateContext, functions};
 
 fn create_env(trust_mode: bool) -> Environment<'static> {
@@ -566,3 +567,100 @@ fn test_exec_raw_unit_exit_code() {
     assert_eq!(result.get_attr("exit_code").unwrap().as_i64(), Some(42));
     assert!(!result.get_attr("success").unwrap().is_true());
 }
+
+// Tests for git()
+
+fn git_cmd(dir: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .output()
+        .unwrap()
+        .status;
+    assert!(status.success(), "git {:?} failed", args);
+}
+
+/// Create a repository with one commit on branch "main"
+fn create_git_repo() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    git_cmd(dir.path(), &["init", "-q", "-b", "main"]);
+    std::fs::write(dir.path().join("file.txt"), "hello").unwrap();
+    git_cmd(dir.path(), &["add", "."]);
+    git_cmd(dir.path(), &["commit", "-q", "-m", "initial"]);
+    dir
+}
+
+fn call_git(dir: &Path, args: Vec<(&str, Value)>) -> Result<Value, minijinja::Error> {
+    let context = Arc::new(TemplateContext::new(dir.to_path_buf(), true));
+    Git::call(context, Kwargs::from_iter(args))
+}
+
+#[test]
+fn test_git_requires_trust_mode() {
+    let env = create_env(false);
+    let result = render_template(&env, "{{ git(field=\"commit\") }}");
+
+    let err = result.unwrap_err().to_string();
+    assert!(err.contains("requires trust mode"));
+}
+
+#[test]
+fn test_git_commit_and_branch() {
+    let repo = create_git_repo();
+
+    let commit = call_git(repo.path(), vec![]).unwrap();
+    let commit = commit.as_str().unwrap();
+    assert_eq!(commit.len(), 40);
+    assert!(commit.chars().all(|c| c.is_ascii_hexdigit()));
+
+    let branch = call_git(repo.path(), vec![("field", Value::from("branch"))]).unwrap();
+    assert_eq!(branch.as_str(), Some("main"));
+}
+
+#[test]
+fn test_git_tag() {
+    let repo = create_git_repo();
+
+    let tag = call_git(repo.path(), vec![("field", Value::from("tag"))]).unwrap();
+    assert_eq!(tag.as_str(), Some(""));
+
+    git_cmd(repo.path(), &["tag", "v1.2.3"]);
+    let tag = call_git(repo.path(), vec![("field", Value::from("tag"))]).unwrap();
+    assert_eq!(tag.as_str(), Some("v1.2.3"));
+}
+
+#[test]
+fn test_git_dirty() {
+    let repo = create_git_repo();
+
+    let dirty = call_git(repo.path(), vec![("field", Value::from("dirty"))]).unwrap();
+    assert!(!dirty.is_true());
+
+    std::fs::write(repo.path().join("file.txt"), "changed").unwrap();
+    let dirty = call_git(repo.path(), vec![("field", Value::from("dirty"))]).unwrap();
+    assert!(dirty.is_true());
+}
+
+#[test]
+fn test_git_not_a_repository() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let commit = call_git(dir.path(), vec![]).unwrap();
+    assert_eq!(commit.as_str(), Some(""));
+
+    let dirty = call_git(dir.path(), vec![("field", Value::from("dirty"))]).unwrap();
+    assert!(!dirty.is_true());
+
+    let err = call_git(dir.path(), vec![("required", Value::from(true))]).unwrap_err();
+    assert!(err.to_string().contains("is not inside a git repository"));
+}
+
+#[test]
+fn test_git_invalid_field() {
+    let repo = create_git_repo();
+
+    let err = call_git(repo.path(), vec![("field", Value::from("author"))]).unwrap_err();
+    assert!(err.to_string().contains("invalid field 'author'"));
+}
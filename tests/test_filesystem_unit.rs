@@ -7,7 +7,7 @@ use std::sync::atomic::{AtomicU32, Ordering};
 use tmpltool::TemplateContext;
 use tmpltool::functions::ContextFunction;
 use tmpltool::functions::filesystem::{
-    FileExists, FileModified, FileSize, Glob, ListDir, ReadFile,
+    FileExists, FileHead, FileModified, FileSize, FileTail, Glob, ListDir, ReadFile,
 };
 
 // Global counter for unique test directories
@@ -111,6 +111,57 @@ fn test_read_file_security_parent_directory() {
     assert!(result.err().unwrap().to_string().contains("Security"));
 }
 
+fn read_file_range(path: &str, offset: Option<i64>, length: Option<i64>) -> Result<String, String> {
+    let context = Arc::new(TemplateContext::new(PathBuf::from("."), false));
+    let mut args = vec![("path", minijinja::Value::from(path))];
+    if let Some(offset) = offset {
+        args.push(("offset", minijinja::Value::from(offset)));
+    }
+    if let Some(length) = length {
+        args.push(("length", minijinja::Value::from(length)));
+    }
+    ReadFile::call(context, Kwargs::from_iter(args))
+        .map(|v| v.as_str().unwrap().to_string())
+        .map_err(|e| e.to_string())
+}
+
+#[test]
+fn test_read_file_byte_range() {
+    let test_dir = get_test_dir();
+    let path = create_test_file(&test_dir, "range.txt", "0123456789");
+
+    assert_eq!(read_file_range(&path, Some(0), Some(4)).unwrap(), "0123");
+    assert_eq!(read_file_range(&path, Some(6), None).unwrap(), "6789");
+    assert_eq!(read_file_range(&path, None, Some(2)).unwrap(), "01");
+    assert_eq!(read_file_range(&path, Some(8), Some(100)).unwrap(), "89");
+    assert_eq!(read_file_range(&path, Some(50), Some(10)).unwrap(), "");
+
+    cleanup_test_dir(&test_dir);
+}
+
+#[test]
+fn test_read_file_byte_range_invalid_utf8() {
+    let test_dir = get_test_dir();
+    // "é" is two bytes; cutting after the first byte leaves invalid UTF-8
+    let path = create_test_file(&test_dir, "utf8.txt", "aé");
+
+    let err = read_file_range(&path, Some(0), Some(2)).unwrap_err();
+    assert!(err.contains("not valid UTF-8"));
+    assert_eq!(read_file_range(&path, Some(0), Some(3)).unwrap(), "aé");
+
+    cleanup_test_dir(&test_dir);
+}
+
+#[test]
+fn test_read_file_byte_range_negative_offset() {
+    let test_dir = get_test_dir();
+    let path = create_test_file(&test_dir, "negative.txt", "data");
+
+    assert!(read_file_range(&path, Some(-1), None).is_err());
+
+    cleanup_test_dir(&test_dir);
+}
+
 #[test]
 fn test_file_exists_true() {
     let test_dir = get_test_dir();
@@ -549,3 +600,167 @@ fn test_file_modified_trust_mode_allows_absolute_path() {
         assert!(!e.to_string().contains("Security"));
     }
 }
+
+// ========== file_head / file_tail tests ==========
+
+fn head_tail_kwargs(path: &str, lines: Option<i64>) -> Kwargs {
+    let mut args = vec![("path", minijinja::Value::from(path))];
+    if let Some(lines) = lines {
+        args.push(("lines", minijinja::Value::from(lines)));
+    }
+    Kwargs::from_iter(args)
+}
+
+#[test]
+fn test_file_head_and_tail() {
+    let test_dir = get_test_dir();
+    let content: String = (1..=20).map(|i| format!("line {}\n", i)).collect();
+    let path = create_test_file(&test_dir, "log.txt", &content);
+    let context = Arc::new(TemplateContext::new(PathBuf::from("."), false));
+
+    let head = FileHead::call(context.clone(), head_tail_kwargs(&path, Some(3))).unwrap();
+    assert_eq!(head.as_str().unwrap(), "line 1\nline 2\nline 3");
+
+    let tail = FileTail::call(context.clone(), head_tail_kwargs(&path, Some(3))).unwrap();
+    assert_eq!(tail.as_str().unwrap(), "line 18\nline 19\nline 20");
+
+    let default_tail = FileTail::call(context.clone(), head_tail_kwargs(&path, None)).unwrap();
+    assert_eq!(default_tail.as_str().unwrap().lines().count(), 10);
+    assert!(default_tail.as_str().unwrap().starts_with("line 11\n"));
+
+    cleanup_test_dir(&test_dir);
+}
+
+#[test]
+fn test_file_head_tail_more_lines_than_file() {
+    let test_dir = get_test_dir();
+    let path = create_test_file(&test_dir, "short.txt", "a\r\nb");
+    let context = Arc::new(TemplateContext::new(PathBuf::from("."), false));
+
+    let head = FileHead::call(context.clone(), head_tail_kwargs(&path, Some(10))).unwrap();
+    assert_eq!(head.as_str().unwrap(), "a\nb");
+
+    let tail = FileTail::call(context.clone(), head_tail_kwargs(&path, Some(10))).unwrap();
+    assert_eq!(tail.as_str().unwrap(), "a\nb");
+
+    let none = FileTail::call(context.clone(), head_tail_kwargs(&path, Some(0))).unwrap();
+    assert_eq!(none.as_str().unwrap(), "");
+
+    cleanup_test_dir(&test_dir);
+}
+
+#[test]
+fn test_file_tail_across_chunks() {
+    let test_dir = get_test_dir();
+    // Lines long enough that the requested tail spans several 8 KiB chunks
+    let content: String = (0..100)
+        .map(|i| format!("{:04}{}\n", i, "x".repeat(500)))
+        .collect();
+    let path = create_test_file(&test_dir, "big.log", &content);
+    let context = Arc::new(TemplateContext::new(PathBuf::from("."), false));
+
+    let tail = FileTail::call(context.clone(), head_tail_kwargs(&path, Some(40))).unwrap();
+    let lines: Vec<&str> = tail.as_str().unwrap().lines().collect();
+    assert_eq!(lines.len(), 40);
+    assert!(lines[0].starts_with("0060"));
+    assert!(lines[39].starts_with("0099"));
+
+    cleanup_test_dir(&test_dir);
+}
+
+#[test]
+fn test_file_head_tail_security() {
+    let context = Arc::new(TemplateContext::new(PathBuf::from("."), false));
+
+    let head = FileHead::call(context.clone(), head_tail_kwargs("/etc/passwd", None));
+    assert!(head.unwrap_err().to_string().contains("Security"));
+
+    let tail = FileTail::call(context.clone(), head_tail_kwargs("../secret.log", None));
+    assert!(tail.unwrap_err().to_string().contains("Security"));
+}
+
+#[test]
+fn test_file_tail_nonexistent() {
+    let context = Arc::new(TemplateContext::new(PathBuf::from("."), false));
+
+    let result = FileTail::call(context, head_tail_kwargs("test_data/missing.log", None));
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("Failed to read file")
+    );
+}
+
+fn render_with_base_dir(base_dir: &std::path::Path, template: &str) -> Result<String, String> {
+    let mut env = minijinja::Environment::new();
+    env.set_undefined_behavior(minijinja::UndefinedBehavior::Strict);
+    tmpltool::functions::register_all(
+        &mut env,
+        TemplateContext::new(base_dir.to_path_buf(), false),
+    );
+    env.render_str(template, ()).map_err(|e| e.to_string())
+}
+
+#[test]
+fn test_include_if_exists_renders_file() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(
+        dir.path().join("overlay.j2"),
+        "port={{ port }} {{ \"x\" | upper }}",
+    )
+    .unwrap();
+
+    let result = render_with_base_dir(
+        dir.path(),
+        "{% set port = 8080 %}{{ include_if_exists(path=\"overlay.j2\") }}",
+    )
+    .unwrap();
+    assert_eq!(result, "port=8080 X");
+}
+
+#[test]
+fn test_include_if_exists_missing_returns_default() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let result = render_with_base_dir(
+        dir.path(),
+        "[{{ include_if_exists(path=\"missing.j2\") }}][{{ include_if_exists(path=\"missing.j2\", default=\"none\") }}]",
+    )
+    .unwrap();
+    assert_eq!(result, "[][none]");
+}
+
+#[test]
+fn test_include_if_exists_directory_is_missing() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::create_dir(dir.path().join("overlays")).unwrap();
+
+    let result = render_with_base_dir(
+        dir.path(),
+        "{{ include_if_exists(path=\"overlays\", default=\"none\") }}",
+    )
+    .unwrap();
+    assert_eq!(result, "none");
+}
+
+#[test]
+fn test_include_if_exists_template_error_propagates() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("broken.j2"), "{{ undefined_var }}").unwrap();
+
+    let result = render_with_base_dir(dir.path(), "{{ include_if_exists(path=\"broken.j2\") }}");
+    assert!(result.unwrap_err().contains("undefined"));
+}
+
+#[test]
+fn test_include_if_exists_security() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let absolute =
+        render_with_base_dir(dir.path(), "{{ include_if_exists(path=\"/etc/passwd\") }}");
+    assert!(absolute.unwrap_err().contains("Security"));
+
+    let parent = render_with_base_dir(dir.path(), "{{ include_if_exists(path=\"../secret.j2\") }}");
+    assert!(parent.unwrap_err().contains("Security"));
+}
@@ -0,0 +1,110 @@
+//! Tests for positional filter arguments declared with `POSITIONAL_ARG`
+//!
+//! `{{ text | truncate(8) }}` must behave like `{{ text | truncate(length=8) }}`.
+
+use minijinja::Environment;
+use tmpltool::filter_functions;
+use tmpltool::filter_functions::FilterFunction;
+use tmpltool::filter_functions::string::{Indent, Slugify, Truncate};
+
+fn render(template: &str) -> Result<String, String> {
+    let mut env = Environment::new();
+    filter_functions::register_all(&mut env);
+    env.render_str(template, ()).map_err(|e| e.to_string())
+}
+
+#[test]
+fn test_positional_matches_keyword() {
+    let cases = [
+        (
+            r#"{{ "Hello World" | truncate(8) }}"#,
+            r#"{{ "Hello World" | truncate(length=8) }}"#,
+        ),
+        (
+            r#"{{ "ab" | repeat(3) }}"#,
+            r#"{{ "ab" | repeat(count=3) }}"#,
+        ),
+        (
+            r#"{{ "ab" | center(6) }}"#,
+            r#"{{ "ab" | center(width=6) }}"#,
+        ),
+        (
+            r#"{{ 3.14159 | round(2) }}"#,
+            r#"{{ 3.14159 | round(decimals=2) }}"#,
+        ),
+        (
+            r#"{{ "7" | pad_left(3) }}"#,
+            r#"{{ "7" | pad_left(length=3) }}"#,
+        ),
+        (
+            r#"{{ "a=b" | partition("=") }}"#,
+            r#"{{ "a=b" | partition(sep="=") }}"#,
+        ),
+        (
+            r#"{{ [1, 2, 3, 4] | array_percentile(50) }}"#,
+            r#"{{ [1, 2, 3, 4] | array_percentile(p=50) }}"#,
+        ),
+    ];
+    for (positional, keyword) in cases {
+        assert_eq!(
+            render(positional).unwrap(),
+            render(keyword).unwrap(),
+            "{}",
+            positional
+        );
+    }
+}
+
+#[test]
+fn test_positional_combines_with_other_keywords() {
+    assert_eq!(
+        render(r#"{{ "7" | pad_left(3, char="0") }}"#).unwrap(),
+        "007"
+    );
+    assert_eq!(
+        render(r#"{{ "Hello World" | truncate(8, suffix="~") }}"#).unwrap(),
+        "Hello W~"
+    );
+}
+
+#[test]
+fn test_indent_accepts_positional_and_keyword() {
+    assert_eq!(render(r#"{{ "a\nb" | indent(2) }}"#).unwrap(), "  a\n  b");
+    assert_eq!(
+        render(r#"{{ "a\nb" | indent(spaces=2) }}"#).unwrap(),
+        "  a\n  b"
+    );
+    assert_eq!(render(r#"{{ "a\nb" | indent }}"#).unwrap(), "    a\n    b");
+}
+
+#[test]
+fn test_positional_and_keyword_conflict() {
+    let err = render(r#"{{ "abc" | truncate(2, length=3) }}"#).unwrap_err();
+    assert!(
+        err.contains("'length' was given both positionally and as a keyword argument"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn test_filters_without_positional_arg_reject_it() {
+    assert_eq!(Slugify::POSITIONAL_ARG, None);
+    assert!(render(r#"{{ "Hello" | slugify(true) }}"#).is_err());
+}
+
+#[test]
+fn test_positional_arg_names_an_argument() {
+    for (positional, metadata) in [
+        (Truncate::POSITIONAL_ARG, &Truncate::METADATA),
+        (Indent::POSITIONAL_ARG, &Indent::METADATA),
+    ] {
+        let positional = positional.unwrap();
+        assert!(
+            metadata.arguments.iter().any(|a| a.name == positional),
+            "{} has no argument '{}'",
+            metadata.name,
+            positional
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use tmpltool::cli::OutputFormat;
+use tmpltool::format_output::format_output;
+
+#[test]
+fn test_format_json_sorts_keys_and_indents() {
+    let formatted = format_output(
+        "{\"b\": 1,\n      \"a\": {\"y\": true, \"x\": null}}",
+        OutputFormat::Json,
+    )
+    .unwrap();
+    assert_eq!(
+        formatted,
+        "{\n  \"a\": {\n    \"x\": null,\n    \"y\": true\n  },\n  \"b\": 1\n}\n"
+    );
+}
+
+#[test]
+fn test_format_yaml_normalizes_indentation() {
+    let formatted = format_output(
+        "name: app\nports:\n      - 80\n      -    443\nenv: {B: 2, A: 1}",
+        OutputFormat::Yaml,
+    )
+    .unwrap();
+    assert_eq!(
+        formatted,
+        "env:\n  A: 1\n  B: 2\nname: app\nports:\n- 80\n- 443\n"
+    );
+}
+
+#[test]
+fn test_format_yaml_multiple_documents() {
+    let formatted = format_output("b: 1\na: 2\n---\nkind: Service\n", OutputFormat::Yaml).unwrap();
+    assert_eq!(formatted, "a: 2\nb: 1\n---\nkind: Service\n");
+}
+
+#[test]
+fn test_format_toml_sorts_keys() {
+    let formatted = format_output(
+        "zeta = 1\nalpha = \"x\"\n\n[server]\nport = 80\nhost = 'db'",
+        OutputFormat::Toml,
+    )
+    .unwrap();
+    assert_eq!(
+        formatted,
+        "alpha = \"x\"\nzeta = 1\n\n[server]\nhost = \"db\"\nport = 80\n"
+    );
+}
+
+#[test]
+fn test_format_json_error_reports_line() {
+    let err = format_output("{\n  \"a\": 1,\n  \"b\" 2\n}", OutputFormat::Json).unwrap_err();
+    assert!(err.contains("rendered output line 3"), "{}", err);
+    assert!(err.contains("3 |   \"b\" 2"), "{}", err);
+}
+
+#[test]
+fn test_format_yaml_error_reports_line() {
+    let err = format_output("a: 1\nb: c: d\n", OutputFormat::Yaml).unwrap_err();
+    assert!(err.contains("rendered output line 2"), "{}", err);
+}
+
+#[test]
+fn test_format_toml_error_reports_line() {
+    let err = format_output("a = 1\nb = \n", OutputFormat::Toml).unwrap_err();
+    assert!(err.contains("rendered output line 2"), "{}", err);
+    assert!(err.contains("2 | b = "), "{}", err);
+}
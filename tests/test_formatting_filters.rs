@@ -1,11 +1,13 @@
-//! Tests for formatting filter-functions (filesizeformat, urlencode)
+//! Tests for formatting filter-functions (filesizeformat, urlencode, phone_format, currency_format)
 //!
 //! Tests both function and filter syntax.
 
 use minijinja::Value;
 use minijinja::value::Kwargs;
 use tmpltool::filter_functions::FilterFunction;
-use tmpltool::filter_functions::formatting::{Filesizeformat, Urlencode};
+use tmpltool::filter_functions::formatting::{
+    CurrencyFormat, Filesizeformat, PhoneFormat, Urlencode,
+};
 
 /// Helper to create empty kwargs
 fn empty_kwargs() -> Kwargs {
@@ -188,3 +190,121 @@ fn test_urlencode_function_syntax_special() {
     let result = Urlencode::call_as_function(kwargs).unwrap();
     assert_eq!(result.as_str().unwrap(), "foo%3Dbar%26baz");
 }
+
+// ========== phone_format tests ==========
+
+#[test]
+fn test_phone_format_defaults_to_e164_us() {
+    let kwargs = Kwargs::from_iter(vec![("string", Value::from("(202) 555-0143"))]);
+    let result = PhoneFormat::call_as_function(kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "+12025550143");
+}
+
+#[test]
+fn test_phone_format_modes() {
+    for (format, expected) in [
+        ("e164", "+12025550143"),
+        ("international", "+1 202-555-0143"),
+        ("national", "(202) 555-0143"),
+        ("rfc3966", "tel:+1-202-555-0143"),
+    ] {
+        let kwargs = Kwargs::from_iter(vec![("format", Value::from(format))]);
+        let result = PhoneFormat::call_as_filter(&Value::from("202.555.0143"), kwargs).unwrap();
+        assert_eq!(result.as_str().unwrap(), expected, "format {}", format);
+    }
+}
+
+#[test]
+fn test_phone_format_country() {
+    let kwargs = Kwargs::from_iter(vec![("country", Value::from("gb"))]);
+    let result = PhoneFormat::call_as_filter(&Value::from("020 7946 0958"), kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "+442079460958");
+
+    // A +country code prefix wins over the country argument
+    let result =
+        PhoneFormat::call_as_filter(&Value::from("+44 20 7946 0958"), empty_kwargs()).unwrap();
+    assert_eq!(result.as_str().unwrap(), "+442079460958");
+}
+
+#[test]
+fn test_phone_format_errors() {
+    let err = PhoneFormat::call_as_filter(&Value::from("123"), empty_kwargs()).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("'123' is not a valid phone number")
+    );
+
+    let kwargs = Kwargs::from_iter(vec![("country", Value::from("XX"))]);
+    let err = PhoneFormat::call_as_filter(&Value::from("2025550143"), kwargs).unwrap_err();
+    assert!(err.to_string().contains("unknown country 'XX'"));
+
+    let kwargs = Kwargs::from_iter(vec![("format", Value::from("pretty"))]);
+    let err = PhoneFormat::call_as_filter(&Value::from("2025550143"), kwargs).unwrap_err();
+    assert!(err.to_string().contains("unknown format 'pretty'"));
+}
+
+// ========== currency_format tests ==========
+
+#[test]
+fn test_currency_format_defaults_to_usd_en_us() {
+    let kwargs = Kwargs::from_iter(vec![("value", Value::from(1234567.891))]);
+    let result = CurrencyFormat::call_as_function(kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "$1,234,567.89");
+}
+
+#[test]
+fn test_currency_format_locales() {
+    for (currency, locale, expected) in [
+        ("EUR", "de-DE", "1.234,50 €"),
+        ("EUR", "fr-FR", "1 234,50 €"),
+        ("EUR", "nl-NL", "€ 1.234,50"),
+        ("GBP", "en-GB", "£1,234.50"),
+        ("PLN", "pl_PL", "1 234,50 zł"),
+        ("CHF", "de-CH", "CHF 1'234.50"),
+        ("JPY", "ja-JP", "¥1,235"),
+    ] {
+        let kwargs = Kwargs::from_iter(vec![
+            ("currency", Value::from(currency)),
+            ("locale", Value::from(locale)),
+        ]);
+        let result = CurrencyFormat::call_as_filter(&Value::from(1234.5), kwargs).unwrap();
+        assert_eq!(
+            result.as_str().unwrap(),
+            expected,
+            "{} {}",
+            currency,
+            locale
+        );
+    }
+}
+
+#[test]
+fn test_currency_format_small_and_negative() {
+    let result = CurrencyFormat::call_as_filter(&Value::from(-42), empty_kwargs()).unwrap();
+    assert_eq!(result.as_str().unwrap(), "-$42.00");
+
+    let result = CurrencyFormat::call_as_filter(&Value::from(999.999), empty_kwargs()).unwrap();
+    assert_eq!(result.as_str().unwrap(), "$1,000.00");
+
+    // Rounds to zero: no negative sign
+    let result = CurrencyFormat::call_as_filter(&Value::from(-0.001), empty_kwargs()).unwrap();
+    assert_eq!(result.as_str().unwrap(), "$0.00");
+
+    // Numeric strings are accepted
+    let result = CurrencyFormat::call_as_filter(&Value::from("19.9"), empty_kwargs()).unwrap();
+    assert_eq!(result.as_str().unwrap(), "$19.90");
+}
+
+#[test]
+fn test_currency_format_errors() {
+    let err = CurrencyFormat::call_as_filter(&Value::from("abc"), empty_kwargs()).unwrap_err();
+    assert!(err.to_string().contains("requires a number"));
+
+    let kwargs = Kwargs::from_iter(vec![("currency", Value::from("XYZ"))]);
+    let err = CurrencyFormat::call_as_filter(&Value::from(1), kwargs).unwrap_err();
+    assert!(err.to_string().contains("unknown currency 'XYZ'"));
+
+    let kwargs = Kwargs::from_iter(vec![("locale", Value::from("xx-XX"))]);
+    let err = CurrencyFormat::call_as_filter(&Value::from(1), kwargs).unwrap_err();
+    assert!(err.to_string().contains("unknown locale 'xx-XX'"));
+}
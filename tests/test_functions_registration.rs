@@ -74,6 +74,24 @@ fn test_functions_register_all_works() {
     functions::register_all(&mut env, context);
 }
 
+#[test]
+fn test_every_registered_function_has_metadata() {
+    let mut env = Environment::new();
+    let context = TemplateContext::new(PathBuf::from("."), false);
+    functions::register_all(&mut env, context);
+
+    // Globals provided by MiniJinja itself rather than tmpltool
+    let builtins = ["range", "namespace"];
+    let names = tmpltool::all_function_names();
+    for (name, _) in env.globals() {
+        assert!(
+            builtins.contains(&name) || names.contains(&name),
+            "Function '{}' is registered but missing from get_all_metadata()",
+            name
+        );
+    }
+}
+
 #[test]
 fn test_functions_work_in_template() {
     let mut env = Environment::new();
@@ -120,12 +138,21 @@ fn test_functions_metadata_categories() {
         "debug",
         "predicate",
         "logic",
+        "convert",
         "array",
         "object",
         "kubernetes",
         "url",
         "data_parsing",
         "exec",
+        "state",
+        "diagram",
+        "qr",
+        "layout",
+        "diff",
+        "docker",
+        "template",
+        "cache",
     ];
 
     for cat in expected {
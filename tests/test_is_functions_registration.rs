@@ -11,11 +11,12 @@ use tmpltool::is_functions;
 #[test]
 fn test_is_functions_get_all_metadata_returns_expected_count() {
     let metadata = is_functions::get_all_metadata();
-    // We expect: email, url, ip, uuid, leap_year, port_available, file, dir, symlink
+    // We expect: email, url, ip, uuid, phone, leap_year, empty, defined, undefined,
+    // contains, between, starts_with, ends_with, port_available, file, dir, symlink
     assert_eq!(
         metadata.len(),
-        9,
-        "Expected 9 is-functions, got {}",
+        17,
+        "Expected 17 is-functions, got {}",
         metadata.len()
     );
 }
@@ -43,7 +44,15 @@ fn test_is_functions_metadata_names() {
         "is_url",
         "is_ip",
         "is_uuid",
+        "is_phone",
         "is_leap_year",
+        "is_empty",
+        "is_defined",
+        "is_undefined",
+        "contains",
+        "between",
+        "starts_with",
+        "ends_with",
         "is_port_available",
         "is_file",
         "is_dir",
@@ -101,7 +110,7 @@ fn test_is_functions_metadata_categories() {
     let metadata = is_functions::get_all_metadata();
     let categories: std::collections::HashSet<&str> = metadata.iter().map(|m| m.category).collect();
 
-    let expected = ["validation", "datetime", "network", "filesystem"];
+    let expected = ["validation", "datetime", "logic", "network", "filesystem"];
 
     for cat in expected {
         assert!(
@@ -0,0 +1,401 @@
+//! Tests for logic is-functions
+//!
+//! Tests both function syntax and "is" test syntax for:
+//! - is_empty / empty
+//! - is_defined / defined
+//! - is_undefined / undefined
+//! - contains / contains(value)
+
+use minijinja::{Environment, UndefinedBehavior, Value};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tmpltool::TemplateContext;
+use tmpltool::is_functions::IsFunction;
+use tmpltool::is_functions::logic::{Between, Contains, Defined, Empty, Undefined};
+
+/// Helper to create a test environment with is-functions registered
+fn create_test_env() -> Environment<'static> {
+    let mut env = Environment::new();
+    env.set_undefined_behavior(UndefinedBehavior::Strict);
+    let context = Arc::new(TemplateContext::new(PathBuf::from("."), false));
+    tmpltool::is_functions::register_all(&mut env, context);
+    env
+}
+
+/// Helper to render a template and check the result
+fn render(env: &Environment, template: &str) -> String {
+    env.render_str(template, ()).unwrap()
+}
+
+// ========== Empty Unit Tests ==========
+
+#[test]
+fn test_empty_check_empty_values() {
+    assert!(Empty::check(&Value::UNDEFINED));
+    assert!(Empty::check(&Value::from(())));
+    assert!(Empty::check(&Value::from("")));
+    assert!(Empty::check(&Value::from(Vec::<i32>::new())));
+    assert!(Empty::check(&Value::from_serialize(serde_json::json!({}))));
+}
+
+#[test]
+fn test_empty_check_non_empty_values() {
+    assert!(!Empty::check(&Value::from(" ")));
+    assert!(!Empty::check(&Value::from(vec![1])));
+    assert!(!Empty::check(&Value::from_serialize(
+        serde_json::json!({"a": 1})
+    )));
+    // Scalars are values, not containers
+    assert!(!Empty::check(&Value::from(0)));
+    assert!(!Empty::check(&Value::from(false)));
+}
+
+// ========== is_empty Function Syntax ==========
+
+#[test]
+fn test_is_empty_function_syntax() {
+    let env = create_test_env();
+    assert_eq!(render(&env, r#"{{ is_empty(value="") }}"#), "true");
+    assert_eq!(render(&env, r#"{{ is_empty(value=none) }}"#), "true");
+    assert_eq!(render(&env, r#"{{ is_empty(value=[]) }}"#), "true");
+    assert_eq!(render(&env, r#"{{ is_empty(value={}) }}"#), "true");
+    assert_eq!(render(&env, r#"{{ is_empty(value="x") }}"#), "false");
+    assert_eq!(render(&env, r#"{{ is_empty(value=[0]) }}"#), "false");
+    assert_eq!(render(&env, r#"{{ is_empty(value=0) }}"#), "false");
+}
+
+#[test]
+fn test_is_empty_function_syntax_undefined() {
+    let env = create_test_env();
+    assert_eq!(render(&env, r#"{{ is_empty(value=missing) }}"#), "true");
+    assert_eq!(
+        render(
+            &env,
+            r#"{% set cfg = {"a": 1} %}{{ is_empty(value=cfg.b) }}"#
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn test_is_empty_function_missing_argument() {
+    let env = create_test_env();
+    assert!(env.render_str(r#"{{ is_empty() }}"#, ()).is_err());
+}
+
+// ========== empty Is-Test Syntax ==========
+
+#[test]
+fn test_empty_is_syntax() {
+    let env = create_test_env();
+    assert_eq!(
+        render(&env, r#"{% if "" is empty %}yes{% else %}no{% endif %}"#),
+        "yes"
+    );
+    assert_eq!(
+        render(&env, r#"{% if [] is empty %}yes{% else %}no{% endif %}"#),
+        "yes"
+    );
+    assert_eq!(
+        render(&env, r#"{% if {} is empty %}yes{% else %}no{% endif %}"#),
+        "yes"
+    );
+    assert_eq!(
+        render(&env, r#"{% if none is empty %}yes{% else %}no{% endif %}"#),
+        "yes"
+    );
+    assert_eq!(
+        render(
+            &env,
+            r#"{% if [1, 2] is empty %}yes{% else %}no{% endif %}"#
+        ),
+        "no"
+    );
+}
+
+#[test]
+fn test_empty_is_syntax_undefined() {
+    let env = create_test_env();
+    assert_eq!(
+        render(
+            &env,
+            r#"{% if missing is empty %}yes{% else %}no{% endif %}"#
+        ),
+        "yes"
+    );
+}
+
+#[test]
+fn test_empty_is_not_syntax() {
+    let env = create_test_env();
+    assert_eq!(
+        render(
+            &env,
+            r#"{% set name = "World" %}{% if name is not empty %}Hello {{ name }}{% endif %}"#
+        ),
+        "Hello World"
+    );
+}
+
+// ========== is_defined / is_undefined ==========
+
+#[test]
+fn test_defined_undefined_unit() {
+    assert!(!Defined::call_as_is(&Value::UNDEFINED));
+    assert!(Defined::call_as_is(&Value::from(())));
+    assert!(Defined::call_as_is(&Value::from("")));
+    assert!(Undefined::call_as_is(&Value::UNDEFINED));
+    assert!(!Undefined::call_as_is(&Value::from(())));
+}
+
+#[test]
+fn test_is_defined_function_syntax() {
+    let env = create_test_env();
+    assert_eq!(render(&env, r#"{{ is_defined(value=missing) }}"#), "false");
+    assert_eq!(render(&env, r#"{{ is_defined(value=none) }}"#), "true");
+    assert_eq!(render(&env, r#"{{ is_defined(value="") }}"#), "true");
+    assert_eq!(render(&env, r#"{{ is_undefined(value=missing) }}"#), "true");
+    assert_eq!(render(&env, r#"{{ is_undefined(value=none) }}"#), "false");
+}
+
+#[test]
+fn test_is_defined_distinguishes_null_from_missing_key() {
+    let env = create_test_env();
+    let ctx = serde_json::json!({"config": {"port": null}});
+    let tmpl = r#"{{ config.port is defined }}/{{ config.host is defined }}/{{ is_undefined(value=config.host) }}"#;
+    assert_eq!(env.render_str(tmpl, &ctx).unwrap(), "true/false/true");
+}
+
+#[test]
+fn test_defined_is_syntax_lenient_mode() {
+    let mut env = Environment::new();
+    let context = Arc::new(TemplateContext::new(PathBuf::from("."), false));
+    tmpltool::is_functions::register_all(&mut env, context);
+    assert_eq!(
+        render(
+            &env,
+            r#"{% if missing is undefined %}yes{% else %}no{% endif %}"#
+        ),
+        "yes"
+    );
+    assert_eq!(render(&env, r#"{{ is_defined(value=missing) }}"#), "false");
+}
+
+#[test]
+fn test_defined_undefined_tests_match_minijinja_builtins() {
+    let env = create_test_env();
+    let mut builtin = Environment::new();
+    builtin.set_undefined_behavior(UndefinedBehavior::Strict);
+    let ctx = serde_json::json!({"config": {"port": null}});
+    for tmpl in [
+        "{{ missing is defined }}",
+        "{{ missing is undefined }}",
+        "{{ config.port is defined }}",
+        "{{ config.host is undefined }}",
+        "{{ missing.attr is defined }}",
+        "{{ config.host.attr is undefined }}",
+    ] {
+        assert_eq!(
+            env.render_str(tmpl, &ctx).map_err(|e| e.kind()),
+            builtin.render_str(tmpl, &ctx).map_err(|e| e.kind()),
+            "{}",
+            tmpl
+        );
+    }
+}
+
+// ========== contains ==========
+
+#[test]
+fn test_contains_check_unit() {
+    let list = Value::from(vec!["a", "b"]);
+    let map = Value::from_serialize(serde_json::json!({"port": 80}));
+    assert_eq!(
+        Contains::check(&Value::from("abc"), &Value::from("bc")),
+        Some(true)
+    );
+    assert_eq!(Contains::check(&list, &Value::from("a")), Some(true));
+    assert_eq!(Contains::check(&list, &Value::from("c")), Some(false));
+    assert_eq!(Contains::check(&map, &Value::from("port")), Some(true));
+    assert_eq!(Contains::check(&map, &Value::from(80)), Some(false));
+    assert_eq!(Contains::check(&Value::from(42), &Value::from(4)), None);
+}
+
+#[test]
+fn test_contains_function_syntax() {
+    let env = create_test_env();
+    assert_eq!(
+        render(
+            &env,
+            r#"{{ contains(container="hello world", value="world") }}"#
+        ),
+        "true"
+    );
+    assert_eq!(
+        render(&env, r#"{{ contains(container=[1, 2, 3], value=2) }}"#),
+        "true"
+    );
+    assert_eq!(
+        render(&env, r#"{{ contains(container=[1, 2, 3], value="2") }}"#),
+        "false"
+    );
+    assert_eq!(
+        render(
+            &env,
+            r#"{{ contains(container={"port": 80}, value="port") }}"#
+        ),
+        "true"
+    );
+    assert_eq!(
+        render(
+            &env,
+            r#"{{ contains(container={"port": 80}, value="host") }}"#
+        ),
+        "false"
+    );
+}
+
+#[test]
+fn test_contains_function_legacy_arguments() {
+    let env = create_test_env();
+    assert_eq!(
+        render(
+            &env,
+            r#"{{ contains(string="hello world", substring="world") }}"#
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn test_contains_function_errors() {
+    let env = create_test_env();
+    let err = env
+        .render_str(r#"{{ contains(container=42, value=4) }}"#, ())
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("container must be a string, array, or object")
+    );
+
+    let err = env
+        .render_str(r#"{{ contains(container="abc", value=1) }}"#, ())
+        .unwrap_err();
+    assert!(err.to_string().contains("value must be a string"));
+}
+
+#[test]
+fn test_contains_is_syntax() {
+    let env = create_test_env();
+    assert_eq!(
+        render(
+            &env,
+            r#"{% if ["admin", "dev"] is contains("admin") %}yes{% else %}no{% endif %}"#
+        ),
+        "yes"
+    );
+    assert_eq!(
+        render(
+            &env,
+            r#"{% if {"port": 80} is contains "port" %}yes{% else %}no{% endif %}"#
+        ),
+        "yes"
+    );
+    assert_eq!(
+        render(
+            &env,
+            r#"{% if "config.yaml" is contains(".json") %}yes{% else %}no{% endif %}"#
+        ),
+        "no"
+    );
+    // Unsupported containers never match
+    assert_eq!(
+        render(
+            &env,
+            r#"{% if 42 is contains(4) %}yes{% else %}no{% endif %}"#
+        ),
+        "no"
+    );
+}
+
+// ========== Between Tests ==========
+
+#[test]
+fn test_between_check() {
+    assert!(Between::check(5.0, 1.0, 10.0, true).unwrap());
+    assert!(Between::check(10.0, 1.0, 10.0, true).unwrap());
+    assert!(!Between::check(10.0, 1.0, 10.0, false).unwrap());
+    assert!(!Between::check(0.5, 1.0, 10.0, true).unwrap());
+    assert!(Between::check(1.0, 10.0, 1.0, true).is_err());
+}
+
+#[test]
+fn test_between_function_syntax() {
+    let env = create_test_env();
+    assert_eq!(
+        render(&env, "{{ between(value=5, min=1, max=10) }}"),
+        "true"
+    );
+    assert_eq!(
+        render(&env, "{{ between(value=2.5, min=0, max=2) }}"),
+        "false"
+    );
+    assert_eq!(
+        render(
+            &env,
+            "{{ between(value=10, min=1, max=10, inclusive=false) }}"
+        ),
+        "false"
+    );
+
+    let err = env
+        .render_str(r#"{{ between(value="5", min=1, max=10) }}"#, ())
+        .unwrap_err();
+    assert!(err.to_string().contains("between: value must be a number"));
+    let err = env
+        .render_str("{{ between(value=5, min=10, max=1) }}", ())
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("min (10) must not be greater than max (1)")
+    );
+}
+
+#[test]
+fn test_between_is_test_syntax() {
+    let env = create_test_env();
+    assert_eq!(
+        render(
+            &env,
+            "{% if 3 is between(min=1, max=10) %}yes{% else %}no{% endif %}"
+        ),
+        "yes"
+    );
+    assert_eq!(
+        render(
+            &env,
+            "{% if 80 is between(1024, 65535) %}yes{% else %}no{% endif %}"
+        ),
+        "no"
+    );
+    assert_eq!(
+        render(
+            &env,
+            "{% if 1 is between(min=1, max=2, inclusive=false) %}yes{% else %}no{% endif %}"
+        ),
+        "no"
+    );
+    // Non-numeric values never match
+    assert_eq!(
+        render(
+            &env,
+            r#"{% if "5" is between(1, 10) %}yes{% else %}no{% endif %}"#
+        ),
+        "no"
+    );
+
+    let err = env
+        .render_str("{% if 5 is between(min=1) %}{% endif %}", ())
+        .unwrap_err();
+    assert!(err.to_string().contains("missing argument 'max'"));
+}
@@ -0,0 +1,142 @@
+//! Tests for string is-functions
+//!
+//! Tests both function syntax and "is" test syntax for:
+//! - starts_with / starts_with(prefix)
+//! - ends_with / ends_with(suffix)
+
+use minijinja::Environment;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tmpltool::TemplateContext;
+
+/// Helper to create a test environment with is-functions registered
+fn create_test_env() -> Environment<'static> {
+    let mut env = Environment::new();
+    let context = Arc::new(TemplateContext::new(PathBuf::from("."), false));
+    tmpltool::is_functions::register_all(&mut env, context);
+    env
+}
+
+/// Helper to render a template and check the result
+fn render(env: &Environment, template: &str) -> String {
+    env.render_str(template, ()).unwrap()
+}
+
+// ========== Function Syntax ==========
+
+#[test]
+fn test_starts_with_function_syntax() {
+    let env = create_test_env();
+    assert_eq!(
+        render(&env, r#"{{ starts_with(string="v1.2.3", prefix="v") }}"#),
+        "true"
+    );
+    assert_eq!(
+        render(&env, r#"{{ starts_with(string="V1.2.3", prefix="v") }}"#),
+        "false"
+    );
+    assert_eq!(
+        render(
+            &env,
+            r#"{{ starts_with(string="V1.2.3", prefix="v", case_insensitive=true) }}"#
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn test_ends_with_function_syntax() {
+    let env = create_test_env();
+    assert_eq!(
+        render(
+            &env,
+            r#"{{ ends_with(string="config.yaml", suffix=".yaml") }}"#
+        ),
+        "true"
+    );
+    assert_eq!(
+        render(
+            &env,
+            r#"{{ ends_with(string="IMAGE.PNG", suffix=".png") }}"#
+        ),
+        "false"
+    );
+    assert_eq!(
+        render(
+            &env,
+            r#"{{ ends_with(string="IMAGE.PNG", suffix=".png", case_insensitive=true) }}"#
+        ),
+        "true"
+    );
+}
+
+// ========== Is-Test Syntax ==========
+
+#[test]
+fn test_starts_with_is_syntax() {
+    let env = create_test_env();
+    assert_eq!(
+        render(
+            &env,
+            r#"{% if "v2.0.0" is starts_with("v") %}release{% else %}dev{% endif %}"#
+        ),
+        "release"
+    );
+    assert_eq!(
+        render(
+            &env,
+            r#"{% if "latest" is starts_with "v" %}release{% else %}dev{% endif %}"#
+        ),
+        "dev"
+    );
+}
+
+#[test]
+fn test_ends_with_is_syntax() {
+    let env = create_test_env();
+    assert_eq!(
+        render(
+            &env,
+            r#"{% if "deploy/app.yaml" is ends_with(".yaml") %}yes{% else %}no{% endif %}"#
+        ),
+        "yes"
+    );
+    assert_eq!(
+        render(
+            &env,
+            r#"{% if "deploy/app.yaml" is not ends_with(".json") %}yes{% else %}no{% endif %}"#
+        ),
+        "yes"
+    );
+}
+
+#[test]
+fn test_is_syntax_case_insensitive() {
+    let env = create_test_env();
+    assert_eq!(
+        render(
+            &env,
+            r#"{% if "Photo.JPG" is ends_with(".jpg", case_insensitive=true) %}yes{% else %}no{% endif %}"#
+        ),
+        "yes"
+    );
+    assert_eq!(
+        render(
+            &env,
+            r#"{% if "Release-1" is starts_with("release", case_insensitive=true) %}yes{% else %}no{% endif %}"#
+        ),
+        "yes"
+    );
+}
+
+#[test]
+fn test_is_syntax_non_string_never_matches() {
+    let env = create_test_env();
+    assert_eq!(
+        render(
+            &env,
+            r#"{% if 123 is starts_with("1") %}yes{% else %}no{% endif %}"#
+        ),
+        "no"
+    );
+}
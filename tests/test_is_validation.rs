@@ -5,12 +5,13 @@
 //! - is_url / url
 //! - is_ip / ip
 //! - is_uuid / uuid
+//! - is_phone / phone
 
 use minijinja::Environment;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tmpltool::TemplateContext;
-use tmpltool::is_functions::validation::{Email, Ip, Url, Uuid};
+use tmpltool::is_functions::validation::{Email, Ip, Phone, Url, Uuid};
 
 /// Helper to create a test environment with is-functions registered
 fn create_test_env() -> Environment<'static> {
@@ -406,6 +407,65 @@ fn test_is_uuid_is_syntax_non_string() {
     );
 }
 
+// ========== Phone Tests ==========
+
+#[test]
+fn test_is_phone_function_syntax() {
+    let env = create_test_env();
+    assert_eq!(
+        render(&env, r#"{{ is_phone(string="(202) 555-0143") }}"#),
+        "true"
+    );
+    assert_eq!(render(&env, r#"{{ is_phone(string="123") }}"#), "false");
+    assert_eq!(
+        render(
+            &env,
+            r#"{{ is_phone(string="020 7946 0958", country="GB") }}"#
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn test_is_phone_test_syntax() {
+    let env = create_test_env();
+    assert_eq!(
+        render(
+            &env,
+            r#"{% if "+44 20 7946 0958" is phone %}yes{% else %}no{% endif %}"#
+        ),
+        "yes"
+    );
+    // A UK national number is not a valid US number
+    assert_eq!(
+        render(
+            &env,
+            r#"{% if "020 7946 0958" is phone %}yes{% else %}no{% endif %}"#
+        ),
+        "no"
+    );
+    assert_eq!(
+        render(
+            &env,
+            r#"{% if "020 7946 0958" is phone("GB") %}yes{% else %}no{% endif %}"#
+        ),
+        "yes"
+    );
+    assert_eq!(
+        render(
+            &env,
+            r#"{% if 2025550143 is phone %}yes{% else %}no{% endif %}"#
+        ),
+        "no"
+    );
+}
+
+#[test]
+fn test_is_phone_unknown_country_is_error() {
+    let err = Phone::validate("2025550143", "XX").unwrap_err();
+    assert!(err.to_string().contains("is_phone: unknown country 'XX'"));
+}
+
 // ========== Combined Tests ==========
 
 #[test]
@@ -2,8 +2,8 @@ use minijinja::Value;
 use minijinja::value::Kwargs;
 use tmpltool::functions::Function;
 use tmpltool::functions::kubernetes::{
-    HelmTpl, K8sBytesToQuantity, K8sPodAffinity, K8sProbe, K8sQuantityToBytes, K8sSelector,
-    K8sToleration,
+    HelmTpl, K8sAnnotations, K8sBytesToQuantity, K8sLabels, K8sName, K8sPodAffinity, K8sProbe,
+    K8sQuantityToBytes, K8sSelector, K8sToleration,
 };
 
 // ============================================================================
@@ -1273,3 +1273,208 @@ fn test_k8s_probe_all_timings() {
     assert!(output.contains("successThreshold: 2"));
     assert!(output.contains("failureThreshold: 6"));
 }
+
+// ============================================================================
+// k8s_labels Tests
+// ============================================================================
+
+#[test]
+fn test_k8s_labels_all_fields() {
+    let result = K8sLabels::call(Kwargs::from_iter(vec![
+        ("name", Value::from("api")),
+        ("instance", Value::from("api-prod")),
+        ("version", Value::from("1.4.2")),
+        ("component", Value::from("backend")),
+        ("part_of", Value::from("shop")),
+        ("managed_by", Value::from("helm")),
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        result.to_string(),
+        "app.kubernetes.io/name: \"api\"\n\
+         app.kubernetes.io/instance: \"api-prod\"\n\
+         app.kubernetes.io/version: \"1.4.2\"\n\
+         app.kubernetes.io/component: \"backend\"\n\
+         app.kubernetes.io/part-of: \"shop\"\n\
+         app.kubernetes.io/managed-by: \"helm\""
+    );
+}
+
+#[test]
+fn test_k8s_labels_defaults_managed_by_and_skips_missing() {
+    let result = K8sLabels::call(Kwargs::from_iter(vec![("name", Value::from("api"))])).unwrap();
+    assert_eq!(
+        result.to_string(),
+        "app.kubernetes.io/name: \"api\"\napp.kubernetes.io/managed-by: \"tmpltool\""
+    );
+}
+
+#[test]
+fn test_k8s_labels_empty_managed_by_is_omitted() {
+    let result = K8sLabels::call(Kwargs::from_iter(vec![
+        ("name", Value::from("api")),
+        ("managed_by", Value::from("")),
+        ("component", Value::from(())),
+    ]))
+    .unwrap();
+    assert_eq!(result.to_string(), "app.kubernetes.io/name: \"api\"");
+}
+
+#[test]
+fn test_k8s_labels_sanitizes_values() {
+    let result = K8sLabels::call(Kwargs::from_iter(vec![
+        ("name", Value::from("My API (beta)")),
+        ("version", Value::from(2.0)),
+        ("managed_by", Value::from("")),
+    ]))
+    .unwrap();
+    assert_eq!(
+        result.to_string(),
+        "app.kubernetes.io/name: \"my-api-beta\"\napp.kubernetes.io/version: \"2.0\""
+    );
+}
+
+#[test]
+fn test_k8s_labels_requires_name() {
+    let result = K8sLabels::call(Kwargs::from_iter(vec![("instance", Value::from("x"))]));
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// k8s_name Tests
+// ============================================================================
+
+fn k8s_name(
+    parts: serde_json::Value,
+    max_length: Option<usize>,
+) -> Result<String, minijinja::Error> {
+    let mut kwargs = vec![("parts", Value::from_serialize(parts))];
+    if let Some(max_length) = max_length {
+        kwargs.push(("max_length", Value::from(max_length)));
+    }
+    K8sName::call(Kwargs::from_iter(kwargs)).map(|v| v.to_string())
+}
+
+#[test]
+fn test_k8s_name_joins_and_sanitizes() {
+    let name = k8s_name(serde_json::json!(["My App", "Worker", 2]), None).unwrap();
+    assert_eq!(name, "my-app-worker-2");
+}
+
+#[test]
+fn test_k8s_name_skips_empty_parts() {
+    let name = k8s_name(serde_json::json!(["api", "", null, "--", "v1"]), None).unwrap();
+    assert_eq!(name, "api-v1");
+}
+
+#[test]
+fn test_k8s_name_short_name_unchanged() {
+    let parts = serde_json::json!(["a".repeat(63)]);
+    assert_eq!(k8s_name(parts, None).unwrap(), "a".repeat(63));
+}
+
+#[test]
+fn test_k8s_name_truncates_with_hash_suffix() {
+    let parts = serde_json::json!([
+        "a-very-long-release-name-for-production",
+        "postgresql-ha",
+        "primary-replica-set"
+    ]);
+    let name = k8s_name(parts.clone(), None).unwrap();
+    assert!(name.len() <= 63, "{}", name);
+    assert!(name.starts_with("a-very-long-release-name-for-production-postgresql-ha-"));
+    // Deterministic
+    assert_eq!(name, k8s_name(parts, None).unwrap());
+}
+
+#[test]
+fn test_k8s_name_truncated_names_stay_distinct() {
+    let a = k8s_name(
+        serde_json::json!(["shared-prefix-long-name", "alpha"]),
+        Some(20),
+    )
+    .unwrap();
+    let b = k8s_name(
+        serde_json::json!(["shared-prefix-long-name", "beta"]),
+        Some(20),
+    )
+    .unwrap();
+    assert!(a.len() <= 20 && b.len() <= 20);
+    assert_ne!(a, b);
+    assert!(a.starts_with("shared-pref-"));
+}
+
+#[test]
+fn test_k8s_name_errors() {
+    assert!(k8s_name(serde_json::json!(["api"]), Some(9)).is_err());
+    assert!(k8s_name(serde_json::json!(["--", ""]), None).is_err());
+    assert!(k8s_name(serde_json::json!("api"), None).is_err());
+}
+
+// ============================================================================
+// k8s_annotations Tests
+// ============================================================================
+
+fn k8s_annotations(object: serde_json::Value) -> Result<String, minijinja::Error> {
+    K8sAnnotations::call(Kwargs::from_iter(vec![(
+        "object",
+        Value::from_serialize(object),
+    )]))
+    .map(|v| v.to_string())
+}
+
+#[test]
+fn test_k8s_annotations_quotes_all_values() {
+    let result = k8s_annotations(serde_json::json!({
+        "prometheus.io/scrape": true,
+        "prometheus.io/port": 9090,
+        "owner": "team-a"
+    }))
+    .unwrap();
+    assert_eq!(
+        result,
+        "owner: \"team-a\"\nprometheus.io/port: \"9090\"\nprometheus.io/scrape: \"true\""
+    );
+}
+
+#[test]
+fn test_k8s_annotations_sanitizes_and_escapes() {
+    let result = k8s_annotations(serde_json::json!({
+        "description": "Line one\nsays \"hi\""
+    }))
+    .unwrap();
+    assert_eq!(result, r#"description: "Line one says \"hi\"""#);
+}
+
+#[test]
+fn test_k8s_annotations_nested_values_become_json() {
+    let result = k8s_annotations(serde_json::json!({"config": {"a": [1, 2]}})).unwrap();
+    assert_eq!(result, r#"config: "{\"a\":[1,2]}""#);
+}
+
+#[test]
+fn test_k8s_annotations_quotes_unusual_keys() {
+    let result = k8s_annotations(serde_json::json!({"note: x": "y"})).unwrap();
+    assert_eq!(result, r#""note: x": "y""#);
+}
+
+#[test]
+fn test_k8s_annotations_output_is_valid_yaml() {
+    let result = k8s_annotations(serde_json::json!({
+        "a/b": "x: y # not a comment",
+        "n": null,
+        "flag": false
+    }))
+    .unwrap();
+    let parsed: serde_yaml::Value = serde_yaml::from_str(&result).unwrap();
+    assert_eq!(parsed["a/b"], "x: y # not a comment");
+    assert_eq!(parsed["n"], "");
+    assert_eq!(parsed["flag"], "false");
+}
+
+#[test]
+fn test_k8s_annotations_empty_and_invalid() {
+    assert_eq!(k8s_annotations(serde_json::json!({})).unwrap(), "{}");
+    assert!(k8s_annotations(serde_json::json!(["a"])).is_err());
+}
@@ -0,0 +1,168 @@
+use minijinja::Environment;
+use std::path::PathBuf;
+use tmpltool::{TemplateContext, functions::register_all};
+
+fn render_template(template: &str) -> String {
+    let mut env = Environment::new();
+    let ctx = TemplateContext::new(PathBuf::from("."), false);
+    register_all(&mut env, ctx);
+
+    let tmpl = env.template_from_str(template).unwrap();
+    tmpl.render(()).unwrap()
+}
+
+fn render_template_err(template: &str) -> String {
+    let mut env = Environment::new();
+    let ctx = TemplateContext::new(PathBuf::from("."), false);
+    register_all(&mut env, ctx);
+
+    let tmpl = env.template_from_str(template).unwrap();
+    tmpl.render(()).unwrap_err().to_string()
+}
+
+// ============================================================================
+// columns
+// ============================================================================
+
+#[test]
+fn test_columns_row_major() {
+    let result =
+        render_template(r#"{{ columns(array=["api", "web", "worker", "db", "cache"], count=3) }}"#);
+    assert_eq!(result, "api  web    worker\ndb   cache");
+}
+
+#[test]
+fn test_columns_column_major() {
+    let result = render_template(
+        r#"{{ columns(array=["api", "web", "worker", "db", "cache"], count=3, order="column") }}"#,
+    );
+    assert_eq!(result, "api  worker  cache\nweb  db");
+}
+
+#[test]
+fn test_columns_fit_width() {
+    let items = r#"["alpha", "beta", "gamma", "delta", "epsilon", "zeta"]"#;
+    let result = render_template(&format!("{{{{ columns(array={}, width=20) }}}}", items));
+    for line in result.lines() {
+        assert!(line.len() <= 20, "line too long: {:?}", line);
+    }
+    assert_eq!(result, "alpha    beta\ngamma    delta\nepsilon  zeta");
+}
+
+#[test]
+fn test_columns_gap_and_wide_characters() {
+    let result = render_template(r#"{{ columns(array=["日本", "a", "b", "c"], count=2, gap=1) }}"#);
+    assert_eq!(result, "日本 a\nb    c");
+}
+
+#[test]
+fn test_columns_non_strings_and_empty() {
+    assert_eq!(
+        render_template(r#"{{ columns(array=[1, 2, 3], count=5) }}"#),
+        "1  2  3"
+    );
+    assert_eq!(render_template(r#"{{ columns(array=[]) }}"#), "");
+}
+
+#[test]
+fn test_columns_errors() {
+    assert!(
+        render_template_err(r#"{{ columns(array=["a"], order="diagonal") }}"#)
+            .contains("invalid order")
+    );
+    assert!(
+        render_template_err(r#"{{ columns(array=["a"], count=0) }}"#)
+            .contains("count must be at least 1")
+    );
+    assert!(render_template_err(r#"{{ columns(array=5) }}"#).contains("requires an array"));
+    assert!(render_template_err(r#"{{ columns(array="abc") }}"#).contains("requires an array"));
+}
+
+// ============================================================================
+// box
+// ============================================================================
+
+#[test]
+fn test_box_single_line() {
+    let result = render_template(r#"{{ box(string="Deploy complete") }}"#);
+    assert_eq!(
+        result,
+        "┌─────────────────┐\n│ Deploy complete │\n└─────────────────┘"
+    );
+}
+
+#[test]
+fn test_box_multiline_styles() {
+    let result = render_template(
+        r#"{{ box(string="WARNING\nGenerated file", style="double", padding=2) }}"#,
+    );
+    assert_eq!(
+        result,
+        "╔══════════════════╗\n║  WARNING         ║\n║  Generated file  ║\n╚══════════════════╝"
+    );
+
+    let result = render_template(r#"{{ box(string="hi", style="rounded", padding=0) }}"#);
+    assert_eq!(result, "╭──╮\n│hi│\n╰──╯");
+
+    let result = render_template(r#"{{ box(string="hi", style="ascii") }}"#);
+    assert_eq!(result, "+----+\n| hi |\n+----+");
+}
+
+#[test]
+fn test_box_wide_characters() {
+    let result = render_template(r#"{{ box(string="日本\nab") }}"#);
+    assert_eq!(result, "┌──────┐\n│ 日本 │\n│ ab   │\n└──────┘");
+}
+
+#[test]
+fn test_box_invalid_style() {
+    let err = render_template_err(r#"{{ box(string="x", style="dotted") }}"#);
+    assert!(err.contains("invalid style 'dotted'"));
+    assert!(err.contains("single, double, rounded"));
+}
+
+// ============================================================================
+// progress_bar
+// ============================================================================
+
+#[test]
+fn test_progress_bar_default() {
+    assert_eq!(
+        render_template("{{ progress_bar(value=50) }}"),
+        "██████████░░░░░░░░░░"
+    );
+    assert_eq!(
+        render_template("{{ progress_bar(value=0) }}"),
+        "░░░░░░░░░░░░░░░░░░░░"
+    );
+}
+
+#[test]
+fn test_progress_bar_custom() {
+    let result =
+        render_template(r##"{{ progress_bar(value=3, max=4, width=8, filled="#", empty=".") }}"##);
+    assert_eq!(result, "######..");
+}
+
+#[test]
+fn test_progress_bar_rounds_and_clamps() {
+    // 0.33 * 10 = 3.3 cells
+    assert_eq!(
+        render_template(r#"{{ progress_bar(value=1, max=3, width=10, filled="=", empty=" ") }}"#),
+        "===       "
+    );
+    assert_eq!(
+        render_template(r#"{{ progress_bar(value=150, width=4, filled="=", empty="-") }}"#),
+        "===="
+    );
+    assert_eq!(
+        render_template(r#"{{ progress_bar(value=-5, width=4, filled="=", empty="-") }}"#),
+        "----"
+    );
+}
+
+#[test]
+fn test_progress_bar_invalid_max() {
+    let err = render_template_err("{{ progress_bar(value=1, max=0) }}");
+    assert!(err.contains("max must be a positive number"));
+}
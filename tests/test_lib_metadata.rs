@@ -203,10 +203,10 @@ fn test_get_all_metadata_required_arguments_have_no_default() {
             if arg.required {
                 // Required arguments typically don't have defaults (except in some edge cases)
                 // This is a soft check - we just verify the metadata is consistent
-                if arg.default.is_some() {
+                if let Some(default) = arg.default {
                     // This is allowed but unusual - just make sure default is valid
                     assert!(
-                        !arg.default.unwrap().is_empty(),
+                        !default.is_empty(),
                         "If required arg '{}' in '{}' has default, it should be non-empty",
                         arg.name,
                         func.name
@@ -216,3 +216,23 @@ fn test_get_all_metadata_required_arguments_have_no_default() {
         }
     }
 }
+
+#[test]
+fn test_get_metadata_by_name_finds_every_kind() {
+    // A filter-function, an is-function and a function-only entry
+    for name in ["to_json", "is_email", "uuid"] {
+        let metadata = tmpltool::get_metadata_by_name(name)
+            .unwrap_or_else(|| panic!("missing metadata for {}", name));
+        assert_eq!(metadata.name, name);
+    }
+    assert!(tmpltool::get_metadata_by_name("no_such_function").is_none());
+}
+
+#[test]
+fn test_all_function_names_sorted_and_complete() {
+    let names = tmpltool::all_function_names();
+    assert!(names.windows(2).all(|pair| pair[0] < pair[1]));
+    for metadata in get_all_metadata() {
+        assert!(names.contains(&metadata.name), "missing {}", metadata.name);
+    }
+}
@@ -1,7 +1,7 @@
 use minijinja::Value;
 use minijinja::value::Kwargs;
 use tmpltool::functions::Function;
-use tmpltool::functions::logic::{Coalesce, Default, InRange, Ternary};
+use tmpltool::functions::logic::{Coalesce, DeepEqual, Default, InRange, Ternary};
 
 // ============================================================================
 // Default Tests
@@ -451,3 +451,148 @@ fn test_in_range_missing_params() {
 
     assert!(result.is_err());
 }
+
+// ============================================================================
+// PipeIf Tests
+// ============================================================================
+
+fn render_pipe_if(template: &str, ctx: minijinja::Value) -> Result<String, minijinja::Error> {
+    let mut env = minijinja::Environment::new();
+    tmpltool::functions::register_all(
+        &mut env,
+        tmpltool::TemplateContext::new(std::path::PathBuf::from("."), false),
+    );
+    env.render_str(template, ctx)
+}
+
+#[test]
+fn test_pipe_if_applies_filter_when_true() {
+    let ctx = minijinja::context! { name => "alice", shout => true };
+    let result = render_pipe_if(
+        r#"{{ name | pipe_if(condition=shout, filter="upper") }}"#,
+        ctx,
+    )
+    .unwrap();
+    assert_eq!(result, "ALICE");
+}
+
+#[test]
+fn test_pipe_if_returns_value_unchanged_when_false() {
+    let ctx = minijinja::context! { name => "alice", shout => false };
+    let result = render_pipe_if(
+        r#"{{ name | pipe_if(condition=shout, filter="upper") | length }}"#,
+        ctx,
+    )
+    .unwrap();
+    assert_eq!(result, "5");
+
+    // The value keeps its type, not just its string form
+    let result = render_pipe_if(
+        r#"{{ [3, 1, 2] | pipe_if(condition=false, filter="sort") | first }}"#,
+        Value::UNDEFINED,
+    )
+    .unwrap();
+    assert_eq!(result, "3");
+}
+
+#[test]
+fn test_pipe_if_function_syntax_and_filter_kwargs() {
+    let result = render_pipe_if(
+        r#"{{ pipe_if(value="hello", condition=true, filter="replace", from="l", to="L") }}"#,
+        Value::UNDEFINED,
+    )
+    .unwrap();
+    assert_eq!(result, "heLLo");
+
+    let result = render_pipe_if(
+        r#"{{ "Hello World" | pipe_if(condition=true, filter="truncate", length=8) }}"#,
+        Value::UNDEFINED,
+    )
+    .unwrap();
+    assert_eq!(result, "Hello...");
+}
+
+#[test]
+fn test_pipe_if_unknown_filter() {
+    let err = render_pipe_if(
+        r#"{{ "x" | pipe_if(condition=true, filter="nope") }}"#,
+        Value::UNDEFINED,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("pipe_if: unknown filter 'nope'"));
+}
+
+// ============================================================================
+// DeepEqual Tests
+// ============================================================================
+
+fn deep_equal(a: serde_json::Value, b: serde_json::Value) -> bool {
+    DeepEqual::call(Kwargs::from_iter(vec![
+        ("a", Value::from_serialize(&a)),
+        ("b", Value::from_serialize(&b)),
+    ]))
+    .unwrap()
+    .is_true()
+}
+
+#[test]
+fn test_deep_equal_objects_ignore_key_order() {
+    assert!(deep_equal(
+        serde_json::json!({"a": 1, "b": {"c": [1, 2], "d": null}}),
+        serde_json::json!({"b": {"d": null, "c": [1, 2]}, "a": 1}),
+    ));
+}
+
+#[test]
+fn test_deep_equal_arrays_are_order_sensitive() {
+    assert!(deep_equal(
+        serde_json::json!([1, 2, 3]),
+        serde_json::json!([1, 2, 3])
+    ));
+    assert!(!deep_equal(
+        serde_json::json!([1, 2, 3]),
+        serde_json::json!([3, 2, 1])
+    ));
+    assert!(!deep_equal(
+        serde_json::json!([1, 2]),
+        serde_json::json!([1, 2, 3])
+    ));
+}
+
+#[test]
+fn test_deep_equal_numbers_compare_by_value() {
+    assert!(deep_equal(
+        serde_json::json!({"n": 1}),
+        serde_json::json!({"n": 1.0})
+    ));
+    assert!(!deep_equal(serde_json::json!(1), serde_json::json!(1.5)));
+}
+
+#[test]
+fn test_deep_equal_distinguishes_types() {
+    assert!(!deep_equal(serde_json::json!("1"), serde_json::json!(1)));
+    assert!(!deep_equal(
+        serde_json::json!(null),
+        serde_json::json!(false)
+    ));
+    assert!(!deep_equal(
+        serde_json::json!({"a": 1}),
+        serde_json::json!({"a": 1, "b": 2})
+    ));
+}
+
+#[test]
+fn test_deep_equal_in_template() {
+    let mut env = minijinja::Environment::new();
+    tmpltool::functions::register_all(
+        &mut env,
+        tmpltool::TemplateContext::new(std::path::PathBuf::from("."), false),
+    );
+    let result = env
+        .render_str(
+            r#"{{ deep_equal(a={"x": [1, {"y": 2}]}, b={"x": [1, {"y": 2}]}) }}"#,
+            minijinja::context! {},
+        )
+        .unwrap();
+    assert_eq!(result, "true");
+}
@@ -1,11 +1,11 @@
-//! Tests for math filter-functions (abs, round, ceil, floor).
+//! Tests for math filter-functions (abs, round, ceil, floor, to_roman, from_roman).
 //!
 //! Tests both function and filter syntax.
 
 use minijinja::Value;
 use minijinja::value::Kwargs;
 use tmpltool::filter_functions::FilterFunction;
-use tmpltool::filter_functions::math::{Abs, Ceil, Floor, Round};
+use tmpltool::filter_functions::math::{Abs, Ceil, Floor, FromRoman, Round, ToRoman};
 
 /// Helper to create empty kwargs
 fn empty_kwargs() -> Kwargs {
@@ -178,3 +178,89 @@ fn test_floor_error_not_number() {
     let result = Floor::call_as_filter(&Value::from("not a number"), empty_kwargs());
     assert!(result.is_err());
 }
+
+// ============================================
+// Roman numeral tests
+// ============================================
+
+#[test]
+fn test_to_roman_values() {
+    for (num, expected) in [
+        (1, "I"),
+        (4, "IV"),
+        (9, "IX"),
+        (14, "XIV"),
+        (40, "XL"),
+        (90, "XC"),
+        (400, "CD"),
+        (1994, "MCMXCIV"),
+        (2024, "MMXXIV"),
+        (3999, "MMMCMXCIX"),
+    ] {
+        let result = ToRoman::call_as_filter(&Value::from(num), empty_kwargs()).unwrap();
+        assert_eq!(result.as_str().unwrap(), expected, "{}", num);
+    }
+}
+
+#[test]
+fn test_to_roman_function_syntax() {
+    let kwargs = Kwargs::from_iter(vec![("value", Value::from(12.0))]);
+    let result = ToRoman::call_as_function(kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "XII");
+}
+
+#[test]
+fn test_to_roman_out_of_range() {
+    for value in [
+        Value::from(0),
+        Value::from(4000),
+        Value::from(-5),
+        Value::from(2.5),
+    ] {
+        let err = ToRoman::call_as_filter(&value, empty_kwargs()).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("must be an integer between 1 and 3999"),
+            "{}",
+            value
+        );
+    }
+}
+
+#[test]
+fn test_from_roman_values() {
+    for (numeral, expected) in [
+        ("I", 1),
+        ("xiv", 14),
+        ("MCMXCIV", 1994),
+        ("MMMCMXCIX", 3999),
+    ] {
+        let result = FromRoman::call_as_filter(&Value::from(numeral), empty_kwargs()).unwrap();
+        assert_eq!(result.as_i64().unwrap(), expected, "{}", numeral);
+    }
+
+    let kwargs = Kwargs::from_iter(vec![("string", Value::from("MMXXIV"))]);
+    let result = FromRoman::call_as_function(kwargs).unwrap();
+    assert_eq!(result.as_i64().unwrap(), 2024);
+}
+
+#[test]
+fn test_from_roman_invalid() {
+    for numeral in ["", "IIII", "IC", "VX", "MMMM", "ABC", "X I"] {
+        let err = FromRoman::call_as_filter(&Value::from(numeral), empty_kwargs()).unwrap_err();
+        assert!(
+            err.to_string().contains("is not a valid Roman numeral"),
+            "{:?}",
+            numeral
+        );
+    }
+}
+
+#[test]
+fn test_roman_round_trip() {
+    for num in 1..=3999 {
+        let roman = ToRoman::call_as_filter(&Value::from(num), empty_kwargs()).unwrap();
+        let back = FromRoman::call_as_filter(&roman, empty_kwargs()).unwrap();
+        assert_eq!(back.as_i64().unwrap(), num);
+    }
+}
@@ -3,7 +3,10 @@ use minijinja::value::Kwargs;
 use tmpltool::filter_functions::FilterFunction;
 use tmpltool::filter_functions::math::{Abs, Ceil, Floor, Round};
 use tmpltool::functions::Function;
-use tmpltool::functions::math::{Max, Min, Percentage};
+use tmpltool::functions::math::{
+    Combinations, Factorial, Gcd, Lcm, Lerp, MapRange, Max, Min, Percent, Percentage, Permutations,
+    Ratio,
+};
 
 // ============================================================================
 // Min Tests
@@ -631,3 +634,246 @@ fn test_percentage_very_small() {
     .unwrap();
     assert_eq!(result.to_string(), "0.01");
 }
+
+// ============================================================================
+// Percent / Ratio Tests
+// ============================================================================
+
+#[test]
+fn test_percent_basic() {
+    let result = Percent::call(Kwargs::from_iter(vec![
+        ("part", Value::from(450)),
+        ("whole", Value::from(500)),
+    ]))
+    .unwrap();
+    assert_eq!(result.to_string(), "90");
+
+    let result = Percent::call(Kwargs::from_iter(vec![
+        ("part", Value::from(1)),
+        ("whole", Value::from(8)),
+    ]))
+    .unwrap();
+    assert_eq!(result.to_string(), "12.5");
+}
+
+#[test]
+fn test_ratio_basic() {
+    let result = Ratio::call(Kwargs::from_iter(vec![
+        ("part", Value::from(3)),
+        ("whole", Value::from(4)),
+    ]))
+    .unwrap();
+    assert_eq!(result.to_string(), "0.75");
+}
+
+#[test]
+fn test_percent_and_ratio_zero_whole() {
+    let result = Percent::call(Kwargs::from_iter(vec![
+        ("part", Value::from(5)),
+        ("whole", Value::from(0)),
+    ]))
+    .unwrap();
+    assert_eq!(result.to_string(), "0");
+
+    let result = Ratio::call(Kwargs::from_iter(vec![
+        ("part", Value::from(5)),
+        ("whole", Value::from(0.0)),
+        ("default", Value::from("n/a")),
+    ]))
+    .unwrap();
+    assert_eq!(result.to_string(), "n/a");
+}
+
+#[test]
+fn test_percent_non_numeric() {
+    let err = Percent::call(Kwargs::from_iter(vec![
+        ("part", Value::from("half")),
+        ("whole", Value::from(10)),
+    ]))
+    .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("percent requires numeric part, found: half")
+    );
+}
+
+// ============================================================================
+// Lerp / MapRange Tests
+// ============================================================================
+
+#[test]
+fn test_lerp() {
+    for (t, expected) in [(0.0, "10"), (0.25, "35"), (1.0, "110"), (1.5, "160")] {
+        let result = Lerp::call(Kwargs::from_iter(vec![
+            ("start", Value::from(10)),
+            ("end", Value::from(110)),
+            ("t", Value::from(t)),
+        ]))
+        .unwrap();
+        assert_eq!(result.to_string(), expected, "t={}", t);
+    }
+}
+
+#[test]
+fn test_map_range() {
+    let result = MapRange::call(Kwargs::from_iter(vec![
+        ("value", Value::from(512)),
+        ("in_min", Value::from(0)),
+        ("in_max", Value::from(1024)),
+        ("out_min", Value::from(0)),
+        ("out_max", Value::from(100)),
+    ]))
+    .unwrap();
+    assert_eq!(result.to_string(), "50");
+
+    // Descending output range
+    let result = MapRange::call(Kwargs::from_iter(vec![
+        ("value", Value::from(25)),
+        ("in_min", Value::from(0)),
+        ("in_max", Value::from(100)),
+        ("out_min", Value::from(1.0)),
+        ("out_max", Value::from(0.0)),
+    ]))
+    .unwrap();
+    assert_eq!(result.to_string(), "0.75");
+}
+
+#[test]
+fn test_map_range_clamp() {
+    let call = |clamp: bool| {
+        MapRange::call(Kwargs::from_iter(vec![
+            ("value", Value::from(150)),
+            ("in_min", Value::from(0)),
+            ("in_max", Value::from(100)),
+            ("out_min", Value::from(0)),
+            ("out_max", Value::from(10)),
+            ("clamp", Value::from(clamp)),
+        ]))
+        .unwrap()
+        .to_string()
+    };
+    assert_eq!(call(false), "15");
+    assert_eq!(call(true), "10");
+}
+
+#[test]
+fn test_map_range_empty_input_range() {
+    let err = MapRange::call(Kwargs::from_iter(vec![
+        ("value", Value::from(1)),
+        ("in_min", Value::from(5)),
+        ("in_max", Value::from(5)),
+        ("out_min", Value::from(0)),
+        ("out_max", Value::from(1)),
+    ]))
+    .unwrap_err();
+    assert!(err.to_string().contains("in_min and in_max must differ"));
+}
+
+// ============================================================================
+// Gcd / Lcm Tests
+// ============================================================================
+
+fn ab(a: i64, b: i64) -> Kwargs {
+    Kwargs::from_iter(vec![("a", Value::from(a)), ("b", Value::from(b))])
+}
+
+#[test]
+fn test_gcd() {
+    assert_eq!(Gcd::call(ab(12, 18)).unwrap(), Value::from(6));
+    assert_eq!(Gcd::call(ab(17, 5)).unwrap(), Value::from(1));
+    assert_eq!(Gcd::call(ab(-12, 18)).unwrap(), Value::from(6));
+    assert_eq!(Gcd::call(ab(0, 7)).unwrap(), Value::from(7));
+    assert_eq!(Gcd::call(ab(0, 0)).unwrap(), Value::from(0));
+}
+
+#[test]
+fn test_lcm() {
+    assert_eq!(Lcm::call(ab(4, 6)).unwrap(), Value::from(12));
+    assert_eq!(Lcm::call(ab(15, 20)).unwrap(), Value::from(60));
+    assert_eq!(Lcm::call(ab(-4, 6)).unwrap(), Value::from(12));
+    assert_eq!(Lcm::call(ab(0, 6)).unwrap(), Value::from(0));
+}
+
+#[test]
+fn test_lcm_overflow() {
+    let err = Lcm::call(ab(i64::MAX, i64::MAX - 1)).unwrap_err();
+    assert!(err.to_string().contains("overflows"));
+}
+
+#[test]
+fn test_gcd_requires_integers() {
+    let err = Gcd::call(Kwargs::from_iter(vec![
+        ("a", Value::from(1.5)),
+        ("b", Value::from(3)),
+    ]))
+    .unwrap_err();
+    assert!(err.to_string().contains("gcd requires integer a"));
+}
+
+// ============================================================================
+// Factorial / Combinations / Permutations Tests
+// ============================================================================
+
+fn nk(n: i64, k: i64) -> Kwargs {
+    Kwargs::from_iter(vec![("n", Value::from(n)), ("k", Value::from(k))])
+}
+
+fn n_only(n: i64) -> Kwargs {
+    Kwargs::from_iter(vec![("n", Value::from(n))])
+}
+
+#[test]
+fn test_factorial() {
+    assert_eq!(Factorial::call(n_only(0)).unwrap(), Value::from(1));
+    assert_eq!(Factorial::call(n_only(5)).unwrap(), Value::from(120));
+    assert_eq!(
+        Factorial::call(n_only(20)).unwrap(),
+        Value::from(2_432_902_008_176_640_000i64)
+    );
+}
+
+#[test]
+fn test_factorial_overflow() {
+    let err = Factorial::call(n_only(21)).unwrap_err();
+    assert!(err.to_string().contains("factorial: result overflows"));
+}
+
+#[test]
+fn test_factorial_negative() {
+    let err = Factorial::call(n_only(-1)).unwrap_err();
+    assert!(err.to_string().contains("n must not be negative"));
+}
+
+#[test]
+fn test_combinations() {
+    assert_eq!(Combinations::call(nk(5, 2)).unwrap(), Value::from(10));
+    assert_eq!(Combinations::call(nk(5, 0)).unwrap(), Value::from(1));
+    assert_eq!(Combinations::call(nk(5, 5)).unwrap(), Value::from(1));
+    assert_eq!(Combinations::call(nk(3, 5)).unwrap(), Value::from(0));
+    // Larger than 20! intermediates, but the result fits
+    assert_eq!(
+        Combinations::call(nk(60, 30)).unwrap(),
+        Value::from(118_264_581_564_861_424i64)
+    );
+    assert_eq!(
+        Combinations::call(nk(1_000_000, 1)).unwrap(),
+        Value::from(1_000_000)
+    );
+}
+
+#[test]
+fn test_combinations_overflow() {
+    let err = Combinations::call(nk(100, 50)).unwrap_err();
+    assert!(err.to_string().contains("combinations: result overflows"));
+}
+
+#[test]
+fn test_permutations() {
+    assert_eq!(Permutations::call(nk(5, 2)).unwrap(), Value::from(20));
+    assert_eq!(Permutations::call(nk(5, 0)).unwrap(), Value::from(1));
+    assert_eq!(Permutations::call(nk(5, 5)).unwrap(), Value::from(120));
+    assert_eq!(Permutations::call(nk(2, 3)).unwrap(), Value::from(0));
+
+    let err = Permutations::call(nk(30, 20)).unwrap_err();
+    assert!(err.to_string().contains("permutations: result overflows"));
+}
@@ -0,0 +1,208 @@
+//! Tests for per-render memoization of expensive functions
+
+use minijinja::value::Kwargs;
+use minijinja::{Environment, Error, ErrorKind, Value};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tempfile::TempDir;
+use tmpltool::TemplateContext;
+use tmpltool::functions::memoize::{MEMOIZED_FUNCTIONS, memoize};
+use tmpltool::functions::register_all;
+
+/// Register a function that counts its calls, memoized with a fresh context
+fn counting_env(calls: Arc<AtomicUsize>) -> Environment<'static> {
+    let mut env = Environment::new();
+    env.add_function("expensive", move |kwargs: Kwargs| -> Result<Value, Error> {
+        calls.fetch_add(1, Ordering::SeqCst);
+        let n: i64 = kwargs.get("n")?;
+        if n < 0 {
+            return Err(Error::new(ErrorKind::InvalidOperation, "negative"));
+        }
+        Ok(Value::from(n * 2))
+    });
+    let context = Arc::new(TemplateContext::new(PathBuf::from("."), false));
+    memoize(&mut env, &context, &["expensive"]);
+    env
+}
+
+#[test]
+fn test_memoize_computes_identical_calls_once() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let env = counting_env(calls.clone());
+    let result = env
+        .render_str(
+            "{% for _ in range(5) %}{{ expensive(n=21) }} {% endfor %}{{ expensive(n=1) }}",
+            (),
+        )
+        .unwrap();
+    assert_eq!(result, "42 42 42 42 42 2");
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_memoize_does_not_cache_errors() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let env = counting_env(calls.clone());
+    for _ in 0..2 {
+        assert!(env.render_str("{{ expensive(n=-1) }}", ()).is_err());
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_memoize_is_per_context() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    counting_env(calls.clone())
+        .render_str("{{ expensive(n=1) }}", ())
+        .unwrap();
+    counting_env(calls.clone())
+        .render_str("{{ expensive(n=1) }}", ())
+        .unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_memoized_functions_are_registered() {
+    let mut env = Environment::new();
+    register_all(&mut env, TemplateContext::new(PathBuf::from("."), false));
+    let globals: Vec<&str> = env.globals().map(|(name, _)| name).collect();
+    for name in MEMOIZED_FUNCTIONS {
+        assert!(globals.contains(name), "{} is not registered", name);
+    }
+}
+
+#[test]
+fn test_read_file_is_memoized_within_a_render() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("data.txt");
+    std::fs::write(&path, "first").unwrap();
+
+    let mut env = Environment::new();
+    register_all(
+        &mut env,
+        TemplateContext::new(dir.path().to_path_buf(), false),
+    );
+    // Rewrites the file between the two reads
+    let rewrite_path = path.clone();
+    env.add_function("rewrite", move || {
+        std::fs::write(&rewrite_path, "second").unwrap();
+        Value::from("")
+    });
+
+    let result = env
+        .render_str(
+            r#"{{ read_file(path="data.txt") }}{{ rewrite() }} {{ read_file(path="data.txt") }}"#,
+            (),
+        )
+        .unwrap();
+    assert_eq!(result, "first first");
+}
+
+#[test]
+fn test_read_file_cache_false_rereads_changed_file() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("data.txt");
+    std::fs::write(&path, "first").unwrap();
+
+    let mut env = Environment::new();
+    register_all(
+        &mut env,
+        TemplateContext::new(dir.path().to_path_buf(), false),
+    );
+    let rewrite_path = path.clone();
+    env.add_function("rewrite", move || {
+        std::fs::write(&rewrite_path, "second").unwrap();
+        Value::from("")
+    });
+
+    let result = env
+        .render_str(
+            r#"{{ read_file(path="data.txt") }}{{ rewrite() }} {{ read_file(path="data.txt", cache=false) }} {{ read_file(path="data.txt") }}"#,
+            (),
+        )
+        .unwrap();
+    // cache=false reads the new content without replacing the stored result
+    assert_eq!(result, "first second first");
+}
+
+#[test]
+fn test_memoize_cache_false_is_not_passed_to_function() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let env = counting_env(calls.clone());
+    let result = env
+        .render_str(
+            "{{ expensive(n=1) }} {{ expensive(n=1, cache=false) }} {{ expensive(n=1, cache=true) }}",
+            (),
+        )
+        .unwrap();
+    assert_eq!(result, "2 2 2");
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+/// Environment with all functions, rooted at `dir`, plus `rewrite(content=...)`
+/// which overwrites `dir/name` mid-render
+fn rewriting_env(dir: &TempDir, name: &str) -> Environment<'static> {
+    let mut env = Environment::new();
+    register_all(
+        &mut env,
+        TemplateContext::new(dir.path().to_path_buf(), false),
+    );
+    let path = dir.path().join(name);
+    env.add_function("rewrite", move |kwargs: Kwargs| {
+        let content: String = kwargs.get("content").unwrap();
+        std::fs::write(&path, content).unwrap();
+        Value::from("")
+    });
+    env
+}
+
+#[test]
+fn test_data_files_are_memoized_within_a_render() {
+    let dir = TempDir::new().unwrap();
+    for (name, first, second) in [
+        ("data.json", r#"{"version": 1}"#, r#"{"version": 2}"#),
+        ("data.yaml", "version: 1", "version: 2"),
+        ("data.toml", "version = 1", "version = 2"),
+    ] {
+        std::fs::write(dir.path().join(name), first).unwrap();
+        let env = rewriting_env(&dir, name);
+        let reader = format!("read_{}_file", name.rsplit('.').next().unwrap());
+        let template = format!(
+            r#"{{{{ {reader}(path="{name}").version }}}}{{{{ rewrite(content='{second}') }}}} {{{{ {reader}(path="{name}").version }}}} {{{{ {reader}(path="{name}", cache=false).version }}}}"#
+        );
+        assert_eq!(
+            env.render_str(&template, ()).unwrap(),
+            "1 1 2",
+            "{}",
+            reader
+        );
+        // A new render starts with an empty cache
+        let env = rewriting_env(&dir, name);
+        std::fs::write(dir.path().join(name), first).unwrap();
+        assert_eq!(
+            env.render_str(&format!(r#"{{{{ {reader}(path="{name}").version }}}}"#), ())
+                .unwrap(),
+            "1"
+        );
+    }
+}
+
+#[test]
+fn test_value_readers_share_the_parsed_file() {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(
+        dir.path().join("config.json"),
+        r#"{"server": {"host": "a", "port": 1}}"#,
+    )
+    .unwrap();
+    let env = rewriting_env(&dir, "config.json");
+
+    let result = env
+        .render_str(
+            r#"{{ read_json_file(path="config.json").server.host }}{{ rewrite(content='{"server": {"host": "b", "port": 2}}') }} {{ read_json_value(path="config.json", key="server.port") }} {{ read_json_value(path="config.json", key="server.host", cache=false) }}"#,
+            (),
+        )
+        .unwrap();
+    assert_eq!(result, "a 1 b");
+}
@@ -0,0 +1,322 @@
+use minijinja::Environment;
+use std::path::PathBuf;
+use tmpltool::{TemplateContext, functions::register_all};
+
+fn render_template(template: &str) -> String {
+    let mut env = Environment::new();
+    let ctx = TemplateContext::new(PathBuf::from("."), false);
+    register_all(&mut env, ctx);
+
+    let tmpl = env.template_from_str(template).unwrap();
+    tmpl.render(()).unwrap()
+}
+
+fn render_template_err(template: &str) -> String {
+    let mut env = Environment::new();
+    let ctx = TemplateContext::new(PathBuf::from("."), false);
+    register_all(&mut env, ctx);
+
+    let tmpl = env.template_from_str(template).unwrap();
+    tmpl.render(()).unwrap_err().to_string()
+}
+
+// ==================== object_map_values Tests ====================
+
+#[test]
+fn test_object_map_values_builtin_filter() {
+    let result = render_template(
+        r#"{{ object_map_values(object={"a": "x", "b": "y"}, filter="upper") | tojson }}"#,
+    );
+    assert_eq!(result, r#"{"a":"X","b":"Y"}"#);
+}
+
+#[test]
+fn test_object_map_values_custom_filter() {
+    let result = render_template(
+        r#"{{ object_map_values(object={"name": "Hello World"}, filter="slugify") | tojson }}"#,
+    );
+    assert_eq!(result, r#"{"name":"hello-world"}"#);
+}
+
+#[test]
+fn test_object_map_values_unknown_filter() {
+    let err = render_template_err(r#"{{ object_map_values(object={"a": 1}, filter="nope") }}"#);
+    assert!(err.contains("nope"));
+}
+
+#[test]
+fn test_object_map_values_not_object() {
+    let err = render_template_err(r#"{{ object_map_values(object=[1, 2], filter="upper") }}"#);
+    assert!(err.contains("requires an object"));
+}
+
+// ==================== object_map_keys Tests ====================
+
+#[test]
+fn test_object_map_keys_snake_case() {
+    let result = render_template(
+        r#"{{ object_map_keys(object={"firstName": "a", "LastName": "b"}, filter="to_snake_case") | tojson }}"#,
+    );
+    assert_eq!(result, r#"{"first_name":"a","last_name":"b"}"#);
+}
+
+#[test]
+fn test_object_map_keys_collision_last_wins() {
+    let result = render_template(
+        r#"{{ object_map_keys(object={"A": 1, "a": 2}, filter="lower") | tojson }}"#,
+    );
+    assert_eq!(result, r#"{"a":2}"#);
+}
+
+#[test]
+fn test_object_map_keys_preserves_values() {
+    let result = render_template(
+        r#"{{ object_map_keys(object={"Key": {"nested": [1, 2]}}, filter="lower") | tojson }}"#,
+    );
+    assert_eq!(result, r#"{"key":{"nested":[1,2]}}"#);
+}
+
+// ==================== object_filter Tests ====================
+
+#[test]
+fn test_object_filter_drop_empty_values() {
+    let result = render_template(
+        r#"{{ object_filter(object={"a": "x", "b": "", "c": "y"}, op="ne", value="") | tojson }}"#,
+    );
+    assert_eq!(result, r#"{"a":"x","c":"y"}"#);
+}
+
+#[test]
+fn test_object_filter_numeric() {
+    let result = render_template(
+        r#"{{ object_filter(object={"a": 1, "b": 5, "c": 10}, op="gte", value=5) | tojson }}"#,
+    );
+    assert_eq!(result, r#"{"b":5,"c":10}"#);
+}
+
+#[test]
+fn test_object_filter_by_key() {
+    let result = render_template(
+        r#"{{ object_filter(object={"DB_HOST": "h", "DB_PORT": 1, "APP": "x"}, op="contains", value="DB_", by="key") | tojson }}"#,
+    );
+    assert_eq!(result, r#"{"DB_HOST":"h","DB_PORT":1}"#);
+}
+
+#[test]
+fn test_object_filter_invalid_operator() {
+    let err = render_template_err(r#"{{ object_filter(object={"a": 1}, op="bad", value=1) }}"#);
+    assert!(err.contains("Invalid operator"));
+}
+
+#[test]
+fn test_object_filter_invalid_by() {
+    let err =
+        render_template_err(r#"{{ object_filter(object={"a": 1}, op="eq", value=1, by="both") }}"#);
+    assert!(err.contains("Invalid by"));
+}
+
+// ==================== object_invert Tests ====================
+
+#[test]
+fn test_object_invert_basic() {
+    let result = render_template(r#"{{ object_invert(object={"a": "x", "b": "y"}) | tojson }}"#);
+    assert_eq!(result, r#"{"x":"a","y":"b"}"#);
+}
+
+#[test]
+fn test_object_invert_non_string_values() {
+    let result =
+        render_template(r#"{{ object_invert(object={"http": 80, "on": true}) | tojson }}"#);
+    assert_eq!(result, r#"{"80":"http","true":"on"}"#);
+}
+
+#[test]
+fn test_object_invert_duplicate_errors() {
+    let err = render_template_err(r#"{{ object_invert(object={"a": "x", "b": "x"}) }}"#);
+    assert!(err.contains("duplicate key 'x'"));
+}
+
+#[test]
+fn test_object_invert_duplicate_last_wins() {
+    let result = render_template(
+        r#"{{ object_invert(object={"a": "x", "b": "x"}, on_duplicate="last") | tojson }}"#,
+    );
+    assert_eq!(result, r#"{"x":"b"}"#);
+}
+
+#[test]
+fn test_object_invert_duplicate_first_wins() {
+    let result = render_template(
+        r#"{{ object_invert(object={"a": "x", "b": "x"}, on_duplicate="first") | tojson }}"#,
+    );
+    assert_eq!(result, r#"{"x":"a"}"#);
+}
+
+#[test]
+fn test_object_invert_invalid_on_duplicate() {
+    let err = render_template_err(r#"{{ object_invert(object={"a": "x"}, on_duplicate="x") }}"#);
+    assert!(err.contains("Invalid on_duplicate"));
+}
+
+// ==================== object_key_of Tests ====================
+
+#[test]
+fn test_object_key_of_found() {
+    let result =
+        render_template(r#"{{ object_key_of(object={"dev": 8080, "prod": 80}, value=80) }}"#);
+    assert_eq!(result, "prod");
+}
+
+#[test]
+fn test_object_key_of_first_match_in_key_order() {
+    // Objects iterate in key order, so the first match is deterministic
+    let result =
+        render_template(r#"{{ object_key_of(object={"zeta": "x", "alpha": "x"}, value="x") }}"#);
+    assert_eq!(result, "alpha");
+}
+
+#[test]
+fn test_object_key_of_not_found_is_null() {
+    let result = render_template(
+        r#"{{ object_key_of(object={"a": 1}, value=2) is none }}/{{ object_key_of(object={"a": 1}, value="1") is none }}"#,
+    );
+    assert_eq!(result, "true/true");
+}
+
+#[test]
+fn test_object_key_of_nested_value() {
+    let result = render_template(
+        r#"{{ object_key_of(object={"a": [1, 2], "b": {"x": 1}}, value={"x": 1}) }}"#,
+    );
+    assert_eq!(result, "b");
+}
+
+#[test]
+fn test_object_key_of_requires_object() {
+    let err = render_template_err(r#"{{ object_key_of(object=[1, 2], value=1) }}"#);
+    assert!(err.contains("object_key_of requires an object"));
+}
+
+// ==================== object_get_all Tests ====================
+
+#[test]
+fn test_object_get_all_array_wildcard() {
+    let result = render_template(
+        r#"{% set cfg = {"servers": [{"host": "a"}, {"host": "b"}, {"port": 1}]} %}{{ object_get_all(object=cfg, path="servers.*.host") | tojson }}"#,
+    );
+    assert_eq!(result, r#"["a","b"]"#);
+}
+
+#[test]
+fn test_object_get_all_object_wildcard() {
+    let result = render_template(
+        r#"{% set cfg = {"dbs": {"main": {"port": 5432}, "replica": {"port": 5433}}} %}{{ object_get_all(object=cfg, path="dbs.*.port") | tojson }}"#,
+    );
+    assert_eq!(result, "[5432,5433]");
+}
+
+#[test]
+fn test_object_get_all_nested_wildcards() {
+    let result = render_template(
+        r#"{% set cfg = {"regions": [{"zones": [{"id": 1}, {"id": 2}]}, {"zones": [{"id": 3}]}]} %}{{ object_get_all(object=cfg, path="regions.*.zones.*.id") | tojson }}"#,
+    );
+    assert_eq!(result, "[1,2,3]");
+}
+
+#[test]
+fn test_object_get_all_without_wildcard() {
+    let result =
+        render_template(r#"{{ object_get_all(object={"a": {"b": 1}}, path="a.b") | tojson }}"#);
+    assert_eq!(result, "[1]");
+}
+
+#[test]
+fn test_object_get_all_no_match() {
+    let result = render_template(r#"{{ object_get_all(object={"a": 1}, path="x.*") | tojson }}"#);
+    assert_eq!(result, "[]");
+}
+
+// ==================== object_merge list_strategy Tests ====================
+
+#[test]
+fn test_object_merge_list_strategy_default_replaces() {
+    let result =
+        render_template(r#"{{ object_merge(obj1={"a": [1, 2]}, obj2={"a": [3]}) | tojson }}"#);
+    assert_eq!(result, r#"{"a":[3]}"#);
+}
+
+#[test]
+fn test_object_merge_list_strategy_append() {
+    let result = render_template(
+        r#"{{ object_merge(obj1={"a": {"b": [1, 2]}}, obj2={"a": {"b": [2, 3]}}, list_strategy="append") | tojson }}"#,
+    );
+    assert_eq!(result, r#"{"a":{"b":[1,2,2,3]}}"#);
+}
+
+#[test]
+fn test_object_merge_list_strategy_unique() {
+    let result = render_template(
+        r#"{{ object_merge(obj1={"a": [1, 2]}, obj2={"a": [2, 3]}, list_strategy="unique") | tojson }}"#,
+    );
+    assert_eq!(result, r#"{"a":[1,2,3]}"#);
+}
+
+#[test]
+fn test_object_merge_list_strategy_array_replaces_scalar() {
+    let result = render_template(
+        r#"{{ object_merge(obj1={"a": 1}, obj2={"a": [2]}, list_strategy="append") | tojson }}"#,
+    );
+    assert_eq!(result, r#"{"a":[2]}"#);
+}
+
+#[test]
+fn test_object_merge_list_strategy_invalid() {
+    let err =
+        render_template_err(r#"{{ object_merge(obj1={}, obj2={}, list_strategy="concat") }}"#);
+    assert!(err.contains("Invalid list_strategy"));
+}
+
+// ==================== dict / object Tests ====================
+
+#[test]
+fn test_dict_zips_keys_and_values() {
+    let result = render_template(r#"{{ dict(keys=["b", "a"], values=[1, 2]) | tojson }}"#);
+    assert_eq!(result, r#"{"a":2,"b":1}"#);
+}
+
+#[test]
+fn test_dict_with_computed_keys() {
+    let result = render_template(
+        r#"{% set services = [{"name": "web", "port": 80}, {"name": "db", "port": 5432}] -%}
+{% set ports = dict(keys=services | map(attribute="name") | list, values=services | map(attribute="port") | list) -%}
+{{ ports.db }} {{ ports["web"] }}"#,
+    );
+    assert_eq!(result, "5432 80");
+}
+
+#[test]
+fn test_dict_length_mismatch() {
+    let err = render_template_err(r#"{{ dict(keys=["a", "b"], values=[1]) }}"#);
+    assert!(err.contains("dict: keys and values must have the same length (2 keys, 1 values)"));
+}
+
+#[test]
+fn test_dict_keeps_builtin_behavior() {
+    let result = render_template(r#"{{ dict(name="web", port=80) | tojson }}"#);
+    assert_eq!(result, r#"{"name":"web","port":80}"#);
+    let result = render_template(r#"{{ dict({"port": 80, "tls": false}, port=443) | tojson }}"#);
+    assert_eq!(result, r#"{"port":443,"tls":false}"#);
+    // Only keys + values together switch to zip mode
+    let result = render_template(r#"{{ dict(keys=["a"]) | tojson }}"#);
+    assert_eq!(result, r#"{"keys":["a"]}"#);
+    assert_eq!(render_template(r#"{{ dict() | tojson }}"#), "{}");
+}
+
+#[test]
+fn test_object_from_kwargs() {
+    let result = render_template(
+        r#"{% set app = "api" %}{{ object(app=app, replicas=3, tags=["x"]) | tojson }}"#,
+    );
+    assert_eq!(result, r#"{"app":"api","replicas":3,"tags":["x"]}"#);
+    assert_eq!(render_template(r#"{{ object() | tojson }}"#), "{}");
+}
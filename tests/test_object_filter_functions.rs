@@ -1,13 +1,14 @@
 //! Tests for object filter-functions.
 //!
 //! Tests both function and filter syntax for:
-//! - object_keys, object_values, object_flatten
+//! - object_keys, object_values, object_flatten, compact, merge
 
 use minijinja::Value;
-use minijinja::value::Kwargs;
+use minijinja::value::{Enumerator, Kwargs, Object};
 use std::collections::BTreeMap;
+use std::sync::Arc;
 use tmpltool::filter_functions::FilterFunction;
-use tmpltool::filter_functions::object::{ObjectFlatten, ObjectKeys, ObjectValues};
+use tmpltool::filter_functions::object::{Compact, Merge, ObjectFlatten, ObjectKeys, ObjectValues};
 
 /// Helper to create empty kwargs
 fn empty_kwargs() -> Kwargs {
@@ -123,6 +124,57 @@ fn test_object_values_error_not_object() {
     assert!(result.is_err());
 }
 
+// ============================================
+// Key order tests
+// ============================================
+
+/// Object that enumerates its keys in a fixed, non-alphabetical order
+/// (like a HashMap-backed object provided by embedding code)
+#[derive(Debug)]
+struct PortMap;
+
+impl Object for PortMap {
+    fn get_value(self: &Arc<Self>, key: &Value) -> Option<Value> {
+        match key.as_str()? {
+            "http" => Some(Value::from(80)),
+            "https" => Some(Value::from(443)),
+            "app" => Some(Value::from(8080)),
+            _ => None,
+        }
+    }
+
+    fn enumerate(self: &Arc<Self>) -> Enumerator {
+        Enumerator::Str(&["http", "https", "app"])
+    }
+}
+
+fn unsorted_object() -> Value {
+    Value::from_object(PortMap)
+}
+
+#[test]
+fn test_object_keys_and_values_keep_object_order() {
+    let keys = ObjectKeys::call_as_filter(&unsorted_object(), empty_kwargs()).unwrap();
+    assert_eq!(keys.to_string(), r#"["http", "https", "app"]"#);
+
+    let values = ObjectValues::call_as_filter(&unsorted_object(), empty_kwargs()).unwrap();
+    assert_eq!(values.to_string(), "[80, 443, 8080]");
+}
+
+#[test]
+fn test_object_keys_and_values_sorted() {
+    let kwargs = Kwargs::from_iter(vec![("sort", Value::from(true))]);
+    let keys = ObjectKeys::call_as_filter(&unsorted_object(), kwargs).unwrap();
+    assert_eq!(keys.to_string(), r#"["app", "http", "https"]"#);
+
+    let kwargs = Kwargs::from_iter(vec![
+        ("object", unsorted_object()),
+        ("sort", Value::from(true)),
+    ]);
+    let values = ObjectValues::call_as_function(kwargs).unwrap();
+    assert_eq!(values.to_string(), "[8080, 80, 443]");
+}
+
 // ============================================
 // ObjectFlatten tests
 // ============================================
@@ -201,3 +253,133 @@ fn test_object_flatten_error_not_object() {
             .contains("requires an object")
     );
 }
+
+// ============================================
+// Compact tests
+// ============================================
+
+#[test]
+fn test_compact_array_filter_syntax() {
+    let arr = Value::from_serialize(serde_json::json!([1, null, "", "a", 0, false]));
+    let result = Compact::call_as_filter(&arr, empty_kwargs()).unwrap();
+    let json: serde_json::Value = serde_json::to_value(&result).unwrap();
+    assert_eq!(json, serde_json::json!([1, "a", 0, false]));
+}
+
+#[test]
+fn test_compact_object_function_syntax() {
+    let obj = Value::from_serialize(serde_json::json!({"name": "app", "tag": null, "note": ""}));
+    let kwargs = Kwargs::from_iter(vec![("value", obj)]);
+    let result = Compact::call_as_function(kwargs).unwrap();
+    let json: serde_json::Value = serde_json::to_value(&result).unwrap();
+    assert_eq!(json, serde_json::json!({"name": "app"}));
+}
+
+#[test]
+fn test_compact_shallow_by_default() {
+    let obj = Value::from_serialize(serde_json::json!({"db": {"host": null}, "tags": [null]}));
+    let result = Compact::call_as_filter(&obj, empty_kwargs()).unwrap();
+    let json: serde_json::Value = serde_json::to_value(&result).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({"db": {"host": null}, "tags": [null]})
+    );
+}
+
+#[test]
+fn test_compact_recursive() {
+    let obj = Value::from_serialize(serde_json::json!({
+        "db": {"host": "localhost", "password": null},
+        "tags": ["a", "", null],
+        "extra": null
+    }));
+    let kwargs = Kwargs::from_iter(vec![("recursive", Value::from(true))]);
+    let result = Compact::call_as_filter(&obj, kwargs).unwrap();
+    let json: serde_json::Value = serde_json::to_value(&result).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({"db": {"host": "localhost"}, "tags": ["a"]})
+    );
+}
+
+#[test]
+fn test_compact_error_not_collection() {
+    let result = Compact::call_as_filter(&Value::from("text"), empty_kwargs());
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("requires an array or object")
+    );
+}
+
+// ============================================
+// Merge tests
+// ============================================
+
+#[test]
+fn test_merge_filter_syntax_deep() {
+    let base = Value::from_serialize(serde_json::json!({"db": {"host": "a", "port": 5432}}));
+    let overlay = Value::from_serialize(serde_json::json!({"db": {"host": "b"}, "debug": true}));
+    let kwargs = Kwargs::from_iter(vec![("other", overlay)]);
+    let result = Merge::call_as_filter(&base, kwargs).unwrap();
+    let json: serde_json::Value = serde_json::to_value(&result).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({"db": {"host": "b", "port": 5432}, "debug": true})
+    );
+}
+
+#[test]
+fn test_merge_function_syntax() {
+    let kwargs = Kwargs::from_iter(vec![
+        ("object", Value::from_serialize(serde_json::json!({"a": 1}))),
+        ("other", Value::from_serialize(serde_json::json!({"b": 2}))),
+    ]);
+    let result = Merge::call_as_function(kwargs).unwrap();
+    let json: serde_json::Value = serde_json::to_value(&result).unwrap();
+    assert_eq!(json, serde_json::json!({"a": 1, "b": 2}));
+}
+
+#[test]
+fn test_merge_list_strategy_unique() {
+    let base = Value::from_serialize(serde_json::json!({"tags": ["a", "b"]}));
+    let kwargs = Kwargs::from_iter(vec![
+        (
+            "other",
+            Value::from_serialize(serde_json::json!({"tags": ["b", "c"]})),
+        ),
+        ("list_strategy", Value::from("unique")),
+    ]);
+    let result = Merge::call_as_filter(&base, kwargs).unwrap();
+    let json: serde_json::Value = serde_json::to_value(&result).unwrap();
+    assert_eq!(json, serde_json::json!({"tags": ["a", "b", "c"]}));
+}
+
+#[test]
+fn test_merge_invalid_list_strategy() {
+    let base = Value::from_serialize(serde_json::json!({}));
+    let kwargs = Kwargs::from_iter(vec![
+        ("other", Value::from_serialize(serde_json::json!({}))),
+        ("list_strategy", Value::from("zip")),
+    ]);
+    let result = Merge::call_as_filter(&base, kwargs);
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid list_strategy")
+    );
+}
+
+#[test]
+fn test_merge_requires_objects() {
+    let kwargs = Kwargs::from_iter(vec![("other", Value::from(1))]);
+    let result = Merge::call_as_filter(&Value::from_serialize(serde_json::json!({})), kwargs);
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("merge requires an object")
+    );
+}
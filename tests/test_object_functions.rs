@@ -2,8 +2,8 @@ use minijinja::Value;
 use minijinja::value::Kwargs;
 use tmpltool::functions::Function;
 use tmpltool::functions::object::{
-    JsonPath, ObjectGet, ObjectHasKey, ObjectMerge, ObjectOmit, ObjectPick, ObjectRenameKeys,
-    ObjectSet, ObjectUnflatten,
+    ApplySchemaDefaults, JsonMergePatch, JsonPatch, JsonPath, ObjectGet, ObjectHasKey, ObjectMerge,
+    ObjectOmit, ObjectPick, ObjectRenameKeys, ObjectSet, ObjectUnflatten,
 };
 
 #[test]
@@ -343,6 +343,216 @@ fn test_object_set_string_value() {
     assert_eq!(json["name"], "new");
 }
 
+// ==================== json_patch Tests ====================
+
+fn apply_patch(
+    obj: serde_json::Value,
+    patch: serde_json::Value,
+) -> Result<serde_json::Value, minijinja::Error> {
+    let result = JsonPatch::call(Kwargs::from_iter(vec![
+        ("object", Value::from_serialize(&obj)),
+        ("patch", Value::from_serialize(&patch)),
+    ]))?;
+    Ok(serde_json::to_value(&result).unwrap())
+}
+
+#[test]
+fn test_json_patch_all_operations() {
+    let obj = serde_json::json!({"a": 1, "b": {"c": 2}, "list": [1, 2]});
+    let patch = serde_json::json!([
+        {"op": "test", "path": "/a", "value": 1},
+        {"op": "replace", "path": "/a", "value": 10},
+        {"op": "add", "path": "/list/-", "value": 3},
+        {"op": "copy", "from": "/b/c", "path": "/d"},
+        {"op": "move", "from": "/b", "path": "/e"},
+        {"op": "remove", "path": "/list/0"}
+    ]);
+
+    let result = apply_patch(obj, patch).unwrap();
+    assert_eq!(
+        result,
+        serde_json::json!({"a": 10, "d": 2, "e": {"c": 2}, "list": [2, 3]})
+    );
+}
+
+#[test]
+fn test_json_patch_failed_test_op() {
+    let obj = serde_json::json!({"a": 1});
+    let patch = serde_json::json!([{"op": "test", "path": "/a", "value": 2}]);
+
+    let err = apply_patch(obj, patch).unwrap_err().to_string();
+    assert!(err.contains("json_patch:"));
+    assert!(err.contains("value did not match"));
+}
+
+#[test]
+fn test_json_patch_invalid_path() {
+    let obj = serde_json::json!({"a": 1});
+    let patch = serde_json::json!([{"op": "remove", "path": "/missing"}]);
+
+    let err = apply_patch(obj, patch).unwrap_err().to_string();
+    assert!(err.contains("path is invalid"));
+}
+
+#[test]
+fn test_json_patch_invalid_operation() {
+    let obj = serde_json::json!({"a": 1});
+    let patch = serde_json::json!([{"op": "explode", "path": "/a"}]);
+
+    let err = apply_patch(obj, patch).unwrap_err().to_string();
+    assert!(err.contains("json_patch: invalid patch"));
+}
+
+#[test]
+fn test_json_patch_empty_patch() {
+    let obj = serde_json::json!({"a": 1});
+
+    let result = apply_patch(obj.clone(), serde_json::json!([])).unwrap();
+    assert_eq!(result, obj);
+}
+
+// ==================== json_merge_patch Tests ====================
+
+fn apply_merge_patch(obj: serde_json::Value, patch: serde_json::Value) -> serde_json::Value {
+    let result = JsonMergePatch::call(Kwargs::from_iter(vec![
+        ("object", Value::from_serialize(&obj)),
+        ("patch", Value::from_serialize(&patch)),
+    ]))
+    .unwrap();
+    serde_json::to_value(&result).unwrap()
+}
+
+#[test]
+fn test_json_merge_patch_null_deletes() {
+    let obj = serde_json::json!({"a": 1, "b": {"c": 2, "d": 3}});
+    let patch = serde_json::json!({"a": null, "b": {"d": null, "e": 4}});
+
+    assert_eq!(
+        apply_merge_patch(obj, patch),
+        serde_json::json!({"b": {"c": 2, "e": 4}})
+    );
+}
+
+#[test]
+fn test_json_merge_patch_arrays_replace() {
+    let obj = serde_json::json!({"tags": ["a", "b"]});
+    let patch = serde_json::json!({"tags": ["c"]});
+
+    assert_eq!(
+        apply_merge_patch(obj, patch),
+        serde_json::json!({"tags": ["c"]})
+    );
+}
+
+#[test]
+fn test_json_merge_patch_non_object_patch_replaces() {
+    let obj = serde_json::json!({"a": 1});
+
+    assert_eq!(
+        apply_merge_patch(obj, serde_json::json!("replaced")),
+        serde_json::json!("replaced")
+    );
+}
+
+#[test]
+fn test_json_merge_patch_object_into_scalar() {
+    let obj = serde_json::json!({"a": "scalar"});
+    let patch = serde_json::json!({"a": {"b": 1, "c": null}});
+
+    assert_eq!(
+        apply_merge_patch(obj, patch),
+        serde_json::json!({"a": {"b": 1}})
+    );
+}
+
+// ==================== apply_schema_defaults Tests ====================
+
+fn apply_defaults(
+    obj: serde_json::Value,
+    schema: serde_json::Value,
+) -> Result<serde_json::Value, minijinja::Error> {
+    let result = ApplySchemaDefaults::call(Kwargs::from_iter(vec![
+        ("object", Value::from_serialize(&obj)),
+        ("schema", Value::from_serialize(&schema)),
+    ]))?;
+    Ok(serde_json::to_value(&result).unwrap())
+}
+
+#[test]
+fn test_apply_schema_defaults_fills_missing() {
+    let schema = serde_json::json!({
+        "properties": {
+            "host": {"default": "0.0.0.0"},
+            "port": {"default": 8080},
+            "debug": {"type": "boolean"}
+        }
+    });
+
+    let result = apply_defaults(serde_json::json!({"port": 9090, "extra": null}), schema).unwrap();
+    assert_eq!(
+        result,
+        serde_json::json!({"host": "0.0.0.0", "port": 9090, "extra": null})
+    );
+}
+
+#[test]
+fn test_apply_schema_defaults_nested_and_items() {
+    let schema = serde_json::json!({
+        "properties": {
+            "tls": {"default": {}, "properties": {"enabled": {"default": false}}},
+            "db": {"properties": {"pool": {"default": 5}}},
+            "workers": {"items": {"properties": {"threads": {"default": 4}}}}
+        }
+    });
+
+    let result = apply_defaults(
+        serde_json::json!({"workers": [{"name": "a"}, {"name": "b", "threads": 8}]}),
+        schema,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        serde_json::json!({
+            "tls": {"enabled": false},
+            "workers": [{"name": "a", "threads": 4}, {"name": "b", "threads": 8}]
+        })
+    );
+}
+
+#[test]
+fn test_apply_schema_defaults_ref_and_all_of() {
+    let schema = serde_json::json!({
+        "$defs": {"port": {"type": "integer", "default": 80}},
+        "allOf": [{"properties": {"name": {"default": "app"}}}],
+        "properties": {"port": {"$ref": "#/$defs/port"}}
+    });
+
+    let result = apply_defaults(serde_json::json!({}), schema).unwrap();
+    assert_eq!(result, serde_json::json!({"name": "app", "port": 80}));
+}
+
+#[test]
+fn test_apply_schema_defaults_unresolvable_ref() {
+    let schema = serde_json::json!({
+        "properties": {"port": {"$ref": "other.json#/port"}}
+    });
+
+    let err = apply_defaults(serde_json::json!({}), schema)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("cannot resolve $ref 'other.json#/port'"));
+}
+
+#[test]
+fn test_apply_schema_defaults_recursive_ref() {
+    let schema = serde_json::json!({"$ref": "#"});
+
+    let err = apply_defaults(serde_json::json!({}), schema)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("schema nesting too deep"));
+}
+
 // ==================== json_path Tests ====================
 
 #[test]
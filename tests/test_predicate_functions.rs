@@ -1,7 +1,8 @@
 use minijinja::Value;
 use minijinja::value::Kwargs;
 use tmpltool::functions::Function;
-use tmpltool::functions::predicates::{ArrayAll, ArrayAny, ArrayContains, EndsWith, StartsWith};
+use tmpltool::functions::predicates::{ArrayAll, ArrayAny, ArrayContains};
+use tmpltool::is_functions::string::{EndsWith, StartsWith};
 
 // ============================================================================
 // Array Any Tests
@@ -282,7 +283,7 @@ fn test_array_contains_error_not_array() {
 
 #[test]
 fn test_starts_with_true() {
-    let result = StartsWith::call(Kwargs::from_iter(vec![
+    let result = StartsWith::call_as_function(Kwargs::from_iter(vec![
         ("string", Value::from("Hello World")),
         ("prefix", Value::from("Hello")),
     ]))
@@ -293,7 +294,7 @@ fn test_starts_with_true() {
 
 #[test]
 fn test_starts_with_false() {
-    let result = StartsWith::call(Kwargs::from_iter(vec![
+    let result = StartsWith::call_as_function(Kwargs::from_iter(vec![
         ("string", Value::from("Hello World")),
         ("prefix", Value::from("World")),
     ]))
@@ -304,7 +305,7 @@ fn test_starts_with_false() {
 
 #[test]
 fn test_starts_with_empty_prefix() {
-    let result = StartsWith::call(Kwargs::from_iter(vec![
+    let result = StartsWith::call_as_function(Kwargs::from_iter(vec![
         ("string", Value::from("Hello")),
         ("prefix", Value::from("")),
     ]))
@@ -315,7 +316,7 @@ fn test_starts_with_empty_prefix() {
 
 #[test]
 fn test_starts_with_same_string() {
-    let result = StartsWith::call(Kwargs::from_iter(vec![
+    let result = StartsWith::call_as_function(Kwargs::from_iter(vec![
         ("string", Value::from("test")),
         ("prefix", Value::from("test")),
     ]))
@@ -326,7 +327,7 @@ fn test_starts_with_same_string() {
 
 #[test]
 fn test_starts_with_case_sensitive() {
-    let result = StartsWith::call(Kwargs::from_iter(vec![
+    let result = StartsWith::call_as_function(Kwargs::from_iter(vec![
         ("string", Value::from("Hello")),
         ("prefix", Value::from("hello")),
     ]))
@@ -337,7 +338,7 @@ fn test_starts_with_case_sensitive() {
 
 #[test]
 fn test_starts_with_longer_prefix() {
-    let result = StartsWith::call(Kwargs::from_iter(vec![
+    let result = StartsWith::call_as_function(Kwargs::from_iter(vec![
         ("string", Value::from("Hi")),
         ("prefix", Value::from("Hello")),
     ]))
@@ -348,7 +349,7 @@ fn test_starts_with_longer_prefix() {
 
 #[test]
 fn test_starts_with_file_path() {
-    let result = StartsWith::call(Kwargs::from_iter(vec![
+    let result = StartsWith::call_as_function(Kwargs::from_iter(vec![
         ("string", Value::from("/usr/local/bin/app")),
         ("prefix", Value::from("/usr/")),
     ]))
@@ -363,7 +364,7 @@ fn test_starts_with_file_path() {
 
 #[test]
 fn test_ends_with_true() {
-    let result = EndsWith::call(Kwargs::from_iter(vec![
+    let result = EndsWith::call_as_function(Kwargs::from_iter(vec![
         ("string", Value::from("readme.txt")),
         ("suffix", Value::from(".txt")),
     ]))
@@ -374,7 +375,7 @@ fn test_ends_with_true() {
 
 #[test]
 fn test_ends_with_false() {
-    let result = EndsWith::call(Kwargs::from_iter(vec![
+    let result = EndsWith::call_as_function(Kwargs::from_iter(vec![
         ("string", Value::from("readme.txt")),
         ("suffix", Value::from(".md")),
     ]))
@@ -385,7 +386,7 @@ fn test_ends_with_false() {
 
 #[test]
 fn test_ends_with_empty_suffix() {
-    let result = EndsWith::call(Kwargs::from_iter(vec![
+    let result = EndsWith::call_as_function(Kwargs::from_iter(vec![
         ("string", Value::from("test")),
         ("suffix", Value::from("")),
     ]))
@@ -396,7 +397,7 @@ fn test_ends_with_empty_suffix() {
 
 #[test]
 fn test_ends_with_same_string() {
-    let result = EndsWith::call(Kwargs::from_iter(vec![
+    let result = EndsWith::call_as_function(Kwargs::from_iter(vec![
         ("string", Value::from("test")),
         ("suffix", Value::from("test")),
     ]))
@@ -407,7 +408,7 @@ fn test_ends_with_same_string() {
 
 #[test]
 fn test_ends_with_case_sensitive() {
-    let result = EndsWith::call(Kwargs::from_iter(vec![
+    let result = EndsWith::call_as_function(Kwargs::from_iter(vec![
         ("string", Value::from("Hello")),
         ("suffix", Value::from("LO")),
     ]))
@@ -418,7 +419,7 @@ fn test_ends_with_case_sensitive() {
 
 #[test]
 fn test_ends_with_longer_suffix() {
-    let result = EndsWith::call(Kwargs::from_iter(vec![
+    let result = EndsWith::call_as_function(Kwargs::from_iter(vec![
         ("string", Value::from("Hi")),
         ("suffix", Value::from("Hello")),
     ]))
@@ -429,7 +430,7 @@ fn test_ends_with_longer_suffix() {
 
 #[test]
 fn test_ends_with_url() {
-    let result = EndsWith::call(Kwargs::from_iter(vec![
+    let result = EndsWith::call_as_function(Kwargs::from_iter(vec![
         ("string", Value::from("https://example.com")),
         ("suffix", Value::from(".com")),
     ]))
@@ -440,7 +441,7 @@ fn test_ends_with_url() {
 
 #[test]
 fn test_ends_with_multiple_extensions() {
-    let result = EndsWith::call(Kwargs::from_iter(vec![
+    let result = EndsWith::call_as_function(Kwargs::from_iter(vec![
         ("string", Value::from("archive.tar.gz")),
         ("suffix", Value::from(".tar.gz")),
     ]))
@@ -482,14 +483,16 @@ fn test_array_contains_missing_value() {
 
 #[test]
 fn test_starts_with_missing_string() {
-    let result = StartsWith::call(Kwargs::from_iter(vec![("prefix", Value::from("test"))]));
+    let result =
+        StartsWith::call_as_function(Kwargs::from_iter(vec![("prefix", Value::from("test"))]));
 
     assert!(result.is_err());
 }
 
 #[test]
 fn test_ends_with_missing_suffix() {
-    let result = EndsWith::call(Kwargs::from_iter(vec![("string", Value::from("test"))]));
+    let result =
+        EndsWith::call_as_function(Kwargs::from_iter(vec![("string", Value::from("test"))]));
 
     assert!(result.is_err());
 }
@@ -0,0 +1,92 @@
+use minijinja::Environment;
+use std::path::PathBuf;
+use tmpltool::{TemplateContext, functions::register_all};
+
+fn render_template(template: &str) -> String {
+    let mut env = Environment::new();
+    let ctx = TemplateContext::new(PathBuf::from("."), false);
+    register_all(&mut env, ctx);
+
+    let tmpl = env.template_from_str(template).unwrap();
+    tmpl.render(()).unwrap()
+}
+
+fn render_template_err(template: &str) -> String {
+    let mut env = Environment::new();
+    let ctx = TemplateContext::new(PathBuf::from("."), false);
+    register_all(&mut env, ctx);
+
+    let tmpl = env.template_from_str(template).unwrap();
+    tmpl.render(()).unwrap_err().to_string()
+}
+
+#[test]
+fn test_qr_svg_is_inline_svg() {
+    let result = render_template(r#"{{ qr_svg(string="https://example.com") }}"#);
+    assert!(result.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert!(result.ends_with("</svg>"));
+    assert!(!result.contains("<?xml"));
+    assert!(result.contains("fill=\"#000000\""));
+    assert!(result.contains("fill=\"#ffffff\""));
+}
+
+#[test]
+fn test_qr_svg_is_deterministic() {
+    let a = render_template(r#"{{ qr_svg(string="hello") }}"#);
+    let b = render_template(r#"{{ qr_svg(string="hello") }}"#);
+    let c = render_template(r#"{{ qr_svg(string="world") }}"#);
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+}
+
+#[test]
+fn test_qr_svg_size_is_minimum() {
+    let result = render_template(r#"{{ qr_svg(string="hello", size=300) }}"#);
+    let width: u32 = result
+        .split("width=\"")
+        .nth(1)
+        .and_then(|s| s.split('"').next())
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(width >= 300);
+}
+
+#[test]
+fn test_qr_svg_colors() {
+    let result =
+        render_template(r#"{{ qr_svg(string="hello", dark="navy", light="transparent") }}"#);
+    assert!(result.contains("fill=\"navy\""));
+    assert!(result.contains("fill=\"transparent\""));
+}
+
+#[test]
+fn test_qr_svg_error_correction_changes_output() {
+    let low = render_template(r#"{{ qr_svg(string="hello", error_correction="L") }}"#);
+    let high = render_template(r#"{{ qr_svg(string="hello", error_correction="h") }}"#);
+    assert_ne!(low, high);
+}
+
+#[test]
+fn test_qr_svg_invalid_error_correction() {
+    let err = render_template_err(r#"{{ qr_svg(string="hello", error_correction="X") }}"#);
+    assert!(err.contains("qr_svg: invalid error_correction 'X'"));
+}
+
+#[test]
+fn test_qr_svg_zero_size() {
+    let err = render_template_err(r#"{{ qr_svg(string="hello", size=0) }}"#);
+    assert!(err.contains("size must be greater than 0"));
+}
+
+#[test]
+fn test_qr_svg_data_too_long() {
+    let err = render_template_err(r#"{{ qr_svg(string="x" * 5000) }}"#);
+    assert!(err.contains("qr_svg: failed to encode data"));
+}
+
+#[test]
+fn test_qr_svg_missing_string() {
+    let err = render_template_err(r#"{{ qr_svg(size=100) }}"#);
+    assert!(err.contains("missing keyword argument"));
+}
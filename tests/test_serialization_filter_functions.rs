@@ -1,7 +1,7 @@
 //! Tests for serialization filter-functions.
 //!
 //! Tests both function and filter syntax for:
-//! - to_json, to_yaml, to_toml
+//! - to_json, to_yaml, to_toml, to_env_file
 //! - parse_json, parse_yaml, parse_toml
 
 use minijinja::Value;
@@ -9,7 +9,7 @@ use minijinja::value::Kwargs;
 use std::collections::BTreeMap;
 use tmpltool::filter_functions::FilterFunction;
 use tmpltool::filter_functions::serialization::{
-    ParseJson, ParseToml, ParseYaml, ToJson, ToToml, ToYaml,
+    ParseJson, ParseToml, ParseYaml, ToEnvFile, ToJson, ToToml, ToYaml,
 };
 
 /// Helper to create empty kwargs
@@ -133,6 +133,92 @@ fn test_to_toml_integer() {
     assert!(toml_str.contains("count = 42"));
 }
 
+// ============================================
+// ToEnvFile tests
+// ============================================
+
+#[test]
+fn test_to_env_file_filter_syntax() {
+    let obj = make_object(vec![
+        ("PORT", Value::from(8080)),
+        ("DEBUG", Value::from(true)),
+        ("URL", Value::from("https://example.com/a?b=c")),
+    ]);
+    let result = ToEnvFile::call_as_filter(&obj, empty_kwargs()).unwrap();
+    assert_eq!(
+        result.as_str().unwrap(),
+        "DEBUG=true\nPORT=8080\nURL=https://example.com/a?b=c\n"
+    );
+}
+
+#[test]
+fn test_to_env_file_function_syntax() {
+    let obj = make_object(vec![
+        ("EMPTY", Value::from(())),
+        ("NAME", Value::from("app")),
+    ]);
+    let kwargs = Kwargs::from_iter(vec![("object", obj)]);
+    let result = ToEnvFile::call_as_function(kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "EMPTY=\nNAME=app\n");
+}
+
+#[test]
+fn test_to_env_file_quotes_only_when_needed() {
+    let obj = make_object(vec![
+        ("A", Value::from("hello world")),
+        ("B", Value::from("say \"hi\" for $5")),
+        ("C", Value::from("back\\slash")),
+        ("D", Value::from("#not-a-comment")),
+        ("E", Value::from("it's")),
+        ("F", Value::from("a#b")),
+    ]);
+    let result = ToEnvFile::call_as_filter(&obj, empty_kwargs()).unwrap();
+    assert_eq!(
+        result.as_str().unwrap(),
+        "A=\"hello world\"\n\
+         B=\"say \\\"hi\\\" for \\$5\"\n\
+         C=\"back\\\\slash\"\n\
+         D=\"#not-a-comment\"\n\
+         E=\"it's\"\n\
+         F=a#b\n"
+    );
+}
+
+#[test]
+fn test_to_env_file_error_nested_value() {
+    let obj = make_object(vec![("DB", make_object(vec![("host", Value::from("x"))]))]);
+    let result = ToEnvFile::call_as_filter(&obj, empty_kwargs());
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("to_env_file requires a flat object, but 'DB' is map")
+    );
+}
+
+#[test]
+fn test_to_env_file_error_invalid_name() {
+    let obj = make_object(vec![("db.host", Value::from("x"))]);
+    let result = ToEnvFile::call_as_filter(&obj, empty_kwargs());
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("'db.host' is not a valid variable name")
+    );
+}
+
+#[test]
+fn test_to_env_file_error_not_object() {
+    let result = ToEnvFile::call_as_filter(&Value::from("x"), empty_kwargs());
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("requires an object")
+    );
+}
+
 // ============================================
 // ParseJson tests
 // ============================================
@@ -286,3 +372,79 @@ fn test_parse_toml_error_not_string() {
             .contains("requires a string")
     );
 }
+
+// ============================================
+// Key order of parsed objects
+// ============================================
+
+/// Keys of parsed objects, in iteration order, as a compact JSON string
+fn key_order(value: &Value) -> String {
+    serde_json::to_string(value).unwrap()
+}
+
+#[test]
+fn test_parsed_keys_are_sorted_recursively() {
+    let json = ParseJson::call_as_filter(
+        &Value::from(r#"{"b": 1, "a": {"z": [{"y": 1, "x": 2}], "c": 2}}"#),
+        empty_kwargs(),
+    )
+    .unwrap();
+    let yaml = ParseYaml::call_as_filter(
+        &Value::from("b: 1\na:\n  z:\n    - y: 1\n      x: 2\n  c: 2\n"),
+        empty_kwargs(),
+    )
+    .unwrap();
+    let toml = ParseToml::call_as_filter(
+        &Value::from("b = 1\n[a]\nc = 2\n[[a.z]]\ny = 1\nx = 2\n"),
+        empty_kwargs(),
+    )
+    .unwrap();
+
+    let expected = r#"{"a":{"c":2,"z":[{"x":2,"y":1}]},"b":1}"#;
+    assert_eq!(key_order(&json), expected);
+    assert_eq!(key_order(&yaml), expected);
+    assert_eq!(key_order(&toml), expected);
+}
+
+#[test]
+fn test_parse_round_trip_is_independent_of_input_order() {
+    let first = ParseYaml::call_as_filter(&Value::from("port: 80\nhost: a\n"), empty_kwargs());
+    let second = ParseYaml::call_as_filter(&Value::from("host: a\nport: 80\n"), empty_kwargs());
+    let first = ToYaml::call_as_filter(&first.unwrap(), empty_kwargs()).unwrap();
+    let second = ToYaml::call_as_filter(&second.unwrap(), empty_kwargs()).unwrap();
+    assert_eq!(first.as_str(), second.as_str());
+}
+
+#[test]
+fn test_parse_accepts_sort_keys() {
+    let expected = r#"{"a":{"c":2,"z":1},"b":1}"#;
+    for sort_keys in [true, false] {
+        let kwargs = || Kwargs::from_iter(vec![("sort_keys", Value::from(sort_keys))]);
+        let json =
+            ParseJson::call_as_filter(&Value::from(r#"{"b": 1, "a": {"z": 1, "c": 2}}"#), kwargs())
+                .unwrap();
+        let yaml = ParseYaml::call_as_function(Kwargs::from_iter(vec![
+            ("string", Value::from("b: 1\na:\n  z: 1\n  c: 2\n")),
+            ("sort_keys", Value::from(sort_keys)),
+        ]))
+        .unwrap();
+        let toml = ParseToml::call_as_filter(&Value::from("b = 1\n[a]\nz = 1\nc = 2\n"), kwargs())
+            .unwrap();
+        assert_eq!(key_order(&json), expected);
+        assert_eq!(key_order(&yaml), expected);
+        assert_eq!(key_order(&toml), expected);
+    }
+}
+
+#[test]
+fn test_parse_sort_keys_in_template() {
+    let mut env = minijinja::Environment::new();
+    tmpltool::filter_functions::register_all(&mut env);
+    let result = env
+        .render_str(
+            r#"{{ parse_yaml(string="b: 1\na: 2", sort_keys=true) | to_json }} {{ '{"y": 1, "x": 2}' | parse_json(sort_keys=true) | to_json }}"#,
+            (),
+        )
+        .unwrap();
+    assert_eq!(result, r#"{"a":2,"b":1} {"x":2,"y":1}"#);
+}
@@ -0,0 +1,139 @@
+use minijinja::Environment;
+use std::path::PathBuf;
+use tmpltool::{TemplateContext, functions::register_all};
+
+fn render_template(template: &str) -> String {
+    let mut env = Environment::new();
+    let ctx = TemplateContext::new(PathBuf::from("."), false);
+    register_all(&mut env, ctx);
+
+    let tmpl = env.template_from_str(template).unwrap();
+    tmpl.render(()).unwrap()
+}
+
+// ==================== counter Tests ====================
+
+#[test]
+fn test_counter_default_starts_at_one() {
+    assert_eq!(
+        render_template("{{ counter() }},{{ counter() }},{{ counter() }}"),
+        "1,2,3"
+    );
+}
+
+#[test]
+fn test_counter_continues_across_loops() {
+    let template = "{% for x in [1, 2] %}{{ counter(name=\"item\") }}{% endfor %}|\
+                    {% for x in [1, 2, 3] %}{{ counter(name=\"item\") }}{% endfor %}";
+    assert_eq!(render_template(template), "12|345");
+}
+
+#[test]
+fn test_counter_names_are_independent() {
+    let template = "{{ counter(name=\"a\") }}{{ counter(name=\"b\") }}{{ counter(name=\"a\") }}";
+    assert_eq!(render_template(template), "112");
+}
+
+#[test]
+fn test_counter_start_applies_to_first_call_only() {
+    let template =
+        "{{ counter(name=\"rules\", start=100) }},{{ counter(name=\"rules\", start=5) }}";
+    assert_eq!(render_template(template), "100,101");
+}
+
+#[test]
+fn test_counter_fresh_for_each_render() {
+    assert_eq!(render_template("{{ counter() }}"), "1");
+    assert_eq!(render_template("{{ counter() }}"), "1");
+}
+
+#[test]
+fn test_context_next_counter() {
+    let ctx = TemplateContext::new(PathBuf::from("."), false);
+    let clone = ctx.clone();
+    assert_eq!(ctx.next_counter("x", 0), 0);
+    assert_eq!(clone.next_counter("x", 0), 1);
+}
+
+// ==================== set_var / get_var Tests ====================
+
+#[test]
+fn test_set_var_returns_empty_and_get_var_reads_it() {
+    let template = r#"[{{ set_var(key="title", value="Report") }}]{{ get_var(key="title") }}"#;
+    assert_eq!(render_template(template), "[]Report");
+}
+
+#[test]
+fn test_get_var_default_when_unset() {
+    assert_eq!(
+        render_template(r#"{{ get_var(key="missing", default="n/a") }}"#),
+        "n/a"
+    );
+}
+
+#[test]
+fn test_get_var_unset_without_default_is_undefined() {
+    assert_eq!(
+        render_template(r#"{{ get_var(key="missing") is defined }}"#),
+        "false"
+    );
+}
+
+#[test]
+fn test_set_var_escapes_loop_scope() {
+    let template = r#"{% for m in ["os", "sys", "os"] %}{{ set_var(key="imports", value=get_var(key="imports", default=[]) + [m]) }}{% endfor %}{{ get_var(key="imports") | unique | join(",") }}"#;
+    assert_eq!(render_template(template), "os,sys");
+}
+
+#[test]
+fn test_set_var_preserves_types() {
+    let template =
+        r#"{{ set_var(key="cfg", value={"port": 8080}) }}{{ get_var(key="cfg").port + 1 }}"#;
+    assert_eq!(render_template(template), "8081");
+}
+
+#[test]
+fn test_set_var_shared_with_includes() {
+    let mut env = Environment::new();
+    register_all(&mut env, TemplateContext::new(PathBuf::from("."), false));
+    env.add_template(
+        "partial",
+        r#"{{ set_var(key="from_partial", value="yes") }}"#,
+    )
+    .unwrap();
+    env.add_template(
+        "main",
+        r#"{% include "partial" %}{{ get_var(key="from_partial") }}"#,
+    )
+    .unwrap();
+
+    let result = env.get_template("main").unwrap().render(()).unwrap();
+    assert_eq!(result, "yes");
+}
+
+// ==================== once Tests ====================
+
+#[test]
+fn test_once_true_only_first_time() {
+    let template = r#"{{ once(key="a") }},{{ once(key="a") }},{{ once(key="b") }}"#;
+    assert_eq!(render_template(template), "true,false,true");
+}
+
+#[test]
+fn test_once_deduplicates_included_header() {
+    let mut env = Environment::new();
+    register_all(&mut env, TemplateContext::new(PathBuf::from("."), false));
+    env.add_template(
+        "partial",
+        r#"{% if once(key="header") %}HEADER;{% endif %}body;"#,
+    )
+    .unwrap();
+    env.add_template(
+        "main",
+        r#"{% include "partial" %}{% include "partial" %}{% include "partial" %}"#,
+    )
+    .unwrap();
+
+    let result = env.get_template("main").unwrap().render(()).unwrap();
+    assert_eq!(result, "HEADER;body;body;body;");
+}
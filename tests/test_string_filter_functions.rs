@@ -6,8 +6,9 @@ use minijinja::Value;
 use minijinja::value::Kwargs;
 use tmpltool::filter_functions::FilterFunction;
 use tmpltool::filter_functions::string::{
-    Dedent, EscapeQuotes, Indent, PadLeft, PadRight, Quote, Repeat, Reverse, Slugify, ToCamelCase,
-    ToKebabCase, ToPascalCase, ToSnakeCase,
+    Center, CodeBlock, Comment, Dedent, EscapeQuotes, Indent, LStrip, MaskEmail, Pad, PadLeft,
+    PadRight, Partition, Quote, RPartition, RStrip, Repeat, Reverse, Slugify, StringWidth, Strip,
+    ToAscii, ToCamelCase, ToKebabCase, ToPascalCase, ToSnakeCase,
 };
 
 /// Helper to create empty kwargs
@@ -39,6 +40,29 @@ fn test_slugify_with_underscores() {
     assert_eq!(result.as_str().unwrap(), "test-case-name");
 }
 
+#[test]
+fn test_slugify_drops_non_ascii_by_default() {
+    let result = Slugify::call_as_filter(&Value::from("Café Olé"), empty_kwargs()).unwrap();
+    assert_eq!(result.as_str().unwrap(), "caf-ol");
+}
+
+#[test]
+fn test_slugify_transliterate_function_syntax() {
+    let kwargs = Kwargs::from_iter(vec![
+        ("string", Value::from("Café Olé Ærøskøbing")),
+        ("transliterate", Value::from(true)),
+    ]);
+    let result = Slugify::call_as_function(kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "cafe-ole-aeroskobing");
+}
+
+#[test]
+fn test_slugify_transliterate_filter_syntax() {
+    let kwargs = Kwargs::from_iter(vec![("transliterate", Value::from(true))]);
+    let result = Slugify::call_as_filter(&Value::from("Zürich Straße"), kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "zurich-strasse");
+}
+
 #[test]
 fn test_slugify_filter_error() {
     let result = Slugify::call_as_filter(&Value::from(123), empty_kwargs());
@@ -427,3 +451,443 @@ fn test_reverse_unicode() {
     let result = Reverse::call_as_function(kwargs).unwrap();
     assert_eq!(result.as_str().unwrap(), "olleh");
 }
+
+// ============================================
+// StringWidth and width-aware padding tests
+// ============================================
+
+#[test]
+fn test_string_width_ascii() {
+    let kwargs = Kwargs::from_iter(vec![("string", Value::from("hello"))]);
+    let result = StringWidth::call_as_function(kwargs).unwrap();
+    assert_eq!(result.as_i64(), Some(5));
+}
+
+#[test]
+fn test_string_width_cjk_filter_syntax() {
+    let result = StringWidth::call_as_filter(&Value::from("日本語"), empty_kwargs()).unwrap();
+    assert_eq!(result.as_i64(), Some(6));
+}
+
+#[test]
+fn test_string_width_combining_mark() {
+    let kwargs = Kwargs::from_iter(vec![("string", Value::from("e\u{301}"))]);
+    let result = StringWidth::call_as_function(kwargs).unwrap();
+    assert_eq!(result.as_i64(), Some(1));
+}
+
+#[test]
+fn test_pad_right_counts_chars_by_default() {
+    let kwargs = Kwargs::from_iter(vec![("length", Value::from(6))]);
+    let result = PadRight::call_as_filter(&Value::from("日本"), kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "日本    ");
+}
+
+#[test]
+fn test_pad_right_width_aware() {
+    let kwargs = Kwargs::from_iter(vec![
+        ("length", Value::from(6)),
+        ("width_aware", Value::from(true)),
+    ]);
+    let result = PadRight::call_as_filter(&Value::from("日本"), kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "日本  ");
+}
+
+#[test]
+fn test_pad_left_width_aware() {
+    let kwargs = Kwargs::from_iter(vec![
+        ("string", Value::from("日本")),
+        ("length", Value::from(5)),
+        ("char", Value::from(".")),
+        ("width_aware", Value::from(true)),
+    ]);
+    let result = PadLeft::call_as_function(kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), ".日本");
+}
+
+#[test]
+fn test_center_width_aware() {
+    let kwargs = Kwargs::from_iter(vec![
+        ("width", Value::from(8)),
+        ("char", Value::from("-")),
+        ("width_aware", Value::from(true)),
+    ]);
+    let result = Center::call_as_filter(&Value::from("日本"), kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "--日本--");
+}
+
+// ============================================
+// Pad tests
+// ============================================
+
+#[test]
+fn test_pad_defaults_to_left() {
+    let kwargs = Kwargs::from_iter(vec![
+        ("string", Value::from("5")),
+        ("length", Value::from(3)),
+        ("char", Value::from("0")),
+    ]);
+    let result = Pad::call_as_function(kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "005");
+}
+
+#[test]
+fn test_pad_side_right_filter_syntax() {
+    let kwargs = Kwargs::from_iter(vec![
+        ("length", Value::from(5)),
+        ("side", Value::from("right")),
+    ]);
+    let result = Pad::call_as_filter(&Value::from("hi"), kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "hi   ");
+}
+
+#[test]
+fn test_pad_both_extra_goes_right() {
+    let kwargs = Kwargs::from_iter(vec![
+        ("length", Value::from(5)),
+        ("char", Value::from("*")),
+        ("side", Value::from("both")),
+    ]);
+    let result = Pad::call_as_filter(&Value::from("ab"), kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "*ab**");
+}
+
+#[test]
+fn test_pad_no_padding_when_long_enough() {
+    let kwargs = Kwargs::from_iter(vec![
+        ("length", Value::from(2)),
+        ("side", Value::from("both")),
+    ]);
+    let result = Pad::call_as_filter(&Value::from("hello"), kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "hello");
+}
+
+#[test]
+fn test_pad_width_aware() {
+    let kwargs = Kwargs::from_iter(vec![
+        ("length", Value::from(6)),
+        ("side", Value::from("right")),
+        ("width_aware", Value::from(true)),
+    ]);
+    let result = Pad::call_as_filter(&Value::from("日本"), kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "日本  ");
+}
+
+#[test]
+fn test_pad_invalid_side() {
+    let kwargs = Kwargs::from_iter(vec![
+        ("length", Value::from(5)),
+        ("side", Value::from("middle")),
+    ]);
+    let result = Pad::call_as_filter(&Value::from("x"), kwargs);
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("invalid side 'middle'")
+    );
+}
+
+// ============================================
+// Comment tests
+// ============================================
+
+#[test]
+fn test_comment_line_styles() {
+    let kwargs = Kwargs::from_iter(vec![
+        ("string", Value::from("Generated file\n\nDo not edit")),
+        ("style", Value::from("//")),
+    ]);
+    let result = Comment::call_as_function(kwargs).unwrap();
+    assert_eq!(
+        result.as_str().unwrap(),
+        "// Generated file\n//\n// Do not edit"
+    );
+
+    let result = Comment::call_as_filter(&Value::from("hello"), empty_kwargs()).unwrap();
+    assert_eq!(result.as_str().unwrap(), "# hello");
+}
+
+#[test]
+fn test_comment_block_styles() {
+    let kwargs = Kwargs::from_iter(vec![("style", Value::from("/* */"))]);
+    let result = Comment::call_as_filter(&Value::from("line1\nline2"), kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "/*\n * line1\n * line2\n */");
+
+    let kwargs = Kwargs::from_iter(vec![("style", Value::from("<!-- -->"))]);
+    let result = Comment::call_as_filter(&Value::from("line1"), kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "<!--\n  line1\n-->");
+}
+
+#[test]
+fn test_comment_block_styles_escape_closers() {
+    let kwargs = Kwargs::from_iter(vec![("style", Value::from("/* */"))]);
+    let result = Comment::call_as_filter(&Value::from("glob: src/**/*.rs */"), kwargs).unwrap();
+    assert_eq!(
+        result.as_str().unwrap(),
+        "/*\n * glob: src/** /*.rs * /\n */"
+    );
+
+    let kwargs = Kwargs::from_iter(vec![("style", Value::from("<!-- -->"))]);
+    let result = Comment::call_as_filter(&Value::from("a --> b"), kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "<!--\n  a -- > b\n-->");
+
+    // Line styles have no closer
+    let kwargs = Kwargs::from_iter(vec![("style", Value::from("//"))]);
+    let result = Comment::call_as_filter(&Value::from("a */ --> b"), kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "// a */ --> b");
+}
+
+#[test]
+fn test_comment_width_wraps_including_prefix() {
+    let kwargs = Kwargs::from_iter(vec![
+        ("style", Value::from("#")),
+        ("width", Value::from(12)),
+    ]);
+    let result =
+        Comment::call_as_filter(&Value::from("The quick brown fox jumps"), kwargs).unwrap();
+    assert_eq!(
+        result.as_str().unwrap(),
+        "# The quick\n# brown fox\n# jumps"
+    );
+    for line in result.as_str().unwrap().lines() {
+        assert!(line.chars().count() <= 12);
+    }
+}
+
+#[test]
+fn test_comment_invalid_style_and_width() {
+    let kwargs = Kwargs::from_iter(vec![("style", Value::from(";;"))]);
+    let err = Comment::call_as_filter(&Value::from("x"), kwargs).unwrap_err();
+    assert!(err.to_string().contains("unknown style ';;'"));
+
+    let kwargs = Kwargs::from_iter(vec![
+        ("style", Value::from("/* */")),
+        ("width", Value::from(3)),
+    ]);
+    let err = Comment::call_as_filter(&Value::from("x"), kwargs).unwrap_err();
+    assert!(err.to_string().contains("width must be greater than 3"));
+}
+
+// ============================================
+// CodeBlock tests
+// ============================================
+
+#[test]
+fn test_code_block_with_lang() {
+    let kwargs = Kwargs::from_iter(vec![
+        ("string", Value::from("port: 8080\n")),
+        ("lang", Value::from("yaml")),
+    ]);
+    let result = CodeBlock::call_as_function(kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "```yaml\nport: 8080\n```");
+
+    let result = CodeBlock::call_as_filter(&Value::from("x = 1"), empty_kwargs()).unwrap();
+    assert_eq!(result.as_str().unwrap(), "```\nx = 1\n```");
+}
+
+#[test]
+fn test_code_block_lengthens_fence_for_backticks() {
+    let kwargs = Kwargs::from_iter(vec![("lang", Value::from("md"))]);
+    let content = "Use `code` and\n```sh\nls\n```";
+    let result = CodeBlock::call_as_filter(&Value::from(content), kwargs).unwrap();
+    assert_eq!(
+        result.as_str().unwrap(),
+        format!("````md\n{}\n````", content)
+    );
+
+    let result = CodeBlock::call_as_filter(&Value::from("a `````b"), empty_kwargs()).unwrap();
+    assert_eq!(result.as_str().unwrap(), "``````\na `````b\n``````");
+}
+
+#[test]
+fn test_code_block_invalid_lang() {
+    let kwargs = Kwargs::from_iter(vec![("lang", Value::from("ya`ml"))]);
+    let err = CodeBlock::call_as_filter(&Value::from("x"), kwargs).unwrap_err();
+    assert!(err.to_string().contains("must not contain backticks"));
+}
+
+// ============================================
+// Strip / LStrip / RStrip tests
+// ============================================
+
+#[test]
+fn test_strip_defaults_to_whitespace() {
+    let result = Strip::call_as_filter(&Value::from(" \t hi there \n"), empty_kwargs()).unwrap();
+    assert_eq!(result.as_str().unwrap(), "hi there");
+}
+
+#[test]
+fn test_strip_function_syntax_with_chars() {
+    let kwargs = Kwargs::from_iter(vec![
+        ("string", Value::from("'\"quoted\"'")),
+        ("chars", Value::from("'\"")),
+    ]);
+    let result = Strip::call_as_function(kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "quoted");
+}
+
+#[test]
+fn test_strip_keeps_inner_characters() {
+    let kwargs = Kwargs::from_iter(vec![("chars", Value::from("-"))]);
+    let result = Strip::call_as_filter(&Value::from("--a-b--"), kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "a-b");
+}
+
+#[test]
+fn test_strip_empty_chars_removes_nothing() {
+    let kwargs = Kwargs::from_iter(vec![("chars", Value::from(""))]);
+    let result = Strip::call_as_filter(&Value::from(" a "), kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), " a ");
+}
+
+#[test]
+fn test_lstrip_only_start() {
+    let kwargs = Kwargs::from_iter(vec![("chars", Value::from("/"))]);
+    let result = LStrip::call_as_filter(&Value::from("//api/v1/"), kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "api/v1/");
+
+    let kwargs = Kwargs::from_iter(vec![("string", Value::from("  x  "))]);
+    let result = LStrip::call_as_function(kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "x  ");
+}
+
+#[test]
+fn test_rstrip_only_end() {
+    let kwargs = Kwargs::from_iter(vec![
+        ("string", Value::from("https://example.com//")),
+        ("chars", Value::from("/")),
+    ]);
+    let result = RStrip::call_as_function(kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "https://example.com");
+
+    let result = RStrip::call_as_filter(&Value::from("  x  "), empty_kwargs()).unwrap();
+    assert_eq!(result.as_str().unwrap(), "  x");
+}
+
+#[test]
+fn test_strip_requires_string() {
+    let err = Strip::call_as_filter(&Value::from(5), empty_kwargs()).unwrap_err();
+    assert!(err.to_string().contains("strip requires a string value"));
+}
+
+// ============================================
+// Partition / RPartition tests
+// ============================================
+
+/// Helper to turn a partition result into owned strings
+fn parts(value: Value) -> Vec<String> {
+    value
+        .try_iter()
+        .unwrap()
+        .map(|part| part.as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn test_partition_splits_on_first_separator() {
+    let kwargs = Kwargs::from_iter(vec![
+        ("string", Value::from("key=value=with=equals")),
+        ("sep", Value::from("=")),
+    ]);
+    let result = Partition::call_as_function(kwargs).unwrap();
+    assert_eq!(parts(result), ["key", "=", "value=with=equals"]);
+}
+
+#[test]
+fn test_rpartition_splits_on_last_separator() {
+    let kwargs = Kwargs::from_iter(vec![("sep", Value::from("."))]);
+    let result = RPartition::call_as_filter(&Value::from("archive.tar.gz"), kwargs).unwrap();
+    assert_eq!(parts(result), ["archive.tar", ".", "gz"]);
+}
+
+#[test]
+fn test_partition_multi_char_separator() {
+    let kwargs = Kwargs::from_iter(vec![("sep", Value::from(": "))]);
+    let result = Partition::call_as_filter(&Value::from("a: b: c"), kwargs).unwrap();
+    assert_eq!(parts(result), ["a", ": ", "b: c"]);
+}
+
+#[test]
+fn test_partition_separator_not_found() {
+    let kwargs = Kwargs::from_iter(vec![("sep", Value::from("="))]);
+    let result = Partition::call_as_filter(&Value::from("abc"), kwargs).unwrap();
+    assert_eq!(parts(result), ["abc", "", ""]);
+
+    let kwargs = Kwargs::from_iter(vec![("sep", Value::from("="))]);
+    let result = RPartition::call_as_filter(&Value::from("abc"), kwargs).unwrap();
+    assert_eq!(parts(result), ["", "", "abc"]);
+}
+
+#[test]
+fn test_partition_empty_separator_is_error() {
+    let kwargs = Kwargs::from_iter(vec![("sep", Value::from(""))]);
+    let err = RPartition::call_as_filter(&Value::from("abc"), kwargs).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("rpartition: separator must not be empty")
+    );
+}
+
+// ============================================
+// ToAscii tests
+// ============================================
+
+#[test]
+fn test_to_ascii_keeps_case_and_separators() {
+    let kwargs = Kwargs::from_iter(vec![("string", Value::from("Crème Brûlée, Zürich-Straße"))]);
+    let result = ToAscii::call_as_function(kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "Creme Brulee, Zurich-Strasse");
+}
+
+#[test]
+fn test_to_ascii_other_scripts() {
+    let result = ToAscii::call_as_filter(&Value::from("Ελλάδα Москва"), empty_kwargs()).unwrap();
+    assert_eq!(result.as_str().unwrap(), "Ellada Moskva");
+}
+
+#[test]
+fn test_to_ascii_unknown_replacement() {
+    let input = Value::from("a\u{e000}b");
+    let result = ToAscii::call_as_filter(&input, empty_kwargs()).unwrap();
+    assert_eq!(result.as_str().unwrap(), "a?b");
+
+    let kwargs = Kwargs::from_iter(vec![("unknown", Value::from(""))]);
+    let result = ToAscii::call_as_filter(&input, kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "ab");
+}
+
+#[test]
+fn test_to_ascii_leaves_ascii_unchanged() {
+    let result = ToAscii::call_as_filter(&Value::from("Hello_World-1.0"), empty_kwargs()).unwrap();
+    assert_eq!(result.as_str().unwrap(), "Hello_World-1.0");
+}
+
+// ============================================
+// MaskEmail tests
+// ============================================
+
+#[test]
+fn test_mask_email_keeps_first_char_and_domain() {
+    let kwargs = Kwargs::from_iter(vec![("string", Value::from("alice.smith@example.com"))]);
+    let result = MaskEmail::call_as_function(kwargs).unwrap();
+    assert_eq!(result.as_str().unwrap(), "a***@example.com");
+}
+
+#[test]
+fn test_mask_email_hides_length_of_short_local_part() {
+    let result = MaskEmail::call_as_filter(&Value::from("b@mail.example.org"), empty_kwargs());
+    assert_eq!(result.unwrap().as_str().unwrap(), "b***@mail.example.org");
+}
+
+#[test]
+fn test_mask_email_rejects_non_email() {
+    for input in ["not-an-email", "@example.com", "a@b"] {
+        let err = MaskEmail::call_as_filter(&Value::from(input), empty_kwargs()).unwrap_err();
+        assert!(
+            err.to_string().contains("is not a valid email address"),
+            "{}",
+            err
+        );
+    }
+}
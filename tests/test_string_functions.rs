@@ -45,6 +45,92 @@ fn test_regex_replace_capture_groups() {
     assert_eq!(result, "world hello");
 }
 
+// ==================== replace Tests ====================
+
+#[test]
+fn test_replace_builtin_positional_form_still_works() {
+    assert_eq!(
+        render_template(r#"{{ "a-b-c" | replace("-", "_") }}"#),
+        "a_b_c"
+    );
+    assert_eq!(
+        render_template(r#"{{ "a-b-c" | replace("-", "_", 1) }}"#),
+        "a_b-c"
+    );
+    assert_eq!(render_template(r#"{{ 1231 | replace("1", "x") }}"#), "x23x");
+}
+
+#[test]
+fn test_replace_count_limits_replacements() {
+    let result = render_template(r#"{{ replace(string="a-b-c", from="-", to="_", count=1) }}"#);
+    assert_eq!(result, "a_b-c");
+    let result = render_template(r#"{{ "aaa" | replace(from="a", to="b", count=0) }}"#);
+    assert_eq!(result, "aaa");
+    let result = render_template(r#"{{ "aaa" | replace(from="a", to="b", count=-1) }}"#);
+    assert_eq!(result, "bbb");
+}
+
+#[test]
+fn test_replace_regex_mode() {
+    let result =
+        render_template(r#"{{ "v1.22.3" | replace(from="[0-9]+", to="N", regex=true, count=2) }}"#);
+    assert_eq!(result, "vN.N.3");
+    let result = render_template(
+        r#"{{ replace(string="john smith", from="(\\w+) (\\w+)", to="$2 $1", regex=true) }}"#,
+    );
+    assert_eq!(result, "smith john");
+    // Without regex=true the pattern is literal
+    let result = render_template(r#"{{ "a.b" | replace(from=".", to="-") }}"#);
+    assert_eq!(result, "a-b");
+}
+
+#[test]
+fn test_replace_invalid_regex_is_error() {
+    let mut env = Environment::new();
+    register_all(&mut env, TemplateContext::new(PathBuf::from("."), false));
+    let err = env
+        .render_str(r#"{{ "x" | replace(from="(", to="", regex=true) }}"#, ())
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("replace: invalid regex pattern '('")
+    );
+}
+
+// ==================== wrap_in / surround Tests ====================
+
+#[test]
+fn test_wrap_in() {
+    let result = render_template(r#"{{ wrap_in(string="item", before="[", after="]") }}"#);
+    assert_eq!(result, "[item]");
+    let result = render_template(r#"{{ "bold" | wrap_in(before="<b>", after="</b>") }}"#);
+    assert_eq!(result, "<b>bold</b>");
+    // Either side may be omitted
+    let result = render_template(r#"{{ "path" | wrap_in(before="/") }}"#);
+    assert_eq!(result, "/path");
+    // Non-string values use their string form
+    let result = render_template(r#"{{ 8080 | wrap_in(before=":") }}"#);
+    assert_eq!(result, ":8080");
+}
+
+#[test]
+fn test_surround() {
+    let result = render_template(r#"{{ surround(string="id", with="`") }}"#);
+    assert_eq!(result, "`id`");
+    let result = render_template(
+        r#"{% for t in ["a", "b"] %}{{ t | surround(with="'") }}{% if not loop.last %}, {% endif %}{% endfor %}"#,
+    );
+    assert_eq!(result, "'a', 'b'");
+}
+
+#[test]
+fn test_surround_requires_with() {
+    let mut env = Environment::new();
+    register_all(&mut env, TemplateContext::new(PathBuf::from("."), false));
+    let err = env.render_str(r#"{{ "x" | surround }}"#, ()).unwrap_err();
+    assert!(err.to_string().contains("with"));
+}
+
 // ==================== regex_match Tests ====================
 
 #[test]
@@ -116,6 +202,24 @@ fn test_substring_out_of_bounds() {
     assert_eq!(result, "");
 }
 
+#[test]
+fn test_substring_splits_emoji_modifier_by_default() {
+    let result = render_template(r#"{{ substring(string="👍🏽x", start=0, length=1) }}"#);
+    assert_eq!(result, "👍");
+}
+
+#[test]
+fn test_substring_graphemes() {
+    let result = render_template(r#"{{ "👍🏽👍🏽x" | substring(start=1, length=1, graphemes=true) }}"#);
+    assert_eq!(result, "👍🏽");
+}
+
+#[test]
+fn test_substring_graphemes_negative_start() {
+    let result = render_template(r#"{{ substring(string="a👨‍👩‍👧b", start=-2, graphemes=true) }}"#);
+    assert_eq!(result, "👨‍👩‍👧b");
+}
+
 // ==================== contains Tests ====================
 
 #[test]
@@ -204,6 +308,20 @@ fn test_truncate_exact_length() {
     assert_eq!(result, "Hello");
 }
 
+#[test]
+fn test_truncate_graphemes_keeps_combining_marks() {
+    let result = render_template(
+        "{{ truncate(string=\"e\u{301}e\u{301}e\u{301}e\u{301}\", length=3, suffix=\"…\", graphemes=true) }}",
+    );
+    assert_eq!(result, "e\u{301}e\u{301}…");
+}
+
+#[test]
+fn test_truncate_graphemes_not_needed() {
+    let result = render_template(r#"{{ "👍🏽👍🏽" | truncate(length=2, graphemes=true) }}"#);
+    assert_eq!(result, "👍🏽👍🏽");
+}
+
 // ==================== word_count Tests ====================
 
 #[test]
@@ -508,6 +626,265 @@ fn test_pluralize_custom_singular() {
     assert_eq!(result, "person");
 }
 
+// ==================== format Tests ====================
+
+#[test]
+fn test_format_sequential() {
+    let result = render_template(r#"{{ format(template="{}-{}-{}", args=["app", "prod", 1]) }}"#);
+    assert_eq!(result, "app-prod-1");
+}
+
+#[test]
+fn test_format_indexed() {
+    let result = render_template(r#"{{ format(template="{1}/{0}/{1}", args=["a", "b"]) }}"#);
+    assert_eq!(result, "b/a/b");
+}
+
+#[test]
+fn test_format_escaped_braces() {
+    let result = render_template(r#"{{ format(template="{{{}}}", args=["x"]) }}"#);
+    assert_eq!(result, "{x}");
+}
+
+#[test]
+fn test_format_no_placeholders() {
+    let result = render_template(r#"{{ format(template="plain") }}"#);
+    assert_eq!(result, "plain");
+}
+
+#[test]
+fn test_format_out_of_range() {
+    let mut env = Environment::new();
+    let ctx = TemplateContext::new(PathBuf::from("."), false);
+    register_all(&mut env, ctx);
+
+    let tmpl = env
+        .template_from_str(r#"{{ format(template="{} {}", args=["a"]) }}"#)
+        .unwrap();
+    let result = tmpl.render(());
+    assert!(result.unwrap_err().to_string().contains("out of range"));
+}
+
+#[test]
+fn test_format_unclosed_brace() {
+    let mut env = Environment::new();
+    let ctx = TemplateContext::new(PathBuf::from("."), false);
+    register_all(&mut env, ctx);
+
+    let tmpl = env
+        .template_from_str(r#"{{ format(template="{0", args=["a"]) }}"#)
+        .unwrap();
+    let result = tmpl.render(());
+    assert!(result.unwrap_err().to_string().contains("unclosed '{'"));
+}
+
+#[test]
+fn test_format_invalid_placeholder() {
+    let mut env = Environment::new();
+    let ctx = TemplateContext::new(PathBuf::from("."), false);
+    register_all(&mut env, ctx);
+
+    let tmpl = env
+        .template_from_str(r#"{{ format(template="{name}", args=["a"]) }}"#)
+        .unwrap();
+    let result = tmpl.render(());
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("invalid placeholder")
+    );
+}
+
+// ==================== printf Tests ====================
+
+#[test]
+fn test_printf_float_zero_padded() {
+    let result = render_template(r#"{{ printf(format="%07.2f", value=3.14159) }}"#);
+    assert_eq!(result, "0003.14");
+}
+
+#[test]
+fn test_printf_float_default_precision() {
+    let result = render_template(r#"{{ printf(format="%f", value=1.5) }}"#);
+    assert_eq!(result, "1.500000");
+}
+
+#[test]
+fn test_printf_integer_width_and_sign() {
+    let result = render_template(
+        r#"[{{ printf(format="%5d", value=42) }}][{{ printf(format="%-5d", value=42) }}][{{ printf(format="%+d", value=7) }}][{{ printf(format="%05d", value=-42) }}]"#,
+    );
+    assert_eq!(result, "[   42][42   ][+7][-0042]");
+}
+
+#[test]
+fn test_printf_hex_octal_binary() {
+    let result = render_template(
+        r#"{{ printf(format="%x", value=255) }} {{ printf(format="0x%08X", value=48879) }} {{ printf(format="%#o", value=8) }} {{ printf(format="%b", value=5) }}"#,
+    );
+    assert_eq!(result, "ff 0x0000BEEF 010 101");
+}
+
+#[test]
+fn test_printf_alternate_octal() {
+    let result = render_template(
+        r#"{{ printf(format="%#o", value=8) }} {{ printf(format="%#o", value=0) }} {{ printf(format="%#5o", value=8) }}"#,
+    );
+    assert_eq!(result, "010 0   010");
+}
+
+#[test]
+fn test_printf_rejects_oversized_width_and_precision() {
+    let mut env = Environment::new();
+    let ctx = TemplateContext::new(PathBuf::from("."), false);
+    register_all(&mut env, ctx);
+
+    for (template, message) in [
+        (
+            r#"{{ printf(format="%99999999999999999999d", value=1) }}"#,
+            "width must be at most 1024",
+        ),
+        (
+            r#"{{ printf(format="%.99999999999999999999f", value=1.0) }}"#,
+            "precision must be at most 1024",
+        ),
+        (
+            r#"{{ printf(format="%2000s", value="x") }}"#,
+            "width must be at most 1024",
+        ),
+    ] {
+        let error = env.render_str(template, ()).unwrap_err();
+        assert_eq!(error.kind(), minijinja::ErrorKind::InvalidOperation);
+        assert!(error.to_string().contains(message), "{}", error);
+    }
+}
+
+#[test]
+fn test_printf_scientific() {
+    let result = render_template(
+        r#"{{ printf(format="%e", value=12345.678) }} {{ printf(format="%.2E", value=0.000123) }}"#,
+    );
+    assert_eq!(result, "1.234568e+04 1.23E-04");
+}
+
+#[test]
+fn test_printf_string_and_literals() {
+    let result = render_template(r#"{{ printf(format="[%-6s] 100%%", value="ab") }}"#);
+    assert_eq!(result, "[ab    ] 100%");
+}
+
+#[test]
+fn test_printf_unsupported_directive() {
+    let mut env = Environment::new();
+    let ctx = TemplateContext::new(PathBuf::from("."), false);
+    register_all(&mut env, ctx);
+
+    let tmpl = env
+        .template_from_str(r#"{{ printf(format="%q", value=1) }}"#)
+        .unwrap();
+    let result = tmpl.render(());
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("unsupported directive '%q'")
+    );
+}
+
+#[test]
+fn test_printf_requires_single_directive() {
+    let mut env = Environment::new();
+    let ctx = TemplateContext::new(PathBuf::from("."), false);
+    register_all(&mut env, ctx);
+
+    let tmpl = env
+        .template_from_str(r#"{{ printf(format="%d %d", value=1) }}"#)
+        .unwrap();
+    let result = tmpl.render(());
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("exactly one directive")
+    );
+}
+
+#[test]
+fn test_printf_non_numeric_value() {
+    let mut env = Environment::new();
+    let ctx = TemplateContext::new(PathBuf::from("."), false);
+    register_all(&mut env, ctx);
+
+    let tmpl = env
+        .template_from_str(r#"{{ printf(format="%d", value="abc") }}"#)
+        .unwrap();
+    let result = tmpl.render(());
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("requires a number")
+    );
+}
+
+// ==================== generated_header Tests ====================
+
+#[test]
+fn test_generated_header_default_style() {
+    let result =
+        render_template(r#"{{ generated_header(source="app.yaml.tmpltool", format="%Y") }}"#);
+    assert!(result.starts_with("# DO NOT EDIT \u{2014} generated from app.yaml.tmpltool at "));
+    let year = result.rsplit(' ').next().unwrap();
+    assert_eq!(year.len(), 4);
+    assert!(year.chars().all(|c| c.is_ascii_digit()));
+}
+
+#[test]
+fn test_generated_header_block_style() {
+    let result =
+        render_template(r#"{{ generated_header(source="schema.sql.tmpl", style="/* */") }}"#);
+    let lines: Vec<&str> = result.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0], "/*");
+    assert!(lines[1].starts_with(" * DO NOT EDIT \u{2014} generated from schema.sql.tmpl at "));
+    assert!(lines[1].ends_with(" UTC"));
+    assert_eq!(lines[2], " */");
+}
+
+#[test]
+fn test_generated_header_escapes_block_closers() {
+    let result = render_template(
+        r#"{{ generated_header(source="src/**/*.sql", style="/* */", format="%Y") }}"#,
+    );
+    let lines: Vec<&str> = result.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[1].contains("generated from src/** /*.sql at "));
+
+    let result = render_template(
+        r#"{{ generated_header(source="a-->b.html", style="<!-- -->", format="%Y") }}"#,
+    );
+    let lines: Vec<&str> = result.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[1].contains("generated from a-- >b.html at "));
+    assert_eq!(lines[2], "-->");
+}
+
+#[test]
+fn test_generated_header_invalid_style() {
+    let mut env = Environment::new();
+    let ctx = TemplateContext::new(PathBuf::from("."), false);
+    register_all(&mut env, ctx);
+
+    let result = env.render_str(r#"{{ generated_header(source="x", style="--") }}"#, ());
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("unknown style '--'")
+    );
+}
+
 // ==================== Direct Unit Tests ====================
 // These tests call the functions directly to ensure coverage of all code paths
 
@@ -519,9 +896,10 @@ mod unit_tests {
     // filter_functions/string.rs with dual function+filter syntax support.
     use tmpltool::functions::Function;
     use tmpltool::functions::string::{
-        Contains, CountOccurrences, IndexOf, Pluralize, RegexFindAll, RegexMatch, SentenceCase,
+        CountOccurrences, IndexOf, Pluralize, RegexFindAll, RegexMatch, SentenceCase,
         ToConstantCase,
     };
+    use tmpltool::is_functions::logic::Contains;
 
     // Note: regex_replace_fn tests removed - function now in filter_functions/string.rs
 
@@ -574,7 +952,7 @@ mod unit_tests {
             ("string", Value::from("hello world")),
             ("substring", Value::from("world")),
         ]);
-        let result = Contains::call(kwargs).unwrap();
+        let result = Contains::call_as_function(kwargs).unwrap();
         assert!(result.is_true());
     }
 
@@ -0,0 +1,91 @@
+use tmpltool::cli::CommentSyntax;
+use tmpltool::strip_comments::strip_comments;
+
+#[test]
+fn test_strip_hash_comment_lines() {
+    let input = "# generated by tmpltool\nname: app\n  # indented note\nport: 8080\n";
+    assert_eq!(
+        strip_comments(input, CommentSyntax::Hash),
+        "name: app\nport: 8080\n"
+    );
+}
+
+#[test]
+fn test_strip_keeps_trailing_comments() {
+    let input = "host: db # primary\n";
+    assert_eq!(strip_comments(input, CommentSyntax::Hash), input);
+}
+
+#[test]
+fn test_strip_keeps_shebang() {
+    let input = "#!/bin/sh\n# setup\necho hi\n";
+    assert_eq!(
+        strip_comments(input, CommentSyntax::Hash),
+        "#!/bin/sh\necho hi\n"
+    );
+}
+
+#[test]
+fn test_strip_keeps_yaml_block_scalar_content() {
+    let input = "script: |\n  # this is part of the script\n  echo hi\n# note\nnext: 1\n";
+    assert_eq!(
+        strip_comments(input, CommentSyntax::Hash),
+        "script: |\n  # this is part of the script\n  echo hi\nnext: 1\n"
+    );
+}
+
+#[test]
+fn test_strip_keeps_yaml_list_block_scalar_content() {
+    let input = "args:\n  - >-\n    # kept\n    text\n  # removed\n  - b\n";
+    assert_eq!(
+        strip_comments(input, CommentSyntax::Hash),
+        "args:\n  - >-\n    # kept\n    text\n  - b\n"
+    );
+}
+
+#[test]
+fn test_strip_keeps_toml_multiline_string_content() {
+    let input = "# header\ntext = \"\"\"\n# inside\n\"\"\"\n# after\nkey = 1\n";
+    assert_eq!(
+        strip_comments(input, CommentSyntax::Hash),
+        "text = \"\"\"\n# inside\n\"\"\"\nkey = 1\n"
+    );
+}
+
+#[test]
+fn test_strip_slash_comments() {
+    let input = "{\n  // comment\n  \"url\": \"http://example.com\"\n}\n";
+    assert_eq!(
+        strip_comments(input, CommentSyntax::Slash),
+        "{\n  \"url\": \"http://example.com\"\n}\n"
+    );
+}
+
+#[test]
+fn test_strip_slash_keeps_template_literal_content() {
+    let input = "const s = `\n// kept\n`;\n// removed\n";
+    assert_eq!(
+        strip_comments(input, CommentSyntax::Slash),
+        "const s = `\n// kept\n`;\n"
+    );
+}
+
+#[test]
+fn test_strip_semicolon_and_dash_comments() {
+    assert_eq!(
+        strip_comments("; note\n[section]\nkey=1\n", CommentSyntax::Semicolon),
+        "[section]\nkey=1\n"
+    );
+    assert_eq!(
+        strip_comments("-- migration 1\nSELECT 1;\n", CommentSyntax::Dash),
+        "SELECT 1;\n"
+    );
+}
+
+#[test]
+fn test_strip_preserves_crlf_and_missing_final_newline() {
+    assert_eq!(
+        strip_comments("# a\r\nb\r\nc", CommentSyntax::Hash),
+        "b\r\nc"
+    );
+}
@@ -0,0 +1,54 @@
+use tmpltool::suggest::{did_you_mean, edit_distance};
+
+#[test]
+fn test_edit_distance() {
+    assert_eq!(edit_distance("", ""), 0);
+    assert_eq!(edit_distance("abc", ""), 3);
+    assert_eq!(edit_distance("kitten", "sitting"), 3);
+    assert_eq!(edit_distance("uppr", "upper"), 1);
+    assert_eq!(edit_distance("héllo", "hello"), 1);
+}
+
+#[test]
+fn test_did_you_mean_picks_closest() {
+    let names = ["upper", "lower", "title"];
+    assert_eq!(did_you_mean("uppr", names), Some("upper".to_string()));
+    assert_eq!(did_you_mean("lowr", names), Some("lower".to_string()));
+}
+
+#[test]
+fn test_did_you_mean_ties_break_alphabetically() {
+    assert_eq!(did_you_mean("bat", ["cat", "hat"]), Some("cat".to_string()));
+}
+
+#[test]
+fn test_did_you_mean_ignores_distant_and_exact_names() {
+    assert_eq!(did_you_mean("xyz", ["upper", "lower"]), None);
+    assert_eq!(did_you_mean("upper", ["upper"]), None);
+}
+
+#[test]
+fn test_filter_and_test_names_are_registered() {
+    use minijinja::{Environment, ErrorKind};
+    use std::path::PathBuf;
+    use tmpltool::TemplateContext;
+    use tmpltool::suggest::{filter_names, test_names};
+
+    let mut env = Environment::new();
+    tmpltool::functions::register_all(&mut env, TemplateContext::new(PathBuf::from("."), false));
+    let state = env.empty_state();
+    for name in filter_names() {
+        assert!(
+            !matches!(state.apply_filter(name, &[]), Err(e) if e.kind() == ErrorKind::UnknownFilter),
+            "filter {} is not registered",
+            name
+        );
+    }
+    for name in test_names() {
+        assert!(
+            !matches!(state.perform_test(name, &[]), Err(e) if e.kind() == ErrorKind::UnknownTest),
+            "test {} is not registered",
+            name
+        );
+    }
+}
@@ -1,9 +1,12 @@
 use minijinja::Value;
 use minijinja::value::Kwargs;
-use tmpltool::functions::Function;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tmpltool::TemplateContext;
 use tmpltool::functions::system::{
-    GetArch, GetCwd, GetHomeDir, GetHostname, GetOs, GetTempDir, GetUsername,
+    GetArch, GetCwd, GetHomeDir, GetHostname, GetOs, GetTempDir, GetUsername, SystemInfo,
 };
+use tmpltool::functions::{ContextFunction, Function};
 
 #[test]
 fn test_get_hostname() {
@@ -119,3 +122,61 @@ fn test_get_cwd_exists() {
         cwd_str
     );
 }
+
+fn call_system_info(trust_mode: bool, field: Option<&str>) -> Result<Value, minijinja::Error> {
+    let context = Arc::new(TemplateContext::new(PathBuf::from("."), trust_mode));
+    let args: Vec<(&str, Value)> = field
+        .map(|f| ("field", Value::from(f)))
+        .into_iter()
+        .collect();
+    SystemInfo::call(context, Kwargs::from_iter(args))
+}
+
+#[test]
+fn test_system_info_requires_trust_mode() {
+    let result = call_system_info(false, Some("os"));
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("requires trust mode")
+    );
+}
+
+#[test]
+fn test_system_info_fields() {
+    assert_eq!(
+        call_system_info(true, Some("os")).unwrap().as_str(),
+        Some(std::env::consts::OS)
+    );
+    assert_eq!(
+        call_system_info(true, Some("arch")).unwrap().as_str(),
+        Some(std::env::consts::ARCH)
+    );
+    assert!(
+        !call_system_info(true, Some("hostname"))
+            .unwrap()
+            .as_str()
+            .unwrap()
+            .is_empty()
+    );
+    let cpus = call_system_info(true, Some("cpus"))
+        .unwrap()
+        .as_i64()
+        .unwrap();
+    assert!(cpus >= 1);
+}
+
+#[test]
+fn test_system_info_all_fields() {
+    let info = call_system_info(true, None).unwrap();
+    for field in ["hostname", "os", "arch", "cpus"] {
+        assert!(!info.get_attr(field).unwrap().is_undefined(), "{}", field);
+    }
+}
+
+#[test]
+fn test_system_info_invalid_field() {
+    let err = call_system_info(true, Some("memory")).unwrap_err();
+    assert!(err.to_string().contains("invalid field 'memory'"));
+}
@@ -0,0 +1,104 @@
+//! Tests for template_exists and has_macro
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+#[allow(deprecated)]
+fn tmpltool() -> Command {
+    Command::cargo_bin("tmpltool").unwrap()
+}
+
+fn render(dir: &TempDir, template: &str) -> Command {
+    let path = dir.path().join("main.tmpltool");
+    fs::write(&path, template).unwrap();
+    let mut cmd = tmpltool();
+    cmd.arg(&path);
+    cmd
+}
+
+fn fixture() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir(dir.path().join("partials")).unwrap();
+    fs::write(dir.path().join("partials/prod.tmpltool"), "prod settings").unwrap();
+    fs::write(
+        dir.path().join("helpers.tmpltool"),
+        "{% macro banner(text) %}== {{ text }} =={% endmacro %}{% set version = 2 %}",
+    )
+    .unwrap();
+    dir
+}
+
+#[test]
+fn test_template_exists_for_existing_file() {
+    let dir = fixture();
+    render(
+        &dir,
+        r#"{{ template_exists(name="partials/prod.tmpltool") }}"#,
+    )
+    .assert()
+    .success()
+    .stdout("true");
+}
+
+#[test]
+fn test_template_exists_for_missing_file() {
+    let dir = fixture();
+    render(
+        &dir,
+        r#"{{ template_exists(name="partials/dev.tmpltool") }}"#,
+    )
+    .assert()
+    .success()
+    .stdout("false");
+}
+
+#[test]
+fn test_template_exists_guards_optional_include() {
+    let dir = fixture();
+    let template = r#"{% for env in ["dev", "prod"] %}{% set p = "partials/" ~ env ~ ".tmpltool" %}{% if template_exists(name=p) %}{% include p %}{% else %}none{% endif %};{% endfor %}"#;
+    render(&dir, template)
+        .assert()
+        .success()
+        .stdout("none;prod settings;");
+}
+
+#[test]
+fn test_template_exists_uses_include_path() {
+    let dir = fixture();
+    let shared = TempDir::new().unwrap();
+    fs::write(shared.path().join("shared.tmpltool"), "x").unwrap();
+    render(&dir, r#"{{ template_exists(name="shared.tmpltool") }}"#)
+        .arg("--include-path")
+        .arg(shared.path())
+        .assert()
+        .success()
+        .stdout("true");
+}
+
+#[test]
+fn test_template_exists_still_enforces_security() {
+    let dir = fixture();
+    render(&dir, r#"{{ template_exists(name="../outside.tmpltool") }}"#)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Parent directory (..) traversal"));
+}
+
+#[test]
+fn test_has_macro() {
+    let dir = fixture();
+    let template = r#"{{ has_macro(template="helpers.tmpltool", name="banner") }} {{ has_macro(template="helpers.tmpltool", name="footer") }} {{ has_macro(template="helpers.tmpltool", name="version") }} {{ has_macro(template="missing.tmpltool", name="banner") }}"#;
+    render(&dir, template)
+        .assert()
+        .success()
+        .stdout("true false false false");
+}
+
+#[test]
+fn test_has_macro_guards_import() {
+    let dir = fixture();
+    let template = r#"{% if has_macro(template="helpers.tmpltool", name="banner") %}{% from "helpers.tmpltool" import banner %}{{ banner("hi") }}{% endif %}"#;
+    render(&dir, template).assert().success().stdout("== hi ==");
+}
@@ -124,3 +124,82 @@ fn test_uuid_invalid_version_v1() {
     let err = result.unwrap_err().to_string();
     assert!(err.contains("Invalid UUID version"));
 }
+
+// ==================== stable_id Tests ====================
+
+#[test]
+fn test_stable_id_deterministic() {
+    let first = render_template(r#"{{ stable_id(inputs=["app", "prod"]) }}"#);
+    let second = render_template(r#"{{ stable_id(inputs=["app", "prod"]) }}"#);
+    assert_eq!(first, second);
+    assert!(is_valid_uuid_format(&first));
+    // Version should be 5 (14th character)
+    assert_eq!(first.chars().nth(14).unwrap(), '5');
+}
+
+#[test]
+fn test_stable_id_known_v5_value() {
+    // RFC 4122 DNS namespace with name "python.org"
+    let result = render_template(
+        r#"{{ stable_id(inputs=["python.org"], namespace="6ba7b810-9dad-11d1-80b4-00c04fd430c8") }}"#,
+    );
+    assert_eq!(result, "886313e1-3b8a-5372-9b90-0c9aee199e5d");
+}
+
+#[test]
+fn test_stable_id_inputs_are_separated() {
+    let a = render_template(r#"{{ stable_id(inputs=["a", "bc"]) }}"#);
+    let b = render_template(r#"{{ stable_id(inputs=["ab", "c"]) }}"#);
+    assert_ne!(a, b);
+}
+
+#[test]
+fn test_stable_id_namespace_changes_result() {
+    let a = render_template(r#"{{ stable_id(inputs=["x"], namespace="one") }}"#);
+    let b = render_template(r#"{{ stable_id(inputs=["x"], namespace="two") }}"#);
+    assert_ne!(a, b);
+}
+
+#[test]
+fn test_stable_id_short_format() {
+    let result = render_template(r#"{{ stable_id(inputs=["app", 1], format="short") }}"#);
+    assert_eq!(result.len(), 8);
+    assert!(result.chars().all(|c| c.is_ascii_hexdigit()));
+
+    let longer =
+        render_template(r#"{{ stable_id(inputs=["app", 1], format="short", length=12) }}"#);
+    assert!(longer.starts_with(&result));
+}
+
+#[test]
+fn test_stable_id_invalid_format() {
+    let mut env = Environment::new();
+    let ctx = TemplateContext::new(PathBuf::from("."), false);
+    register_all(&mut env, ctx);
+
+    let tmpl = env
+        .template_from_str(r#"{{ stable_id(inputs=["a"], format="long") }}"#)
+        .unwrap();
+    let result = tmpl.render(());
+    assert!(result.is_err());
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid stable_id format")
+    );
+}
+
+#[test]
+fn test_stable_id_invalid_length() {
+    let mut env = Environment::new();
+    let ctx = TemplateContext::new(PathBuf::from("."), false);
+    register_all(&mut env, ctx);
+
+    let tmpl = env
+        .template_from_str(r#"{{ stable_id(inputs=["a"], format="short", length=65) }}"#)
+        .unwrap();
+    let result = tmpl.render(());
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("between 1 and 64"));
+}
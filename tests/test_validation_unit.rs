@@ -1,11 +1,11 @@
-//! Unit tests for validation functions (matches_regex)
+//! Unit tests for validation functions (matches_regex, check digits)
 //!
 //! Note: is_email, is_url, is_ip, and is_uuid have been migrated to
 //! src/is_functions/validation.rs and are tested in tests/test_is_validation.rs
 
 use minijinja::value::Kwargs;
 use tmpltool::functions::Function;
-use tmpltool::functions::validation::MatchesRegex;
+use tmpltool::functions::validation::{EanValid, IsbnValid, Luhn, LuhnValid, MatchesRegex};
 
 // Helper to create kwargs for testing
 fn create_kwargs(args: Vec<(&str, &str)>) -> Kwargs {
@@ -108,3 +108,102 @@ fn test_matches_regex_missing_string() {
     let result = MatchesRegex::call(kwargs);
     assert!(result.is_err());
 }
+
+// ========== check digit tests ==========
+
+#[test]
+fn test_luhn_check_digit() {
+    let kwargs = create_kwargs(vec![("number", "7992739871")]);
+
+    let result = Luhn::call(kwargs).unwrap();
+    assert_eq!(result, minijinja::Value::from(3));
+}
+
+#[test]
+fn test_luhn_check_digit_from_integer() {
+    let kwargs = Kwargs::from_iter([("number", minijinja::Value::from(411111111111111_i64))]);
+
+    let result = Luhn::call(kwargs).unwrap();
+    assert_eq!(result, minijinja::Value::from(1));
+}
+
+#[test]
+fn test_luhn_check_digit_zero() {
+    let kwargs = create_kwargs(vec![("number", "0")]);
+
+    let result = Luhn::call(kwargs).unwrap();
+    assert_eq!(result, minijinja::Value::from(0));
+}
+
+#[test]
+fn test_luhn_not_a_number() {
+    let kwargs = create_kwargs(vec![("number", "12a4")]);
+
+    let result = Luhn::call(kwargs);
+    assert!(result.unwrap_err().to_string().contains("is not a number"));
+}
+
+#[test]
+fn test_luhn_valid() {
+    for number in ["79927398713", "4111 1111 1111 1111", "4111-1111-1111-1111"] {
+        let kwargs = create_kwargs(vec![("number", number)]);
+        assert!(LuhnValid::call(kwargs).unwrap().is_true(), "{}", number);
+    }
+}
+
+#[test]
+fn test_luhn_invalid() {
+    for number in ["79927398710", "4111111111111112", "abc", "", "7"] {
+        let kwargs = create_kwargs(vec![("number", number)]);
+        assert!(!LuhnValid::call(kwargs).unwrap().is_true(), "{}", number);
+    }
+}
+
+#[test]
+fn test_isbn_valid() {
+    for number in [
+        "978-0-306-40615-7",
+        "9780306406157",
+        "0-306-40615-2",
+        "0-8044-2957-X",
+        "080442957x",
+    ] {
+        let kwargs = create_kwargs(vec![("number", number)]);
+        assert!(IsbnValid::call(kwargs).unwrap().is_true(), "{}", number);
+    }
+}
+
+#[test]
+fn test_isbn_invalid() {
+    for number in [
+        "978-0-306-40615-8",
+        "0-306-40615-3",
+        "X-306-40615-2",
+        "4006381333931",
+        "12345",
+    ] {
+        let kwargs = create_kwargs(vec![("number", number)]);
+        assert!(!IsbnValid::call(kwargs).unwrap().is_true(), "{}", number);
+    }
+}
+
+#[test]
+fn test_ean_valid() {
+    for number in [
+        "4006381333931",
+        "96385074",
+        "036000291452",
+        "10614141000415",
+    ] {
+        let kwargs = create_kwargs(vec![("number", number)]);
+        assert!(EanValid::call(kwargs).unwrap().is_true(), "{}", number);
+    }
+}
+
+#[test]
+fn test_ean_invalid() {
+    for number in ["4006381333932", "96385075", "123", "40063813339a1"] {
+        let kwargs = create_kwargs(vec![("number", number)]);
+        assert!(!EanValid::call(kwargs).unwrap().is_true(), "{}", number);
+    }
+}
@@ -1,5 +1,8 @@
+use minijinja::Environment;
+use std::path::PathBuf;
 use tmpltool::cli::ValidateFormat;
-use tmpltool::validator::validate_output;
+use tmpltool::validator::{lint_template, validate_output};
+use tmpltool::{TemplateContext, functions};
 
 // ============================================================================
 // JSON Validation Tests
@@ -154,3 +157,59 @@ fn test_validate_output_toml() {
     let toml = r#"test = true"#;
     assert!(validate_output(toml, ValidateFormat::Toml).is_ok());
 }
+
+// ============================================================================
+// Template Linting Tests
+// ============================================================================
+
+fn lint(source: &str) -> Result<(), String> {
+    let mut env = Environment::new();
+    functions::register_all(&mut env, TemplateContext::new(PathBuf::from("."), false));
+    lint_template(&env, "t", source)
+}
+
+#[test]
+fn test_lint_valid_template() {
+    let source = "{% for x in [1] %}{% if x is even %}{{ x | upper }}{% endif %}{% endfor %}\n\
+                  {% macro m() %}{% endmacro %}{{ m() }}{% set a = 1 %}{% set b %}x{% endset %}";
+    assert!(lint(source).is_ok());
+}
+
+#[test]
+#[cfg(feature = "strict-parse")]
+fn test_lint_reports_unclosed_blocks_where_opened() {
+    let err = lint("{% for x in items %}\n  {% if x %}\n{% endfor %}\n{% endwith %}").unwrap_err();
+    assert!(err.contains("2 issue(s)"), "{}", err);
+    assert!(
+        err.contains("t:2:6: unclosed {% if %} (missing {% endif %})"),
+        "{}",
+        err
+    );
+    assert!(err.contains("t:4:4: unexpected {% endwith %}"), "{}", err);
+
+    let err = lint("{% block body %}\n{% set x %}").unwrap_err();
+    assert!(err.contains("t:1:4: unclosed {% block %}"), "{}", err);
+    assert!(err.contains("t:2:4: unclosed {% set %}"), "{}", err);
+}
+
+#[test]
+#[cfg(feature = "strict-parse")]
+fn test_lint_collects_unknown_names_with_columns() {
+    let err = lint("{{ x | uppr }}\n{{ 2 is evn }} {{ no_macro() }}").unwrap_err();
+    assert!(err.contains("3 issue(s)"), "{}", err);
+    assert!(err.contains("t:1:8: unknown filter 'uppr'"), "{}", err);
+    assert!(err.contains("t:2:9: unknown test 'evn'"), "{}", err);
+    assert!(err.contains("unknown function 'no_macro'"), "{}", err);
+}
+
+#[test]
+fn test_lint_reports_syntax_errors() {
+    let err = lint("ok\n{{ 1 + }}").unwrap_err();
+    assert!(err.contains("t:2:"), "{}", err);
+    assert!(err.contains("syntax error"), "{}", err);
+}
+
+#[test]
+fn test_validate_output_ignores_template_format() {
+    assert!(validate_output("not json", ValidateFormat::Template).is_ok());
+}